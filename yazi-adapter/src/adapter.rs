@@ -2,7 +2,7 @@ use std::{env, fmt::Display, path::Path, sync::Arc};
 
 use anyhow::Result;
 use ratatui::layout::Rect;
-use tracing::warn;
+use tracing::{info, warn};
 use yazi_shared::env_exists;
 
 use super::{Iterm2, Kitty, KittyOld};
@@ -82,7 +82,9 @@ impl Adapter {
 
 impl Adapter {
 	pub fn matches() -> Self {
-		let mut protocols = Emulator::detect().adapters();
+		let emulator = Emulator::detect();
+		let mut protocols = emulator.clone().adapters();
+		info!(?emulator, ?protocols, tmux = *TMUX, "[Adapter] Detected the emulator");
 
 		#[cfg(windows)]
 		protocols.retain(|p| *p == Self::Iterm2);
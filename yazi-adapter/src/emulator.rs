@@ -4,7 +4,7 @@ use anyhow::{anyhow, bail, Result};
 use crossterm::{cursor::{RestorePosition, SavePosition}, execute, style::Print, terminal::{disable_raw_mode, enable_raw_mode}};
 use scopeguard::defer;
 use tokio::{io::{AsyncReadExt, BufReader}, time::timeout};
-use tracing::{error, warn};
+use tracing::{debug, error, warn};
 use yazi_shared::env_exists;
 
 use crate::{Adapter, CLOSE, ESCAPE, START, TMUX};
@@ -131,6 +131,7 @@ impl Emulator {
 		)?;
 
 		let resp = futures::executor::block_on(Self::read_until_da1());
+		debug!(?resp, "[Adapter] Response to the CSI queries");
 		let names = [
 			("kitty", Self::Kitty),
 			("Konsole", Self::Konsole),
@@ -20,7 +20,7 @@ impl Image {
 		})
 		.await??;
 
-		let (mut w, mut h) = (PREVIEW.max_width, PREVIEW.max_height);
+		let (mut w, mut h) = (PREVIEW.load().max_width, PREVIEW.load().max_height);
 		if (5..=8).contains(&orientation) {
 			(w, h) = (h, w);
 		}
@@ -36,7 +36,7 @@ impl Image {
 			}
 
 			let mut buf = Vec::new();
-			JpegEncoder::new_with_quality(&mut buf, PREVIEW.image_quality).encode(
+			JpegEncoder::new_with_quality(&mut buf, PREVIEW.load().image_quality).encode(
 				img.as_bytes(),
 				img.width(),
 				img.height(),
@@ -77,13 +77,25 @@ impl Image {
 		.await?
 	}
 
+	// The full image turned as its EXIF orientation says, for the transformations of it
+	pub async fn upright(path: &Path) -> Result<DynamicImage> {
+		let orientation = Self::orientation(path).await?;
+
+		let path = path.to_owned();
+		tokio::task::spawn_blocking(move || {
+			let img = Self::set_limits(image::ImageReader::open(path)?.with_guessed_format()?).decode()?;
+			Ok(Self::rotate(img, orientation))
+		})
+		.await?
+	}
+
 	pub(super) fn max_pixel(rect: Rect) -> (u32, u32) {
 		Dimension::ratio()
 			.map(|(r1, r2)| {
 				let (w, h) = ((rect.width as f64 * r1) as u32, (rect.height as f64 * r2) as u32);
-				(w.min(PREVIEW.max_width), h.min(PREVIEW.max_height))
+				(w.min(PREVIEW.load().max_width), h.min(PREVIEW.load().max_height))
 			})
-			.unwrap_or((PREVIEW.max_width, PREVIEW.max_height))
+			.unwrap_or((PREVIEW.load().max_width, PREVIEW.load().max_height))
 	}
 
 	pub(super) fn pixel_area(size: (u32, u32), rect: Rect) -> Rect {
@@ -99,7 +111,7 @@ impl Image {
 
 	#[inline]
 	fn filter() -> FilterType {
-		match PREVIEW.image_filter.as_str() {
+		match PREVIEW.load().image_filter.as_str() {
 			"nearest" => FilterType::Nearest,
 			"triangle" => FilterType::Triangle,
 			"catmull-rom" => FilterType::CatmullRom,
@@ -112,7 +124,7 @@ impl Image {
 	async fn orientation(path: &Path) -> Result<u8> {
 		// We don't want to read the orientation of the cached image that has been
 		// rotated in the `Self::precache()` step.
-		if path.parent() == Some(&PREVIEW.cache_dir) {
+		if path.parent() == Some(&PREVIEW.load().cache_dir) {
 			return Ok(0);
 		}
 
@@ -157,14 +169,14 @@ impl Image {
 
 	fn set_limits(mut r: image::ImageReader<BufReader<File>>) -> image::ImageReader<BufReader<File>> {
 		let mut limits = Limits::no_limits();
-		if TASKS.image_alloc > 0 {
-			limits.max_alloc = Some(TASKS.image_alloc as u64);
+		if TASKS.load().image_alloc > 0 {
+			limits.max_alloc = Some(TASKS.load().image_alloc as u64);
 		}
-		if TASKS.image_bound[0] > 0 {
-			limits.max_image_width = Some(TASKS.image_bound[0] as u32);
+		if TASKS.load().image_bound[0] > 0 {
+			limits.max_image_width = Some(TASKS.load().image_bound[0] as u32);
 		}
-		if TASKS.image_bound[1] > 0 {
-			limits.max_image_height = Some(TASKS.image_bound[1] as u32);
+		if TASKS.load().image_bound[1] > 0 {
+			limits.max_image_height = Some(TASKS.load().image_bound[1] as u32);
 		}
 		r.limits(limits);
 		r
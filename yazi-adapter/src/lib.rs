@@ -19,6 +19,7 @@ use iterm2::*;
 use kitty::*;
 use kitty_old::*;
 use sixel::*;
+use tracing::info;
 use ueberzug::*;
 use yazi_shared::{env_exists, RoCell};
 
@@ -53,5 +54,6 @@ pub fn init() {
 	SHOWN.with(<_>::default);
 
 	ADAPTOR.init(Adapter::matches());
+	info!(adapter = %*ADAPTOR, "[Adapter] Chosen");
 	ADAPTOR.start();
 }
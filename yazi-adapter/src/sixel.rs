@@ -44,7 +44,7 @@ impl Sixel {
 
 		tokio::task::spawn_blocking(move || {
 			let img = img.into_rgba8();
-			let nq = NeuQuant::new(PREVIEW.sixel_fraction as i32, 256 - alpha as usize, &img);
+			let nq = NeuQuant::new(PREVIEW.load().sixel_fraction as i32, 256 - alpha as usize, &img);
 
 			let mut buf: Vec<u8> = Vec::with_capacity(1 << 16);
 			write!(buf, "{}P0;1;8q\"1;1;{};{}", START, img.width(), img.height())?;
@@ -54,8 +54,8 @@ impl Ueberzug {
 			.map(|(r1, r2)| Rect {
 				x:      max.x,
 				y:      max.y,
-				width:  max.width.min((w.min(PREVIEW.max_width as _) as f64 / r1).ceil() as _),
-				height: max.height.min((h.min(PREVIEW.max_height as _) as f64 / r2).ceil() as _),
+				width:  max.width.min((w.min(PREVIEW.load().max_width as _) as f64 / r1).ceil() as _),
+				height: max.height.min((h.min(PREVIEW.load().max_height as _) as f64 / r2).ceil() as _),
 			})
 			.unwrap_or(max);
 
@@ -90,8 +90,8 @@ impl Ueberzug {
 	}
 
 	fn adjust_rect(mut rect: Rect) -> Rect {
-		let scale = PREVIEW.ueberzug_scale;
-		let (x, y, w, h) = PREVIEW.ueberzug_offset;
+		let scale = PREVIEW.load().ueberzug_scale;
+		let (x, y, w, h) = PREVIEW.load().ueberzug_offset;
 
 		rect.x = 0f32.max(rect.x as f32 * scale + x) as u16;
 		rect.y = 0f32.max(rect.y as f32 * scale + y) as u16;
@@ -46,15 +46,17 @@ impl Actions {
 		writeln!(s, "    EDITOR             : {:?}", env::var_os("EDITOR"))?;
 		writeln!(s, "    YAZI_FILE_ONE      : {:?}", env::var_os("YAZI_FILE_ONE"))?;
 		writeln!(s, "    YAZI_CONFIG_HOME   : {:?}", env::var_os("YAZI_CONFIG_HOME"))?;
+		writeln!(s, "    YAZI_LOG           : {:?}", env::var_os("YAZI_LOG"))?;
 		writeln!(s, "    ZELLIJ_SESSION_NAME: {:?}", env::var_os("ZELLIJ_SESSION_NAME"))?;
 
+		let open = yazi_config::OPEN.load();
 		writeln!(s, "\nText Opener")?;
 		writeln!(
 			s,
 			"    default: {:?}",
-			yazi_config::OPEN.openers("f75a.txt", "text/plain").and_then(|a| a.first().cloned())
+			open.openers("f75a.txt", "text/plain").and_then(|a| a.first().cloned())
 		)?;
-		writeln!(s, "    block  : {:?}", yazi_config::OPEN.block_opener("bulk.txt", "text/plain"))?;
+		writeln!(s, "    block  : {:?}", open.block_opener("bulk.txt", "text/plain"))?;
 
 		writeln!(s, "\ntmux")?;
 		writeln!(s, "    TMUX   : {:?}", *yazi_adapter::TMUX)?;
@@ -81,7 +83,7 @@ impl Actions {
 		writeln!(s, "\n\n--------------------------------------------------")?;
 		writeln!(
 			s,
-			"When reporting a bug, please also upload the `yazi.log` log file - only upload the most recent content by time."
+			"When reporting a bug, please also upload the latest `yazi.<date>.log` log file - only upload the most recent content by time."
 		)?;
 		writeln!(s, "You can find it in the {:?} directory.", Xdg::state_dir())?;
 
@@ -11,10 +11,19 @@ pub struct Args {
 
 	/// Write the cwd on exit to this file
 	#[arg(long)]
-	pub cwd_file:     Option<PathBuf>,
-	/// Write the selected files to this file on open fired
+	pub cwd_file:        Option<PathBuf>,
+	/// Write the selected files to this file on open fired, and exit with 1 if quit without
 	#[arg(long)]
-	pub chooser_file: Option<PathBuf>,
+	pub chooser_file:    Option<PathBuf>,
+	/// Choose directories instead, or the cwd if the hovered one is a file
+	#[arg(long, requires = "chooser_file")]
+	pub choose_dir:      bool,
+	/// Choose at most this number of files
+	#[arg(long, requires = "chooser_file")]
+	pub choose_multiple: Option<usize>,
+	/// Only the files matching this glob can be chosen, can be repeated
+	#[arg(long, requires = "chooser_file")]
+	pub choose_filter:   Vec<String>,
 
 	/// Clear the cache directory
 	#[arg(long)]
@@ -30,9 +39,19 @@ pub struct Args {
 	#[arg(long)]
 	pub remote_events: Option<String>,
 
+	/// Run the core without the terminal UI
+	#[arg(long)]
+	pub headless: bool,
+	/// Run the Lua script in headless mode, and exit once it finishes
+	#[arg(long, requires = "headless")]
+	pub script:   Option<PathBuf>,
+
 	/// Print debug information
 	#[arg(long)]
-	pub debug: bool,
+	pub debug:      bool,
+	/// Record the timings of startup, rendering and previews, and show them in an overlay
+	#[arg(long)]
+	pub debug_perf: bool,
 
 	/// Print version
 	#[arg(short = 'V', long)]
@@ -3,6 +3,8 @@ use std::{collections::HashSet, ffi::OsString, path::{Path, PathBuf}};
 use serde::Serialize;
 use yazi_shared::{fs::{current_cwd, expand_path}, Xdg};
 
+use crate::Chooser;
+
 #[derive(Debug, Default, Serialize)]
 pub struct Boot {
 	pub cwd:  PathBuf,
@@ -11,6 +13,8 @@ pub struct Boot {
 	pub local_events:  HashSet<String>,
 	pub remote_events: HashSet<String>,
 
+	pub chooser: Option<Chooser>,
+
 	pub config_dir: PathBuf,
 	pub flavor_dir: PathBuf,
 	pub plugin_dir: PathBuf,
@@ -56,6 +60,8 @@ impl From<&crate::Args> for Boot {
 			local_events,
 			remote_events,
 
+			chooser: Chooser::new(args),
+
 			flavor_dir: config_dir.join("flavors"),
 			plugin_dir: config_dir.join("plugins"),
 			config_dir,
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use yazi_config::Pattern;
+
+use crate::Args;
+
+#[derive(Debug, Default, Serialize)]
+pub struct Chooser {
+	pub dir:      bool,
+	pub multiple: Option<usize>,
+	pub filter:   Vec<String>,
+
+	#[serde(skip)]
+	patterns: Vec<Pattern>,
+}
+
+impl Chooser {
+	pub(super) fn new(args: &Args) -> Option<Self> {
+		args.chooser_file.as_ref()?;
+
+		let mut patterns = Vec::with_capacity(args.choose_filter.len());
+		for s in &args.choose_filter {
+			match Pattern::try_from(s.as_str()) {
+				Ok(p) => patterns.push(p),
+				Err(e) => {
+					eprintln!("Invalid `--choose-filter` pattern `{s}`: {e}");
+					std::process::exit(2);
+				}
+			}
+		}
+
+		Some(Self {
+			dir: args.choose_dir,
+			multiple: args.choose_multiple,
+			filter: args.choose_filter.clone(),
+			patterns,
+		})
+	}
+
+	// Directories can still be chosen along with the files as before, unless there's a filter
+	pub fn accepts(&self, path: &std::path::Path, is_dir: bool) -> bool {
+		if self.dir {
+			is_dir
+		} else if is_dir {
+			self.patterns.is_empty()
+		} else {
+			self.patterns.is_empty() || self.patterns.iter().any(|p| p.match_path(path, false))
+		}
+	}
+}
@@ -1,12 +1,14 @@
 use clap::Parser;
-use yazi_shared::RoCell;
+use yazi_shared::{RoCell, PERF};
 
 mod actions;
 mod args;
 mod boot;
+mod chooser;
 
 pub use args::*;
 pub use boot::*;
+pub use chooser::*;
 
 pub static ARGS: RoCell<Args> = RoCell::new();
 pub static BOOT: RoCell<Boot> = RoCell::new();
@@ -14,6 +16,9 @@ pub static BOOT: RoCell<Boot> = RoCell::new();
 pub fn init() {
 	ARGS.with(<_>::parse);
 	BOOT.init(From::from(&*ARGS));
+	if ARGS.debug_perf {
+		PERF.enable();
+	}
 
 	actions::Actions::act(&ARGS);
 }
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::PathBuf};
 
 use anyhow::{bail, Result};
 use clap::{command, Parser, Subcommand};
@@ -24,6 +24,15 @@ pub(super) enum Command {
 	Sub(CommandSub),
 	/// Manage packages.
 	Pack(CommandPack),
+	/// Manage flavors.
+	#[command(subcommand)]
+	Flavor(CommandFlavor),
+	/// Check the config files for shadowed keys, unknown commands, and unreachable open rules.
+	CheckConfig,
+	/// Generate Lua type annotations for the plugin API.
+	EmitLuaTypes(CommandEmitLuaTypes),
+	/// Serve the XDG Desktop Portal FileChooser, picking the files with Yazi in a terminal.
+	Portal(CommandPortal),
 }
 
 #[derive(clap::Args)]
@@ -76,12 +85,18 @@ pub(super) struct CommandPubTo {
 pub(super) struct CommandSub {
 	/// The kind of messages to subscribe to, separated by commas if multiple.
 	#[arg(index = 1)]
-	pub(super) kinds: String,
+	pub(super) kinds:  String,
+	/// Replay the last N messages of each kind retained by the server, e.g. the current `cd`.
+	#[arg(long, default_value_t = 0)]
+	pub(super) replay: usize,
 }
 
 #[derive(clap::Args)]
 #[command(arg_required_else_help = true)]
 pub(super) struct CommandPack {
+	#[command(subcommand)]
+	pub(super) command: Option<CommandPackSub>,
+
 	/// Add a package.
 	#[arg(short = 'a', long)]
 	pub(super) add:     Option<String>,
@@ -96,6 +111,54 @@ pub(super) struct CommandPack {
 	pub(super) upgrade: bool,
 }
 
+#[derive(Subcommand)]
+pub(super) enum CommandPackSub {
+	/// Search the package index.
+	Search {
+		/// Match against the names and descriptions of packages.
+		#[arg(index = 1)]
+		query: String,
+	},
+	/// Show the details of a package in the index.
+	Info {
+		/// The name of the package.
+		#[arg(index = 1)]
+		name: String,
+	},
+}
+
+#[derive(Subcommand)]
+pub(super) enum CommandFlavor {
+	/// List all installed flavors.
+	List,
+	/// Apply a flavor to the running instances temporarily.
+	Preview {
+		/// The name of the flavor.
+		#[arg(index = 1)]
+		name: String,
+	},
+	/// Use a flavor in theme.toml, and apply it to the running instances.
+	Use {
+		/// The name of the flavor.
+		#[arg(index = 1)]
+		name: String,
+	},
+}
+
+#[derive(clap::Args)]
+pub(super) struct CommandEmitLuaTypes {
+	/// Write the annotations to this file instead of stdout.
+	#[arg(short = 'o', long)]
+	pub(super) output: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+pub(super) struct CommandPortal {
+	/// The terminal to run Yazi in, followed by the Yazi command line. It must wait for Yazi to exit.
+	#[arg(long, default_value = "xterm -e")]
+	pub(super) terminal: String,
+}
+
 // --- Macros
 macro_rules! impl_body {
 	($name:ident) => {
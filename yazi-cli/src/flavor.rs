@@ -0,0 +1,58 @@
+use anyhow::{bail, Context, Result};
+use tokio::fs;
+use toml_edit::DocumentMut;
+use yazi_config::theme;
+use yazi_shared::Xdg;
+
+pub(super) struct Flavor;
+
+impl Flavor {
+	pub(super) fn list() -> Result<()> {
+		let current = std::fs::read_to_string(Xdg::config_dir().join("theme.toml"))
+			.ok()
+			.and_then(|s| theme::Flavor::parse_use(&s))
+			.unwrap_or_default();
+
+		println!("flavors:");
+		for name in theme::Flavor::list() {
+			let mark = if name == current { " (current)" } else { "" };
+			println!("\t{name}{mark}");
+		}
+		Ok(())
+	}
+
+	pub(super) async fn preview(name: &str) -> Result<()> {
+		Self::ensure_exists(name).await?;
+
+		// Broadcast to all the instances, unless run inside one of them
+		let receiver = std::env::var("YAZI_ID").ok().and_then(|s| s.parse().ok()).unwrap_or(0);
+		yazi_dds::Client::shot("dds-flavor", receiver, &serde_json::to_string(name)?)
+			.await
+			.context("Cannot send message to the running instances")
+	}
+
+	pub(super) async fn use_(name: &str) -> Result<()> {
+		Self::ensure_exists(name).await?;
+
+		let path = Xdg::config_dir().join("theme.toml");
+		let mut doc = fs::read_to_string(&path)
+			.await
+			.unwrap_or_default()
+			.parse::<DocumentMut>()
+			.context("Failed to parse theme.toml")?;
+
+		doc.entry("flavor").or_insert(toml_edit::table())["use"] = toml_edit::value(name);
+		fs::write(&path, doc.to_string()).await.context("Failed to write theme.toml")?;
+
+		Self::preview(name).await.ok();
+		Ok(())
+	}
+
+	async fn ensure_exists(name: &str) -> Result<()> {
+		let p = Xdg::config_dir().join(format!("flavors/{name}.yazi/flavor.toml"));
+		if fs::metadata(&p).await.is_err() {
+			bail!("Flavor `{name}` not found in {p:?}");
+		}
+		Ok(())
+	}
+}
@@ -1,5 +1,8 @@
 mod args;
+mod flavor;
 mod package;
+#[cfg(all(unix, not(target_os = "macos")))]
+mod portal;
 
 use args::*;
 use clap::Parser;
@@ -38,14 +41,19 @@ async fn main() -> anyhow::Result<()> {
 		Command::Sub(cmd) => {
 			yazi_boot::init_default();
 			yazi_dds::init();
-			yazi_dds::Client::draw(cmd.kinds.split(',').collect()).await?;
+			yazi_dds::Client::draw(cmd.kinds.split(',').collect(), cmd.replay).await?;
 
 			tokio::signal::ctrl_c().await?;
 		}
 
 		Command::Pack(cmd) => {
 			package::init();
-			if cmd.install {
+			if let Some(sub) = cmd.command {
+				match sub {
+					CommandPackSub::Search { query } => package::Package::search(&query).await?,
+					CommandPackSub::Info { name } => package::Package::info(&name).await?,
+				}
+			} else if cmd.install {
 				package::Package::install_from_config("plugin", false).await?;
 				package::Package::install_from_config("flavor", false).await?;
 			} else if cmd.list {
@@ -58,6 +66,60 @@ async fn main() -> anyhow::Result<()> {
 				package::Package::add_to_config(repo).await?;
 			}
 		}
+
+		Command::Flavor(cmd) => {
+			yazi_boot::init_default();
+			yazi_dds::init();
+			match cmd {
+				CommandFlavor::List => flavor::Flavor::list()?,
+				CommandFlavor::Preview { name } => flavor::Flavor::preview(&name).await?,
+				CommandFlavor::Use { name } => flavor::Flavor::use_(&name).await?,
+			}
+		}
+
+		Command::CheckConfig => {
+			yazi_shared::init();
+			yazi_config::init()?;
+			yazi_boot::init_default();
+			if let Err(e) = yazi_plugin::init() {
+				eprintln!("Cannot load init.lua, commands registered by plugins are unknown: {e}");
+			}
+
+			let diagnostics = yazi_config::Checker::check(|s| yazi_plugin::COMMANDS.get(s).is_some());
+			for d in &diagnostics {
+				println!("{d}");
+			}
+			if !diagnostics.is_empty() {
+				eprintln!("\n{} problem(s) found", diagnostics.len());
+				std::process::exit(1);
+			}
+			println!("No problems found");
+		}
+
+		Command::EmitLuaTypes(cmd) => {
+			yazi_shared::init();
+			yazi_config::init()?;
+			yazi_boot::init_default();
+			yazi_plugin::init()?;
+
+			let types = yazi_plugin::Types::emit()?;
+			match cmd.output {
+				Some(p) => std::fs::write(p, types)?,
+				None => print!("{types}"),
+			}
+		}
+
+		Command::Portal(cmd) => {
+			#[cfg(all(unix, not(target_os = "macos")))]
+			portal::Portal::serve(cmd.terminal).await?;
+
+			#[cfg(not(all(unix, not(target_os = "macos"))))]
+			{
+				_ = cmd;
+				eprintln!("The XDG Desktop Portal is only available on Linux and BSDs");
+				std::process::exit(1);
+			}
+		}
 	}
 
 	Ok(())
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use semver::{Version, VersionReq};
+use tokio::fs;
+use toml_edit::{DocumentMut, InlineTable, Value};
+use yazi_shared::fs::must_exists;
+
+use super::{Git, Package};
+
+impl Package {
+	// Resolves the dependencies declared in the `manifest.toml` of each plugin recursively,
+	// and locks them in `package.toml` with `dependency = true`, next to the user's own ones.
+	pub(super) async fn install_deps(doc: &mut DocumentMut, upgrade: bool) -> Result<()> {
+		let deps = doc["plugin"]["deps"].as_array_mut().context("`deps` must be an array")?;
+
+		let (mut roots, mut locked) = (vec![], HashMap::new());
+		for dep in deps.iter() {
+			let Some(t) = dep.as_inline_table() else { continue };
+			let use_ = t.get("use").and_then(|d| d.as_str()).context("Missing `use` field")?;
+			let commit = t.get("commit").and_then(|d| d.as_str());
+
+			let package = Package::new(use_, commit);
+			let Some(name) = package.name().map(ToOwned::to_owned) else { continue };
+			if t.get("dependency").and_then(|d| d.as_bool()) == Some(true) {
+				locked.insert(name, package);
+			} else {
+				roots.push((name, package));
+			}
+		}
+
+		let mut resolved: HashMap<String, Option<Version>> = HashMap::new();
+		let mut queue = vec![];
+		for (name, package) in roots {
+			resolved.insert(name.clone(), package.version().await?);
+			queue.extend(package.dependencies().await?.into_iter().map(|(u, r)| (name.clone(), u, r)));
+		}
+
+		let mut installed = vec![];
+		while let Some((from, use_, req)) = queue.pop() {
+			let mut package = Package::new(&use_, None);
+			let Some(name) = package.name().map(ToOwned::to_owned) else {
+				bail!("Invalid dependency `{use_}` of `{from}`");
+			};
+
+			if let Some(version) = resolved.get(&name) {
+				Self::ensure_satisfied(&from, &name, &req, version.as_ref())?;
+				continue;
+			}
+
+			match locked.remove(&name) {
+				Some(mut p) if !upgrade && !p.commit.is_empty() => {
+					p.install().await?;
+					package = p;
+				}
+				_ => package.install_matching(&req).await?,
+			}
+
+			let version = package.version().await?;
+			Self::ensure_satisfied(&from, &name, &req, version.as_ref())?;
+
+			resolved.insert(name.clone(), version);
+			queue.extend(package.dependencies().await?.into_iter().map(|(u, r)| (name.clone(), u, r)));
+			installed.push(package);
+		}
+
+		deps.retain(|d| {
+			d.as_inline_table().and_then(|t| t.get("dependency")).and_then(|d| d.as_bool()) != Some(true)
+		});
+		for package in installed {
+			let mut table = InlineTable::new();
+			table.insert("use", package.use_().as_ref().into());
+			table.insert("commit", package.commit.into());
+			table.insert("dependency", true.into());
+			deps.push(table);
+		}
+
+		Ok(())
+	}
+
+	async fn install_matching(&mut self, req: &VersionReq) -> Result<()> {
+		self.header("Resolving dependency `{name}`")?;
+
+		let path = self.local();
+		if !must_exists(&path).await {
+			Git::clone(&self.remote(), &path).await?;
+		} else {
+			Git::fetch(&path).await?;
+		}
+
+		let tag = Git::tags(&path)
+			.await?
+			.into_iter()
+			.filter_map(|t| Version::parse(t.trim_start_matches('v')).ok().map(|v| (v, t)))
+			.filter(|(v, _)| req.matches(v))
+			.max_by(|a, b| a.0.cmp(&b.0))
+			.map(|(_, t)| t);
+
+		let Some(tag) = tag else {
+			bail!("No version of `{}` matches `{req}`", self.use_());
+		};
+
+		Git::checkout(&path, &tag).await?;
+		self.commit = Git::hash(&path).await?;
+		self.deploy().await
+	}
+
+	fn ensure_satisfied(
+		from: &str,
+		name: &str,
+		req: &VersionReq,
+		version: Option<&Version>,
+	) -> Result<()> {
+		match version {
+			Some(v) if !req.matches(v) => {
+				bail!("`{from}` requires `{name}` {req}, but version {v} is installed")
+			}
+			None if *req != VersionReq::STAR => {
+				bail!("`{from}` requires `{name}` {req}, but it doesn't declare a version")
+			}
+			_ => Ok(()),
+		}
+	}
+
+	async fn manifest(&self) -> Result<Option<DocumentMut>> {
+		let path = self.local().join(&self.child).join("manifest.toml");
+		let Ok(s) = fs::read_to_string(&path).await else {
+			return Ok(None);
+		};
+		Ok(Some(s.parse().with_context(|| format!("Failed to parse {}", path.display()))?))
+	}
+
+	async fn version(&self) -> Result<Option<Version>> {
+		let Some(doc) = self.manifest().await? else { return Ok(None) };
+		let Some(s) = doc.get("version").and_then(|v| v.as_str()) else { return Ok(None) };
+		Ok(Some(Version::parse(s).with_context(|| format!("Invalid version of `{}`", self.use_()))?))
+	}
+
+	async fn dependencies(&self) -> Result<Vec<(String, VersionReq)>> {
+		let Some(doc) = self.manifest().await? else { return Ok(vec![]) };
+		let Some(deps) = doc.get("dependencies").and_then(|d| d.as_table_like()) else {
+			return Ok(vec![]);
+		};
+
+		let mut v = Vec::with_capacity(deps.len());
+		for (use_, req) in deps.iter() {
+			let Some(Value::String(req)) = req.as_value() else {
+				bail!("Version requirement of `{use_}` must be a string");
+			};
+			let req = VersionReq::parse(req.value())
+				.with_context(|| format!("Invalid version requirement of `{use_}`"))?;
+			v.push((use_.to_owned(), req));
+		}
+		Ok(v)
+	}
+}
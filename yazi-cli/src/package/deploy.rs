@@ -33,11 +33,14 @@ For safety, please manually delete it from your plugin/flavor directory and re-r
 		let files = if self.is_flavor {
 			&["flavor.toml", "tmtheme.xml", "README.md", "preview.png", "LICENSE", "LICENSE-tmtheme"][..]
 		} else {
-			&["init.lua", "README.md", "LICENSE"][..]
+			&["init.lua", "manifest.toml", "README.md", "LICENSE"][..]
 		};
 
 		for file in files {
 			let (from, to) = (from.join(file), to.join(file));
+			if *file == "manifest.toml" && !maybe_exists(&from).await {
+				continue;
+			}
 
 			fs::copy(&from, &to)
 				.await
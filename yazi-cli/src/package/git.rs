@@ -25,6 +25,21 @@ impl Git {
 		Ok(())
 	}
 
+	pub(super) async fn tags(path: &Path) -> Result<Vec<String>> {
+		let output = Command::new("git")
+			.current_dir(path)
+			.args(["tag", "--list"])
+			.output()
+			.await
+			.context("Failed to list tags")?;
+
+		if !output.status.success() {
+			bail!("Listing tags failed: {}", output.status);
+		}
+
+		Ok(String::from_utf8(output.stdout)?.lines().map(ToOwned::to_owned).collect())
+	}
+
 	pub(super) async fn hash(path: &Path) -> Result<String> {
 		let output = Command::new("git")
 			.current_dir(path)
@@ -0,0 +1,98 @@
+use anyhow::{bail, Context, Result};
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+
+use super::Package;
+
+const INDEX: &str = "https://yazi-rs.github.io/packages/index.json";
+
+#[derive(Deserialize)]
+struct Index {
+	packages: Vec<IndexEntry>,
+}
+
+#[derive(Deserialize)]
+struct IndexEntry {
+	name:     String,
+	#[serde(rename = "use")]
+	use_:     String,
+	#[serde(default)]
+	desc:     String,
+	#[serde(default)]
+	versions: Vec<IndexVersion>,
+}
+
+#[derive(Deserialize)]
+struct IndexVersion {
+	version: Version,
+	#[serde(default)]
+	yazi:    Option<VersionReq>,
+}
+
+impl IndexEntry {
+	fn latest_compatible(&self) -> Option<&IndexVersion> {
+		self.versions.iter().filter(|v| v.compatible()).max_by(|a, b| a.version.cmp(&b.version))
+	}
+}
+
+impl IndexVersion {
+	fn compatible(&self) -> bool {
+		let current = Version::parse(env!("CARGO_PKG_VERSION")).unwrap();
+		self.yazi.as_ref().map_or(true, |r| r.matches(&current))
+	}
+}
+
+impl Package {
+	pub(crate) async fn search(query: &str) -> Result<()> {
+		let query = query.to_lowercase();
+		let index = Self::fetch_index().await?;
+
+		let mut found = false;
+		for entry in &index.packages {
+			if !entry.name.to_lowercase().contains(&query) && !entry.desc.to_lowercase().contains(&query)
+			{
+				continue;
+			}
+
+			found = true;
+			match entry.latest_compatible() {
+				Some(v) => println!("{} ({})\n\t{}", entry.name, v.version, entry.desc),
+				None => println!("{} (incompatible)\n\t{}", entry.name, entry.desc),
+			}
+		}
+
+		if !found {
+			println!("No packages found for `{query}`");
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn info(name: &str) -> Result<()> {
+		let index = Self::fetch_index().await?;
+		let Some(entry) = index.packages.iter().find(|e| e.name == name) else {
+			bail!("Package `{name}` not found in the index");
+		};
+
+		println!("name: {}", entry.name);
+		println!("use:  {}", entry.use_);
+		println!("desc: {}", entry.desc);
+		println!("versions:");
+		for v in &entry.versions {
+			let yazi = v.yazi.as_ref().map_or_else(|| "*".to_owned(), ToString::to_string);
+			let mark = if v.compatible() { "" } else { " (incompatible)" };
+			println!("\t{} (yazi {yazi}){mark}", v.version);
+		}
+		Ok(())
+	}
+
+	async fn fetch_index() -> Result<Index> {
+		let url = std::env::var("YAZI_PACKAGE_INDEX").unwrap_or_else(|_| INDEX.to_owned());
+		reqwest::get(&url)
+			.await
+			.and_then(|r| r.error_for_status())
+			.with_context(|| format!("Failed to fetch the package index from {url}"))?
+			.json()
+			.await
+			.context("Failed to parse the package index")
+	}
+}
@@ -1,8 +1,10 @@
 #![allow(clippy::module_inception)]
 
 mod add;
+mod dependency;
 mod deploy;
 mod git;
+mod index;
 mod install;
 mod package;
 mod parser;
@@ -46,6 +46,10 @@ impl Package {
 		let deps = deps.as_array_mut().context("`deps` must be an array")?;
 		for dep in deps.iter_mut() {
 			let dep = dep.as_inline_table_mut().context("Dependency must be an inline table")?;
+			if dep.get("dependency").and_then(|d| d.as_bool()) == Some(true) {
+				continue;
+			}
+
 			let use_ = dep.get("use").and_then(|d| d.as_str()).context("Missing `use` field")?;
 			let commit = dep.get("commit").and_then(|d| d.as_str());
 
@@ -63,6 +67,10 @@ impl Package {
 			}
 		}
 
+		if section == "plugin" {
+			Self::install_deps(&mut doc, upgrade).await?;
+		}
+
 		fs::write(path, doc.to_string()).await.context("Failed to write package.toml")
 	}
 
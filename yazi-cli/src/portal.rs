@@ -0,0 +1,169 @@
+use std::{collections::HashMap, ffi::OsString, os::unix::ffi::{OsStrExt, OsStringExt}, path::{Path, PathBuf}};
+
+use anyhow::{bail, Result};
+use percent_encoding::percent_encode;
+use yazi_shared::fs::PATH_ENCODE_SET;
+use zbus::{connection::Builder, interface, zvariant::{OwnedObjectPath, OwnedValue, Value}};
+
+const NAME: &str = "org.freedesktop.impl.portal.desktop.yazi";
+const PATH: &str = "/org/freedesktop/portal/desktop";
+
+// The responses of a portal request
+const SUCCESS: u32 = 0;
+const CANCELLED: u32 = 1;
+const FAILED: u32 = 2;
+
+type Options = HashMap<String, OwnedValue>;
+
+// Install the files in `assets/portal`, and select it in `portals.conf`:
+//   [preferred]
+//   org.freedesktop.impl.portal.FileChooser=yazi
+pub(super) struct Portal {
+	terminal: Vec<String>,
+}
+
+#[interface(name = "org.freedesktop.impl.portal.FileChooser")]
+impl Portal {
+	async fn open_file(
+		&self,
+		_handle: OwnedObjectPath,
+		_app_id: String,
+		_parent_window: String,
+		_title: String,
+		options: Options,
+	) -> (u32, Options) {
+		let mut args: Vec<OsString> = vec![];
+		if Self::opt::<bool>(&options, "directory").unwrap_or(false) {
+			args.push("--choose-dir".into());
+		}
+		if !Self::opt::<bool>(&options, "multiple").unwrap_or(false) {
+			args.push("--choose-multiple=1".into());
+		}
+		for glob in Self::globs(&options) {
+			args.push(format!("--choose-filter={glob}").into());
+		}
+		if let Some(dir) = Self::opt_path(&options, "current_folder") {
+			args.push(dir.into());
+		}
+
+		Self::respond(self.choose(args).await)
+	}
+
+	// Only the directory is chosen, and the file is named after `current_name` to be created in it
+	async fn save_file(
+		&self,
+		_handle: OwnedObjectPath,
+		_app_id: String,
+		_parent_window: String,
+		_title: String,
+		options: Options,
+	) -> (u32, Options) {
+		let mut args: Vec<OsString> = vec!["--choose-dir".into(), "--choose-multiple=1".into()];
+		let current = Self::opt_path(&options, "current_file");
+		if let Some(dir) = Self::opt_path(&options, "current_folder")
+			.or_else(|| current.as_ref().and_then(|p| p.parent()).map(Path::to_owned))
+		{
+			args.push(dir.into());
+		}
+
+		let name = Self::opt::<String>(&options, "current_name")
+			.map(OsString::from)
+			.or_else(|| current.as_ref().and_then(|p| p.file_name()).map(ToOwned::to_owned))
+			.unwrap_or_else(|| "Untitled".into());
+
+		let chosen = self.choose(args).await;
+		Self::respond(chosen.map(|dirs| dirs.map(|d| d.into_iter().map(|d| d.join(&name)).collect())))
+	}
+}
+
+impl Portal {
+	pub(super) async fn serve(terminal: String) -> Result<()> {
+		let terminal = yazi_shared::shell::split_unix(&terminal)?;
+		if terminal.is_empty() {
+			bail!("No terminal to run Yazi in");
+		}
+
+		let _conn = Builder::session()?.name(NAME)?.serve_at(PATH, Self { terminal })?.build().await?;
+		std::future::pending::<()>().await;
+		Ok(())
+	}
+
+	// Runs Yazi in the chooser mode, `None` if it's quit without choosing anything
+	async fn choose(&self, args: Vec<OsString>) -> Result<Option<Vec<PathBuf>>> {
+		let file = std::env::temp_dir().join(format!(".yazi_portal-{}", yazi_shared::timestamp_us()));
+
+		let status = tokio::process::Command::new(&self.terminal[0])
+			.args(&self.terminal[1..])
+			.arg("yazi")
+			.arg("--chooser-file")
+			.arg(&file)
+			.args(args)
+			.status()
+			.await?;
+
+		// The exit code of Yazi may be lost by the terminal, so the file is the only source of truth
+		let chosen = tokio::fs::read(&file).await.unwrap_or_default();
+		tokio::fs::remove_file(&file).await.ok();
+		if chosen.is_empty() && !status.success() {
+			bail!("The terminal exited with {status}");
+		}
+
+		let paths: Vec<_> = chosen
+			.split(|&b| b == b'\n')
+			.filter(|l| !l.is_empty())
+			.map(|l| PathBuf::from(OsString::from_vec(l.to_vec())))
+			.collect();
+		Ok(Some(paths).filter(|p| !p.is_empty()))
+	}
+
+	fn respond(result: Result<Option<Vec<PathBuf>>>) -> (u32, Options) {
+		let paths = match result {
+			Ok(Some(paths)) => paths,
+			Ok(None) => return (CANCELLED, Options::new()),
+			Err(e) => {
+				eprintln!("Failed to choose with Yazi: {e}");
+				return (FAILED, Options::new());
+			}
+		};
+
+		let uris: Vec<_> = paths
+			.iter()
+			.map(|p| format!("file://{}", percent_encode(p.as_os_str().as_bytes(), PATH_ENCODE_SET)))
+			.collect();
+		match OwnedValue::try_from(Value::from(uris)) {
+			Ok(v) => (SUCCESS, Options::from([("uris".to_owned(), v)])),
+			Err(_) => (FAILED, Options::new()),
+		}
+	}
+
+	fn opt<T: TryFrom<OwnedValue>>(options: &Options, key: &str) -> Option<T> {
+		options.get(key)?.try_clone().ok()?.try_into().ok()
+	}
+
+	// The paths are null-terminated byte arrays, not strings
+	fn opt_path(options: &Options, key: &str) -> Option<PathBuf> {
+		let mut b = Self::opt::<Vec<u8>>(options, key)?;
+		while b.last() == Some(&0) {
+			b.pop();
+		}
+		Some(b).filter(|b| !b.is_empty()).map(|b| PathBuf::from(OsString::from_vec(b)))
+	}
+
+	// Globs of the current filter, or of all the filters if none is current
+	fn globs(options: &Options) -> Vec<String> {
+		type Filter = (String, Vec<(u32, String)>);
+
+		let filters = match Self::opt::<Filter>(options, "current_filter") {
+			Some(f) => vec![f],
+			None => Self::opt::<Vec<Filter>>(options, "filters").unwrap_or_default(),
+		};
+
+		let globs: Vec<_> =
+			filters.iter().flat_map(|(_, rules)| rules).filter(|(ty, _)| *ty == 0).collect();
+		// The MIME types can't be told by names, so the filters with any are ignored to not refuse them
+		if globs.len() < filters.iter().map(|(_, r)| r.len()).sum() {
+			return vec![];
+		}
+		globs.into_iter().map(|(_, g)| g.clone()).collect()
+	}
+}
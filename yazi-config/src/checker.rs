@@ -0,0 +1,276 @@
+use std::{fmt::Display, path::PathBuf, str::FromStr, sync::Arc};
+
+use yazi_shared::{event::Cmd, Layer, Xdg};
+
+use crate::{keymap::Control, manager::SortBy, theme::IconProfile, Pattern, KEYMAP, MACROS, OPEN};
+
+#[derive(Debug)]
+pub struct Diagnostic {
+	pub file:    PathBuf,
+	pub line:    Option<usize>,
+	pub message: String,
+}
+
+impl Display for Diagnostic {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.line {
+			Some(n) => write!(f, "{}:{n}: {}", self.file.display(), self.message),
+			None => write!(f, "{}: {}", self.file.display(), self.message),
+		}
+	}
+}
+
+pub struct Checker {
+	keymap: (PathBuf, String),
+	yazi:   (PathBuf, String),
+	out:    Vec<Diagnostic>,
+}
+
+impl Checker {
+	// Looks for the mistakes in the loaded config that don't fail the parsing, but make
+	// some of it silently ineffective. `is_command` tells the commands registered by plugins.
+	pub fn check(is_command: impl Fn(&str) -> bool) -> Vec<Diagnostic> {
+		let read = |name: &str| {
+			let p = Xdg::config_dir().join(name);
+			let s = std::fs::read_to_string(&p).unwrap_or_default();
+			(p, s)
+		};
+
+		let mut me = Self { keymap: read("keymap.toml"), yazi: read("yazi.toml"), out: vec![] };
+		for layer in
+			[Layer::Manager, Layer::Tasks, Layer::Select, Layer::Input, Layer::Help, Layer::Completion]
+		{
+			me.check_layer(layer, KEYMAP.load().get(layer), &is_command);
+		}
+
+		let keymap = KEYMAP.load();
+		let mut layers: Vec<_> = keymap.layers.iter().collect();
+		layers.sort_unstable_by(|a, b| a.0.cmp(b.0));
+		for (_, controls) in layers {
+			me.check_layer(Layer::Manager, controls, &is_command);
+		}
+
+		me.check_rules();
+		me.out
+	}
+
+	fn check_layer(
+		&mut self,
+		layer: Layer,
+		controls: &[Arc<Control>],
+		is_command: &impl Fn(&str) -> bool,
+	) {
+		for (i, ctrl) in controls.iter().enumerate() {
+			let line = self.line_of(controls, i);
+
+			// Overriding the preset ones is the point of `prepend_keymap`, so they're not reported
+			if let (Some(by), Some(_)) = (Self::shadowed_by(controls, i), line) {
+				let message = format!(
+					"`{}` ({}) is shadowed by `{}` ({})",
+					ctrl.on(),
+					ctrl.desc_or_run(),
+					by.on(),
+					by.desc_or_run()
+				);
+				self.keymap(layer, line, message);
+			}
+
+			for cmd in &ctrl.run {
+				if !Self::is_known(layer, &cmd.name) && !is_command(&cmd.name) {
+					self.keymap(layer, line, format!("unknown command `{}`", cmd.name));
+				} else if let Some(e) = Self::bad_args(layer, cmd) {
+					self.keymap(layer, line, format!("`{cmd}`: {e}"));
+				}
+			}
+		}
+	}
+
+	fn check_rules(&mut self) {
+		let (mut file, mut dir) = (false, false);
+		for rule in &OPEN.load().rules {
+			let pattern = rule.mime.as_ref().or(rule.name.as_ref());
+			let what = match (&rule.name, &rule.mime) {
+				(_, Some(p)) => format!("mime = \"{p}\""),
+				(Some(p), None) => format!("name = \"{p}\""),
+				(None, None) => "without `name` or `mime`".to_owned(),
+			};
+
+			// A rule with only a name pattern applies to either files or directories,
+			// while one with a mime pattern may apply to both.
+			let unreachable = match (&rule.name, &rule.mime) {
+				(Some(p), None) if p.is_dir() => dir,
+				(Some(_), None) => file,
+				_ => file && dir,
+			};
+			if unreachable {
+				self.yazi(pattern, format!("open rule {what} is unreachable after a catch-all one"));
+			}
+
+			for use_ in &rule.use_ {
+				if !OPEN.load().openers.contains_key(use_) {
+					self.yazi(pattern, format!("open rule {what} uses an undefined opener `{use_}`"));
+				}
+			}
+
+			// `mime = "*"` matches directories too, as they have the `inode/directory` type
+			if let Some(p) = &rule.mime {
+				(file, dir) = (file || p.any_file(), dir || p.any_file());
+			}
+			if let Some(p) = &rule.name {
+				(file, dir) = (file || p.any_file(), dir || p.any_dir());
+			}
+		}
+	}
+
+	// Only unconditional bindings can shadow others, since the conditional ones may fall through.
+	// The first key is matched in order, while a shorter chord always wins over the longer ones.
+	fn shadowed_by(controls: &[Arc<Control>], i: usize) -> Option<&Control> {
+		let ctrl = &controls[i];
+		controls.iter().enumerate().filter(|&(j, c)| j != i && !c.is_conditional()).find_map(|(j, c)| {
+			let single = c.on.len() == 1 || ctrl.on.len() == 1;
+			let earlier = j < i && (c.on == ctrl.on || (single && c.on[0] == ctrl.on[0]));
+			let prefix = c.on.len() > 1 && c.on.len() < ctrl.on.len() && ctrl.on.starts_with(&c.on);
+			(earlier || prefix).then_some(&**c)
+		})
+	}
+
+	fn is_known(layer: Layer, name: &str) -> bool {
+		macro_rules! on {
+			(TABS, $name:ident) => {
+				if name == concat!("tab_", stringify!($name)) {
+					return true;
+				}
+			};
+			($kind:ident, $name:ident $(, $extra:ident)?) => {
+				if name == stringify!($name) {
+					return true;
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if name == $alias {
+					return true;
+				}
+			};
+			($name:ident) => {
+				if name == stringify!($name) {
+					return true;
+				}
+			};
+		}
+
+		match layer {
+			Layer::Manager => crate::manager_commands!(on),
+			Layer::Tasks => crate::tasks_commands!(on),
+			Layer::Help => crate::help_commands!(on),
+			Layer::Select => crate::select_commands!(on),
+			Layer::Input => crate::input_commands!(on),
+			Layer::Completion => crate::completion_commands!(on),
+			Layer::App | Layer::Which => {}
+		}
+		layer == Layer::Manager && MACROS.load().contains(name)
+	}
+
+	fn bad_args(layer: Layer, cmd: &Cmd) -> Option<String> {
+		if layer != Layer::Manager {
+			return None;
+		}
+
+		let first = cmd.str("0");
+		match cmd.name.as_str() {
+			"arrow" | "tab_switch" | "tab_swap" => {
+				let n = first.map(|s| s.strip_suffix('%').unwrap_or(s).parse::<isize>());
+				if !matches!(n, Some(Ok(_))) && cmd.first().and_then(|d| d.as_isize()).is_none() {
+					return Some("expected a number of steps".to_owned());
+				}
+			}
+			"sort" => {
+				if let Some(Err(e)) = first.map(SortBy::from_str) {
+					return Some(e.to_string());
+				}
+			}
+			"cd" if cmd.bool("interactive") && cmd.bool("device") => {
+				return Some("cannot use both `--interactive` and `--device`".to_owned());
+			}
+			"hidden" => {
+				if first.is_some_and(|s| !matches!(s, "show" | "hide" | "toggle")) {
+					return Some("expected `show`, `hide` or `toggle`".to_owned());
+				}
+			}
+			"linemode" => {
+				if !first.is_some_and(|s| !s.is_empty() && s.len() <= 20) {
+					return Some("expected a name of 1 to 20 bytes".to_owned());
+				}
+			}
+			"copy" => {
+				let type_ = first.unwrap_or_default();
+				if !matches!(type_, "path" | "dirname" | "filename" | "name_without_ext") {
+					return Some("expected `path`, `dirname`, `filename` or `name_without_ext`".to_owned());
+				}
+				let format = cmd.str("format").unwrap_or("lines");
+				if !matches!(format, "lines" | "shell" | "uri" | "windows" | "json") {
+					return Some("expected `lines`, `shell`, `uri`, `windows` or `json` format".to_owned());
+				}
+			}
+			"attrib" => {
+				if !first.is_some_and(|s| matches!(s, "hidden" | "system" | "readonly")) {
+					return Some("expected `hidden`, `system` or `readonly`".to_owned());
+				}
+			}
+			"icons" => {
+				if let Some(Err(e)) = first.map(IconProfile::from_str) {
+					return Some(e.to_string());
+				}
+			}
+			"plugin" => {
+				if first.map_or(true, str::is_empty) {
+					return Some("expected the name of a plugin".to_owned());
+				}
+			}
+			"shell" => match (cmd.bool("interactive"), cmd.bool("confirm")) {
+				(false, false) => {
+					return Some("expected either `--interactive` or `--confirm`".to_owned());
+				}
+				(true, true) => {
+					return Some("cannot use both `--interactive` and `--confirm`".to_owned());
+				}
+				_ => {}
+			},
+			_ => {}
+		}
+		None
+	}
+
+	fn keymap(&mut self, layer: Layer, line: Option<usize>, message: String) {
+		self.out.push(Diagnostic {
+			file: self.keymap.0.clone(),
+			line,
+			message: if line.is_some() { message } else { format!("[{layer}] {message} (preset)") },
+		});
+	}
+
+	fn line_of(&self, controls: &[Arc<Control>], i: usize) -> Option<usize> {
+		let ctrl = &controls[i];
+		let nth = controls[..i].iter().filter(|c| c.on == ctrl.on).count();
+
+		let mut needles: Vec<_> = ctrl.on.iter().map(|k| format!("\"{k}\"")).collect();
+		needles.extend(ctrl.run.first().map(|c| c.name.clone()));
+		Self::locate(&self.keymap.1, &needles, nth)
+	}
+
+	fn yazi(&mut self, pattern: Option<&Pattern>, message: String) {
+		let line = pattern.and_then(|p| Self::locate(&self.yazi.1, &[format!("\"{p}\"")], 0));
+		self.out.push(Diagnostic { file: self.yazi.0.clone(), line, message });
+	}
+
+	// Finds the 1-based line number of the `nth` line containing all of the `needles`,
+	// which is a best-effort guess since the merged config doesn't keep the spans.
+	fn locate(text: &str, needles: &[String], nth: usize) -> Option<usize> {
+		let found: Vec<_> = text
+			.lines()
+			.enumerate()
+			.filter(|(_, l)| needles.iter().all(|n| l.contains(n.as_str())))
+			.map(|(i, _)| i + 1)
+			.collect();
+		found.get(nth).or(found.last()).copied()
+	}
+}
@@ -0,0 +1,211 @@
+// The commands of the layers, the executor dispatches them by calling `$on!` for each,
+// and `Checker` tells the known ones from them, so both never get out of sync.
+//
+// `MANAGER` and `ACTIVE` are the receivers of the manager layer, with `tasks` for the ones
+// taking the tasks too, `NORMAL` and `INSERT` the ones of the input only in that mode,
+// and `OTHER` are the ones dispatched by hand in the executor.
+
+#[macro_export]
+macro_rules! manager_commands {
+	($on:ident) => {{
+		$on!(MANAGER, update_task);
+		$on!(MANAGER, update_files, tasks);
+		$on!(MANAGER, update_mimetype, tasks);
+		$on!(MANAGER, update_paged, tasks);
+		$on!(MANAGER, update_tags);
+		$on!(MANAGER, update_security);
+		$on!(MANAGER, update_yanked);
+		$on!(MANAGER, update_bookmarks);
+		$on!(MANAGER, update_selected);
+		$on!(MANAGER, update_vcs);
+		$on!(MANAGER, update_space);
+		$on!(MANAGER, update_commit);
+		$on!(MANAGER, hover);
+		$on!(MANAGER, peek);
+		$on!(MANAGER, seek);
+		$on!(MANAGER, refresh, tasks);
+		$on!(MANAGER, refetch, tasks);
+		$on!(MANAGER, quit, tasks);
+		$on!(MANAGER, close, tasks);
+		$on!(MANAGER, suspend);
+		$on!(MANAGER, flavor);
+		$on!(MANAGER, icons);
+		$on!(MANAGER, layer);
+		$on!(ACTIVE, escape);
+		$on!(ACTIVE, preview);
+
+		// Navigation
+		$on!(ACTIVE, arrow);
+		$on!(ACTIVE, leave);
+		$on!(ACTIVE, enter);
+		$on!(ACTIVE, back);
+		$on!(ACTIVE, forward);
+		$on!(ACTIVE, cd);
+		$on!(ACTIVE, reveal);
+
+		// Selection
+		$on!(ACTIVE, select);
+		$on!(ACTIVE, select_all);
+		$on!(ACTIVE, visual_mode);
+
+		// Operation
+		$on!(MANAGER, open, tasks);
+		$on!(MANAGER, open_do, tasks);
+		$on!(MANAGER, open_terminal, tasks);
+		$on!(MANAGER, send_to, tasks);
+		$on!(MANAGER, touch, tasks);
+		$on!(MANAGER, image, tasks);
+		$on!(MANAGER, compare, tasks);
+		$on!(MANAGER, edit_link);
+		$on!(MANAGER, bookmark_set);
+		$on!(MANAGER, bookmark_jump);
+		$on!(MANAGER, bookmark_delete);
+		$on!(MANAGER, yank);
+		$on!(MANAGER, unyank);
+		$on!(MANAGER, paste, tasks);
+		$on!(MANAGER, link, tasks);
+		$on!(MANAGER, hardlink, tasks);
+		$on!(MANAGER, remove, tasks);
+		$on!(MANAGER, remove_do, tasks);
+		$on!(MANAGER, create);
+		$on!(MANAGER, rename);
+		$on!(MANAGER, attrib);
+		$on!(MANAGER, mount);
+		$on!(MANAGER, mount_do);
+		$on!(MANAGER, unmount);
+		$on!(MANAGER, encrypt);
+		$on!(MANAGER, decrypt);
+		$on!(MANAGER, crypt_do, tasks);
+		$on!(MANAGER, drag, tasks);
+		$on!(MANAGER, show_items);
+		$on!(MANAGER, rpc_state);
+		$on!(MANAGER, perf);
+		$on!(MANAGER, restore);
+		$on!(ACTIVE, copy);
+		$on!(ACTIVE, shell);
+		$on!(ACTIVE, hidden);
+		$on!(ACTIVE, linemode, tasks);
+		$on!(ACTIVE, usage, tasks);
+		$on!(ACTIVE, search);
+		$on!(ACTIVE, search_do);
+
+		// Filter
+		$on!(ACTIVE, filter);
+		$on!(ACTIVE, filter_do);
+
+		// Find
+		$on!(ACTIVE, find);
+		$on!(ACTIVE, find_do);
+		$on!(ACTIVE, find_arrow);
+
+		// Sorting
+		$on!(ACTIVE, sort, tasks);
+
+		// Tabs
+		$on!(TABS, create);
+		$on!(TABS, close);
+		$on!(TABS, switch);
+		$on!(TABS, swap);
+
+		$on!(OTHER, tasks_show);
+		$on!(OTHER, macro_record);
+		$on!(OTHER, macro_play);
+		$on!(OTHER, terminal);
+		$on!(OTHER, notify_history);
+		$on!(OTHER, help);
+		$on!(OTHER, plugin);
+	}};
+}
+
+#[macro_export]
+macro_rules! tasks_commands {
+	($on:ident) => {{
+		$on!(toggle, "close");
+		$on!(arrow);
+		$on!(inspect);
+		$on!(cancel);
+		$on!(retry);
+		$on!(open_with);
+		$on!(plugin_task);
+		$on!(process_exec);
+		$on!(shell_each);
+
+		$on!(OTHER, help);
+		$on!(OTHER, plugin);
+	}};
+}
+
+#[macro_export]
+macro_rules! help_commands {
+	($on:ident) => {{
+		$on!(escape);
+		$on!(arrow);
+		$on!(filter);
+		$on!(run);
+
+		$on!(OTHER, close);
+		$on!(OTHER, plugin);
+	}};
+}
+
+#[macro_export]
+macro_rules! select_commands {
+	($on:ident) => {{
+		$on!(show);
+		$on!(close);
+		$on!(arrow);
+		$on!(extend);
+		$on!(filter);
+		$on!(toggle);
+		$on!(preview);
+
+		$on!(OTHER, help);
+		$on!(OTHER, plugin);
+	}};
+}
+
+#[macro_export]
+macro_rules! input_commands {
+	($on:ident) => {{
+		$on!(show);
+		$on!(close);
+		$on!(escape);
+		$on!(move_, "move");
+		$on!(backward);
+		$on!(forward);
+		$on!(history);
+
+		// Normal mode
+		$on!(NORMAL, insert);
+		$on!(NORMAL, visual);
+		$on!(NORMAL, delete);
+		$on!(NORMAL, yank);
+		$on!(NORMAL, paste);
+		$on!(NORMAL, undo);
+		$on!(NORMAL, redo);
+
+		// Insert mode
+		$on!(INSERT, backspace);
+		$on!(INSERT, kill);
+		$on!(INSERT, paste);
+		$on!(INSERT, undo);
+
+		$on!(OTHER, complete);
+		$on!(OTHER, help);
+		$on!(OTHER, plugin);
+	}};
+}
+
+#[macro_export]
+macro_rules! completion_commands {
+	($on:ident) => {{
+		$on!(trigger);
+		$on!(show);
+		$on!(close);
+		$on!(arrow);
+
+		$on!(OTHER, close_input);
+		$on!(OTHER, help);
+		$on!(OTHER, plugin);
+	}};
+}
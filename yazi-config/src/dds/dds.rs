@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Dds {
+	// Serve `org.freedesktop.FileManager1` on the session bus, for the "Show in folder" of other apps
+	pub file_manager1:   bool,
+	// Serve JSON-RPC on a socket of each instance, whose path is in `$YAZI_RPC`
+	pub rpc:             bool,
+	// The address to serve the same JSON-RPC over WebSocket on, e.g. `127.0.0.1:7878`,
+	// which is refused without a token
+	pub websocket:       String,
+	pub websocket_token: String,
+}
+
+impl FromStr for Dds {
+	type Err = toml::de::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			dds: Dds,
+		}
+
+		Ok(toml::from_str::<Outer>(s)?.dds)
+	}
+}
@@ -0,0 +1,3 @@
+mod dds;
+
+pub use dds::*;
@@ -1,10 +1,11 @@
-use std::{borrow::Cow, collections::VecDeque, sync::OnceLock};
+use std::{borrow::Cow, collections::VecDeque, path::Path, sync::OnceLock};
 
 use regex::Regex;
 use serde::Deserialize;
-use yazi_shared::event::Cmd;
+use yazi_shared::{event::Cmd, Condition, MIME_DIR};
 
 use super::Key;
+use crate::{Pattern, LOCALE};
 
 static RE: OnceLock<Regex> = OnceLock::new();
 
@@ -15,11 +16,42 @@ pub struct Control {
 	#[serde(deserialize_with = "super::deserialize_run")]
 	pub run:  Vec<Cmd>,
 	pub desc: Option<String>,
+
+	// Conditions
+	#[serde(rename = "if")]
+	pub if_:  Option<Condition>,
+	pub name: Option<Pattern>,
+	pub mime: Option<Pattern>,
 }
 
 impl Control {
 	#[inline]
 	pub fn to_seq(&self) -> VecDeque<Cmd> { self.run.iter().map(|c| c.shallow_clone()).collect() }
+
+	#[inline]
+	pub fn is_conditional(&self) -> bool {
+		self.if_.is_some() || self.name.is_some() || self.mime.is_some()
+	}
+
+	// `hovered` is the path and mime type of the hovered file, if any,
+	// the binding only applies when it matches either `name` or `mime` if specified.
+	pub fn satisfied(
+		&self,
+		hovered: Option<(&Path, &str)>,
+		f: impl Fn(&str) -> bool + Copy,
+	) -> bool {
+		if self.if_.as_ref().and_then(|c| c.eval(f)) == Some(false) {
+			return false;
+		}
+		if self.name.is_none() && self.mime.is_none() {
+			return true;
+		}
+
+		hovered.is_some_and(|(path, mime)| {
+			self.mime.as_ref().is_some_and(|p| !mime.is_empty() && p.match_mime(mime))
+				|| self.name.as_ref().is_some_and(|p| p.match_path(path, mime == MIME_DIR))
+		})
+	}
 }
 
 impl Control {
@@ -31,8 +63,11 @@ impl Control {
 			.into_owned()
 	}
 
+	// Translated, as the preset descriptions are the English messages in the catalog
 	pub fn desc(&self) -> Option<Cow<str>> {
-		self.desc.as_ref().map(|s| RE.get_or_init(|| Regex::new(r"\s+").unwrap()).replace_all(s, " "))
+		let desc = self.desc.as_ref()?;
+		let desc = RE.get_or_init(|| Regex::new(r"\s+").unwrap()).replace_all(desc, " ");
+		Some(Cow::Owned(LOCALE.load().get(&desc).to_owned()))
 	}
 
 	pub fn desc_or_run(&self) -> Cow<str> { self.desc().unwrap_or_else(|| self.run().into()) }
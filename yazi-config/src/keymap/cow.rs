@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, ops::Deref};
+use std::{collections::VecDeque, ops::Deref, sync::Arc};
 
 use yazi_shared::event::Cmd;
 
@@ -7,11 +7,11 @@ use super::Control;
 #[derive(Debug)]
 pub enum ControlCow {
 	Owned(Control),
-	Borrowed(&'static Control),
+	Shared(Arc<Control>),
 }
 
-impl From<&'static Control> for ControlCow {
-	fn from(c: &'static Control) -> Self { Self::Borrowed(c) }
+impl From<Arc<Control>> for ControlCow {
+	fn from(c: Arc<Control>) -> Self { Self::Shared(c) }
 }
 
 impl From<Control> for ControlCow {
@@ -24,7 +24,7 @@ impl Deref for ControlCow {
 	fn deref(&self) -> &Self::Target {
 		match self {
 			Self::Owned(c) => c,
-			Self::Borrowed(c) => c,
+			Self::Shared(c) => c,
 		}
 	}
 }
@@ -37,7 +37,7 @@ impl ControlCow {
 	pub fn into_seq(self) -> VecDeque<Cmd> {
 		match self {
 			Self::Owned(c) => c.run.into(),
-			Self::Borrowed(c) => c.to_seq(),
+			Self::Shared(c) => c.to_seq(),
 		}
 	}
 }
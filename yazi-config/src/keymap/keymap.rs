@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr, sync::{Arc, RwLock}};
 
 use serde::{Deserialize, Deserializer};
 use yazi_shared::Layer;
@@ -8,17 +8,22 @@ use crate::Preset;
 
 #[derive(Debug)]
 pub struct Keymap {
-	pub manager:    Vec<Control>,
-	pub tasks:      Vec<Control>,
-	pub select:     Vec<Control>,
-	pub input:      Vec<Control>,
-	pub help:       Vec<Control>,
-	pub completion: Vec<Control>,
+	pub manager:    Vec<Arc<Control>>,
+	pub tasks:      Vec<Arc<Control>>,
+	pub select:     Vec<Arc<Control>>,
+	pub input:      Vec<Arc<Control>>,
+	pub help:       Vec<Arc<Control>>,
+	pub completion: Vec<Arc<Control>>,
+
+	// Named layers that can be toggled on top of the manager
+	pub layers: HashMap<String, Vec<Arc<Control>>>,
+
+	registered: RwLock<Vec<(Layer, Arc<Control>)>>,
 }
 
 impl Keymap {
 	#[inline]
-	pub fn get(&self, layer: Layer) -> &Vec<Control> {
+	pub fn get(&self, layer: Layer) -> &Vec<Arc<Control>> {
 		match layer {
 			Layer::App => unreachable!(),
 			Layer::Manager => &self.manager,
@@ -30,6 +35,35 @@ impl Keymap {
 			Layer::Which => unreachable!(),
 		}
 	}
+
+	/// All controls of the layer, followed by the ones registered by plugins,
+	/// so that the user's keymap.toml always takes precedence.
+	pub fn iter(&self, layer: Layer) -> impl Iterator<Item = Arc<Control>> + '_ {
+		let registered: Vec<_> = self
+			.registered
+			.read()
+			.unwrap()
+			.iter()
+			.filter(|(l, _)| *l == layer)
+			.map(|(_, c)| c.clone())
+			.collect();
+
+		self.get(layer).iter().cloned().chain(registered)
+	}
+
+	// Registering the same keys again replaces the control registered before,
+	// which is dropped once the help or the which that may be showing it is done with it.
+	pub fn register(&self, layer: Layer, control: Control) {
+		let mut registered = self.registered.write().unwrap();
+		registered.retain(|(l, c)| *l != layer || c.on != control.on);
+		registered.push((layer, Arc::new(control)));
+	}
+
+	// Carries the controls registered by plugins over to a reloaded keymap,
+	// as the plugins that registered them won't be set up again.
+	pub(crate) fn inherit(&self, old: &Self) {
+		*self.registered.write().unwrap() = old.registered.read().unwrap().clone();
+	}
 }
 
 impl FromStr for Keymap {
@@ -51,9 +85,12 @@ impl<'de> Deserialize<'de> for Keymap {
 			input:      Inner,
 			help:       Inner,
 			completion: Inner,
+			#[serde(default)]
+			layers:     HashMap<String, Inner>,
 		}
 		#[derive(Deserialize)]
 		struct Inner {
+			#[serde(default)]
 			keymap:         Vec<Control>,
 			#[serde(default)]
 			prepend_keymap: Vec<Control>,
@@ -76,13 +113,25 @@ impl<'de> Deserialize<'de> for Keymap {
 		#[rustfmt::skip]
 		Preset::mix(&mut shadow.completion.keymap, shadow.completion.prepend_keymap, shadow.completion.append_keymap);
 
+		let shared = |v: Vec<Control>| v.into_iter().map(Arc::new).collect();
 		Ok(Self {
-			manager:    shadow.manager.keymap,
-			tasks:      shadow.tasks.keymap,
-			select:     shadow.select.keymap,
-			input:      shadow.input.keymap,
-			help:       shadow.help.keymap,
-			completion: shadow.completion.keymap,
+			manager:    shared(shadow.manager.keymap),
+			tasks:      shared(shadow.tasks.keymap),
+			select:     shared(shadow.select.keymap),
+			input:      shared(shadow.input.keymap),
+			help:       shared(shadow.help.keymap),
+			completion: shared(shadow.completion.keymap),
+
+			layers: shadow
+				.layers
+				.into_iter()
+				.map(|(name, mut inner)| {
+					Preset::mix(&mut inner.keymap, inner.prepend_keymap, inner.append_keymap);
+					(name, shared(inner.keymap))
+				})
+				.collect(),
+
+			registered: Default::default(),
 		})
 	}
 }
@@ -1,12 +1,18 @@
 #![allow(clippy::module_inception)]
 
-use std::str::FromStr;
+use std::{str::FromStr, sync::Mutex};
 
-use yazi_shared::{RoCell, Xdg};
+use yazi_shared::{RoCell, SwapCell, Xdg};
 
+mod checker;
+mod commands;
+pub mod dds;
 pub mod keymap;
 mod layout;
+mod local;
+mod locale;
 mod log;
+mod macros;
 pub mod manager;
 pub mod open;
 mod pattern;
@@ -15,51 +21,89 @@ pub mod popup;
 mod preset;
 pub mod preview;
 mod priority;
+mod target;
 mod tasks;
 pub mod theme;
+pub mod vcs;
+pub mod vfs;
+mod watcher;
 pub mod which;
 
+pub use checker::*;
 pub use layout::*;
-pub(crate) use pattern::*;
+pub use local::*;
+pub use pattern::*;
 pub(crate) use preset::*;
 pub use priority::*;
+pub(crate) use target::*;
 
 pub static LAYOUT: RoCell<arc_swap::ArcSwap<Layout>> = RoCell::new();
 
-pub static KEYMAP: RoCell<keymap::Keymap> = RoCell::new();
+pub static DDS: RoCell<dds::Dds> = RoCell::new();
+pub static KEYMAP: SwapCell<keymap::Keymap> = SwapCell::new();
+pub static LOCALE: SwapCell<locale::Locale> = SwapCell::new();
 pub static LOG: RoCell<log::Log> = RoCell::new();
-pub static MANAGER: RoCell<manager::Manager> = RoCell::new();
-pub static OPEN: RoCell<open::Open> = RoCell::new();
-pub static PLUGIN: RoCell<plugin::Plugin> = RoCell::new();
-pub static PREVIEW: RoCell<preview::Preview> = RoCell::new();
-pub static TASKS: RoCell<tasks::Tasks> = RoCell::new();
-pub static THEME: RoCell<theme::Theme> = RoCell::new();
-pub static INPUT: RoCell<popup::Input> = RoCell::new();
-pub static SELECT: RoCell<popup::Select> = RoCell::new();
-pub static WHICH: RoCell<which::Which> = RoCell::new();
+pub static MACROS: SwapCell<macros::Macros> = SwapCell::new();
+pub static MANAGER: SwapCell<manager::Manager> = SwapCell::new();
+pub static OPEN: SwapCell<open::Open> = SwapCell::new();
+pub static PLUGIN: SwapCell<plugin::Plugin> = SwapCell::new();
+pub static PREVIEW: SwapCell<preview::Preview> = SwapCell::new();
+pub static TASKS: SwapCell<tasks::Tasks> = SwapCell::new();
+pub static THEME: SwapCell<theme::Theme> = SwapCell::new();
+pub static VCS: SwapCell<vcs::Vcs> = SwapCell::new();
+pub static VFS: SwapCell<vfs::Vfs> = SwapCell::new();
+pub static WATCHER: SwapCell<watcher::Watcher> = SwapCell::new();
+pub static INPUT: SwapCell<popup::Input> = SwapCell::new();
+pub static SELECT: SwapCell<popup::Select> = SwapCell::new();
+pub static WHICH: SwapCell<which::Which> = SwapCell::new();
+
+// The merged sources of `yazi.toml`, `keymap.toml` and `theme.toml` that were loaded last,
+// used to tell which of them have changed when reloading.
+static SOURCES: Mutex<[String; 3]> = Mutex::new([String::new(), String::new(), String::new()]);
+
+// The flavor and the icon profile switched to at runtime, kept over the reloads of `theme.toml`
+static SWITCHED: Mutex<(Option<String>, Option<String>)> = Mutex::new((None, None));
 
 pub fn init() -> anyhow::Result<()> {
 	let config_dir = Xdg::config_dir();
 	let yazi_toml = &Preset::yazi(&config_dir)?;
 	let keymap_toml = &Preset::keymap(&config_dir)?;
-	let theme_toml = &Preset::theme(&config_dir)?;
+	let theme_toml = &Preset::theme(&config_dir, None, None)?;
+	*SOURCES.lock().unwrap() =
+		[yazi_toml.to_string(), keymap_toml.to_string(), theme_toml.to_string()];
 
 	LAYOUT.with(<_>::default);
 
+	DDS.init(<_>::from_str(yazi_toml)?);
 	KEYMAP.init(<_>::from_str(keymap_toml)?);
+	LOCALE.init(<_>::from_str(yazi_toml)?);
 	LOG.init(<_>::from_str(yazi_toml)?);
+	MACROS.init(<_>::from_str(yazi_toml)?);
 	MANAGER.init(<_>::from_str(yazi_toml)?);
+	yazi_shared::set_emoji_width(MANAGER.load().emoji_width);
 	OPEN.init(<_>::from_str(yazi_toml)?);
 	PLUGIN.init(<_>::from_str(yazi_toml)?);
 	PREVIEW.init(<_>::from_str(yazi_toml)?);
 	TASKS.init(<_>::from_str(yazi_toml)?);
 	THEME.init(<_>::from_str(theme_toml)?);
+	VCS.init(<_>::from_str(yazi_toml)?);
+	VFS.init(<_>::from_str(yazi_toml)?);
+	WATCHER.init(<_>::from_str(yazi_toml)?);
 	INPUT.init(<_>::from_str(yazi_toml)?);
 	SELECT.init(<_>::from_str(yazi_toml)?);
 	WHICH.init(<_>::from_str(yazi_toml)?);
 
 	// TODO: Remove in v0.3.2
-	for c in &KEYMAP.manager {
+	if popup::Select::legacy_overwrite(yazi_toml).is_some() {
+		eprintln!(
+			r#"WARNING: In Yazi v0.3, `overwrite_title`, `overwrite_origin` and `overwrite_offset` of `[input]` have been deprecated, the overwrite confirmation is a select now.
+
+Please move `overwrite_title` to the `[select]` section in your yazi.toml, and drop the `(y/N)` from it, while `overwrite_origin` and `overwrite_offset` are replaced by `confirm_origin` and `confirm_offset` of `[select]`."#
+		);
+	}
+
+	// TODO: Remove in v0.3.2
+	for c in &KEYMAP.load().manager {
 		for r in &c.run {
 			if r.name != "shell" {
 				continue;
@@ -82,3 +126,103 @@ Please replace e.g. `shell` with `shell --interactive`, `shell "my-template"` wi
 
 	Ok(())
 }
+
+// Replaces the theme with the one using the given flavor and icon profile
+pub fn reload_theme(flavor: Option<&str>, icons: Option<&str>) -> anyhow::Result<()> {
+	let theme_toml = Preset::theme(&Xdg::config_dir(), flavor, icons)?;
+	THEME.replace(<_>::from_str(&theme_toml)?);
+
+	SOURCES.lock().unwrap()[2] = theme_toml.into_owned();
+	*SWITCHED.lock().unwrap() = (flavor.map(str::to_owned), icons.map(str::to_owned));
+	Ok(())
+}
+
+#[derive(Default)]
+pub struct Reloaded {
+	pub yazi:   bool,
+	pub keymap: bool,
+	pub theme:  bool,
+}
+
+// Re-parses the config files and replaces the ones that have changed, all of them are
+// parsed before any replacement so that an invalid config doesn't get partially applied.
+pub fn reload() -> anyhow::Result<Reloaded> {
+	let config_dir = Xdg::config_dir();
+	let yazi_toml = Preset::yazi(&config_dir)?.into_owned();
+	let keymap_toml = Preset::keymap(&config_dir)?.into_owned();
+	let theme_toml = {
+		let (flavor, icons) = &*SWITCHED.lock().unwrap();
+		Preset::theme(&config_dir, flavor.as_deref(), icons.as_deref())?.into_owned()
+	};
+
+	let mut sources = SOURCES.lock().unwrap();
+	let r = Reloaded {
+		yazi:   sources[0] != yazi_toml,
+		keymap: sources[1] != keymap_toml,
+		theme:  sources[2] != theme_toml,
+	};
+
+	let yazi = if r.yazi {
+		Some((
+			locale::Locale::from_str(&yazi_toml)?,
+			macros::Macros::from_str(&yazi_toml)?,
+			manager::Manager::from_str(&yazi_toml)?,
+			open::Open::from_str(&yazi_toml)?,
+			plugin::Plugin::from_str(&yazi_toml)?,
+			preview::Preview::from_str(&yazi_toml)?,
+			tasks::Tasks::from_str(&yazi_toml)?,
+			vcs::Vcs::from_str(&yazi_toml)?,
+			vfs::Vfs::from_str(&yazi_toml)?,
+			watcher::Watcher::from_str(&yazi_toml)?,
+			popup::Input::from_str(&yazi_toml)?,
+			popup::Select::from_str(&yazi_toml)?,
+			which::Which::from_str(&yazi_toml)?,
+		))
+	} else {
+		None
+	};
+	let keymap = if r.keymap { Some(keymap::Keymap::from_str(&keymap_toml)?) } else { None };
+	let theme = if r.theme { Some(theme::Theme::from_str(&theme_toml)?) } else { None };
+
+	if let Some((
+		locale,
+		macros,
+		manager,
+		open,
+		plugin,
+		preview,
+		tasks,
+		vcs,
+		vfs,
+		watcher,
+		input,
+		select,
+		which,
+	)) = yazi
+	{
+		LOCALE.replace(locale);
+		MACROS.replace(macros);
+		MANAGER.replace(manager);
+		yazi_shared::set_emoji_width(MANAGER.load().emoji_width);
+		OPEN.replace(open);
+		PLUGIN.replace(plugin);
+		PREVIEW.replace(preview);
+		TASKS.replace(tasks);
+		VCS.replace(vcs);
+		VFS.replace(vfs);
+		WATCHER.replace(watcher);
+		INPUT.replace(input);
+		SELECT.replace(select);
+		WHICH.replace(which);
+	}
+	if let Some(keymap) = keymap {
+		keymap.inherit(&KEYMAP.load());
+		KEYMAP.replace(keymap);
+	}
+	if let Some(theme) = theme {
+		THEME.replace(theme);
+	}
+
+	*sources = [yazi_toml, keymap_toml, theme_toml];
+	Ok(r)
+}
@@ -0,0 +1,61 @@
+use std::{path::{Path, PathBuf}, sync::Arc};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use toml::Table;
+use yazi_shared::Xdg;
+
+use crate::{manager::SortBy, open::Open, Preset};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LocalManager {
+	// Sorting
+	pub sort_by:        Option<SortBy>,
+	pub sort_sensitive: Option<bool>,
+	pub sort_reverse:   Option<bool>,
+	pub sort_dir_first: Option<bool>,
+	pub sort_translit:  Option<bool>,
+
+	// Display
+	pub linemode:    Option<String>,
+	pub show_hidden: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct Local {
+	pub file:    PathBuf,
+	pub manager: LocalManager,
+	pub open:    Option<Arc<Open>>,
+}
+
+impl Local {
+	pub const NAME: &'static str = ".yazi.toml";
+
+	// The nearest `.yazi.toml` applies to its whole subtree.
+	pub fn find(dir: &Path) -> Option<PathBuf> {
+		dir.ancestors().map(|p| p.join(Self::NAME)).find(|p| p.is_file())
+	}
+
+	pub fn load(file: &Path, content: &str) -> Result<Self> {
+		let mut t: Table = content.parse().with_context(|| format!("Loading {file:?}"))?;
+
+		let manager = match t.remove("manager") {
+			Some(v) => v.try_into().with_context(|| format!("Invalid `[manager]` in {file:?}"))?,
+			None => LocalManager::default(),
+		};
+
+		// Openers are layered over the user's `yazi.toml`
+		let open = if t.contains_key("open") || t.contains_key("opener") {
+			let dir = Xdg::config_dir();
+			let base = Preset::yazi(&dir)?;
+			let merged = Preset::merge_str(&t.to_string(), &base)?;
+			Some(Arc::new(
+				merged.parse::<Open>().with_context(|| format!("Invalid openers in {file:?}"))?,
+			))
+		} else {
+			None
+		};
+
+		Ok(Self { file: file.to_owned(), manager, open })
+	}
+}
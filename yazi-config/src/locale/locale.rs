@@ -0,0 +1,92 @@
+use std::{collections::HashMap, env, fmt::{Display, Write}, str::FromStr};
+
+use serde::Deserialize;
+use yazi_shared::Xdg;
+
+// The translations of the user-facing strings, keyed by their English text in the gettext style,
+// so a message falls back to itself when it's not translated yet. The catalogs are read from
+// `<config>/locale/<language>.toml`, e.g. `zh.toml` then `zh_CN.toml`, as a `[messages]` table.
+#[derive(Debug, Default)]
+pub struct Locale {
+	pub language: String,
+	messages:     HashMap<String, String>,
+}
+
+impl FromStr for Locale {
+	type Err = toml::de::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			locale: Shadow,
+		}
+		#[derive(Deserialize)]
+		struct Shadow {
+			language: String,
+		}
+
+		let language = toml::from_str::<Outer>(s)?.locale.language;
+		let language = if language.is_empty() { Self::detect() } else { language };
+		Ok(Self { messages: Self::load(&language), language })
+	}
+}
+
+impl Locale {
+	#[inline]
+	pub fn get<'a>(&'a self, msg: &'a str) -> &'a str {
+		self.messages.get(msg).map_or(msg, |s| s.as_str())
+	}
+
+	pub fn fill(&self, msg: &str, args: &[(&str, &dyn Display)]) -> String {
+		let mut rest = self.get(msg);
+		let mut s = String::with_capacity(rest.len());
+		while let Some(i) = rest.find('{') {
+			s.push_str(&rest[..i]);
+			rest = &rest[i..];
+
+			let name = rest.find('}').map(|j| &rest[1..j]);
+			if let Some((n, value)) = args.iter().find(|&&(n, _)| Some(n) == name) {
+				write!(s, "{value}").ok();
+				rest = &rest[n.len() + 2..];
+			} else {
+				s.push('{');
+				rest = &rest[1..];
+			}
+		}
+		s.push_str(rest);
+		s
+	}
+
+	// From `LC_ALL`, `LC_MESSAGES` or `LANG`, e.g. `zh_CN.UTF-8` to `zh_CN`
+	fn detect() -> String {
+		let s = ["LC_ALL", "LC_MESSAGES", "LANG"].into_iter().find_map(|k| env::var(k).ok());
+		let s = s.filter(|s| !s.is_empty() && s != "C" && s != "POSIX").unwrap_or_default();
+		s.split(['.', '@']).next().unwrap_or_default().to_owned()
+	}
+
+	fn load(language: &str) -> HashMap<String, String> {
+		#[derive(Deserialize)]
+		struct Catalog {
+			#[serde(default)]
+			messages: HashMap<String, String>,
+		}
+
+		if language.is_empty() || language.starts_with("en") {
+			return Default::default();
+		}
+
+		let dir = Xdg::config_dir().join("locale");
+		let general = language.split(['_', '-']).next().unwrap_or(language);
+		let mut messages = HashMap::new();
+
+		// The general one first, so the regional one takes precedence
+		for name in [general, language] {
+			let Ok(s) = std::fs::read_to_string(dir.join(format!("{name}.toml"))) else { continue };
+			match toml::from_str::<Catalog>(&s) {
+				Ok(c) => messages.extend(c.messages),
+				Err(e) => eprintln!("Failed to parse the locale `{name}`: {e}"),
+			}
+		}
+		messages
+	}
+}
@@ -0,0 +1,18 @@
+mod locale;
+
+pub use locale::*;
+
+// Translates a message by its English text, with the `{name}` placeholders in it filled in
+// after the translation, e.g. `t!("Delete {target}", target = url)`.
+// The placeholders are filled in a single pass, so the values are never substituted again.
+#[macro_export]
+macro_rules! t {
+	($msg:literal) => {
+		$crate::LOCALE.load().get($msg).to_owned()
+	};
+	($msg:literal, $($name:ident = $value:expr),+ $(,)?) => {
+		$crate::LOCALE
+			.load()
+			.fill($msg, &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+])
+	};
+}
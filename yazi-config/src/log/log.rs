@@ -5,6 +5,9 @@ use serde::{Deserialize, Deserializer};
 #[derive(Debug)]
 pub struct Log {
 	pub enabled: bool,
+	pub level:   String,
+	// The number of the daily log files to keep
+	pub keep:    usize,
 }
 
 impl FromStr for Log {
@@ -25,10 +28,12 @@ impl<'de> Deserialize<'de> for Log {
 		#[derive(Deserialize)]
 		struct Shadow {
 			enabled: bool,
+			level:   String,
+			keep:    usize,
 		}
 
 		let outer = Outer::deserialize(deserializer)?;
 
-		Ok(Self { enabled: outer.log.enabled })
+		Ok(Self { enabled: outer.log.enabled, level: outer.log.level, keep: outer.log.keep.max(1) })
 	}
 }
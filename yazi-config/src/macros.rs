@@ -0,0 +1,106 @@
+use std::{collections::{HashMap, VecDeque}, str::FromStr, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+use serde::Deserialize;
+use yazi_shared::{event::{Cmd, Data}, shell};
+
+use crate::keymap::Control;
+
+#[derive(Debug, Default)]
+pub struct Macros {
+	inner:    HashMap<String, Vec<String>>,
+	controls: Vec<Arc<Control>>,
+}
+
+impl Macros {
+	// Expands a call to the macro into its commands, where `$1` to `$9` are replaced with
+	// the positional arguments of the call, and `$@` with all of them.
+	pub fn expand(&self, cmd: &Cmd) -> Option<Result<VecDeque<Cmd>>> {
+		let run = self.inner.get(&cmd.name)?;
+
+		let mut args = vec![];
+		while let Some(Data::String(s)) = cmd.get(&args.len().to_string()) {
+			args.push(s.as_str());
+		}
+
+		Some(run.iter().map(|s| Cmd::from_str(&Self::substitute(s, &args))).collect())
+	}
+
+	#[inline]
+	pub fn contains(&self, name: &str) -> bool { self.inner.contains_key(name) }
+
+	// Macros are listed in the help of the manager, as bindings without keys.
+	#[inline]
+	pub fn controls(&self) -> impl Iterator<Item = &Arc<Control>> { self.controls.iter() }
+
+	fn substitute(s: &str, args: &[&str]) -> String {
+		let mut out = String::with_capacity(s.len());
+		let mut chars = s.chars().peekable();
+		while let Some(c) = chars.next() {
+			if c != '$' {
+				out.push(c);
+				continue;
+			}
+
+			match chars.peek().copied() {
+				Some('@') => {
+					chars.next();
+					let all: Vec<_> = args.iter().map(|a| shell::escape_unix(a)).collect();
+					out.push_str(&all.join(" "));
+				}
+				Some(d @ '1'..='9') => {
+					chars.next();
+					if let Some(a) = args.get(d as usize - '1' as usize) {
+						out.push_str(&shell::escape_unix(a));
+					}
+				}
+				_ => out.push(c),
+			}
+		}
+		out
+	}
+}
+
+impl FromStr for Macros {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			#[serde(default, rename = "macro")]
+			macros: HashMap<String, Shadow>,
+		}
+		#[derive(Deserialize)]
+		struct Shadow {
+			run:  Vec<String>,
+			desc: Option<String>,
+		}
+
+		let outer = toml::from_str::<Outer>(s)?;
+		let (mut inner, mut controls) = (HashMap::new(), vec![]);
+		for (name, shadow) in &outer.macros {
+			if name.is_empty() || name.contains(char::is_whitespace) {
+				bail!("invalid macro name `{name}`");
+			}
+
+			// Macros calling each other could end up in a loop, so it's not allowed
+			for s in &shadow.run {
+				let cmd = Cmd::from_str(&Self::substitute(s, &[]))
+					.map_err(|e| anyhow!("invalid command `{s}` in macro `{name}`: {e}"))?;
+				if outer.macros.contains_key(&cmd.name) {
+					bail!("macro `{name}` cannot call another macro `{}`", cmd.name);
+				}
+			}
+
+			controls.push(Arc::new(Control {
+				run: vec![Cmd::new(name)],
+				desc: shadow.desc.clone().or_else(|| Some(shadow.run.join("; "))),
+				..Default::default()
+			}));
+			inner.insert(name.clone(), shadow.run.clone());
+		}
+
+		controls.sort_unstable_by(|a, b| a.run[0].name.cmp(&b.run[0].name));
+		Ok(Self { inner, controls })
+	}
+}
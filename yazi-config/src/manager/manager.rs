@@ -3,7 +3,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-use super::{ManagerRatio, MouseEvents, SortBy};
+use super::{ManagerRatio, MouseEvents, SendTo, SortBy};
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct Manager {
@@ -20,10 +20,44 @@ pub struct Manager {
 	#[validate(length(min = 1, max = 20, message = "must be between 1 and 20 characters"))]
 	pub linemode:     String,
 	pub show_hidden:  bool,
+	pub show_ignored: bool,
 	pub show_symlink: bool,
 	pub scrolloff:    u8,
 	pub mouse_events: MouseEvents,
 	pub title_format: String,
+	pub local_config: bool,
+
+	// Minutes within which a modification makes the file `is = "recent"`, 0 to disable
+	pub recent_within: u64,
+	// Whether `recent://` also lists the ones from the `recently-used.xbel` of other apps
+	pub recent_xbel:   bool,
+
+	// Whether to fall back to OSC 52 for the clipboard, when none of the tools are available
+	pub osc52: bool,
+
+	// Whether to warn about the names invalid on Windows when creating or renaming files
+	pub portable_names: bool,
+
+	// The columns the emoji take, as the terminal draws them
+	#[validate(range(min = 1, max = 2, message = "must be either 1 or 2"))]
+	pub emoji_width: u8,
+
+	// The most results of the fetchers kept in the cache across sessions, 0 to disable it
+	pub fetch_cache: usize,
+
+	// Whether to calculate the sizes of the directories for the `size` linemode too,
+	// not only when sorting by size
+	pub calculate_sizes: bool,
+
+	// Whether `paste` goes into the hovered directory instead of the cwd, as `paste --smart` does
+	pub smart_paste: bool,
+
+	// The command `open_terminal` runs, with the directory as `$1` (`%1` on Windows),
+	// or empty to detect the terminal emulator
+	pub terminal: String,
+
+	// The destinations `send_to` copies or moves the selected files to
+	pub send_to: Vec<SendTo>,
 }
 
 impl FromStr for Manager {
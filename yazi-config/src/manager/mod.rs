@@ -1,9 +1,11 @@
 mod manager;
 mod mouse;
 mod ratio;
+mod send_to;
 mod sorting;
 
 pub use manager::*;
 pub use mouse::*;
 pub use ratio::*;
+pub use send_to::*;
 pub use sorting::*;
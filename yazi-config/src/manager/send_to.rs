@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::{expand_path, Url};
+
+// A destination of `send_to`, picked by its `key` or by the 1-based index of it
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SendTo {
+	pub name: String,
+	pub path: String,
+	#[serde(default)]
+	pub key:  Option<char>,
+}
+
+impl SendTo {
+	pub fn url(&self) -> Url {
+		let mut url = Url::from(self.path.as_str());
+		if url.is_regular() {
+			url.set_path(expand_path(&url));
+		}
+		url
+	}
+}
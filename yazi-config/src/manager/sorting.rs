@@ -15,6 +15,7 @@ pub enum SortBy {
 	Natural,
 	Size,
 	Random,
+	Vcs,
 }
 
 impl FromStr for SortBy {
@@ -30,6 +31,7 @@ impl FromStr for SortBy {
 			"natural" => Self::Natural,
 			"size" => Self::Size,
 			"random" => Self::Random,
+			"vcs" => Self::Vcs,
 			_ => bail!("invalid sort_by value: {s}"),
 		})
 	}
@@ -52,6 +54,7 @@ impl Display for SortBy {
 			Self::Natural => "natural",
 			Self::Size => "size",
 			Self::Random => "random",
+			Self::Vcs => "vcs",
 		})
 	}
 }
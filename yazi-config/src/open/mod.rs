@@ -1,7 +1,9 @@
 mod open;
 mod opener;
+mod output;
 mod rule;
 
 pub use open::*;
 pub use opener::*;
+pub use output::*;
 use rule::*;
@@ -9,8 +9,8 @@ use crate::{open::OpenRule, Preset};
 
 #[derive(Debug)]
 pub struct Open {
-	rules:   Vec<OpenRule>,
-	openers: HashMap<String, IndexSet<Opener>>,
+	pub(crate) rules:   Vec<OpenRule>,
+	pub(crate) openers: HashMap<String, IndexSet<Opener>>,
 }
 
 impl Open {
@@ -47,6 +47,12 @@ impl Open {
 		self.openers(path, mime).and_then(|o| o.into_iter().find(|o| o.block))
 	}
 
+	// The first one of the opener available on this platform, regardless of the rules
+	#[inline]
+	pub fn named(&self, name: &str) -> Option<&Opener> {
+		self.openers.get(name).and_then(|o| o.first())
+	}
+
 	pub fn common_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<&Opener> {
 		let grouped: Vec<_> = targets.iter().filter_map(|(p, m)| self.openers(p, m)).collect();
 		let flat: IndexSet<_> = grouped.iter().flatten().copied().collect();
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Deserializer};
 
+use super::OpenerOutput;
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Opener {
 	pub run:    String,
 	pub block:  bool,
 	pub orphan: bool,
+	pub output: OpenerOutput,
 	pub desc:   String,
 	pub for_:   Option<String>,
 	pub spread: bool,
@@ -37,6 +40,8 @@ impl<'de> Deserialize<'de> for Opener {
 			block:  bool,
 			#[serde(default)]
 			orphan: bool,
+			#[serde(default)]
+			output: OpenerOutput,
 			desc:   Option<String>,
 			#[serde(rename = "for")]
 			for_:   Option<String>,
@@ -52,6 +57,14 @@ impl<'de> Deserialize<'de> for Opener {
 		let desc = shadow.desc.unwrap_or_else(|| run.split_whitespace().next().unwrap().to_string());
 
 		let spread = run.contains("$@") || run.contains("%*") || run.contains("$*");
-		Ok(Self { run, block: shadow.block, orphan: shadow.orphan, desc, for_: shadow.for_, spread })
+		Ok(Self {
+			run,
+			block: shadow.block,
+			orphan: shadow.orphan,
+			output: shadow.output,
+			desc,
+			for_: shadow.for_,
+			spread,
+		})
 	}
 }
@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String")]
+pub enum OpenerOutput {
+	#[default]
+	None,
+	Notify,
+}
+
+impl FromStr for OpenerOutput {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"none" => Self::None,
+			"notify" => Self::Notify,
+			_ => bail!("invalid opener output: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for OpenerOutput {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
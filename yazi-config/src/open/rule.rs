@@ -5,12 +5,12 @@ use serde::{de::{self, Visitor}, Deserialize, Deserializer};
 use crate::pattern::Pattern;
 
 #[derive(Debug, Deserialize)]
-pub(super) struct OpenRule {
-	pub(super) name: Option<Pattern>,
-	pub(super) mime: Option<Pattern>,
+pub(crate) struct OpenRule {
+	pub(crate) name: Option<Pattern>,
+	pub(crate) mime: Option<Pattern>,
 	#[serde(rename = "use")]
 	#[serde(deserialize_with = "OpenRule::deserialize")]
-	pub(super) use_: Vec<String>,
+	pub(crate) use_: Vec<String>,
 }
 
 impl OpenRule {
@@ -1,14 +1,16 @@
-use std::path::Path;
+use std::{fmt::Display, path::Path};
 
-use globset::GlobBuilder;
+use globset::{Candidate, GlobBuilder, GlobSet, GlobSetBuilder};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 #[serde(try_from = "String")]
 pub struct Pattern {
+	glob:    globset::Glob,
 	inner:   globset::GlobMatcher,
 	is_dir:  bool,
 	is_star: bool,
+	raw:     String,
 }
 
 impl Pattern {
@@ -27,6 +29,9 @@ impl Pattern {
 
 	#[inline]
 	pub fn any_dir(&self) -> bool { self.is_star && self.is_dir }
+
+	#[inline]
+	pub fn is_dir(&self) -> bool { self.is_dir }
 }
 
 impl TryFrom<&str> for Pattern {
@@ -36,15 +41,20 @@ impl TryFrom<&str> for Pattern {
 		let a = s.trim_start_matches("\\s");
 		let b = a.trim_end_matches('/');
 
-		let inner = GlobBuilder::new(b)
+		let glob = GlobBuilder::new(b)
 			.case_insensitive(a.len() == s.len())
 			.literal_separator(false)
 			.backslash_escape(false)
 			.empty_alternates(true)
-			.build()?
-			.compile_matcher();
+			.build()?;
 
-		Ok(Self { inner, is_dir: b.len() < a.len(), is_star: b == "*" })
+		Ok(Self {
+			inner: glob.compile_matcher(),
+			glob,
+			is_dir: b.len() < a.len(),
+			is_star: b == "*",
+			raw: s.to_owned(),
+		})
 	}
 }
 
@@ -53,3 +63,55 @@ impl TryFrom<String> for Pattern {
 
 	fn try_from(s: String) -> Result<Self, Self::Error> { Self::try_from(s.as_str()) }
 }
+
+impl Display for Pattern {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(&self.raw) }
+}
+
+// The patterns compiled into one matcher, which looks up the extensions and literal names
+// in a hash table, and the rest with an Aho-Corasick automaton, instead of one by one.
+#[derive(Debug)]
+pub struct PatternSet {
+	files:    GlobSet,
+	dirs:     GlobSet,
+	// The rule indices of the patterns in each set
+	file_ids: Vec<usize>,
+	dir_ids:  Vec<usize>,
+}
+
+impl PatternSet {
+	// Each of the patterns comes with the index of the rule it belongs to
+	pub fn new<'a>(patterns: impl IntoIterator<Item = (usize, &'a Pattern)>) -> anyhow::Result<Self> {
+		let (mut files, mut dirs) = (GlobSetBuilder::new(), GlobSetBuilder::new());
+		let (mut file_ids, mut dir_ids) = (vec![], vec![]);
+		for (i, p) in patterns {
+			if p.is_dir {
+				dirs.add(p.glob.clone());
+				dir_ids.push(i);
+			} else {
+				files.add(p.glob.clone());
+				file_ids.push(i);
+			}
+		}
+		Ok(Self { files: files.build()?, dirs: dirs.build()?, file_ids, dir_ids })
+	}
+
+	// The rule indices of all the patterns matching the path, in ascending order
+	#[inline]
+	pub fn matches(&self, path: impl AsRef<Path>, is_dir: bool) -> Vec<usize> {
+		let (set, ids) =
+			if is_dir { (&self.dirs, &self.dir_ids) } else { (&self.files, &self.file_ids) };
+		set.matches_candidate(&Candidate::new(path.as_ref())).into_iter().map(|i| ids[i]).collect()
+	}
+
+	// Mime types have no directory patterns, since a directory is also `inode/directory`
+	#[inline]
+	pub fn matches_mime(&self, mime: &str) -> Vec<usize> {
+		self.files.matches(mime).into_iter().map(|i| self.file_ids[i]).collect()
+	}
+
+	#[inline]
+	pub fn first(&self, path: impl AsRef<Path>, is_dir: bool) -> Option<usize> {
+		self.matches(path, is_dir).first().copied()
+	}
+}
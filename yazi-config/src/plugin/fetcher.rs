@@ -21,8 +21,18 @@ pub struct Fetcher {
 }
 
 impl Fetcher {
+	// Besides the factors, `unix`, `windows`, `linux` and `macos` in the condition
+	// are true on the platform they stand for, for the fetchers only available there.
 	#[inline]
 	pub fn matches(&self, path: &Path, mime: Option<&str>, f: impl Fn(&str) -> bool + Copy) -> bool {
+		let f = |s: &str| match s {
+			"unix" => cfg!(unix),
+			"windows" => cfg!(windows),
+			"linux" => cfg!(target_os = "linux"),
+			"macos" => cfg!(target_os = "macos"),
+			_ => f(s),
+		};
+
 		self.if_.as_ref().and_then(|c| c.eval(f)) != Some(false)
 			&& (self.mime.as_ref().zip(mime).map_or(false, |(p, m)| p.match_mime(m))
 				|| self.name.as_ref().is_some_and(|p| p.match_path(path, mime == Some(MIME_DIR))))
@@ -13,6 +13,11 @@ pub struct Input {
 	pub cd_origin: Origin,
 	pub cd_offset: Offset,
 
+	// paste to
+	pub paste_to_title:  [String; 2],
+	pub paste_to_origin: Origin,
+	pub paste_to_offset: Offset,
+
 	// create
 	pub create_title:  String,
 	pub create_origin: Origin,
@@ -23,6 +28,11 @@ pub struct Input {
 	pub rename_origin: Origin,
 	pub rename_offset: Offset,
 
+	// edit link
+	pub edit_link_title:  String,
+	pub edit_link_origin: Origin,
+	pub edit_link_offset: Offset,
+
 	// trash
 	pub trash_title:  String,
 	pub trash_origin: Origin,
@@ -53,15 +63,35 @@ pub struct Input {
 	pub shell_origin: Origin,
 	pub shell_offset: Offset,
 
-	// overwrite
-	pub overwrite_title:  String,
-	pub overwrite_origin: Origin,
-	pub overwrite_offset: Offset,
-
 	// quit
 	pub quit_title:  String,
 	pub quit_origin: Origin,
 	pub quit_offset: Offset,
+
+	// permit
+	pub permit_title:  String,
+	pub permit_origin: Origin,
+	pub permit_offset: Offset,
+
+	// trust
+	pub trust_title:  String,
+	pub trust_origin: Origin,
+	pub trust_offset: Offset,
+
+	// passphrase
+	pub passphrase_title:  [String; 2],
+	pub passphrase_origin: Origin,
+	pub passphrase_offset: Offset,
+
+	// touch
+	pub touch_title:  String,
+	pub touch_origin: Origin,
+	pub touch_offset: Offset,
+
+	// bookmark
+	pub bookmark_title:  String,
+	pub bookmark_origin: Origin,
+	pub bookmark_offset: Offset,
 }
 
 impl Input {
@@ -1,5 +1,14 @@
-use super::{Offset, Position};
-use crate::{INPUT, SELECT};
+use super::{Offset, Origin, Position};
+use crate::{t, INPUT, LOCALE, SELECT};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputCompletion {
+	#[default]
+	None,
+	Path,
+	Shell,
+	Destination,
+}
 
 #[derive(Default)]
 pub struct InputCfg {
@@ -8,120 +17,208 @@ pub struct InputCfg {
 	pub cursor:     Option<usize>,
 	pub position:   Position,
 	pub realtime:   bool,
-	pub completion: bool,
+	pub completion: InputCompletion,
 	pub highlight:  bool,
+	pub obscure:    bool,
+	pub history:    &'static str,
 }
 
 #[derive(Default)]
 pub struct SelectCfg {
 	pub title:    String,
 	pub items:    Vec<String>,
+	pub keys:     Vec<char>,
+	pub multi:    bool,
+	pub filter:   bool,
 	pub position: Position,
 }
 
 impl InputCfg {
 	#[inline]
 	pub fn cd() -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.cd_title.to_owned(),
-			position: Position::new(INPUT.cd_origin, INPUT.cd_offset),
-			completion: true,
+			title: LOCALE.load().get(&input.cd_title).to_owned(),
+			position: Position::new(input.cd_origin, input.cd_offset),
+			completion: InputCompletion::Path,
+			history: "cd",
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn paste_to(cut: bool) -> Self {
+		let input = INPUT.load();
+		Self {
+			title: LOCALE.load().get(&input.paste_to_title[cut as usize]).to_owned(),
+			position: Position::new(input.paste_to_origin, input.paste_to_offset),
+			completion: InputCompletion::Destination,
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn create() -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.create_title.to_owned(),
-			position: Position::new(INPUT.create_origin, INPUT.create_offset),
+			title: LOCALE.load().get(&input.create_title).to_owned(),
+			position: Position::new(input.create_origin, input.create_offset),
+			history: "create",
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn rename() -> Self {
+		let input = INPUT.load();
+		Self {
+			title: LOCALE.load().get(&input.rename_title).to_owned(),
+			position: Position::new(input.rename_origin, input.rename_offset),
+			history: "rename",
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn edit_link() -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.rename_title.to_owned(),
-			position: Position::new(INPUT.rename_origin, INPUT.rename_offset),
+			title: LOCALE.load().get(&input.edit_link_title).to_owned(),
+			position: Position::new(input.edit_link_origin, input.edit_link_offset),
+			completion: InputCompletion::Path,
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn trash(n: usize) -> Self {
-		let title = INPUT.trash_title.replace("{n}", &n.to_string());
+		let input = INPUT.load();
+		let s = if n > 1 { "s" } else { "" };
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
-			position: Position::new(INPUT.trash_origin, INPUT.trash_offset),
+			title: LOCALE.load().fill(&input.trash_title, &[("n", &n), ("s", &s)]),
+			position: Position::new(input.trash_origin, input.trash_offset),
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn delete(n: usize) -> Self {
-		let title = INPUT.delete_title.replace("{n}", &n.to_string());
+		let input = INPUT.load();
+		let s = if n > 1 { "s" } else { "" };
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
-			position: Position::new(INPUT.delete_origin, INPUT.delete_offset),
+			title: LOCALE.load().fill(&input.delete_title, &[("n", &n), ("s", &s)]),
+			position: Position::new(input.delete_origin, input.delete_offset),
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn filter() -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.filter_title.to_owned(),
-			position: Position::new(INPUT.filter_origin, INPUT.filter_offset),
+			title: LOCALE.load().get(&input.filter_title).to_owned(),
+			position: Position::new(input.filter_origin, input.filter_offset),
 			realtime: true,
+			history: "filter",
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn find(prev: bool) -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.find_title[prev as usize].to_owned(),
-			position: Position::new(INPUT.find_origin, INPUT.find_offset),
+			title: LOCALE.load().get(&input.find_title[prev as usize]).to_owned(),
+			position: Position::new(input.find_origin, input.find_offset),
 			realtime: true,
+			history: "find",
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn search(name: &str) -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.search_title.replace("{n}", name),
-			position: Position::new(INPUT.search_origin, INPUT.search_offset),
+			title: LOCALE.load().fill(&input.search_title, &[("n", &name)]),
+			position: Position::new(input.search_origin, input.search_offset),
+			history: "search",
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn shell(block: bool) -> Self {
+		let input = INPUT.load();
 		Self {
-			title: INPUT.shell_title[block as usize].to_owned(),
-			position: Position::new(INPUT.shell_origin, INPUT.shell_offset),
+			title: LOCALE.load().get(&input.shell_title[block as usize]).to_owned(),
+			position: Position::new(input.shell_origin, input.shell_offset),
+			completion: InputCompletion::Shell,
 			highlight: true,
+			history: "shell",
 			..Default::default()
 		}
 	}
 
 	#[inline]
-	pub fn overwrite() -> Self {
+	pub fn quit(n: usize) -> Self {
+		let input = INPUT.load();
+		let s = if n > 1 { "s" } else { "" };
 		Self {
-			title: INPUT.overwrite_title.to_owned(),
-			position: Position::new(INPUT.overwrite_origin, INPUT.overwrite_offset),
+			title: LOCALE.load().fill(&input.quit_title, &[("n", &n), ("s", &s)]),
+			position: Position::new(input.quit_origin, input.quit_offset),
 			..Default::default()
 		}
 	}
 
 	#[inline]
-	pub fn quit(n: usize) -> Self {
-		let title = INPUT.quit_title.replace("{n}", &n.to_string());
+	pub fn permit(plugin: &str, desc: &str) -> Self {
+		let input = INPUT.load();
+		Self {
+			title: LOCALE.load().fill(&input.permit_title, &[("plugin", &plugin), ("desc", &desc)]),
+			position: Position::new(input.permit_origin, input.permit_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn trust(file: &str) -> Self {
+		let input = INPUT.load();
+		Self {
+			title: LOCALE.load().fill(&input.trust_title, &[("file", &file)]),
+			position: Position::new(input.trust_origin, input.trust_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn passphrase(confirm: bool) -> Self {
+		let input = INPUT.load();
+		Self {
+			title: LOCALE.load().get(&input.passphrase_title[confirm as usize]).to_owned(),
+			position: Position::new(input.passphrase_origin, input.passphrase_offset),
+			obscure: true,
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn touch() -> Self {
+		let input = INPUT.load();
+		Self {
+			title: LOCALE.load().get(&input.touch_title).to_owned(),
+			position: Position::new(input.touch_origin, input.touch_offset),
+			history: "touch",
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn bookmark() -> Self {
+		let input = INPUT.load();
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
-			position: Position::new(INPUT.quit_origin, INPUT.quit_offset),
+			title: LOCALE.load().get(&input.bookmark_title).to_owned(),
+			position: Position::new(input.bookmark_origin, input.bookmark_offset),
 			..Default::default()
 		}
 	}
@@ -137,24 +234,145 @@ impl InputCfg {
 		self.cursor = cursor;
 		self
 	}
+
+	#[inline]
+	pub fn with_inline(mut self, width: u16) -> Self {
+		self.position = Position::new(Origin::Inline, Offset { width, ..Offset::line() });
+		self
+	}
 }
 
 impl SelectCfg {
 	#[inline]
-	fn max_height(len: usize) -> u16 {
-		SELECT.open_offset.height.min(SELECT.border().saturating_add(len as u16))
+	fn max_height(offset: Offset, len: usize) -> u16 {
+		offset.height.min(SELECT.load().border().saturating_add(len as u16))
 	}
 
 	#[inline]
 	pub fn open(items: Vec<String>) -> Self {
-		let max_height = Self::max_height(items.len());
+		let select = SELECT.load();
+		let max_height = Self::max_height(select.open_offset, items.len());
+		Self {
+			title: LOCALE.load().get(&select.open_title).to_owned(),
+			items,
+			position: Position::new(select.open_origin, Offset {
+				height: max_height,
+				..select.open_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn notify(items: Vec<String>) -> Self {
+		let select = SELECT.load();
+		let max_height = Self::max_height(select.notify_offset, items.len());
+		Self {
+			title: LOCALE.load().get(&select.notify_title).to_owned(),
+			items,
+			position: Position::new(select.notify_origin, Offset {
+				height: max_height,
+				..select.notify_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn find() -> Self {
+		let select = SELECT.load();
+		Self {
+			title: LOCALE.load().get(&select.find_title).to_owned(),
+			filter: true,
+			position: Position::new(select.find_origin, select.find_offset),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn flavor(items: Vec<String>) -> Self {
+		let select = SELECT.load();
+		let max_height = Self::max_height(select.flavor_offset, items.len());
+		Self {
+			title: LOCALE.load().get(&select.flavor_title).to_owned(),
+			items,
+			position: Position::new(select.flavor_origin, Offset {
+				height: max_height,
+				..select.flavor_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn device(items: Vec<String>) -> Self {
+		let select = SELECT.load();
+		let max_height = Self::max_height(select.device_offset, items.len());
 		Self {
-			title: SELECT.open_title.to_owned(),
+			title: LOCALE.load().get(&select.device_title).to_owned(),
 			items,
-			position: Position::new(SELECT.open_origin, Offset {
+			position: Position::new(select.device_origin, Offset {
 				height: max_height,
-				..SELECT.open_offset
+				..select.device_offset
 			}),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn bookmarks(delete: bool, items: Vec<String>) -> Self {
+		let select = SELECT.load();
+		let max_height = Self::max_height(select.bookmarks_offset, items.len());
+		Self {
+			title: LOCALE.load().get(&select.bookmarks_title[delete as usize]).to_owned(),
+			items,
+			filter: true,
+			position: Position::new(select.bookmarks_origin, Offset {
+				height: max_height,
+				..select.bookmarks_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	// Each choice can be picked with its key directly, which defaults to its 1-based index.
+	pub fn confirm(title: impl Into<String>, choices: Vec<(Option<char>, String)>) -> Self {
+		let select = SELECT.load();
+		let max_height = Self::max_height(select.confirm_offset, choices.len());
+		let (keys, items) = choices
+			.into_iter()
+			.enumerate()
+			.map(|(i, (k, s))| (k.or_else(|| char::from_digit(i as u32 + 1, 10)).unwrap_or(' '), s))
+			.unzip();
+
+		Self {
+			title: title.into(),
+			items,
+			keys,
+			position: Position::new(select.confirm_origin, Offset {
+				height: max_height,
+				..select.confirm_offset
+			}),
+			..Default::default()
 		}
 	}
+
+	#[inline]
+	pub fn overwrite() -> Self {
+		Self::confirm(LOCALE.load().get(&SELECT.load().overwrite_title), vec![
+			(Some('o'), t!("Overwrite")),
+			(Some('b'), t!("Keep both")),
+			(Some('c'), t!("Cancel")),
+		])
+	}
+
+	#[inline]
+	pub fn unportable(reason: &str, sanitized: &str) -> Self {
+		let title = LOCALE.load().fill(&SELECT.load().unportable_title, &[("reason", &reason)]);
+		Self::confirm(title, vec![
+			(Some('s'), t!("Rename to `{name}`", name = sanitized)),
+			(Some('n'), t!("Keep it")),
+			(Some('c'), t!("Cancel")),
+		])
+	}
 }
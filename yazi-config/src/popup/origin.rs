@@ -17,6 +17,7 @@ pub enum Origin {
 
 	Center,
 	Hovered,
+	Inline,
 }
 
 impl FromStr for Origin {
@@ -34,6 +35,7 @@ impl FromStr for Origin {
 
 			"center" => Self::Center,
 			"hovered" => Self::Hovered,
+			"inline" => Self::Inline,
 			_ => bail!("Invalid `origin` value: {s}"),
 		})
 	}
@@ -58,6 +60,7 @@ impl Display for Origin {
 
 			Self::Center => "center",
 			Self::Hovered => "hovered",
+			Self::Inline => "inline",
 		})
 	}
 }
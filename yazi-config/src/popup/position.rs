@@ -24,7 +24,7 @@ impl Position {
 				(columns / 2).saturating_sub(width / 2).saturating_add_signed(x).clamp(0, max_x)
 			}
 			TopRight | BottomRight => max_x.saturating_add_signed(x).clamp(0, max_x),
-			Hovered => unreachable!(),
+			Hovered | Inline => unreachable!(),
 		};
 
 		let max_y = rows.saturating_sub(height);
@@ -32,7 +32,7 @@ impl Position {
 			TopLeft | TopCenter | TopRight => y.clamp(0, max_y as i16) as u16,
 			Center => (max_y / 2).saturating_sub(height / 2).saturating_add_signed(y).clamp(0, max_y),
 			BottomLeft | BottomCenter | BottomRight => max_y.saturating_add_signed(y).clamp(0, max_y),
-			Hovered => unreachable!(),
+			Hovered | Inline => unreachable!(),
 		};
 
 		Rect {
@@ -10,10 +10,62 @@ pub struct Select {
 	pub open_title:  String,
 	pub open_origin: Origin,
 	pub open_offset: Offset,
+
+	// flavor
+	pub flavor_title:  String,
+	pub flavor_origin: Origin,
+	pub flavor_offset: Offset,
+
+	// device
+	pub device_title:  String,
+	pub device_origin: Origin,
+	pub device_offset: Offset,
+
+	// find
+	pub find_title:  String,
+	pub find_origin: Origin,
+	pub find_offset: Offset,
+
+	// notify
+	pub notify_title:  String,
+	pub notify_origin: Origin,
+	pub notify_offset: Offset,
+
+	// bookmarks
+	pub bookmarks_title:  [String; 2],
+	pub bookmarks_origin: Origin,
+	pub bookmarks_offset: Offset,
+
+	// confirm
+	pub confirm_origin: Origin,
+	pub confirm_offset: Offset,
+
+	// overwrite
+	pub overwrite_title: String,
+
+	// unportable
+	pub unportable_title: String,
 }
 
 impl Select {
 	pub const fn border(&self) -> u16 { 2 }
+
+	// TODO: Remove in v0.3.2
+	// The `overwrite_title` of `[input]`, for the configs written before it moved to `[select]`.
+	pub(crate) fn legacy_overwrite(s: &str) -> Option<String> {
+		#[derive(Deserialize)]
+		struct Outer {
+			#[serde(default)]
+			input: Legacy,
+		}
+		#[derive(Default, Deserialize)]
+		struct Legacy {
+			overwrite_title: Option<String>,
+		}
+
+		let title = toml::from_str::<Outer>(s).ok()?.input.overwrite_title?;
+		Some(title.trim_end().trim_end_matches("(y/N)").trim_end().to_owned())
+	}
 }
 
 impl FromStr for Select {
@@ -25,6 +77,10 @@ impl FromStr for Select {
 			select: Select,
 		}
 
-		Ok(toml::from_str::<Outer>(s)?.select)
+		let mut select = toml::from_str::<Outer>(s)?.select;
+		if let Some(title) = Self::legacy_overwrite(s) {
+			select.overwrite_title = title;
+		}
+		Ok(select)
 	}
 }
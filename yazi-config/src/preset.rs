@@ -1,9 +1,9 @@
 use std::{borrow::Cow, mem, path::{Path, PathBuf}};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use toml::{Table, Value};
 
-use crate::theme::Flavor;
+use crate::{theme::Flavor, Target};
 
 pub(crate) struct Preset;
 
@@ -16,10 +16,30 @@ impl Preset {
 		Self::merge_path(p.join("keymap.toml"), include_str!("../preset/keymap.toml"))
 	}
 
-	pub(crate) fn theme(p: &Path) -> Result<Cow<str>> {
-		let Ok(user) = std::fs::read_to_string(p.join("theme.toml")) else {
+	// The `flavor` and the icon `profile` override the ones in the user's `theme.toml` if any.
+	pub(crate) fn theme(
+		p: &Path,
+		flavor: Option<&str>,
+		icons: Option<&str>,
+	) -> Result<Cow<'static, str>> {
+		let mut user = Self::read_user(&p.join("theme.toml"))?;
+		if flavor.is_some() || icons.is_some() {
+			let mut t: Table = user.parse()?;
+			for (section, key, value) in [("flavor", "use", flavor), ("icon", "profile", icons)] {
+				let Some(value) = value else { continue };
+				if let Some(s) = t.entry(section).or_insert(Value::Table(Table::new())).as_table_mut() {
+					s.insert(key.to_owned(), Value::String(value.to_owned()));
+				}
+			}
+			user = t.to_string();
+		}
+		if user.is_empty() {
 			return Ok(include_str!("../preset/theme.toml").into());
-		};
+		}
+		if user.contains("extends") {
+			user = Self::extends(p, user.parse()?, 0)?.to_string();
+		}
+
 		let Some(use_) = Flavor::parse_use(&user) else {
 			return Self::merge_str(&user, include_str!("../preset/theme.toml"));
 		};
@@ -31,6 +51,28 @@ impl Preset {
 		Self::merge_str(&user, &Self::merge_str(&flavor, include_str!("../preset/theme.toml"))?)
 	}
 
+	// Resolves the chain of `extends = "<flavor>"`, where the keys of the extending
+	// theme are deep-merged over the ones of the flavor, down to each style attribute.
+	fn extends(p: &Path, mut t: Table, depth: u8) -> Result<Table> {
+		let name = match t.remove("extends") {
+			Some(Value::String(s)) => s,
+			Some(_) => bail!("`extends` must be a string"),
+			None => return Ok(t),
+		};
+		if depth >= 10 {
+			bail!("Too many levels of `extends`, is there a cycle at flavor `{name}`?");
+		}
+
+		let path = p.join(format!("flavors/{name}.yazi/flavor.toml"));
+		let base = std::fs::read_to_string(&path)
+			.with_context(|| anyhow!("Failed to load flavor {path:?}"))?
+			.parse()
+			.with_context(|| anyhow!("Loading {path:?}"))?;
+
+		Self::merge(&mut t, Self::extends(p, base, depth + 1)?, u8::MAX);
+		Ok(t)
+	}
+
 	#[inline]
 	pub(crate) fn mix<T>(a: &mut Vec<T>, b: Vec<T>, c: Vec<T>) {
 		*a = b.into_iter().chain(mem::take(a)).chain(c).collect();
@@ -46,7 +88,7 @@ impl Preset {
 
 	#[inline]
 	fn merge_path(user: PathBuf, base: &str) -> Result<Cow<str>> {
-		let s = std::fs::read_to_string(&user).unwrap_or_default();
+		let s = Self::read_user(&user)?;
 		if s.is_empty() {
 			return Ok(base.into());
 		}
@@ -54,6 +96,147 @@ impl Preset {
 		Self::merge_str(&s, base).with_context(|| anyhow!("Loading {user:?}"))
 	}
 
+	// Reads the user's config file, with the files listed in its `include` layered beneath it.
+	fn read_user(path: &Path) -> Result<String> {
+		let s = std::fs::read_to_string(path).unwrap_or_default();
+		if !s.contains("include") && !s.contains("target") && !s.contains("${") {
+			return Ok(s);
+		}
+
+		let mut top: Table = s.parse().with_context(|| anyhow!("Loading {path:?}"))?;
+		Self::resolve(&mut top).with_context(|| anyhow!("Loading {path:?}"))?;
+		let Some(include) = top.remove("include") else {
+			return Ok(top.to_string());
+		};
+
+		let Value::Array(patterns) = include else {
+			bail!("`include` in {path:?} must be an array of strings");
+		};
+
+		let dir = path.parent().unwrap_or(Path::new("."));
+		let mut layered = Table::new();
+		for pattern in patterns {
+			let Value::String(pattern) = pattern else {
+				bail!("`include` in {path:?} must be an array of strings");
+			};
+			for file in Self::expand(dir, &pattern)? {
+				let mut t = std::fs::read_to_string(&file)
+					.with_context(|| anyhow!("Failed to include {file:?}"))?
+					.parse()
+					.with_context(|| anyhow!("Loading {file:?}"))?;
+				Self::resolve(&mut t).with_context(|| anyhow!("Loading {file:?}"))?;
+				Self::layer(&mut layered, t);
+			}
+		}
+
+		Self::layer(&mut layered, top);
+		Ok(layered.to_string())
+	}
+
+	// Layers the `[target.'cfg(...)']` sections matching the current platform over the rest,
+	// and then interpolates `${VAR}` in the strings with the environment variables.
+	fn resolve(t: &mut Table) -> Result<()> {
+		if let Some(targets) = t.remove("target") {
+			let Value::Table(targets) = targets else {
+				bail!("`target` must be a table");
+			};
+			for (cfg, section) in targets {
+				let Value::Table(section) = section else {
+					bail!("`target.'{cfg}'` must be a table");
+				};
+				if Target::matches(&cfg)? {
+					Self::layer(t, section);
+				}
+			}
+		}
+
+		t.iter_mut().for_each(|(_, v)| Self::interpolate(v));
+		Ok(())
+	}
+
+	fn interpolate(v: &mut Value) {
+		match v {
+			Value::String(s) if s.contains("${") => *s = Self::interpolate_str(s),
+			Value::Array(a) => a.iter_mut().for_each(Self::interpolate),
+			Value::Table(t) => t.iter_mut().for_each(|(_, v)| Self::interpolate(v)),
+			_ => {}
+		}
+	}
+
+	// Only `${NAME}` is replaced, where an unset variable becomes empty, and `$${NAME}` is
+	// kept as `${NAME}`, so that shell expansions like `${EDITOR:-vi}` are left as they are.
+	fn interpolate_str(s: &str) -> String {
+		let mut out = String::with_capacity(s.len());
+		let mut rest = s;
+		while let Some(i) = rest.find("${") {
+			let (before, after) = rest.split_at(i);
+			let name_len = after[2..]
+				.find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+				.filter(|&n| n > 0 && after[2 + n..].starts_with('}'));
+
+			let Some(n) = name_len else {
+				out.push_str(before);
+				out.push_str("${");
+				rest = &after[2..];
+				continue;
+			};
+
+			if let Some(before) = before.strip_suffix('$') {
+				out.push_str(before);
+				out.push_str(&after[..3 + n]);
+			} else {
+				out.push_str(before);
+				out.push_str(&std::env::var(&after[2..2 + n]).unwrap_or_default());
+			}
+			rest = &after[3 + n..];
+		}
+		out.push_str(rest);
+		out
+	}
+
+	// Only the file name can contain wildcards, matches are included in alphabetical order.
+	fn expand(dir: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+		let path = dir.join(pattern);
+		let name = path.file_name().and_then(|s| s.to_str()).unwrap_or_default();
+		if !name.contains(['*', '?', '[', '{']) {
+			return Ok(vec![path]);
+		}
+
+		let glob = globset::Glob::new(name)?.compile_matcher();
+		let Ok(it) = std::fs::read_dir(path.parent().unwrap_or(dir)) else {
+			return Ok(vec![]);
+		};
+
+		let mut files: Vec<_> = it
+			.flatten()
+			.map(|e| e.path())
+			.filter(|p| p.is_file() && p.file_name().is_some_and(|n| glob.is_match(n)))
+			.collect();
+		files.sort_unstable();
+		Ok(files)
+	}
+
+	// Lays `upper` over `lower`: tables are merged, other values are replaced, except for
+	// `prepend_*` and `append_*` arrays that wrap around the lower ones, i.e. the result
+	// is the same as if each file were mixed into the config one after another.
+	fn layer(lower: &mut Table, upper: Table) {
+		for (k, v) in upper {
+			let Some(a) = lower.get_mut(&k) else {
+				lower.insert(k, v);
+				continue;
+			};
+
+			match (a, v) {
+				(Value::Table(a), Value::Table(b)) => Self::layer(a, b),
+				(Value::Array(a), Value::Array(b)) if k.starts_with("prepend_") => {
+					*a = b.into_iter().chain(mem::take(a)).collect();
+				}
+				(Value::Array(a), Value::Array(b)) if k.starts_with("append_") => a.extend(b),
+				(a, v) => *a = v,
+			}
+		}
+	}
+
 	fn merge(a: &mut Table, b: Table, max: u8) {
 		for (k, v) in b {
 			let Some(a) = a.get_mut(&k) else {
@@ -0,0 +1,131 @@
+use std::{env::consts, iter::Peekable, str::Chars};
+
+use anyhow::{bail, Result};
+
+// Evaluates Cargo-like `cfg(...)` expressions against the current platform, supports
+// `unix`, `windows`, `target_os`, `target_family`, `target_arch`, and `not`, `any`, `all`.
+pub(crate) struct Target<'a> {
+	chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Target<'a> {
+	pub(crate) fn matches(expr: &'a str) -> Result<bool> {
+		let Some(inner) = expr.trim().strip_prefix("cfg(").and_then(|s| s.strip_suffix(')')) else {
+			bail!("invalid target `{expr}`, expected `cfg(...)`");
+		};
+
+		let mut me = Self { chars: inner.chars().peekable() };
+		let b = me.pred()?;
+		me.skip_ws();
+		if me.chars.peek().is_some() {
+			bail!("invalid target `{expr}`, unexpected trailing characters");
+		}
+		Ok(b)
+	}
+
+	fn pred(&mut self) -> Result<bool> {
+		let ident = self.ident()?;
+		self.skip_ws();
+
+		match (ident.as_str(), self.chars.peek()) {
+			("not", Some('(')) => {
+				let list = self.list()?;
+				if list.len() != 1 {
+					bail!("`not()` takes exactly one predicate");
+				}
+				Ok(!list[0])
+			}
+			("any", Some('(')) => Ok(self.list()?.into_iter().any(|b| b)),
+			("all", Some('(')) => Ok(self.list()?.into_iter().all(|b| b)),
+			(key, Some('=')) => {
+				self.chars.next();
+				let value = self.string()?;
+				Ok(match key {
+					"target_os" => value == consts::OS,
+					"target_family" => value == consts::FAMILY,
+					"target_arch" => value == consts::ARCH,
+					_ => bail!("unknown key `{key}` in target"),
+				})
+			}
+			("unix", _) => Ok(cfg!(unix)),
+			("windows", _) => Ok(cfg!(windows)),
+			(s, _) => bail!("unknown predicate `{s}` in target"),
+		}
+	}
+
+	fn list(&mut self) -> Result<Vec<bool>> {
+		self.chars.next(); // (
+		let mut v = vec![];
+		loop {
+			self.skip_ws();
+			match self.chars.peek() {
+				Some(')') => {
+					self.chars.next();
+					return Ok(v);
+				}
+				Some(',') => {
+					self.chars.next();
+				}
+				Some(_) => v.push(self.pred()?),
+				None => bail!("unclosed parenthesis in target"),
+			}
+		}
+	}
+
+	fn ident(&mut self) -> Result<String> {
+		self.skip_ws();
+		let mut s = String::new();
+		while let Some(&c) = self.chars.peek().filter(|c| c.is_ascii_alphanumeric() || **c == '_') {
+			s.push(c);
+			self.chars.next();
+		}
+		if s.is_empty() {
+			bail!("expected an identifier in target");
+		}
+		Ok(s)
+	}
+
+	fn string(&mut self) -> Result<String> {
+		self.skip_ws();
+		if self.chars.next() != Some('"') {
+			bail!("expected a string in target");
+		}
+
+		let mut s = String::new();
+		for c in self.chars.by_ref() {
+			if c == '"' {
+				return Ok(s);
+			}
+			s.push(c);
+		}
+		bail!("unclosed string in target")
+	}
+
+	fn skip_ws(&mut self) {
+		while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+			self.chars.next();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_matches() {
+		assert_eq!(Target::matches("cfg(unix)").unwrap(), cfg!(unix));
+		assert_eq!(Target::matches("cfg(not(windows))").unwrap(), !cfg!(windows));
+		assert_eq!(Target::matches("cfg(any(unix, windows))").unwrap(), cfg!(any(unix, windows)));
+		assert_eq!(
+			Target::matches(r#"cfg(all(unix, target_os = "macos"))"#).unwrap(),
+			cfg!(all(unix, target_os = "macos"))
+		);
+		assert!(Target::matches(&format!(r#"cfg(target_os = "{}")"#, consts::OS)).unwrap());
+
+		assert!(Target::matches("unix").is_err());
+		assert!(Target::matches("cfg(linux)").is_err());
+		assert!(Target::matches("cfg(not(unix, windows))").is_err());
+		assert!(Target::matches(r#"cfg(target_os = "linux""#).is_err());
+	}
+}
@@ -11,6 +11,9 @@ pub struct Tasks {
 	pub macro_workers: u8,
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
 	pub bizarre_retry: u8,
+	// How many of the commands of `shell --each` run at the same time
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub shell_jobs:    u8,
 
 	pub image_alloc: u32,
 	pub image_bound: [u16; 2],
@@ -1,8 +1,8 @@
-use serde::{Deserialize, Deserializer};
+use serde::{de, Deserialize, Deserializer};
 use yazi_shared::{fs::File, theme::{Color, Style, StyleShadow}};
 
 use super::Is;
-use crate::Pattern;
+use crate::{Pattern, PatternSet};
 
 pub struct Filetype {
 	pub is:    Is,
@@ -11,19 +11,27 @@ pub struct Filetype {
 	pub style: Style,
 }
 
-impl Filetype {
-	pub fn matches(&self, file: &File, mime: Option<&str>) -> bool {
-		if !self.is.check(&file.cha) {
-			return false;
-		}
+pub struct Filetypes {
+	rules: Vec<Filetype>,
+	names: PatternSet,
+	mimes: PatternSet,
+}
 
-		self.mime.as_ref().zip(mime).map_or(false, |(p, m)| p.match_mime(m))
-			|| self.name.as_ref().is_some_and(|n| n.match_path(&file.url, file.is_dir()))
+impl Filetypes {
+	// The first rule whose name or mime pattern matches, found with the compiled sets
+	// rather than trying each rule, as it's looked up for every file on the screen.
+	pub fn matches(&self, file: &File, mime: Option<&str>) -> Option<&Filetype> {
+		let mut ids = self.names.matches(&file.url, file.is_dir());
+		if let Some(mime) = mime {
+			ids.extend(self.mimes.matches_mime(mime));
+			ids.sort_unstable();
+		}
+		ids.into_iter().map(|i| &self.rules[i]).find(|r| r.is.check(&file.cha))
 	}
 }
 
 impl Filetype {
-	pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Filetype>, D::Error>
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Filetypes, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
@@ -60,30 +68,36 @@ impl Filetype {
 			crossed:     bool,
 		}
 
-		Ok(
-			FiletypeOuter::deserialize(deserializer)?
-				.rules
-				.into_iter()
-				.map(|r| Filetype {
-					is:    r.is,
-					name:  r.name,
-					mime:  r.mime,
-					style: StyleShadow {
-						fg:          r.fg,
-						bg:          r.bg,
-						bold:        r.bold,
-						dim:         r.dim,
-						italic:      r.italic,
-						underline:   r.underline,
-						blink:       r.blink,
-						blink_rapid: r.blink_rapid,
-						reversed:    r.reversed,
-						hidden:      r.hidden,
-						crossed:     r.crossed,
-					}
-					.into(),
-				})
-				.collect(),
-		)
+		let rules: Vec<_> = FiletypeOuter::deserialize(deserializer)?
+			.rules
+			.into_iter()
+			.map(|r| Filetype {
+				is:    r.is,
+				name:  r.name,
+				mime:  r.mime,
+				style: StyleShadow {
+					fg:          r.fg,
+					bg:          r.bg,
+					bold:        r.bold,
+					dim:         r.dim,
+					italic:      r.italic,
+					underline:   r.underline,
+					blink:       r.blink,
+					blink_rapid: r.blink_rapid,
+					reversed:    r.reversed,
+					hidden:      r.hidden,
+					crossed:     r.crossed,
+				}
+				.into(),
+			})
+			.collect();
+
+		let names = rules.iter().enumerate().filter_map(|(i, r)| Some((i, r.name.as_ref()?)));
+		let mimes = rules.iter().enumerate().filter_map(|(i, r)| Some((i, r.mime.as_ref()?)));
+		Ok(Filetypes {
+			names: PatternSet::new(names).map_err(de::Error::custom)?,
+			mimes: PatternSet::new(mimes).map_err(de::Error::custom)?,
+			rules,
+		})
 	}
 }
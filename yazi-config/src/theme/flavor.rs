@@ -1,12 +1,31 @@
 use serde::{Deserialize, Serialize};
+use yazi_shared::Xdg;
 
 #[derive(Deserialize, Serialize)]
 pub struct Flavor {
 	#[serde(rename = "use")]
-	pub use_: String,
+	pub use_:  String,
+	#[serde(default)]
+	pub dark:  String,
+	#[serde(default)]
+	pub light: String,
 }
 
 impl Flavor {
+	pub fn list() -> Vec<String> {
+		let Ok(it) = std::fs::read_dir(Xdg::config_dir().join("flavors")) else {
+			return vec![];
+		};
+
+		let mut names: Vec<_> = it
+			.flatten()
+			.filter(|e| e.path().join("flavor.toml").is_file())
+			.filter_map(|e| e.file_name().to_str()?.strip_suffix(".yazi").map(ToOwned::to_owned))
+			.collect();
+		names.sort_unstable();
+		names
+	}
+
 	pub fn parse_use(s: &str) -> Option<String> {
 		#[derive(Deserialize)]
 		struct Outer {
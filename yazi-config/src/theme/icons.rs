@@ -1,13 +1,15 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
-use anyhow::Result;
-use serde::{Deserialize, Deserializer};
+use anyhow::{bail, Result};
+use serde::{de, Deserialize, Deserializer};
 use yazi_shared::{fs::File, theme::{Color, Icon, Style}, Condition};
 
-use crate::{Pattern, Preset};
+use crate::{Pattern, PatternSet, Preset};
 
 pub struct Icons {
-	globs: Vec<(Pattern, Icon)>,
+	pub profile: IconProfile,
+
+	globs: (PatternSet, Vec<Icon>),
 	dirs:  HashMap<String, Icon>,
 	files: HashMap<String, Icon>,
 	exts:  HashMap<String, Icon>,
@@ -43,7 +45,7 @@ impl Icons {
 
 	#[inline]
 	fn match_by_glob(&self, file: &File) -> Option<&Icon> {
-		self.globs.iter().find(|(p, _)| p.match_path(&file.url, file.is_dir())).map(|(_, i)| i)
+		self.globs.0.first(&file.url, file.is_dir()).map(|i| &self.globs.1[i])
 	}
 
 	#[inline]
@@ -68,6 +70,9 @@ impl<'de> Deserialize<'de> for Icons {
 	{
 		#[derive(Deserialize)]
 		pub struct Shadow {
+			#[serde(default)]
+			profile: IconProfile,
+
 			globs:         Vec<ShadowPat>,
 			#[serde(default)]
 			prepend_globs: Vec<ShadowPat>,
@@ -125,19 +130,40 @@ impl<'de> Deserialize<'de> for Icons {
 		}
 
 		let mut shadow = Shadow::deserialize(deserializer)?;
+		if shadow.profile != IconProfile::Nerd {
+			shadow.globs.clear();
+			shadow.dirs.clear();
+			shadow.files.clear();
+			shadow.exts.clear();
+			shadow.conds = shadow
+				.profile
+				.conds()
+				.iter()
+				.map(|&(if_, text)| ShadowCond {
+					if_:      if_.parse().unwrap(),
+					text:     text.to_owned(),
+					fg_dark:  None,
+					fg_light: None,
+				})
+				.collect();
+		}
+
 		Preset::mix(&mut shadow.globs, shadow.prepend_globs, shadow.append_globs);
 		Preset::mix(&mut shadow.dirs, shadow.prepend_dirs, shadow.append_dirs);
 		Preset::mix(&mut shadow.files, shadow.prepend_files, shadow.append_files);
 		Preset::mix(&mut shadow.exts, shadow.prepend_exts, shadow.append_exts);
 		Preset::mix(&mut shadow.conds, shadow.prepend_conds, shadow.append_conds);
 
-		let globs = shadow
-			.globs
-			.into_iter()
-			.map(|v| {
-				(v.name, Icon { text: v.text, style: Style { fg: v.fg_dark, ..Default::default() } })
-			})
-			.collect();
+		let set = PatternSet::new(shadow.globs.iter().map(|v| &v.name).enumerate())
+			.map_err(de::Error::custom)?;
+		let globs = (
+			set,
+			shadow
+				.globs
+				.into_iter()
+				.map(|v| Icon { text: v.text, style: Style { fg: v.fg_dark, ..Default::default() } })
+				.collect(),
+		);
 
 		let conds = shadow
 			.conds
@@ -160,6 +186,7 @@ impl<'de> Deserialize<'de> for Icons {
 		}
 
 		Ok(Self {
+			profile: shadow.profile,
 			globs,
 			dirs: as_map(shadow.dirs),
 			files: as_map(shadow.files),
@@ -168,3 +195,86 @@ impl<'de> Deserialize<'de> for Icons {
 		})
 	}
 }
+
+// The built-in sets of icons, for the terminals without a Nerd Font, where the user's
+// `prepend_*` and `append_*` rules still apply, but the preset ones are replaced.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IconProfile {
+	#[default]
+	Nerd,
+	Unicode,
+	Ascii,
+}
+
+impl IconProfile {
+	#[inline]
+	pub fn next(self) -> Self {
+		match self {
+			Self::Nerd => Self::Unicode,
+			Self::Unicode => Self::Ascii,
+			Self::Ascii => Self::Nerd,
+		}
+	}
+
+	fn conds(self) -> &'static [(&'static str, &'static str)] {
+		match self {
+			Self::Nerd => &[],
+			Self::Unicode => &[
+				("orphan", "✗"),
+				("link", "→"),
+				("block", "◆"),
+				("char", "◇"),
+				("fifo", "∣"),
+				("sock", "◎"),
+				("dir", "▸"),
+				("exec", "✱"),
+				("!dir", "·"),
+			],
+			// The same as the indicators of `ls -F`
+			Self::Ascii => &[
+				("orphan", "!"),
+				("link", "@"),
+				("block", "#"),
+				("char", "%"),
+				("fifo", "|"),
+				("sock", "="),
+				("dir", "/"),
+				("exec", "*"),
+				("!dir", "-"),
+			],
+		}
+	}
+
+	// Replaces the Nerd Font glyphs, which are in the Private Use Areas, in the other icons
+	// and separators of the theme, and leaves the user's own ones alone.
+	pub(super) fn fallback(self, s: &mut String, unicode: &str, ascii: &str) {
+		let pua = |c: char| matches!(c as u32, 0xE000..=0xF8FF | 0xF0000..=0x10FFFF);
+		if self != Self::Nerd && s.chars().any(pua) {
+			*s = if self == Self::Unicode { unicode } else { ascii }.to_owned();
+		}
+	}
+}
+
+impl FromStr for IconProfile {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"nerd" => Self::Nerd,
+			"unicode" => Self::Unicode,
+			"ascii" => Self::Ascii,
+			_ => bail!("Invalid icon profile: {s}, expected `nerd`, `unicode` or `ascii`"),
+		})
+	}
+}
+
+impl Display for IconProfile {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::Nerd => "nerd",
+			Self::Unicode => "unicode",
+			Self::Ascii => "ascii",
+		})
+	}
+}
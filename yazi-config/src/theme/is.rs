@@ -4,6 +4,8 @@ use anyhow::bail;
 use serde::Deserialize;
 use yazi_shared::fs::Cha;
 
+use crate::MANAGER;
+
 #[derive(Default, Deserialize)]
 #[serde(try_from = "String")]
 pub enum Is {
@@ -18,6 +20,7 @@ pub enum Is {
 	Sock,
 	Exec,
 	Sticky,
+	Recent,
 }
 
 impl FromStr for Is {
@@ -34,6 +37,7 @@ impl FromStr for Is {
 			"sock" => Self::Sock,
 			"exec" => Self::Exec,
 			"sticky" => Self::Sticky,
+			"recent" => Self::Recent,
 			_ => bail!("invalid filetype: {s}"),
 		})
 	}
@@ -46,6 +50,11 @@ impl TryFrom<String> for Is {
 }
 
 impl Is {
+	fn is_recent(cha: &Cha) -> bool {
+		let within = MANAGER.load().recent_within * 60;
+		within > 0 && cha.mtime.and_then(|t| t.elapsed().ok()).is_some_and(|d| d.as_secs() < within)
+	}
+
 	#[inline]
 	pub fn check(&self, cha: &Cha) -> bool {
 		match self {
@@ -59,6 +68,7 @@ impl Is {
 			Self::Sock => cha.is_sock(),
 			Self::Exec => cha.is_exec(),
 			Self::Sticky => cha.is_sticky(),
+			Self::Recent => Self::is_recent(cha),
 		}
 	}
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 use yazi_shared::{fs::expand_path, theme::Style, Xdg};
 
-use super::{Filetype, Flavor, Icons};
+use super::{Filetype, Filetypes, Flavor, Icons};
 
 #[derive(Deserialize, Serialize)]
 pub struct Theme {
@@ -18,10 +18,11 @@ pub struct Theme {
 	pub which:      Which,
 	pub help:       Help,
 	pub notify:     Notify,
+	pub vcs:        Vcs,
 
 	// File-specific styles
 	#[serde(rename = "filetype", deserialize_with = "Filetype::deserialize", skip_serializing)]
-	pub filetypes: Vec<Filetype>,
+	pub filetypes: Filetypes,
 	#[serde(rename = "icon", skip_serializing)]
 	pub icons:     Icons,
 }
@@ -41,6 +42,16 @@ impl FromStr for Theme {
 				Xdg::config_dir().join(format!("flavors/{}.yazi/tmtheme.xml", theme.flavor.use_));
 		}
 
+		let p = theme.icons.profile;
+		p.fallback(&mut theme.status.separator_open, "", "");
+		p.fallback(&mut theme.status.separator_close, "", "");
+		p.fallback(&mut theme.completion.icon_file, "·", "-");
+		p.fallback(&mut theme.completion.icon_folder, "▸", "/");
+		p.fallback(&mut theme.completion.icon_command, "❯", ">");
+		p.fallback(&mut theme.notify.icon_info, "ℹ", "i");
+		p.fallback(&mut theme.notify.icon_warn, "⚠", "!");
+		p.fallback(&mut theme.notify.icon_error, "✗", "x");
+
 		Ok(theme)
 	}
 }
@@ -58,10 +69,11 @@ pub struct Manager {
 	find_position: Style,
 
 	// Marker
-	marker_copied:   Style,
-	marker_cut:      Style,
-	marker_marked:   Style,
-	marker_selected: Style,
+	marker_copied:     Style,
+	marker_cut:        Style,
+	marker_marked:     Style,
+	marker_selected:   Style,
+	marker_bookmarked: Style,
 
 	// Tab
 	tab_active:   Style,
@@ -69,6 +81,10 @@ pub struct Manager {
 	#[validate(range(min = 1, message = "Must be greater than 0"))]
 	tab_width:    u8,
 
+	// Symlink
+	symlink_target: Style,
+	symlink_broken: Style,
+
 	// Count
 	count_copied:   Style,
 	count_cut:      Style,
@@ -104,6 +120,10 @@ struct Status {
 	pub permissions_w: Style,
 	pub permissions_x: Style,
 	pub permissions_s: Style,
+
+	// Selection
+	pub selection:         Style,
+	pub selection_pending: Style,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -172,3 +192,27 @@ pub struct Notify {
 	pub icon_warn:  String,
 	pub icon_error: String,
 }
+
+#[derive(Deserialize, Serialize)]
+pub struct Vcs {
+	pub modified:   Style,
+	pub added:      Style,
+	pub deleted:    Style,
+	pub renamed:    Style,
+	pub untracked:  Style,
+	pub ignored:    Style,
+	pub conflicted: Style,
+
+	pub sign_modified:   String,
+	pub sign_added:      String,
+	pub sign_deleted:    String,
+	pub sign_renamed:    String,
+	pub sign_untracked:  String,
+	pub sign_ignored:    String,
+	pub sign_conflicted: String,
+
+	pub branch: Style,
+	pub ahead:  Style,
+	pub behind: Style,
+	pub hash:   Style,
+}
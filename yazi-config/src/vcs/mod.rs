@@ -0,0 +1,5 @@
+mod provider;
+mod vcs;
+
+pub use provider::*;
+pub use vcs::*;
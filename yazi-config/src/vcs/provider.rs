@@ -0,0 +1,57 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
+pub enum VcsProvider {
+	Git,
+	Jujutsu,
+	Mercurial,
+	Subversion,
+}
+
+impl VcsProvider {
+	// The directory that marks the root of a repo
+	#[inline]
+	pub fn marker(self) -> &'static str {
+		match self {
+			Self::Git => ".git",
+			Self::Jujutsu => ".jj",
+			Self::Mercurial => ".hg",
+			Self::Subversion => ".svn",
+		}
+	}
+}
+
+impl FromStr for VcsProvider {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"git" => Self::Git,
+			"jj" => Self::Jujutsu,
+			"hg" => Self::Mercurial,
+			"svn" => Self::Subversion,
+			_ => bail!("invalid vcs provider: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for VcsProvider {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
+
+impl Display for VcsProvider {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::Git => "git",
+			Self::Jujutsu => "jj",
+			Self::Mercurial => "hg",
+			Self::Subversion => "svn",
+		})
+	}
+}
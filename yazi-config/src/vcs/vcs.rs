@@ -0,0 +1,33 @@
+use std::{path::{Path, PathBuf}, str::FromStr};
+
+use serde::Deserialize;
+
+use super::VcsProvider;
+
+#[derive(Debug, Deserialize)]
+pub struct Vcs {
+	pub providers: Vec<VcsProvider>,
+}
+
+impl Vcs {
+	// Walks up from the directory, the nearest repo wins, and the providers are tried in order
+	// within the same directory, e.g. `jj` before `git` for a colocated repo.
+	pub fn detect(&self, dir: &Path) -> Option<(PathBuf, VcsProvider)> {
+		dir.ancestors().find_map(|p| {
+			self.providers.iter().find(|v| p.join(v.marker()).exists()).map(|&v| (p.to_owned(), v))
+		})
+	}
+}
+
+impl FromStr for Vcs {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			vcs: Vcs,
+		}
+
+		Ok(toml::from_str::<Outer>(s)?.vcs)
+	}
+}
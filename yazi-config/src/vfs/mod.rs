@@ -0,0 +1,3 @@
+mod vfs;
+
+pub use vfs::*;
@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Vfs {
+	// Passed to `ssh` before the host, the rest comes from `~/.ssh/config`
+	pub ssh_args:         Vec<String>,
+	// Remote files larger than this aren't downloaded for previewing
+	pub preview_max_size: u64,
+
+	// An empty endpoint means AWS itself, otherwise it's one compatible with it, e.g. MinIO or R2
+	pub s3_region:   String,
+	pub s3_endpoint: String,
+
+	pub dav_credentials: Vec<DavCredential>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DavCredential {
+	pub host:     String,
+	pub user:     String,
+	#[serde(default)]
+	pub password: String,
+}
+
+impl Vfs {
+	// The user and password for the `host[:port]`, the password is looked up
+	// in the keyring by the caller when it's not given in the config.
+	pub fn dav_credential(&self, host: &str) -> Option<&DavCredential> {
+		self.dav_credentials.iter().find(|c| c.host == host)
+	}
+}
+
+impl FromStr for Vfs {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			vfs: Vfs,
+		}
+
+		Ok(toml::from_str::<Outer>(s)?.vfs)
+	}
+}
@@ -0,0 +1,3 @@
+mod watcher;
+
+pub use watcher::*;
@@ -0,0 +1,46 @@
+use std::{path::Path, str::FromStr};
+
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::Pattern;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Watcher {
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub poll_interval: u64,
+	poll_fstypes:      Vec<String>,
+	poll_paths:        Vec<Pattern>,
+	exclude:           Vec<Pattern>,
+}
+
+impl Watcher {
+	// Whether the directory should be polled instead, as for the network filesystems
+	// where the change events of the OS never arrive.
+	pub fn poll(&self, path: &Path, fstype: Option<&str>) -> bool {
+		fstype.is_some_and(|t| self.poll_fstypes.iter().any(|s| s == t))
+			|| self.poll_paths.iter().any(|p| p.match_path(path, p.is_dir()))
+	}
+
+	// Excluded directories are neither watched nor descended into when calculating sizes.
+	#[inline]
+	pub fn excluded(&self, path: &Path) -> bool {
+		self.exclude.iter().any(|p| p.match_path(path, p.is_dir()))
+	}
+}
+
+impl FromStr for Watcher {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			watcher: Watcher,
+		}
+
+		let watcher = toml::from_str::<Outer>(s)?.watcher;
+		watcher.validate()?;
+
+		Ok(watcher)
+	}
+}
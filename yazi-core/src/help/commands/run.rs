@@ -0,0 +1,16 @@
+use yazi_shared::{emit, event::Cmd};
+
+use crate::help::Help;
+
+impl Help {
+	// Runs the hovered binding, which makes the help a palette of the commands.
+	pub fn run(&mut self, _: Cmd) {
+		let Some(ctrl) = self.bindings.get(self.cursor).cloned() else {
+			return;
+		};
+
+		let layer = self.layer;
+		self.toggle(layer);
+		emit!(Seq(ctrl.to_seq(), layer));
+	}
+}
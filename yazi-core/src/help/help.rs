@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use crossterm::event::KeyCode;
 use unicode_width::UnicodeWidthStr;
 use yazi_adapter::Dimension;
-use yazi_config::{keymap::{Control, Key}, KEYMAP};
+use yazi_config::{keymap::{Control, Key}, KEYMAP, MACROS};
 use yazi_shared::{render, render_and, Layer};
 
 use super::HELP_MARGIN;
@@ -11,7 +13,7 @@ use crate::input::Input;
 pub struct Help {
 	pub visible:         bool,
 	pub layer:           Layer,
-	pub(super) bindings: Vec<&'static Control>,
+	pub(super) bindings: Vec<Arc<Control>>,
 
 	// Filter
 	pub(super) keyword:   String,
@@ -67,12 +69,16 @@ impl Help {
 	pub(super) fn filter_apply(&mut self) {
 		let kw = self.in_filter.as_ref().map_or("", |i| i.value());
 
+		let manager = self.layer == Layer::Manager;
+		let (keymap, macros) = (KEYMAP.load(), MACROS.load());
+		let macros = macros.controls().filter(move |_| manager).cloned();
 		if kw.is_empty() {
 			self.keyword = String::new();
-			self.bindings = KEYMAP.get(self.layer).iter().collect();
+			self.bindings = keymap.iter(self.layer).chain(macros).collect();
 		} else if self.keyword != kw {
 			self.keyword = kw.to_owned();
-			self.bindings = KEYMAP.get(self.layer).iter().filter(|&c| c.contains(kw)).collect();
+			self.bindings =
+				keymap.iter(self.layer).chain(macros).filter(|c| c.contains(kw)).collect();
 		}
 
 		self.arrow(0);
@@ -93,7 +99,7 @@ impl Help {
 
 	// --- Bindings
 	#[inline]
-	pub fn window(&self) -> &[&Control] {
+	pub fn window(&self) -> &[Arc<Control>] {
 		let end = (self.offset + Self::limit()).min(self.bindings.len());
 		&self.bindings[self.offset..end]
 	}
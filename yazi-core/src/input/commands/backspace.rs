@@ -23,17 +23,13 @@ impl Input {
 		let opt = opt.into() as Opt;
 		if !opt.under && snap.cursor < 1 {
 			return;
-		} else if opt.under && snap.cursor >= snap.value.len() {
+		} else if opt.under && snap.cursor >= snap.count() {
 			return;
 		}
 
-		if opt.under {
-			snap.value.remove(snap.idx(snap.cursor).unwrap());
-			self.move_(0);
-		} else {
-			snap.value.remove(snap.idx(snap.cursor - 1).unwrap());
-			self.move_(-1);
-		}
+		let n = if opt.under { snap.cursor } else { snap.cursor - 1 };
+		snap.value.replace_range(snap.idx(n).unwrap()..snap.idx(n + 1).unwrap(), "");
+		self.move_(if opt.under { 0 } else { -1 });
 
 		self.flush_value();
 		render!();
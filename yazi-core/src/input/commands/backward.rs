@@ -1,3 +1,4 @@
+use unicode_segmentation::UnicodeSegmentation;
 use yazi_shared::{event::Cmd, CharKind};
 
 use crate::input::Input;
@@ -10,10 +11,10 @@ impl Input {
 		}
 
 		let idx = snap.idx(snap.cursor).unwrap_or(snap.len());
-		let mut it = snap.value[..idx].chars().rev().enumerate();
-		let mut prev = CharKind::new(it.next().unwrap().1);
-		for (i, c) in it {
-			let c = CharKind::new(c);
+		let mut it = snap.value[..idx].graphemes(true).rev().enumerate();
+		let mut prev = CharKind::of(it.next().unwrap().1);
+		for (i, g) in it {
+			let c = CharKind::of(g);
 			if prev != CharKind::Space && prev != c {
 				return self.move_(-(i as isize));
 			}
@@ -21,7 +22,7 @@ impl Input {
 		}
 
 		if prev != CharKind::Space {
-			self.move_(-(snap.len() as isize));
+			self.move_(-(snap.count() as isize));
 		}
 	}
 }
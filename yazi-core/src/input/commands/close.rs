@@ -18,12 +18,15 @@ impl Input {
 	pub fn close(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 
-		if self.completion {
+		if self.completer.is_some() {
 			CompletionProxy::close();
 		}
 
 		if let Some(cb) = self.callback.take() {
 			let value = self.snap_mut().value.clone();
+			if opt.submit {
+				self.history.push(&value);
+			}
 			_ = cb.send(if opt.submit { Ok(value) } else { Err(InputError::Canceled(value)) });
 		}
 
@@ -31,20 +31,25 @@ impl Input {
 			return;
 		}
 
+		let Some(completer) = &self.completer else { return };
 		let [before, after] = self.partition();
-		let new = if let Some((prefix, _)) = before.rsplit_once(SEPARATOR) {
-			format!("{prefix}/{}{after}", opt.word).replace(SEPARATOR, MAIN_SEPARATOR_STR)
-		} else {
-			format!("{}{after}", opt.word).replace(SEPARATOR, MAIN_SEPARATOR_STR)
+
+		let word = completer.word(before);
+		let kept = &before[..before.len() - word.len()];
+		let word = match word.rsplit_once(SEPARATOR) {
+			Some((prefix, _)) => format!("{prefix}/{}", opt.word),
+			None => opt.word,
 		};
+		let new = format!("{kept}{}{after}", word.replace(SEPARATOR, MAIN_SEPARATOR_STR));
 
 		let snap = self.snaps.current_mut();
 		if new == snap.value {
 			return;
 		}
 
-		let delta = new.chars().count() as isize - snap.value.chars().count() as isize;
+		let count = snap.count();
 		snap.value = new;
+		let delta = snap.count() as isize - count as isize;
 
 		self.move_(delta);
 		self.flush_value();
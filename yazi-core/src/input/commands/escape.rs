@@ -26,13 +26,15 @@ impl Input {
 				snap.mode = InputMode::Normal;
 				self.move_(-1);
 
-				if self.completion {
+				if self.completer.is_some() {
 					CompletionProxy::close();
 				}
 			}
 		}
 
-		self.snaps.tag(self.limit());
+		if !self.obscure {
+			self.snaps.tag(self.limit());
+		}
 		render!();
 	}
 }
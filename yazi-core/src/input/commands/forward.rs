@@ -1,3 +1,4 @@
+use unicode_segmentation::UnicodeSegmentation;
 use yazi_shared::{event::Cmd, CharKind};
 
 use crate::input::{op::InputOp, Input};
@@ -15,13 +16,13 @@ impl Input {
 		let opt = opt.into() as Opt;
 		let snap = self.snap();
 
-		let mut it = snap.value.chars().skip(snap.cursor).enumerate();
-		let Some(mut prev) = it.next().map(|(_, c)| CharKind::new(c)) else {
+		let mut it = snap.value.graphemes(true).skip(snap.cursor).enumerate();
+		let Some(mut prev) = it.next().map(|(_, g)| CharKind::of(g)) else {
 			return self.move_(0);
 		};
 
-		for (i, c) in it {
-			let c = CharKind::new(c);
+		for (i, g) in it {
+			let c = CharKind::of(g);
 			let b = if opt.end_of_word {
 				prev != CharKind::Space && prev != c && i != 1
 			} else {
@@ -35,6 +36,6 @@ impl Input {
 			prev = c;
 		}
 
-		self.move_(snap.len() as isize)
+		self.move_(snap.count() as isize)
 	}
 }
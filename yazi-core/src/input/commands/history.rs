@@ -0,0 +1,29 @@
+use yazi_shared::{event::{Cmd, Data}, render};
+
+use crate::input::Input;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { step: c.first().and_then(Data::as_isize).unwrap_or(0) } }
+}
+
+impl Input {
+	pub fn history(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let current = self.snap().value.clone();
+		let Some(value) = self.history.step(&current, opt.step) else {
+			return;
+		};
+
+		let (value, mode) = (value.to_owned(), self.mode());
+		self.snaps.reset(value, self.limit());
+		self.snap_mut().mode = mode;
+		self.move_(0);
+
+		self.flush_value();
+		render!();
+	}
+}
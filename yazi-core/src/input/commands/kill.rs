@@ -1,9 +1,12 @@
 use std::ops::RangeBounds;
 
+use unicode_segmentation::UnicodeSegmentation;
 use yazi_shared::{event::Cmd, render, CharKind};
 
 use crate::input::Input;
 
+const KILL_RING: usize = 20;
+
 pub struct Opt {
 	kind: String,
 }
@@ -20,10 +23,19 @@ impl Input {
 			std::ops::Bound::Excluded(_) => unreachable!(),
 			std::ops::Bound::Unbounded => 0,
 		};
-		if snap.value.drain(range).next().is_none() {
+
+		let killed: String = snap.value.drain(range).collect();
+		if killed.is_empty() {
 			return;
 		}
 
+		// Goes to the kill ring rather than the clipboard, to be yanked back with `paste --ring`,
+		// except for the passphrases, which aren't kept anywhere
+		if !self.obscure {
+			self.kill_ring.push_front(killed);
+			self.kill_ring.truncate(KILL_RING);
+		}
+
 		self.move_(0);
 		self.flush_value();
 		render!();
@@ -43,26 +55,26 @@ impl Input {
 	///
 	/// Otherwise, returns how many characters to move to reach right *AFTER* the
 	/// word boundary, or the end of the iterator.
-	fn find_word_boundary(input: impl Iterator<Item = char> + Clone) -> usize {
-		fn count_spaces(input: impl Iterator<Item = char>) -> usize {
+	fn find_word_boundary<'a>(input: impl Iterator<Item = &'a str> + Clone) -> usize {
+		fn count_spaces<'a>(input: impl Iterator<Item = &'a str>) -> usize {
 			// Move until we don't see any more whitespace.
-			input.take_while(|&c| CharKind::new(c) == CharKind::Space).count()
+			input.take_while(|&g| CharKind::of(g) == CharKind::Space).count()
 		}
 
-		fn count_characters(mut input: impl Iterator<Item = char>) -> usize {
+		fn count_characters<'a>(mut input: impl Iterator<Item = &'a str>) -> usize {
 			// Determine the current character class.
 			let first = match input.next() {
-				Some(c) => CharKind::new(c),
+				Some(g) => CharKind::of(g),
 				None => return 0,
 			};
 
 			// Move until we see a different character class or the end of the iterator.
-			input.take_while(|&c| CharKind::new(c) == first).count() + 1
+			input.take_while(|&g| CharKind::of(g) == first).count() + 1
 		}
 
 		let n = count_spaces(input.clone());
 		let n = n + count_characters(input.clone().skip(n));
-		input.take(n).fold(0, |acc, c| acc + c.len_utf8())
+		input.take(n).fold(0, |acc, g| acc + g.len())
 	}
 
 	pub fn kill(&mut self, opt: impl Into<Opt>) {
@@ -81,12 +93,12 @@ impl Input {
 			}
 			"backward" => {
 				let end = snap.idx(snap.cursor).unwrap_or(snap.len());
-				let start = end - Self::find_word_boundary(snap.value[..end].chars().rev());
+				let start = end - Self::find_word_boundary(snap.value[..end].graphemes(true).rev());
 				self.kill_range(start..end)
 			}
 			"forward" => {
 				let start = snap.idx(snap.cursor).unwrap_or(snap.len());
-				let end = start + Self::find_word_boundary(snap.value[start..].chars());
+				let end = start + Self::find_word_boundary(snap.value[start..].graphemes(true));
 				self.kill_range(start..end)
 			}
 			_ => {}
@@ -1,5 +1,5 @@
-use unicode_width::UnicodeWidthStr;
-use yazi_shared::{event::{Cmd, Data}, render};
+use unicode_segmentation::UnicodeSegmentation;
+use yazi_shared::{event::{Cmd, Data}, render, str_width};
 
 use crate::input::{op::InputOp, snap::InputSnap, Input};
 
@@ -46,8 +46,8 @@ impl Input {
 		} else {
 			let delta = snap.mode.delta();
 			let s = snap.slice(snap.offset..snap.cursor + delta);
-			if s.width() >= limit {
-				let s = s.chars().rev().collect::<String>();
+			if str_width(s) >= limit {
+				let s = s.graphemes(true).rev().collect::<String>();
 				snap.offset = snap.cursor - InputSnap::find_window(&s, 0, limit).end.saturating_sub(delta);
 			}
 		}
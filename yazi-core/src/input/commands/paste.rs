@@ -1,31 +1,41 @@
 use yazi_plugin::CLIPBOARD;
 use yazi_shared::{event::Cmd, render};
 
-use crate::input::{op::InputOp, Input};
+use crate::input::{op::InputOp, Input, InputMode};
 
 pub struct Opt {
 	before: bool,
+	ring:   bool,
 }
 
 impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { before: c.bool("before") } }
+	fn from(c: Cmd) -> Self { Self { before: c.bool("before"), ring: c.bool("ring") } }
 }
 
 impl Input {
 	pub fn paste(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let s = if opt.ring {
+			self.kill_ring.front().cloned().unwrap_or_default()
+		} else {
+			futures::executor::block_on(CLIPBOARD.get()).to_string_lossy().into_owned()
+		};
+
+		// Insert mode pastes at the cursor, like yanking in a shell prompt
+		if self.mode() == InputMode::Insert {
+			return self.type_str(&s);
+		}
+
 		if let Some(start) = self.snap().op.start() {
 			self.snap_mut().op = InputOp::Delete(false, false, start);
 			self.handle_op(self.snap().cursor, true);
 		}
-
-		let s = futures::executor::block_on(CLIPBOARD.get());
 		if s.is_empty() {
 			return;
 		}
 
-		let opt = opt.into() as Opt;
 		self.insert(!opt.before);
-		self.type_str(&s.to_string_lossy());
+		self.type_str(&s);
 		self.escape(());
 		render!();
 	}
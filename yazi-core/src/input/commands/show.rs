@@ -2,7 +2,7 @@ use tokio::sync::mpsc;
 use yazi_config::popup::InputCfg;
 use yazi_shared::{event::Cmd, render, InputError};
 
-use crate::input::Input;
+use crate::input::{completer, Input};
 
 pub struct Opt {
 	cfg: InputCfg,
@@ -29,12 +29,14 @@ impl Input {
 		// Typing
 		self.callback = Some(opt.tx);
 		self.realtime = opt.cfg.realtime;
-		self.completion = opt.cfg.completion;
+		self.completer = completer(opt.cfg.completion);
 
 		// Shell
 		self.highlight = opt.cfg.highlight;
+		self.obscure = opt.cfg.obscure;
 
 		// Reset snaps
+		self.history.start(opt.cfg.history, &opt.cfg.value);
 		self.snaps.reset(opt.cfg.value, self.limit());
 
 		// Set cursor after reset
@@ -4,12 +4,21 @@ use crate::input::{Input, InputMode};
 
 impl Input {
 	pub fn undo(&mut self, _: Cmd) {
+		// What's typed since entering the insert mode is only snapshotted when leaving it
+		let insert = self.snap().mode == InputMode::Insert;
+		if insert {
+			self.escape(());
+		}
+
 		if !self.snaps.undo() {
 			return;
 		}
 		if self.snap().mode == InputMode::Insert {
 			self.escape(());
 		}
+		if insert {
+			self.insert(true);
+		}
 		render!();
 	}
 }
@@ -0,0 +1,98 @@
+use yazi_config::popup::InputCompletion;
+use yazi_proxy::CompletionProxy;
+
+use crate::manager::DESTINATIONS;
+
+// The word before the cursor is what gets completed, and replaced with the candidate selected.
+pub trait Completer: Send + Sync {
+	fn word<'a>(&self, before: &'a str) -> &'a str;
+
+	fn trigger(&self, word: &str, ticket: usize) { CompletionProxy::trigger(word, ticket, None); }
+}
+
+pub(super) fn completer(kind: InputCompletion) -> Option<Box<dyn Completer>> {
+	match kind {
+		InputCompletion::None => None,
+		InputCompletion::Path => Some(Box::new(PathCompleter)),
+		InputCompletion::Shell => Some(Box::new(ShellCompleter)),
+		InputCompletion::Destination => Some(Box::new(DestCompleter)),
+	}
+}
+
+// Completes the whole input as a path, e.g. the target of `cd`.
+pub struct PathCompleter;
+
+impl Completer for PathCompleter {
+	fn word<'a>(&self, before: &'a str) -> &'a str { before }
+}
+
+// Completes the destination of `paste --to`, offering the ones used before ranked by frecency,
+// until it looks like a path.
+pub struct DestCompleter;
+
+impl Completer for DestCompleter {
+	fn word<'a>(&self, before: &'a str) -> &'a str { before }
+
+	fn trigger(&self, word: &str, ticket: usize) {
+		if word.contains(std::path::is_separator) {
+			return CompletionProxy::trigger(word, ticket, None);
+		}
+		CompletionProxy::trigger(word, ticket, Some(DESTINATIONS.ranked()));
+	}
+}
+
+// Completes the last argument of a shell command, either as a file relative to the cwd,
+// or as one of the placeholders for the selected files if it starts with `$` (`%` on Windows).
+pub struct ShellCompleter;
+
+impl ShellCompleter {
+	#[cfg(unix)]
+	const PLACEHOLDERS: (char, &'static [&'static str]) =
+		('$', &["$0", "$@", "$1", "$2", "$3", "$4", "$5", "$6", "$7", "$8", "$9"]);
+	#[cfg(windows)]
+	const PLACEHOLDERS: (char, &'static [&'static str]) =
+		('%', &["%0", "%*", "%1", "%2", "%3", "%4", "%5", "%6", "%7", "%8", "%9"]);
+}
+
+impl Completer for ShellCompleter {
+	fn word<'a>(&self, before: &'a str) -> &'a str {
+		let mut start = 0;
+		let mut it = before.char_indices().peekable();
+		while let Some((i, c)) = it.next() {
+			if c == '\\' && cfg!(unix) {
+				it.next();
+			} else if c.is_whitespace() || c == '"' || c == '\'' {
+				start = i + c.len_utf8();
+			}
+		}
+		&before[start..]
+	}
+
+	fn trigger(&self, word: &str, ticket: usize) {
+		let (prefix, placeholders) = Self::PLACEHOLDERS;
+		if !word.starts_with(prefix) {
+			return CompletionProxy::trigger(word, ticket, None);
+		}
+
+		let cands = placeholders.iter().map(|&s| s.to_owned()).collect();
+		CompletionProxy::trigger(word, ticket, Some(cands));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(unix)]
+	#[test]
+	fn test_shell_word() {
+		let word = |s| ShellCompleter.word(s);
+		assert_eq!(word(""), "");
+		assert_eq!(word("vim"), "vim");
+		assert_eq!(word("vim "), "");
+		assert_eq!(word("vim foo/ba"), "foo/ba");
+		assert_eq!(word("vim 'foo/ba"), "foo/ba");
+		assert_eq!(word(r"vim foo\ bar/b"), r"foo\ bar/b");
+		assert_eq!(word("cp $@ $"), "$");
+	}
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+const MAX: usize = 100;
+
+// Each kind of input has its own history, and like a shell prompt, the recalled
+// entries can be edited before submitting, without changing the history itself.
+#[derive(Default)]
+pub(super) struct InputHistory {
+	entries: HashMap<&'static str, Vec<String>>,
+
+	kind:    &'static str,
+	working: Vec<String>,
+	idx:     usize,
+}
+
+impl InputHistory {
+	pub(super) fn start(&mut self, kind: &'static str, value: &str) {
+		self.kind = kind;
+		self.working = self.entries.get(kind).cloned().unwrap_or_default();
+		self.working.push(value.to_owned());
+		self.idx = self.working.len() - 1;
+	}
+
+	pub(super) fn step(&mut self, current: &str, step: isize) -> Option<&str> {
+		if self.kind.is_empty() {
+			return None;
+		}
+
+		let idx = self.idx.saturating_add_signed(step).min(self.working.len() - 1);
+		if idx == self.idx {
+			return None;
+		}
+
+		self.working[self.idx] = current.to_owned();
+		self.idx = idx;
+		Some(&self.working[idx])
+	}
+
+	pub(super) fn push(&mut self, value: &str) {
+		if self.kind.is_empty() || value.is_empty() {
+			return;
+		}
+
+		let entries = self.entries.entry(self.kind).or_default();
+		entries.retain(|s| s != value);
+		entries.push(value.to_owned());
+		if entries.len() > MAX {
+			entries.drain(..entries.len() - MAX);
+		}
+	}
+}
@@ -1,12 +1,11 @@
-use std::ops::Range;
+use std::{collections::VecDeque, ops::Range};
 
 use tokio::sync::mpsc::UnboundedSender;
-use unicode_width::UnicodeWidthStr;
-use yazi_config::{popup::Position, INPUT};
+use yazi_config::{popup::{Origin, Position}, INPUT};
 use yazi_plugin::CLIPBOARD;
-use yazi_shared::{render, InputError};
+use yazi_shared::{render, str_width, InputError};
 
-use super::{mode::InputMode, op::InputOp, InputSnap, InputSnaps};
+use super::{mode::InputMode, op::InputOp, Completer, InputHistory, InputSnap, InputSnaps};
 
 #[derive(Default)]
 pub struct Input {
@@ -20,33 +19,45 @@ pub struct Input {
 	// Typing
 	pub(super) callback:   Option<UnboundedSender<Result<String, InputError>>>,
 	pub(super) realtime:   bool,
-	pub(super) completion: bool,
+	pub(super) completer:  Option<Box<dyn Completer>>,
 
 	// Shell
 	pub highlight: bool,
+
+	// Passphrase, which is masked and never goes to the clipboard
+	pub obscure: bool,
+
+	// Readline
+	pub(super) history:   InputHistory,
+	pub(super) kill_ring: VecDeque<String>,
 }
 
 impl Input {
 	#[inline]
 	pub(super) fn limit(&self) -> usize {
-		self.position.offset.width.saturating_sub(INPUT.border()) as usize
+		let border = if self.is_inline() { 0 } else { INPUT.load().border() };
+		self.position.offset.width.saturating_sub(border) as usize
 	}
 
 	pub fn type_str(&mut self, s: &str) {
 		let snap = self.snaps.current_mut();
+		let count = snap.count();
 		if snap.cursor < 1 {
 			snap.value.insert_str(0, s);
 		} else {
 			snap.value.insert_str(snap.idx(snap.cursor).unwrap(), s);
 		}
 
-		self.move_(s.chars().count() as isize);
+		// A combining mark joins the character before it, rather than adding a new one
+		let delta = snap.count() as isize - count as isize;
+		self.move_(delta);
 		self.flush_value();
 		render!();
 	}
 
 	pub(super) fn handle_op(&mut self, cursor: usize, include: bool) -> bool {
 		let old = self.snap().clone();
+		let obscure = self.obscure;
 		let snap = self.snaps.current_mut();
 
 		match snap.op {
@@ -58,7 +69,7 @@ impl Input {
 				let Range { start, end } = snap.idx(range.start)..snap.idx(range.end);
 
 				let drain = snap.value.drain(start.unwrap()..end.unwrap()).collect::<String>();
-				if cut {
+				if cut && !obscure {
 					futures::executor::block_on(CLIPBOARD.set(&drain));
 				}
 
@@ -72,7 +83,9 @@ impl Input {
 				let yanked = &snap.value[start.unwrap()..end.unwrap()];
 
 				snap.op = InputOp::None;
-				futures::executor::block_on(CLIPBOARD.set(yanked));
+				if !obscure {
+					futures::executor::block_on(CLIPBOARD.set(yanked));
+				}
 			}
 		};
 
@@ -80,8 +93,11 @@ impl Input {
 		if snap == &old {
 			return false;
 		}
-		if !matches!(old.op, InputOp::None | InputOp::Select(_)) {
-			self.snaps.tag(self.limit()).then(|| self.flush_value());
+		// Passphrases are kept out of the undo history
+		if !matches!(old.op, InputOp::None | InputOp::Select(_))
+			&& (obscure || self.snaps.tag(self.limit()))
+		{
+			self.flush_value();
 		}
 		true
 	}
@@ -95,9 +111,8 @@ impl Input {
 			tx.send(Err(InputError::Typed(value))).ok();
 		}
 
-		if self.completion {
-			let before = self.partition()[0].to_owned();
-			tx.send(Err(InputError::Completed(before, self.ticket))).ok();
+		if let Some(c) = &self.completer {
+			c.trigger(c.word(self.partition()[0]), self.ticket);
 		}
 	}
 }
@@ -109,10 +124,17 @@ impl Input {
 	#[inline]
 	pub fn mode(&self) -> InputMode { self.snap().mode }
 
+	// Inline inputs are drawn over the row of the hovered file, without a border or title.
+	#[inline]
+	pub fn is_inline(&self) -> bool { self.position.origin == Origin::Inline }
+
 	#[inline]
 	pub fn cursor(&self) -> u16 {
 		let snap = self.snap();
-		snap.slice(snap.offset..snap.cursor).width() as u16
+		if self.obscure {
+			return snap.slice(snap.offset..snap.cursor).chars().count() as u16;
+		}
+		str_width(snap.slice(snap.offset..snap.cursor)) as u16
 	}
 
 	pub fn selected(&self) -> Option<Range<u16>> {
@@ -125,8 +147,8 @@ impl Input {
 		let win = snap.window(self.limit());
 		let Range { start, end } = start.max(win.start)..end.min(win.end);
 
-		let s = snap.slice(snap.offset..start).width() as u16;
-		Some(s..s + snap.slice(start..end).width() as u16)
+		let s = str_width(snap.slice(snap.offset..start)) as u16;
+		Some(s..s + str_width(snap.slice(start..end)) as u16)
 	}
 
 	#[inline]
@@ -1,10 +1,14 @@
 mod commands;
+mod completer;
+mod history;
 mod input;
 mod mode;
 mod op;
 mod snap;
 mod snaps;
 
+pub use completer::*;
+use history::*;
 pub use input::*;
 pub use mode::*;
 use op::*;
@@ -1,6 +1,7 @@
 use std::ops::Range;
 
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use yazi_shared::grapheme_width;
 
 use super::{InputMode, InputOp};
 
@@ -32,24 +33,26 @@ impl InputSnap {
 
 	#[inline]
 	pub(super) fn reset(&mut self, limit: usize) {
-		self.cursor = self.cursor.min(self.value.chars().count().saturating_sub(self.mode.delta()));
+		self.cursor = self.cursor.min(self.count().saturating_sub(self.mode.delta()));
 		self.offset =
 			self.offset.min(self.cursor.saturating_sub(Self::find_window(&self.rev(), 0, limit).end));
 	}
 }
 
+// The cursor and offset are counted in grapheme clusters, so a character with its combining
+// marks, or an emoji sequence, is moved over and deleted as a whole.
 impl InputSnap {
 	#[inline]
 	pub(super) fn len(&self) -> usize { self.value.len() }
 
 	#[inline]
-	pub(super) fn count(&self) -> usize { self.value.chars().count() }
+	pub(super) fn count(&self) -> usize { self.value.graphemes(true).count() }
 
 	#[inline]
 	pub(super) fn idx(&self, n: usize) -> Option<usize> {
 		self
 			.value
-			.char_indices()
+			.grapheme_indices(true)
 			.nth(n)
 			.map(|(i, _)| i)
 			.or_else(|| if n == self.count() { Some(self.len()) } else { None })
@@ -62,7 +65,7 @@ impl InputSnap {
 	}
 
 	#[inline]
-	pub(super) fn rev(&self) -> String { self.value.chars().rev().collect::<String>() }
+	pub(super) fn rev(&self) -> String { self.value.graphemes(true).rev().collect::<String>() }
 
 	#[inline]
 	pub(super) fn window(&self, limit: usize) -> Range<usize> {
@@ -73,11 +76,11 @@ impl InputSnap {
 	pub(super) fn find_window(s: &str, offset: usize, limit: usize) -> Range<usize> {
 		let mut width = 0;
 		let v: Vec<_> = s
-			.chars()
+			.graphemes(true)
 			.enumerate()
 			.skip(offset)
-			.map_while(|(i, c)| {
-				width += c.width().unwrap_or(0);
+			.map_while(|(i, g)| {
+				width += grapheme_width(g);
 				if width < limit { Some(i) } else { None }
 			})
 			.collect();
@@ -11,12 +11,18 @@ pub mod help;
 pub mod input;
 pub mod manager;
 pub mod notify;
+pub mod recorder;
 pub mod select;
 pub mod tab;
 pub mod tasks;
+pub mod terminal;
+pub mod vcs;
 pub mod which;
 
 pub fn init() {
 	manager::WATCHED.with(<_>::default);
 	manager::LINKED.with(<_>::default);
+	manager::FETCH_CACHE.with(manager::FetchCache::load);
+	yazi_fs::GITIGNORES.with(<_>::default);
+	yazi_fs::DIR_SIZES.with(<_>::default);
 }
@@ -0,0 +1,151 @@
+use std::{collections::BTreeMap, io, ops::Deref, path::PathBuf, process};
+
+use yazi_boot::BOOT;
+use yazi_config::t;
+use yazi_dds::Pubsub;
+use yazi_proxy::AppProxy;
+use yazi_shared::{emit, event::Cmd, fs::{FilesOp, Url}, Layer};
+
+// The named marks on directories and files, kept in the state directory across sessions.
+#[derive(Default)]
+pub struct Bookmarks {
+	marks:   BTreeMap<String, Url>,
+	// The ones set and removed since saved last, layered over the ones saved by the other instances
+	changes: BookmarkChanges,
+
+	version:  u64,
+	revision: u64,
+}
+
+pub type BookmarkChanges = BTreeMap<String, Option<Url>>;
+
+impl Deref for Bookmarks {
+	type Target = BTreeMap<String, Url>;
+
+	fn deref(&self) -> &Self::Target { &self.marks }
+}
+
+impl Bookmarks {
+	pub fn load() -> Self { Self { marks: Self::read().unwrap_or_default(), ..Default::default() } }
+
+	pub fn set(&mut self, name: String, url: Url) {
+		if self.marks.get(&name) != Some(&url) {
+			self.marks.insert(name.clone(), url.clone());
+			self.changes.insert(name, Some(url));
+			self.revision += 1;
+		}
+	}
+
+	pub fn remove(&mut self, name: &str) -> Option<Url> {
+		let url = self.marks.remove(name)?;
+		self.changes.insert(name.to_owned(), None);
+		self.revision += 1;
+		Some(url)
+	}
+
+	// Taken from another instance through DDS, which has saved them already,
+	// while the changes not saved yet here are kept over them.
+	pub fn replace(&mut self, mut marks: BTreeMap<String, Url>) -> bool {
+		Self::layer(&mut marks, &self.changes);
+		if self.marks == marks {
+			return false;
+		}
+
+		self.marks = marks;
+		self.revision += 1;
+		if self.changes.is_empty() {
+			self.version = self.revision;
+		}
+		true
+	}
+
+	// The first of the names marking the url, in order
+	#[inline]
+	pub fn name_of(&self, url: &Url) -> Option<&str> {
+		self.marks.iter().find(|(_, u)| *u == url).map(|(n, _)| n.as_str())
+	}
+
+	// Follows the marked files when they're renamed, the deleted ones are kept
+	// in case they come back, and warned about when jumped to.
+	pub fn apply_op(&mut self, op: &FilesOp) {
+		let (FilesOp::Updating(_, urls) | FilesOp::Upserting(_, urls)) = op else { return };
+
+		for (name, url) in self.marks.iter_mut() {
+			if let Some(f) = urls.get(url).filter(|f| f.url != *url) {
+				*url = f.url();
+				self.changes.insert(name.clone(), Some(f.url()));
+				self.revision += 1;
+			}
+		}
+	}
+
+	// Saves the changes off the main thread, which are only dropped once they've been written,
+	// and the marks saved are sent back to `saved()`.
+	pub fn catchup_revision(&mut self) -> bool {
+		if self.version == self.revision {
+			return false;
+		}
+
+		self.version = self.revision;
+		let (marks, changes) = (self.marks.clone(), self.changes.clone());
+		tokio::spawn(async move {
+			match tokio::task::spawn_blocking(move || Self::save(marks, changes)).await {
+				Ok(Ok((marks, changes))) => emit!(Call(
+					Cmd::new("update_bookmarks").with_any("saved", (marks, changes)),
+					Layer::Manager
+				)),
+				Ok(Err(e)) => {
+					AppProxy::notify_warn("Bookmarks", &t!("Failed to save the bookmarks: {e}", e = e))
+				}
+				Err(_) => {}
+			}
+		});
+		true
+	}
+
+	// The changes already written are dropped, and the rest are kept over the marks saved
+	pub fn saved(&mut self, marks: BTreeMap<String, Url>, changes: BookmarkChanges) -> bool {
+		self.changes.retain(|name, url| changes.get(name) != Some(url));
+		let changed = self.replace(marks);
+
+		Pubsub::pub_from_bookmark(&self.marks);
+		changed
+	}
+
+	fn save(
+		marks: BTreeMap<String, Url>,
+		changes: BookmarkChanges,
+	) -> io::Result<(BTreeMap<String, Url>, BookmarkChanges)> {
+		// Merged with the ones saved by the other instances in the meantime, not to lose them
+		let mut marks = Self::read().unwrap_or(marks);
+		Self::layer(&mut marks, &changes);
+
+		// Written to a file of its own first, so the others never read it half-written
+		let path = Self::path();
+		let tmp = path.with_extension(format!("json.{}", process::id()));
+		let result = serde_json::to_vec_pretty(&marks)
+			.map_err(Into::into)
+			.and_then(|b| std::fs::write(&tmp, b))
+			.and_then(|()| std::fs::rename(&tmp, &path));
+		if result.is_err() {
+			std::fs::remove_file(&tmp).ok();
+		}
+		result.map(|()| (marks, changes))
+	}
+
+	fn layer(marks: &mut BTreeMap<String, Url>, changes: &BookmarkChanges) {
+		for (name, url) in changes {
+			match url {
+				Some(url) => _ = marks.insert(name.clone(), url.clone()),
+				None => _ = marks.remove(name),
+			}
+		}
+	}
+
+	fn read() -> Option<BTreeMap<String, Url>> {
+		std::fs::read(Self::path()).ok().and_then(|b| serde_json::from_slice(&b).ok())
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("bookmarks.json") }
+}
@@ -0,0 +1,58 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::Cmd;
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	attr: String,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { attr: c.take_first_str().unwrap_or_default() } }
+}
+
+impl Manager {
+	// Toggles the `hidden`, `system` or `readonly` attribute of the selected files on Windows
+	pub fn attrib(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let flag: u32 = match opt.attr.as_str() {
+			"readonly" => 0x1, // FILE_ATTRIBUTE_READONLY
+			"hidden" => 0x2,   // FILE_ATTRIBUTE_HIDDEN
+			"system" => 0x4,   // FILE_ATTRIBUTE_SYSTEM
+			_ => return AppProxy::notify_warn("Attributes", "Expected `hidden`, `system` or `readonly`"),
+		};
+
+		#[cfg(not(windows))]
+		{
+			_ = flag;
+			AppProxy::notify_warn("Attributes", "File attributes are only available on Windows");
+		}
+
+		#[cfg(windows)]
+		self.attrib_do(flag);
+	}
+
+	#[cfg(windows)]
+	fn attrib_do(&mut self, flag: u32) {
+		if !self.active_mut().try_escape_visual() {
+			return;
+		}
+
+		let targets: Vec<_> = self.selected_or_hovered(false).cloned().collect();
+		if targets.is_empty() || Self::refuse_remote("Attributes", targets.iter()) {
+			return;
+		}
+
+		tokio::spawn(async move {
+			for u in targets {
+				if let Err(e) = yazi_shared::fs::toggle_attributes(u.to_path_buf(), flag).await {
+					let name = u.file_name().unwrap_or_default().to_string_lossy();
+					AppProxy::notify_warn(
+						"Attributes",
+						&yazi_config::t!("Failed to change `{name}`: {e}", name = name, e = e),
+					);
+				}
+			}
+		});
+	}
+}
@@ -0,0 +1,26 @@
+use yazi_config::t;
+use yazi_proxy::AppProxy;
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { name: c.take_first_str() } }
+}
+
+impl Manager {
+	pub fn bookmark_delete(&mut self, opt: impl Into<Opt>) {
+		let Some(name) = (opt.into() as Opt).name else {
+			return self.bookmark_pick(true);
+		};
+
+		if self.bookmarks.remove(&name).is_none() {
+			return AppProxy::notify_warn("Bookmark", &t!("No bookmark named `{name}`", name = name));
+		}
+		render!(self.bookmarks.catchup_revision());
+	}
+}
@@ -0,0 +1,60 @@
+use tokio::fs;
+use yazi_config::{popup::SelectCfg, t};
+use yazi_proxy::{AppProxy, SelectProxy, TabProxy};
+use yazi_shared::{emit, event::Cmd, Layer};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { name: c.take_first_str() } }
+}
+
+impl Manager {
+	// Goes into the bookmarked directory, or to the bookmarked file, by its name
+	// or picked from the list of them.
+	pub fn bookmark_jump(&mut self, opt: impl Into<Opt>) {
+		let Some(name) = (opt.into() as Opt).name else {
+			return self.bookmark_pick(false);
+		};
+
+		let Some(url) = self.bookmarks.get(&name).cloned() else {
+			return AppProxy::notify_warn("Bookmark", &t!("No bookmark named `{name}`", name = name));
+		};
+		if !url.is_regular() {
+			return TabProxy::cd(&url);
+		}
+
+		tokio::spawn(async move {
+			match fs::metadata(&url).await {
+				Ok(m) if m.is_dir() => TabProxy::cd(&url),
+				Ok(_) => TabProxy::reveal(&url),
+				Err(_) => AppProxy::notify_warn(
+					"Bookmark",
+					&t!("`{name}` no longer exists: {url}", name = name, url = url),
+				),
+			}
+		});
+	}
+
+	// Lists the bookmarks to be filtered, and runs `bookmark_jump` or `bookmark_delete`
+	// with the one picked.
+	pub(super) fn bookmark_pick(&self, delete: bool) {
+		if self.bookmarks.is_empty() {
+			return AppProxy::notify_warn("Bookmark", "No bookmarks, set one with `bookmark_set`");
+		}
+
+		let (names, items): (Vec<_>, Vec<_>) =
+			self.bookmarks.iter().map(|(n, u)| (n.clone(), format!("{n}  {u}"))).unzip();
+
+		tokio::spawn(async move {
+			let Ok(i) = SelectProxy::show(SelectCfg::bookmarks(delete, items)).await else { return };
+
+			let name = if delete { "bookmark_delete" } else { "bookmark_jump" };
+			emit!(Call(Cmd::args(name, vec![names[i].clone()]), Layer::Manager));
+		});
+	}
+}
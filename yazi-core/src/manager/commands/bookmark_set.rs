@@ -0,0 +1,53 @@
+use yazi_config::popup::InputCfg;
+use yazi_proxy::{AppProxy, InputProxy};
+use yazi_shared::{emit, event::Cmd, fs::Url, render, Layer};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name: Option<String>,
+	cwd:  bool,
+	url:  Option<Url>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { name: c.take_first_str(), cwd: c.bool("cwd"), url: c.take_any("url") }
+	}
+}
+
+impl Manager {
+	// Marks the hovered file, or the CWD with `--cwd` or in an empty directory, with a name,
+	// asked for if not given. A name already taken is moved onto it.
+	pub fn bookmark_set(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let url = opt
+			.url
+			.or_else(|| self.hovered().filter(|_| !opt.cwd).map(|h| h.url()))
+			.unwrap_or_else(|| self.cwd().clone());
+
+		if url.is_search() || url.is_recent() {
+			return AppProxy::notify_warn("Bookmark", "Search results can't be bookmarked");
+		}
+
+		let Some(name) = opt.name.filter(|s| !s.is_empty()) else {
+			let value = self.bookmarks.name_of(&url).unwrap_or_default().to_owned();
+			return Self::bookmark_ask(url, value);
+		};
+
+		self.bookmarks.set(name, url);
+		render!(self.bookmarks.catchup_revision());
+	}
+
+	fn bookmark_ask(url: Url, value: String) {
+		tokio::spawn(async move {
+			let cfg = InputCfg::bookmark().with_value(value);
+			let Some(Ok(name)) = InputProxy::show(cfg).recv().await else { return };
+			if name.is_empty() {
+				return;
+			}
+
+			emit!(Call(Cmd::args("bookmark_set", vec![name]).with_any("url", url), Layer::Manager));
+		});
+	}
+}
@@ -1,18 +1,18 @@
-use std::{borrow::Cow, collections::HashMap, ffi::{OsStr, OsString}, io::{stderr, BufWriter, Write}, path::PathBuf};
+use std::{borrow::Cow, collections::HashMap, ffi::{OsStr, OsString}, io::{stderr, BufWriter, Write}, path::{Path, PathBuf}};
 
 use anyhow::{anyhow, Result};
 use scopeguard::defer;
 use tokio::{fs::{self, OpenOptions}, io::{stdin, AsyncReadExt, AsyncWriteExt}};
-use yazi_config::{OPEN, PREVIEW};
+use yazi_config::{MANAGER, OPEN, PREVIEW};
 use yazi_dds::Pubsub;
 use yazi_proxy::{AppProxy, TasksProxy, HIDER, WATCHER};
-use yazi_shared::{fs::{max_common_root, maybe_exists, paths_to_same_file, File, FilesOp, Url}, terminal_clear};
+use yazi_shared::{fs::{is_case_rename, max_common_root, maybe_exists, paths_to_same_file, rename_case, sanitize_name, unportable_name, File, FilesOp, Url}, terminal_clear};
 
 use crate::manager::Manager;
 
 impl Manager {
 	pub(super) fn bulk_rename(&self) {
-		let Some(opener) = OPEN.block_opener("bulk.txt", "text/plain") else {
+		let Some(opener) = OPEN.load().block_opener("bulk.txt", "text/plain").cloned() else {
 			return AppProxy::notify_warn("Bulk rename", "No text opener found");
 		};
 
@@ -23,7 +23,7 @@ impl Manager {
 		let old: Vec<_> = old.into_iter().map(|p| p.strip_prefix(&root).unwrap().to_owned()).collect();
 
 		tokio::spawn(async move {
-			let tmp = PREVIEW.tmpfile("bulk");
+			let tmp = PREVIEW.load().tmpfile("bulk");
 			let s = old.iter().map(|o| o.as_os_str()).collect::<Vec<_>>().join(OsStr::new("\n"));
 			OpenOptions::new()
 				.write(true)
@@ -34,7 +34,7 @@ impl Manager {
 				.await?;
 
 			defer! { tokio::spawn(fs::remove_file(tmp.clone())); }
-			TasksProxy::process_exec(vec![OsString::new(), tmp.to_owned().into()], Cow::Borrowed(opener))
+			TasksProxy::process_exec(vec![OsString::new(), tmp.to_owned().into()], Cow::Owned(opener))
 				.await;
 
 			let _permit = HIDER.acquire().await.unwrap();
@@ -64,29 +64,50 @@ impl Manager {
 			return Ok(());
 		}
 
+		let mut unportable = false;
 		{
 			let mut stderr = BufWriter::new(stderr().lock());
 			for (o, n) in &todo {
-				writeln!(stderr, "{} -> {}", o.display(), n.display())?;
+				match Self::bulk_unportable(n) {
+					Some(r) => {
+						unportable = true;
+						writeln!(stderr, "{} -> {}  (not portable, {r})", o.display(), n.display())?;
+					}
+					None => writeln!(stderr, "{} -> {}", o.display(), n.display())?,
+				}
+			}
+			if unportable {
+				write!(stderr, "Continue to rename? (y/N, or s to sanitize the unportable names): ")?;
+			} else {
+				write!(stderr, "Continue to rename? (y/N): ")?;
 			}
-			write!(stderr, "Continue to rename? (y/N): ")?;
 			stderr.flush()?;
 		}
 
 		let mut buf = [0; 10];
 		_ = stdin().read(&mut buf).await?;
-		if buf[0] != b'y' && buf[0] != b'Y' {
+		let sanitize = unportable && (buf[0] == b's' || buf[0] == b'S');
+		if buf[0] != b'y' && buf[0] != b'Y' && !sanitize {
 			return Ok(());
 		}
 
+		let todo: Vec<_> = if sanitize {
+			todo.into_iter().map(|(o, n)| (o, Self::bulk_sanitize(n))).collect()
+		} else {
+			todo
+		};
+
 		let permit = WATCHER.acquire().await.unwrap();
 		let (mut failed, mut succeeded) = (Vec::new(), HashMap::with_capacity(todo.len()));
 		for (o, n) in todo {
 			let (old, new) = (root.join(&o), root.join(&n));
 
-			if maybe_exists(&new).await && !paths_to_same_file(&old, &new).await {
+			let case = is_case_rename(&old, &new).await;
+			if !case && maybe_exists(&new).await && !paths_to_same_file(&old, &new).await {
 				failed.push((o, n, anyhow!("Destination already exists")));
-			} else if let Err(e) = fs::rename(&old, &new).await {
+			} else if let Err(e) =
+				if case { rename_case(&old, &new).await } else { fs::rename(&old, &new).await }
+			{
 				failed.push((o, n, e.into()));
 			} else if let Ok(f) = File::from(new.into()).await {
 				succeeded.insert(Url::from(old), f);
@@ -107,6 +128,20 @@ impl Manager {
 		Ok(())
 	}
 
+	fn bulk_unportable(new: &Path) -> Option<String> {
+		if !MANAGER.load().portable_names {
+			return None;
+		}
+		new.file_name().and_then(|s| s.to_str()).and_then(unportable_name)
+	}
+
+	fn bulk_sanitize(new: PathBuf) -> PathBuf {
+		match new.file_name().and_then(|s| s.to_str()) {
+			Some(s) if Self::bulk_unportable(&new).is_some() => new.with_file_name(sanitize_name(s)),
+			_ => new,
+		}
+	}
+
 	async fn output_failed(failed: Vec<(PathBuf, PathBuf, anyhow::Error)>) -> Result<()> {
 		terminal_clear(&mut stderr())?;
 
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use yazi_config::OPEN;
+use yazi_proxy::AppProxy;
+use yazi_shared::event::Cmd;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+impl Manager {
+	// Compares the two selected files, or the hovered ones of this tab and the next, with the
+	// `compare` opener, which blocks for the terminal tools and is an orphan for the GUI ones.
+	pub fn compare(&mut self, _: Cmd, tasks: &Tasks) {
+		let tab = self.active();
+		let pair = if tab.selected.len() == 2 {
+			let mut it = tab.selected_or_hovered(true).cloned();
+			it.next().zip(it.next())
+		} else if self.tabs.len() > 1 {
+			let next = &self.tabs[(self.tabs.cursor + 1) % self.tabs.len()];
+			tab.current.hovered().zip(next.current.hovered()).map(|(a, b)| (a.url(), b.url()))
+		} else {
+			None
+		};
+
+		let Some((a, b)) = pair.filter(|(a, b)| a != b) else {
+			return AppProxy::notify_warn("Compare", "Select two files, or hover one in each of two tabs");
+		};
+		if Self::refuse_remote("Compare", [&a, &b].into_iter()) {
+			return;
+		}
+
+		let Some(opener) = OPEN.load().named("compare").cloned() else {
+			return AppProxy::notify_warn("Compare", "No `compare` opener available for this platform");
+		};
+
+		let (a, b) = (a.into_os_string(), b.into_os_string());
+		tasks.process_once(Cow::Owned(opener), vec![a.clone(), a, b]);
+	}
+}
@@ -2,9 +2,10 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use tokio::fs;
-use yazi_config::popup::InputCfg;
-use yazi_proxy::{InputProxy, TabProxy, WATCHER};
-use yazi_shared::{event::Cmd, fs::{maybe_exists, ok_or_not_found, symlink_realpath, File, FilesOp, Url}};
+use yazi_config::popup::{InputCfg, SelectCfg};
+use yazi_fs::Recent;
+use yazi_proxy::{InputProxy, SelectProxy, TabProxy, WATCHER};
+use yazi_shared::{event::Cmd, fs::{maybe_exists, ok_or_not_found, symlink_realpath, unique_name, File, FilesOp, Url}};
 
 use crate::manager::Manager;
 
@@ -18,6 +19,10 @@ impl From<Cmd> for Opt {
 
 impl Manager {
 	pub fn create(&self, opt: impl Into<Opt>) {
+		if Self::refuse_remote("Create", [self.cwd()].into_iter()) {
+			return;
+		}
+
 		let opt = opt.into() as Opt;
 		let cwd = self.cwd().to_owned();
 		tokio::spawn(async move {
@@ -28,11 +33,15 @@ impl Manager {
 			if name.is_empty() {
 				return Ok(());
 			}
+			let Some(name) = Self::portable_name(name).await else {
+				return Ok(());
+			};
 
-			let new = cwd.join(&name);
+			let mut new = cwd.join(&name);
 			if !opt.force && maybe_exists(&new).await {
-				match InputProxy::show(InputCfg::overwrite()).recv().await {
-					Some(Ok(c)) if c == "y" || c == "Y" => (),
+				match SelectProxy::show(SelectCfg::overwrite()).await {
+					Ok(0) => {}
+					Ok(1) => new = unique_name(new).await,
 					_ => return Ok(()),
 				}
 			}
@@ -57,6 +66,7 @@ impl Manager {
 			fs::File::create(&new).await?;
 		}
 
+		Recent::touch([&new]);
 		if let Ok(f) = File::from(new.clone()).await {
 			FilesOp::Upserting(parent, HashMap::from_iter([(f.url(), f)])).emit();
 			TabProxy::reveal(&new)
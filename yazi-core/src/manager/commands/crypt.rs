@@ -0,0 +1,133 @@
+use std::ffi::OsString;
+
+use tokio::fs;
+use yazi_config::popup::InputCfg;
+use yazi_proxy::{AppProxy, InputProxy};
+use yazi_shared::{emit, event::Cmd, fs::{unique_name, Url}, Layer};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	gpg: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { gpg: c.bool("gpg") } }
+}
+
+impl Manager {
+	// Encrypts each of the selected files into a `.age` next to it, or a `.gpg` with `--gpg`
+	pub fn encrypt(&mut self, opt: impl Into<Opt>) {
+		let Some(targets) = self.crypt_targets("Encrypt") else { return };
+
+		let opt = opt.into() as Opt;
+		tokio::spawn(async move {
+			let targets = Self::crypt_files(targets).await;
+			if targets.is_empty() {
+				return AppProxy::notify_warn("Encrypt", "Only files can be encrypted");
+			}
+			let Some(passphrase) = Self::passphrase(true).await else { return };
+
+			let mut pairs = Vec::with_capacity(targets.len());
+			for from in targets {
+				let mut name = OsString::from(from.file_name().unwrap_or_default());
+				name.push(if opt.gpg { ".gpg" } else { ".age" });
+
+				let Some(parent) = from.parent_url() else { continue };
+				pairs.push((from, unique_name(parent.join(name)).await));
+			}
+			Self::crypt_emit(pairs, passphrase, true, opt.gpg);
+		});
+	}
+
+	// Decrypts the selected `.age` ones with the built-in age, and the `.gpg` or `.asc` ones with gpg
+	pub fn decrypt(&mut self, _: Cmd) {
+		let Some(targets) = self.crypt_targets("Decrypt") else { return };
+
+		tokio::spawn(async move {
+			let (mut age, mut gpg) = (vec![], vec![]);
+			for from in Self::crypt_files(targets).await {
+				let ext = from.extension().map(|e| e.to_ascii_lowercase());
+				let (Some(parent), Some(stem)) = (from.parent_url(), from.file_stem()) else { continue };
+
+				let to = unique_name(parent.join(stem)).await;
+				match ext.as_ref().and_then(|e| e.to_str()) {
+					Some("age") => age.push((from, to)),
+					Some("gpg" | "pgp" | "asc") => gpg.push((from, to)),
+					_ => {}
+				}
+			}
+
+			if age.is_empty() && gpg.is_empty() {
+				return AppProxy::notify_warn("Decrypt", "No `.age`, `.gpg` or `.asc` files selected");
+			}
+			let Some(passphrase) = Self::passphrase(false).await else { return };
+
+			if !age.is_empty() {
+				Self::crypt_emit(age, passphrase.clone(), false, false);
+			}
+			if !gpg.is_empty() {
+				Self::crypt_emit(gpg, passphrase, false, true);
+			}
+		});
+	}
+
+	pub fn crypt_do(&mut self, mut c: Cmd, tasks: &Tasks) {
+		let Some(targets) = c.take_any::<Vec<(Url, Url)>>("targets") else { return };
+		let Some(passphrase) = c.take_any::<String>("passphrase") else { return };
+
+		tasks.file_crypt(targets, &passphrase, c.bool("encrypt"), c.bool("gpg"));
+	}
+
+	fn crypt_targets(&mut self, title: &str) -> Option<Vec<Url>> {
+		if !self.active_mut().try_escape_visual() {
+			return None;
+		}
+
+		let targets: Vec<_> = self.selected_or_hovered(false).cloned().collect();
+		if targets.is_empty() || Self::refuse_remote(title, targets.iter()) {
+			return None;
+		}
+		Some(targets)
+	}
+
+	async fn crypt_files(targets: Vec<Url>) -> Vec<Url> {
+		let mut files = Vec::with_capacity(targets.len());
+		for u in targets {
+			if fs::metadata(&u).await.is_ok_and(|m| m.is_file()) {
+				files.push(u);
+			}
+		}
+		files
+	}
+
+	// Asks for it twice when encrypting, as a typo there would make the files unrecoverable
+	async fn passphrase(confirm: bool) -> Option<String> {
+		let Some(Ok(first)) = InputProxy::show(InputCfg::passphrase(false)).recv().await else {
+			return None;
+		};
+		if first.is_empty() || !confirm {
+			return Some(first).filter(|s| !s.is_empty());
+		}
+
+		let Some(Ok(second)) = InputProxy::show(InputCfg::passphrase(true)).recv().await else {
+			return None;
+		};
+		if first != second {
+			AppProxy::notify_warn("Encrypt", "The passphrases don't match");
+			return None;
+		}
+		Some(first)
+	}
+
+	fn crypt_emit(targets: Vec<(Url, Url)>, passphrase: String, encrypt: bool, gpg: bool) {
+		emit!(Call(
+			Cmd::new("crypt_do")
+				.with_any("targets", targets)
+				.with_any("passphrase", passphrase)
+				.with_bool("encrypt", encrypt)
+				.with_bool("gpg", gpg),
+			Layer::Manager
+		));
+	}
+}
@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+use yazi_config::OPEN;
+use yazi_proxy::AppProxy;
+use yazi_shared::event::Cmd;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+impl Manager {
+	// Terminals can't be the source of a drag, so the selected files are handed to a small window
+	// that can be, through the `drag` opener, which is `ripdrag` or `dragon-drop` by default.
+	pub fn drag(&mut self, _: Cmd, tasks: &Tasks) {
+		let Some(hovered) = self.hovered().map(|h| h.url()) else {
+			return;
+		};
+
+		let targets: Vec<_> = self.selected_or_hovered(true).cloned().collect();
+		if Self::refuse_remote("Drag", targets.iter()) {
+			return;
+		}
+
+		let Some(opener) = OPEN.load().named("drag").cloned() else {
+			return AppProxy::notify_warn("Drag", "No `drag` opener available for this platform");
+		};
+
+		let args = [hovered].into_iter().chain(targets).map(|u| u.into_os_string()).collect();
+		tasks.process_from_opener(Cow::Owned(opener), args);
+	}
+}
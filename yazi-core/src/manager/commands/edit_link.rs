@@ -0,0 +1,106 @@
+use std::{collections::HashMap, io, path::{Path, PathBuf}};
+
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::{popup::InputCfg, t};
+use yazi_proxy::{AppProxy, InputProxy, TabProxy, WATCHER};
+use yazi_shared::{event::Cmd, fs::{canonicalize, clean_path, path_relative_to, File, FilesOp, Url}};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	relative: bool,
+	absolute: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { relative: c.bool("relative"), absolute: c.bool("absolute") } }
+}
+
+impl Manager {
+	// Edits the target of the hovered symlink, shown as it is, or converted to be relative
+	// to the link with `--relative`, or absolute with `--absolute`.
+	pub fn edit_link(&mut self, opt: impl Into<Opt>) {
+		let Some(hovered) = self.hovered() else { return };
+		if !hovered.is_link() {
+			return AppProxy::notify_warn("Edit link", "The hovered file is not a symlink");
+		}
+
+		let url = hovered.url();
+		if Self::refuse_remote("Edit link", [&url].into_iter()) {
+			return;
+		}
+
+		let opt = opt.into() as Opt;
+		tokio::spawn(async move {
+			let (old, value) = match Self::link_target(&url, &opt).await {
+				Ok(v) => v,
+				Err(e) => return AppProxy::notify_warn("Edit link", &t!("Failed to read: {e}", e = e)),
+			};
+
+			let cfg = InputCfg::edit_link().with_value(value.to_string_lossy());
+			let Some(Ok(new)) = InputProxy::show(cfg).recv().await else { return };
+			if new.is_empty() || Path::new(&new) == old {
+				return;
+			}
+
+			if let Err(e) = Self::relink(&url, Path::new(&new)).await {
+				AppProxy::notify_warn("Edit link", &t!("Failed to retarget: {e}", e = e));
+			}
+		});
+	}
+
+	async fn link_target(url: &Url, opt: &Opt) -> io::Result<(PathBuf, PathBuf)> {
+		let old = fs::read_link(url).await?;
+		if !opt.relative && !opt.absolute {
+			return Ok((old.clone(), old));
+		}
+
+		let parent = canonicalize(url.parent().unwrap_or(url)).await?;
+		let absolute = clean_path(parent.join(&old));
+		let value = if opt.relative {
+			path_relative_to(&absolute, &parent).into_owned()
+		} else {
+			absolute
+		};
+		Ok((old, value))
+	}
+
+	// On Unix a new link is made next to it and moved over, so it's never missing in between
+	async fn relink(url: &Url, to: &Path) -> Result<()> {
+		let Some(parent) = url.parent_url() else { return Ok(()) };
+		let _permit = WATCHER.acquire().await.unwrap();
+
+		#[cfg(unix)]
+		{
+			use std::ffi::OsString;
+
+			let mut tmp = OsString::from(".");
+			tmp.push(url.file_name().unwrap_or_default());
+			tmp.push(format!(".{}", yazi_shared::timestamp_us()));
+			let tmp = url.with_file_name(tmp);
+
+			fs::symlink(to, &tmp).await?;
+			if let Err(e) = fs::rename(&tmp, url).await {
+				fs::remove_file(&tmp).await.ok();
+				Err(e)?;
+			}
+		}
+		#[cfg(windows)]
+		{
+			let dir = fs::metadata(parent.join(to)).await.is_ok_and(|m| m.is_dir());
+			if fs::remove_file(url).await.is_err() {
+				fs::remove_dir(url).await?;
+			}
+			if dir {
+				fs::symlink_dir(to, url).await?;
+			} else {
+				fs::symlink_file(to, url).await?;
+			}
+		}
+
+		let file = File::from(url.clone()).await?;
+		FilesOp::Upserting(parent, HashMap::from_iter([(url.clone(), file)])).emit();
+		Ok(TabProxy::reveal(url))
+	}
+}
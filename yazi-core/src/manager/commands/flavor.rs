@@ -0,0 +1,54 @@
+use yazi_config::{popup::SelectCfg, theme::Flavor, THEME};
+use yazi_plugin::{external::Highlighter, Config, LUA};
+use yazi_proxy::{AppProxy, ManagerProxy, SelectProxy};
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { name: c.take_first_str() } }
+}
+
+impl Manager {
+	pub fn flavor(&mut self, opt: impl Into<Opt>) {
+		let Some(name) = opt.into().name else {
+			return Self::flavor_pick();
+		};
+
+		// The icon profile switched at runtime is kept
+		let icons = THEME.load().icons.profile.to_string();
+		if let Err(e) = yazi_config::reload_theme(Some(&name), Some(&icons)) {
+			return AppProxy::notify_error("Failed to apply flavor", &e.to_string());
+		}
+		if let Err(e) = Config::new(&LUA).install_theme() {
+			return AppProxy::notify_error("Failed to apply flavor", &e.to_string());
+		}
+
+		Highlighter::reset_theme();
+		self.active_mut().preview.reset();
+		self.peek(true);
+		render!();
+	}
+
+	fn flavor_pick() {
+		let names = Flavor::list();
+		if names.is_empty() {
+			return AppProxy::notify_warn("Flavor", "No flavors installed");
+		}
+
+		let items = names
+			.iter()
+			.map(|s| if *s == THEME.load().flavor.use_ { format!("{s} (current)") } else { s.clone() })
+			.collect();
+
+		tokio::spawn(async move {
+			if let Ok(choice) = SelectProxy::show(SelectCfg::flavor(items)).await {
+				ManagerProxy::flavor(&names[choice]);
+			}
+		});
+	}
+}
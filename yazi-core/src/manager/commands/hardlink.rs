@@ -16,6 +16,9 @@ impl Manager {
 		if self.yanked.cut {
 			return;
 		}
+		if Self::refuse_remote("Hardlink", self.yanked.iter().chain([self.cwd()])) {
+			return;
+		}
 
 		let opt = opt.into() as Opt;
 		tasks.file_hardlink(&self.yanked, self.cwd(), opt.force, opt.follow);
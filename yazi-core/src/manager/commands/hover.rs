@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use yazi_dds::Pubsub;
-use yazi_shared::{event::{Cmd, Data}, fs::Url, render};
+use yazi_shared::{event::{damage_regions, Cmd, Data, Region}, fs::Url};
 
 use crate::manager::Manager;
 
@@ -21,7 +21,9 @@ impl Manager {
 		let opt = opt.into() as Opt;
 
 		// Hover on the file
-		render!(self.current_mut().repos(opt.url.as_ref()));
+		if self.current_mut().repos(opt.url.as_ref()) {
+			damage_regions(&[Region::Header, Region::Current, Region::Status]);
+		}
 		if opt.url.zip(self.current().hovered()).is_some_and(|(u, f)| u == f.url) {
 			// `hover(Some)` occurs after user actions, such as create, rename, reveal, etc.
 			// At this point, it's intuitive to track the location of this file regardless.
@@ -42,8 +44,14 @@ impl Manager {
 				to_watch.insert(&h.url);
 			}
 		}
+		self.vcs.watch(to_watch.iter().copied());
 		self.watcher.watch(to_watch);
 
+		// Last commit of the hovered file
+		if let Some(url) = self.hovered().filter(|h| !h.is_dir() && !h.url.is_remote()).map(|h| h.url()) {
+			self.vcs.peek(&url);
+		}
+
 		// Publish through DDS
 		Pubsub::pub_from_hover(self.active().idx, self.hovered().map(|h| &h.url));
 	}
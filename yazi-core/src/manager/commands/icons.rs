@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use yazi_config::{theme::IconProfile, THEME};
+use yazi_plugin::{Config, LUA};
+use yazi_proxy::AppProxy;
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	profile: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { profile: c.take_first_str() } }
+}
+
+impl Manager {
+	// Switches to the given icon profile, or the next one of `nerd`, `unicode` and `ascii`,
+	// keeping the flavor in use.
+	pub fn icons(&mut self, opt: impl Into<Opt>) {
+		let profile = match opt.into().profile.as_deref().map(IconProfile::from_str) {
+			Some(Ok(p)) => p,
+			Some(Err(e)) => return AppProxy::notify_warn("Icons", &e.to_string()),
+			None => THEME.load().icons.profile.next(),
+		};
+
+		let flavor = Some(THEME.load().flavor.use_.clone()).filter(|s| !s.is_empty());
+		if let Err(e) = yazi_config::reload_theme(flavor.as_deref(), Some(&profile.to_string())) {
+			return AppProxy::notify_error("Failed to switch icons", &e.to_string());
+		}
+		if let Err(e) = Config::new(&LUA).install_theme() {
+			return AppProxy::notify_error("Failed to switch icons", &e.to_string());
+		}
+
+		self.active_mut().preview.reset();
+		self.peek(true);
+		render!();
+	}
+}
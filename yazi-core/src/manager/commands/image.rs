@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use yazi_proxy::AppProxy;
+use yazi_scheduler::ImageOp;
+use yazi_shared::event::{Cmd, Data};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	op:      Result<ImageOp, String>,
+	quality: u8,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		let max = c.get("max").and_then(Data::as_usize);
+		let op = match (c.take_first_str().as_deref(), c.take_str("1").as_deref()) {
+			(Some("rotate"), _) => Ok(ImageOp::Rotate(!c.bool("ccw"))),
+			(Some("resize"), _) => match max {
+				Some(n @ 1..) => Ok(ImageOp::Resize(n.min(u32::MAX as usize) as u32)),
+				_ => Err("Expected `--max=<pixels>` to resize to".to_owned()),
+			},
+			(Some("convert"), ext) => ext
+				.and_then(ImageOp::convert)
+				.ok_or_else(|| "Expected `jpeg`, `webp` or `png` to convert to".to_owned()),
+			_ => Err("Expected `rotate`, `resize` or `convert`".to_owned()),
+		};
+
+		let quality = c.get("quality").and_then(Data::as_usize).unwrap_or(90).clamp(1, 100) as u8;
+		Self { op, quality }
+	}
+}
+
+impl Manager {
+	// Rotates, resizes or converts each of the selected images into a new file next to it,
+	// one task per image.
+	pub fn image(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let opt = opt.into() as Opt;
+		let op = match opt.op {
+			Ok(op) => op,
+			Err(e) => return AppProxy::notify_warn("Image", &e),
+		};
+
+		if !self.active_mut().try_escape_visual() {
+			return;
+		}
+
+		// The directories are left out, as far as the cwd tells
+		let dirs: HashSet<_> =
+			self.current().files.iter().filter(|f| f.is_dir()).map(|f| &f.url).collect();
+		let targets: Vec<_> =
+			self.selected_or_hovered(false).filter(|&u| !dirs.contains(u)).cloned().collect();
+		if targets.is_empty() || Self::refuse_remote("Image", targets.iter()) {
+			return;
+		}
+
+		for from in targets {
+			tasks.file_image(from, op, opt.quality);
+		}
+	}
+}
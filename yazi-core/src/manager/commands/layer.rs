@@ -0,0 +1,32 @@
+use yazi_config::KEYMAP;
+use yazi_proxy::AppProxy;
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { name: c.take_first_str() } }
+}
+
+impl Manager {
+	// Toggles the named layer, or leaves the most recent one if no name is given.
+	pub fn layer(&mut self, opt: impl Into<Opt>) {
+		let Some(name) = opt.into().name else {
+			return render!(self.layers.pop().is_some());
+		};
+
+		if let Some(i) = self.layers.iter().position(|l| *l == name) {
+			self.layers.remove(i);
+		} else if KEYMAP.load().layers.contains_key(&name) {
+			self.layers.push(name);
+		} else {
+			let msg = format!("Layer `{name}` is not defined in keymap.toml");
+			return AppProxy::notify_warn("Layer", &msg);
+		}
+		render!();
+	}
+}
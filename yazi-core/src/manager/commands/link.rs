@@ -5,19 +5,48 @@ use crate::{manager::Manager, tasks::Tasks};
 pub struct Opt {
 	relative: bool,
 	force:    bool,
+	selected: bool,
 }
 
 impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { relative: c.bool("relative"), force: c.bool("force") } }
+	fn from(c: Cmd) -> Self {
+		Self { relative: c.bool("relative"), force: c.bool("force"), selected: c.bool("selected") }
+	}
 }
 
 impl Manager {
 	pub fn link(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let opt = opt.into() as Opt;
+		if opt.selected {
+			return self.link_selected(opt, tasks);
+		}
+
 		if self.yanked.cut {
 			return;
 		}
+		if Self::refuse_remote("Link", self.yanked.iter().chain([self.cwd()])) {
+			return;
+		}
 
-		let opt = opt.into() as Opt;
 		tasks.file_link(&self.yanked, self.cwd(), opt.relative, opt.force);
 	}
+
+	// Links the selected files into the hovered directory, or the cwd if it's one of them,
+	// all in one task.
+	fn link_selected(&mut self, opt: Opt, tasks: &Tasks) {
+		if !self.active_mut().try_escape_visual() {
+			return;
+		}
+
+		let targets: Vec<_> = self.selected_or_hovered(true).cloned().collect();
+		let dest = match self.hovered() {
+			Some(h) if h.is_dir() && !targets.contains(&h.url) => &h.url,
+			_ => self.cwd(),
+		};
+		if targets.is_empty() || Self::refuse_remote("Link", targets.iter().chain([dest])) {
+			return;
+		}
+
+		tasks.file_link_many(targets, dest, opt.relative, opt.force);
+	}
 }
@@ -0,0 +1,137 @@
+use std::{collections::{HashMap, HashSet}, path::{Path, PathBuf}, sync::Arc};
+
+use md5::{Digest, Md5};
+use parking_lot::Mutex;
+use yazi_boot::BOOT;
+use yazi_config::{popup::InputCfg, Local, MANAGER};
+use yazi_proxy::{AppProxy, InputProxy, ManagerProxy};
+
+use crate::{manager::Manager, tab::Tab};
+
+// Trusted local configs, along with the digest of their content when trusted
+static TRUSTED: Mutex<Option<HashMap<PathBuf, String>>> = Mutex::new(None);
+
+// Trusted local configs that have been parsed, along with the digest of their content
+static LOADED: Mutex<Option<HashMap<PathBuf, Loaded>>> = Mutex::new(None);
+
+type Loaded = (String, Arc<Local>);
+
+// Local configs that have been prompted in this session
+static ASKED: Mutex<Option<HashSet<PathBuf>>> = Mutex::new(None);
+
+impl Manager {
+	// Applies the `.yazi.toml` nearest to the cwd of each tab if it's trusted, or asks the user.
+	pub(super) fn local(&mut self) {
+		if !MANAGER.load().local_config {
+			return;
+		}
+
+		let active = self.tabs.cursor;
+		for (i, tab) in self.tabs.iter_mut().enumerate() {
+			// The active one is re-sorted by the refresh that comes with it
+			if Self::local_apply(tab) && i != active {
+				tab.apply_files_attrs();
+			}
+		}
+	}
+
+	fn local_apply(tab: &mut Tab) -> bool {
+		let file = tab.current.cwd.is_regular().then(|| Local::find(&tab.current.cwd)).flatten();
+		if tab.local.as_ref().map(|(l, _)| &l.file) == file.as_ref() {
+			return false;
+		}
+
+		// Leaving the subtree of a local config restores the config from before entering it
+		let mut changed = false;
+		if let Some((_, before)) = tab.local.take() {
+			changed = tab.conf.patch(|c| *c = before);
+		}
+
+		let Some(local) = file.and_then(|f| Self::local_load(&f)) else { return changed };
+		let before = tab.conf.clone();
+		changed |= tab.conf.patch(|c| {
+			let m = &local.manager;
+			c.sort_by = m.sort_by.unwrap_or(c.sort_by);
+			c.sort_sensitive = m.sort_sensitive.unwrap_or(c.sort_sensitive);
+			c.sort_reverse = m.sort_reverse.unwrap_or(c.sort_reverse);
+			c.sort_dir_first = m.sort_dir_first.unwrap_or(c.sort_dir_first);
+			c.sort_translit = m.sort_translit.unwrap_or(c.sort_translit);
+			c.show_hidden = m.show_hidden.unwrap_or(c.show_hidden);
+			if let Some(s) = m.linemode.as_ref().filter(|s| !s.is_empty() && s.len() <= 20) {
+				c.linemode = s.clone();
+			}
+		});
+
+		tab.local = Some((local, before));
+		changed
+	}
+
+	// Parses the local config once for each content it has had, and shares it among the tabs
+	fn local_load(file: &Path) -> Option<Arc<Local>> {
+		let content = std::fs::read_to_string(file).ok()?;
+		let digest = format!("{:x}", Md5::new_with_prefix(&content).finalize());
+
+		let mut loaded = LOADED.lock();
+		let loaded = loaded.get_or_insert_with(Default::default);
+		if let Some((d, local)) = loaded.get(file) {
+			if *d == digest {
+				return Some(local.clone());
+			}
+		}
+
+		if !Self::local_trusted(file, &digest) {
+			Self::local_ask(file.to_owned(), digest);
+			return None;
+		}
+
+		match Local::load(file, &content) {
+			Ok(local) => {
+				let local = Arc::new(local);
+				loaded.insert(file.to_owned(), (digest, local.clone()));
+				Some(local)
+			}
+			Err(e) => {
+				AppProxy::notify_error("Local config", &format!("{e:#}"));
+				None
+			}
+		}
+	}
+
+	fn local_trusted(file: &Path, digest: &str) -> bool {
+		let mut trusted = TRUSTED.lock();
+		let trusted = trusted.get_or_insert_with(|| {
+			std::fs::read(Self::local_store())
+				.ok()
+				.and_then(|b| serde_json::from_slice(&b).ok())
+				.unwrap_or_default()
+		});
+		trusted.get(file).is_some_and(|s| s == digest)
+	}
+
+	fn local_ask(file: PathBuf, digest: String) {
+		if !ASKED.lock().get_or_insert_with(Default::default).insert(file.clone()) {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let mut rx = InputProxy::show(InputCfg::trust(&file.to_string_lossy()));
+			if !matches!(rx.recv().await, Some(Ok(s)) if s == "y" || s == "Y") {
+				return;
+			}
+
+			{
+				let mut trusted = TRUSTED.lock();
+				let trusted = trusted.get_or_insert_with(Default::default);
+				trusted.insert(file, digest);
+				if let Ok(s) = serde_json::to_string(trusted) {
+					let (store, tmp) = (Self::local_store(), Self::local_store().with_extension("json.tmp"));
+					std::fs::write(&tmp, s).and_then(|_| std::fs::rename(tmp, store)).ok();
+				}
+			}
+			ManagerProxy::refresh();
+		});
+	}
+
+	#[inline]
+	fn local_store() -> PathBuf { BOOT.state_dir.join("trusted.json") }
+}
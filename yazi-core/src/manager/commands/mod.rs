@@ -1,26 +1,56 @@
+mod attrib;
+mod bookmark_delete;
+mod bookmark_jump;
+mod bookmark_set;
 mod bulk_rename;
 mod close;
+mod compare;
 mod create;
+mod crypt;
+mod drag;
+mod edit_link;
+mod flavor;
 mod hardlink;
 mod hover;
+mod icons;
+mod image;
+mod layer;
 mod link;
+mod local;
+mod mount;
 mod open;
+mod open_terminal;
 mod paste;
 mod peek;
+mod perf;
 mod quit;
+mod refetch;
 mod refresh;
 mod remove;
 mod rename;
+mod restore;
+mod rpc_state;
 mod seek;
+mod send_to;
+mod show_items;
 mod suspend;
 mod tab_close;
 mod tab_create;
 mod tab_swap;
 mod tab_switch;
+mod touch;
+mod unmount;
 mod unyank;
+mod update_bookmarks;
+mod update_commit;
 mod update_files;
 mod update_mimetype;
 mod update_paged;
+mod update_security;
+mod update_selected;
+mod update_space;
+mod update_tags;
 mod update_task;
+mod update_vcs;
 mod update_yanked;
 mod yank;
@@ -0,0 +1,81 @@
+use std::mem;
+
+use yazi_config::{popup::SelectCfg, t};
+use yazi_fs::Image;
+use yazi_proxy::{AppProxy, SelectProxy, TabProxy};
+use yazi_shared::{emit, event::Cmd, fs::{Url, UrlScheme}, Layer};
+
+use crate::manager::Manager;
+
+impl Manager {
+	// Mounts the hovered disk image and goes into it, or browses it read-only when it's an ISO
+	// that can't be mounted, e.g. without the privileges to do so.
+	pub fn mount(&mut self, _: Cmd) {
+		let Some(hovered) = self.hovered().filter(|h| !h.is_dir()).map(|h| h.url()) else {
+			return;
+		};
+		if Self::refuse_remote("Mount", [&hovered].into_iter()) {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let e = match Image::attach(&hovered).await {
+				Ok(image) => {
+					let mount = Url::from(&image.mount);
+					emit!(Call(Cmd::new("mount_do").with_any("image", image), Layer::Manager));
+					return TabProxy::cd(&mount);
+				}
+				Err(e) => e,
+			};
+
+			let iso = hovered.extension().is_some_and(|e| e.eq_ignore_ascii_case("iso"));
+			if !iso {
+				return AppProxy::notify_warn(
+					"Mount",
+					&t!("Failed to mount {hovered}: {e}", hovered = format!("{hovered:?}"), e = e),
+				);
+			}
+
+			AppProxy::notify_warn("Mount", &t!("Failed to mount it ({e}), browsing it read-only", e = e));
+			let root = Url::from("/").into_remote(UrlScheme::Iso, hovered.to_string_lossy().into_owned());
+			TabProxy::cd(&root);
+		});
+	}
+
+	pub fn mount_do(&mut self, mut c: Cmd) {
+		if let Some(image) = c.take_any("image") {
+			self.images.push(image);
+		}
+	}
+
+	// Offers to detach the images mounted by `mount` once none of the tabs is in them anymore
+	pub(super) fn detach_left(&mut self) {
+		let tabs = &self.tabs;
+		let inside = |i: &Image| {
+			tabs.iter().any(|t| t.current.cwd.is_regular() && t.current.cwd.starts_with(&i.mount))
+		};
+
+		let (kept, left): (_, Vec<_>) = mem::take(&mut self.images).into_iter().partition(inside);
+
+		self.images = kept;
+		for image in left {
+			tokio::spawn(async move {
+				let name = image.path.file_name().unwrap_or_default().to_string_lossy();
+				let cfg = SelectCfg::confirm(format!("Unmount `{name}`?"), vec![
+					(Some('u'), "Unmount".to_owned()),
+					(Some('n'), "Keep it mounted".to_owned()),
+				]);
+				if !matches!(SelectProxy::show(cfg).await, Ok(0)) {
+					return;
+				}
+
+				if let Err(e) = image.detach().await {
+					AppProxy::notify_warn(
+						"Unmount",
+						&t!("Failed to unmount `{name}`: {e}", name = name, e = e),
+					);
+				}
+			});
+		}
+	}
+}
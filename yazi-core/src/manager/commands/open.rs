@@ -1,11 +1,12 @@
 use std::{borrow::Cow, ffi::OsString};
 
 use tracing::error;
-use yazi_boot::ARGS;
-use yazi_config::{popup::SelectCfg, OPEN};
-use yazi_fs::Folder;
+use yazi_boot::BOOT;
+use yazi_config::{popup::SelectCfg, t};
+use yazi_dds::Editor;
+use yazi_fs::{Folder, Recent};
 use yazi_plugin::isolate;
-use yazi_proxy::{options::OpenDoOpt, ManagerProxy, TasksProxy};
+use yazi_proxy::{options::OpenDoOpt, AppProxy, ManagerProxy, TasksProxy};
 use yazi_shared::{emit, event::{Cmd, EventQuit}, fs::{File, Url}, MIME_DIR};
 
 use crate::{manager::Manager, tasks::Tasks};
@@ -35,7 +36,10 @@ impl Manager {
 		let selected =
 			if opt.hovered { vec![&hovered] } else { self.selected_or_hovered(true).collect() };
 
-		if Self::quit_with_selected(opt, &selected) {
+		if Self::refuse_remote("Open", selected.iter().copied()) {
+			return;
+		}
+		if self.quit_with_selected(opt, &selected) {
 			return;
 		}
 
@@ -64,7 +68,7 @@ impl Manager {
 			}
 
 			done.extend(files.iter().map(|f| (f.url(), String::new())));
-			if let Err(e) = isolate::fetch("mime", files).await {
+			if let Err(e) = isolate::fetch("mime", files, Default::default(), true).await {
 				error!("Fetch `mime` failed in opening: {e}");
 			}
 
@@ -74,7 +78,7 @@ impl Manager {
 
 	pub fn open_do(&mut self, opt: impl Into<OpenDoOpt>, tasks: &Tasks) {
 		let opt = opt.into() as OpenDoOpt;
-		let targets: Vec<_> = opt
+		let mut targets: Vec<_> = opt
 			.targets
 			.into_iter()
 			.filter_map(|(u, m)| {
@@ -82,13 +86,22 @@ impl Manager {
 			})
 			.collect();
 
+		if targets.is_empty() {
+			return;
+		}
+
+		Recent::touch(targets.iter().map(|(u, _)| u));
+		if !opt.interactive {
+			targets = self.open_in_editor(targets);
+		}
 		if targets.is_empty() {
 			return;
 		} else if !opt.interactive {
-			return tasks.process_from_files(opt.hovered, targets);
+			return tasks.process_from_files(&self.active().open(), opt.hovered, targets);
 		}
 
-		let openers: Vec<_> = OPEN.common_openers(&targets);
+		let mut openers: Vec<_> =
+			self.active().open().common_openers(&targets).into_iter().cloned().collect();
 		if openers.is_empty() {
 			return;
 		}
@@ -99,7 +112,7 @@ impl Manager {
 				openers.iter().map(|o| o.desc.clone()).collect(),
 			));
 			if let Ok(choice) = result.await {
-				TasksProxy::open_with(urls, Cow::Borrowed(openers[choice]));
+				TasksProxy::open_with(urls, Cow::Owned(openers.swap_remove(choice)));
 			}
 		});
 	}
@@ -121,12 +134,49 @@ impl Manager {
 			|| find(self.active().history.get(&p))
 	}
 
-	fn quit_with_selected(opt: Opt, selected: &[&Url]) -> bool {
-		if opt.interactive || ARGS.chooser_file.is_none() {
+	// Hands the files that would be opened with `edit` over to the editor attached through DDS,
+	// which embeds this instance, and returns the others to be opened as usual.
+	fn open_in_editor(&self, targets: Vec<(Url, String)>) -> Vec<(Url, String)> {
+		let open = self.active().open();
+		let Some(edit) = open.named("edit").filter(|_| Editor::attached().is_some()) else {
+			return targets;
+		};
+
+		let (edited, mut rest): (Vec<_>, Vec<_>) = targets.into_iter().partition(|(u, m)| {
+			m != MIME_DIR && open.openers(u, m).and_then(|o| o.first().copied()) == Some(edit)
+		});
+
+		// Opened as usual if the editor has just left
+		if !edited.is_empty() && !Editor::open(&edited.iter().map(|(u, _)| u.clone()).collect()) {
+			rest.extend(edited);
+		}
+		rest
+	}
+
+	fn quit_with_selected(&self, opt: Opt, selected: &[&Url]) -> bool {
+		let Some(chooser) = BOOT.chooser.as_ref().filter(|_| !opt.interactive) else {
 			return false;
+		};
+
+		// Without a selection, a hovered file stands for the cwd when choosing directories
+		let cwd = self.cwd();
+		let none = opt.hovered || self.active().selected.is_empty();
+		let chosen = match selected {
+			[u] if chooser.dir && none && !self.guess_folder(u) => vec![cwd],
+			_ => selected.to_vec(),
+		};
+
+		if let Some(u) = chosen.iter().find(|&&u| !chooser.accepts(u, self.guess_folder(u))) {
+			let name = u.file_name().unwrap_or(u.as_os_str()).to_string_lossy();
+			AppProxy::notify_warn("Choose", &t!("`{name}` cannot be chosen", name = name));
+			return true;
+		}
+		if let Some(n) = chooser.multiple.filter(|&n| chosen.len() > n) {
+			AppProxy::notify_warn("Choose", &t!("At most {n} can be chosen", n = n));
+			return true;
 		}
 
-		let paths = selected.iter().fold(OsString::new(), |mut s, &u| {
+		let paths = chosen.iter().fold(OsString::new(), |mut s, &u| {
 			s.push(u.as_os_str());
 			s.push("\n");
 			s
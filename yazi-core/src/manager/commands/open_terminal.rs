@@ -0,0 +1,107 @@
+use std::{borrow::Cow, env};
+
+use yazi_config::{open::Opener, MANAGER};
+use yazi_proxy::AppProxy;
+use yazi_shared::event::Cmd;
+#[cfg(unix)]
+use yazi_shared::env_exists;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+// The emulators that can be started in a directory, by the name of their executable
+#[cfg(all(unix, not(target_os = "macos")))]
+const KNOWN: &[(&str, &str)] = &[
+	("kitty", r#"kitty --directory "$1""#),
+	("wezterm", r#"wezterm start --cwd "$1""#),
+	("ghostty", r#"ghostty --working-directory="$1""#),
+	("alacritty", r#"alacritty --working-directory "$1""#),
+	("foot", r#"foot --working-directory="$1""#),
+	("konsole", r#"konsole --workdir "$1""#),
+	("gnome-terminal", r#"gnome-terminal --working-directory="$1""#),
+	("xfce4-terminal", r#"xfce4-terminal --working-directory="$1""#),
+	("tilix", r#"tilix --working-directory="$1""#),
+	("x-terminal-emulator", r#"cd "$1" && exec x-terminal-emulator"#),
+	("xterm", r#"cd "$1" && exec xterm"#),
+];
+
+impl Manager {
+	// Opens a terminal emulator in the hovered directory, or the current one otherwise,
+	// as a process of its own that outlives Yazi.
+	pub fn open_terminal(&mut self, _: Cmd, tasks: &Tasks) {
+		let dir = self.hovered().filter(|h| h.is_dir()).map_or_else(|| self.cwd().clone(), |h| h.url());
+		if Self::refuse_remote("Open terminal", [&dir].into_iter()) {
+			return;
+		}
+
+		let terminal = MANAGER.load().terminal.clone();
+		let run = if terminal.is_empty() { detect() } else { Some(terminal) };
+		let Some(run) = run else {
+			return AppProxy::notify_warn(
+				"Open terminal",
+				"No terminal emulator found, set one with `terminal` under `[manager]` of yazi.toml",
+			);
+		};
+
+		let opener = Opener {
+			run,
+			block: false,
+			orphan: true,
+			output: Default::default(),
+			desc: "Open terminal".to_owned(),
+			for_: None,
+			spread: false,
+		};
+		let dir = dir.into_os_string();
+		tasks.process_from_opener(Cow::Owned(opener), vec![dir.clone(), dir]);
+	}
+}
+
+// `$TERMINAL` first, then the emulator running Yazi, then the first of the known ones installed
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect() -> Option<String> {
+	let known = |name: &str| KNOWN.iter().find(|&&(n, _)| n == name).map(|&(_, run)| run.to_owned());
+
+	if let Some(term) = env::var("TERMINAL").ok().filter(|s| !s.is_empty()) {
+		let name = std::path::Path::new(&term).file_name().map(|s| s.to_string_lossy().into_owned());
+		return name.and_then(|n| known(&n)).or_else(|| Some(format!(r#"cd "$1" && exec {term}"#)));
+	}
+
+	let running = [
+		("KITTY_WINDOW_ID", "kitty"),
+		("WEZTERM_EXECUTABLE", "wezterm"),
+		("GHOSTTY_RESOURCES_DIR", "ghostty"),
+		("ALACRITTY_WINDOW_ID", "alacritty"),
+		("KONSOLE_VERSION", "konsole"),
+		("GNOME_TERMINAL_SCREEN", "gnome-terminal"),
+		("TILIX_ID", "tilix"),
+	];
+	if let Some(&(_, name)) = running.iter().find(|&&(v, name)| env_exists(v) && in_path(name)) {
+		return known(name);
+	}
+	if env::var("TERM").is_ok_and(|t| t.starts_with("foot")) && in_path("foot") {
+		return known("foot");
+	}
+
+	KNOWN.iter().find(|&&(name, _)| in_path(name)).map(|&(_, run)| run.to_owned())
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> Option<String> {
+	let run = match env::var("TERM_PROGRAM").unwrap_or_default().as_str() {
+		"iTerm.app" => r#"open -a iTerm "$1""#,
+		"WezTerm" if in_path("wezterm") => r#"wezterm start --cwd "$1""#,
+		"ghostty" => r#"open -na Ghostty --args --working-directory="$1""#,
+		_ if env_exists("KITTY_WINDOW_ID") && in_path("kitty") => r#"kitty --directory "$1""#,
+		_ => r#"open -a Terminal "$1""#,
+	};
+	Some(run.to_owned())
+}
+
+#[cfg(windows)]
+fn detect() -> Option<String> {
+	Some(if in_path("wt.exe") { r#"wt -d "%1""# } else { r#"start "" /D "%1" cmd"# }.to_owned())
+}
+
+fn in_path(name: &str) -> bool {
+	env::var_os("PATH").is_some_and(|p| env::split_paths(&p).any(|d| d.join(name).is_file()))
+}
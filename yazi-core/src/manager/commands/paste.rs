@@ -1,28 +1,194 @@
-use yazi_shared::event::Cmd;
+use std::{collections::HashSet, iter};
 
-use crate::{manager::Manager, tasks::Tasks};
+use yazi_config::{popup::{InputCfg, SelectCfg}, t, MANAGER};
+use yazi_fs::readable_size;
+use yazi_plugin::CLIPBOARD;
+use yazi_proxy::{AppProxy, InputProxy, SelectProxy};
+use yazi_shared::{emit, event::Cmd, fs::{expand_path, Url}, Layer};
+
+use crate::{manager::{Manager, DESTINATIONS}, tasks::Tasks};
 
 pub struct Opt {
-	force:  bool,
-	follow: bool,
+	force:   bool,
+	follow:  bool,
+	system:  bool,
+	smart:   bool,
+	targets: Option<(bool, Vec<Url>)>,
+	// Whether the free space of the destination has been checked already
+	checked: bool,
+
+	to:   bool,
+	dest: Option<Url>,
 }
 
 impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { force: c.bool("force"), follow: c.bool("follow") } }
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			force:   c.bool("force"),
+			follow:  c.bool("follow"),
+			system:  c.bool("system"),
+			smart:   c.maybe_bool("smart").unwrap_or(MANAGER.load().smart_paste),
+			targets: c.take_any("targets"),
+			checked: c.bool("checked"),
+
+			to:   c.bool("to"),
+			dest: c.take_any("dest"),
+		}
+	}
 }
 
 impl Manager {
 	pub fn paste(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
 		let opt = opt.into() as Opt;
-		let (src, dest) = (self.yanked.iter().collect::<Vec<_>>(), self.cwd());
+		if let Some((cut, urls)) = &opt.targets {
+			let src: Vec<_> = urls.iter().collect();
+			let dest = opt.dest.as_ref().unwrap_or_else(|| self.paste_target(opt.smart));
+			return if *cut {
+				tasks.file_cut(&src, dest, opt.force)
+			} else {
+				self.paste_copy(&opt, &src, dest, tasks)
+			};
+		} else if opt.to && self.yanked.is_empty() {
+			return AppProxy::notify_warn("Paste", "Nothing yanked");
+		} else if opt.to {
+			return Self::paste_to(opt, self.yanked.cut);
+		} else if opt.system {
+			return Self::paste_system(opt);
+		}
+
+		let dest = opt.dest.as_ref().unwrap_or_else(|| self.paste_target(opt.smart));
+		if opt.dest.is_some() {
+			if dest.is_regular() && !dest.is_dir() {
+				return AppProxy::notify_warn(
+					"Paste",
+					&t!("{dest} is not a directory", dest = format!("{dest:?}")),
+				);
+			}
+			DESTINATIONS.bump(&dest.to_string_lossy());
+		}
 
+		let src: Vec<_> = self.yanked.iter().collect();
 		if self.yanked.cut {
 			tasks.file_cut(&src, dest, opt.force);
 
 			self.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&src, false));
 			self.unyank(());
 		} else {
-			tasks.file_copy(&src, dest, opt.force, opt.follow);
+			self.paste_copy(&opt, &src, dest, tasks);
 		}
 	}
+
+	// Copies the files once they're known to fit in the free space of `dest`, or the user
+	// chooses to copy them anyway, rather than running out of it halfway through.
+	fn paste_copy(&self, opt: &Opt, src: &[&Url], dest: &Url, tasks: &Tasks) {
+		if opt.checked || !dest.is_regular() {
+			return tasks.file_copy(src, dest, opt.force, opt.follow);
+		}
+
+		let (known, rest) = self.known_size(src);
+		let free = self.spaces.get(dest).map(|(s, _)| s.free);
+		if rest.is_empty() && free.is_some_and(|f| known <= f) {
+			return tasks.file_copy(src, dest, opt.force, opt.follow);
+		}
+
+		let (force, follow) = (opt.force, opt.follow);
+		let Some(rx) = tasks.prework_measure(rest, dest) else { return };
+
+		let (urls, dest): (Vec<Url>, _) = (src.iter().map(|&u| u.clone()).collect(), dest.clone());
+		tokio::spawn(async move {
+			let Ok((size, free)) = rx.await else { return };
+			let size = known + size;
+			if let Some(free) = free.filter(|&f| size > f) {
+				let (size, free) = (readable_size(size), readable_size(free));
+				let cfg = SelectCfg::confirm(format!("{size} to copy, but only {free} free there"), vec![
+					(Some('c'), "Copy anyway".to_owned()),
+					(Some('n'), "Cancel".to_owned()),
+				]);
+				if !matches!(SelectProxy::show(cfg).await, Ok(0)) {
+					return;
+				}
+			}
+
+			emit!(Call(
+				Cmd::new("paste")
+					.with_bool("force", force)
+					.with_bool("follow", follow)
+					.with_bool("checked", true)
+					.with_any("targets", (false, urls))
+					.with_any("dest", dest),
+				Layer::Manager
+			));
+		});
+	}
+
+	// The total size of the files looked up in the folders loaded by the tabs, along with
+	// the ones that aren't there, or are directories whose size hasn't been calculated yet.
+	fn known_size(&self, urls: &[&Url]) -> (u64, Vec<Url>) {
+		let mut urls: HashSet<_> = urls.iter().copied().collect();
+		let mut parents: HashSet<_> = urls.iter().filter_map(|u| u.parent_url()).collect();
+
+		let folders = self.tabs.iter().flat_map(|t| {
+			iter::once(&t.current).chain(t.parent.as_ref()).chain(t.history.values())
+		});
+
+		let (mut size, mut rest) = (0, vec![]);
+		for folder in folders.filter(|f| parents.remove(&f.cwd)) {
+			for f in folder.files.iter().filter(|f| urls.remove(&f.url)) {
+				match folder.files.sizes.get(&f.url) {
+					_ if !f.is_dir() => size += f.len,
+					Some(&n) => size += n,
+					None => rest.push(f.url.clone()),
+				}
+			}
+		}
+
+		rest.extend(urls.into_iter().cloned());
+		(size, rest)
+	}
+
+	// The hovered directory with `--smart`, unless it's one of the yanked files, or the cwd otherwise
+	pub fn paste_target(&self, smart: bool) -> &Url {
+		match self.hovered() {
+			Some(h) if smart && h.is_dir() && !self.yanked.contains(&h.url) => &h.url,
+			_ => self.cwd(),
+		}
+	}
+
+	// Asks where to paste the yanked files instead of the cwd
+	fn paste_to(opt: Opt, cut: bool) {
+		tokio::spawn(async move {
+			let mut result = InputProxy::show(InputCfg::paste_to(cut));
+			let Some(Ok(s)) = result.recv().await else { return };
+			if s.is_empty() {
+				return;
+			}
+
+			emit!(Call(
+				Cmd::new("paste")
+					.with_bool("force", opt.force)
+					.with_bool("follow", opt.follow)
+					.with_any("dest", Url::from(expand_path(s))),
+				Layer::Manager
+			));
+		});
+	}
+
+	// The files copied in the other apps, with `--system`
+	fn paste_system(opt: Opt) {
+		tokio::spawn(async move {
+			let Some((cut, paths)) = CLIPBOARD.get_files().await else {
+				return AppProxy::notify_warn("Paste", "No files in the system clipboard");
+			};
+
+			let urls: Vec<_> = paths.into_iter().map(Url::from).collect();
+			emit!(Call(
+				Cmd::new("paste")
+					.with_bool("force", opt.force)
+					.with_bool("follow", opt.follow)
+					.with_bool("smart", opt.smart)
+					.with_any("targets", (cut, urls)),
+				Layer::Manager
+			));
+		});
+	}
 }
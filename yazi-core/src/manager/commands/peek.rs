@@ -1,4 +1,4 @@
-use yazi_shared::{event::{Cmd, Data}, fs::Url, render};
+use yazi_shared::{event::{damage_regions, Cmd, Data, Region}, fs::Url};
 
 use crate::manager::Manager;
 
@@ -27,13 +27,13 @@ impl From<bool> for Opt {
 impl Manager {
 	pub fn peek(&mut self, opt: impl Into<Opt>) {
 		let Some(hovered) = self.hovered().cloned() else {
-			return render!(self.active_mut().preview.reset());
+			return self.reset_preview();
 		};
 
 		let folder = self.active().hovered_folder().map(|f| (f.offset, f.cha));
 		if !self.active().preview.same_url(&hovered.url) {
 			self.active_mut().preview.skip = folder.map(|f| f.0).unwrap_or_default();
-			render!(self.active_mut().preview.reset());
+			self.reset_preview();
 		}
 
 		let opt = opt.into() as Opt;
@@ -55,10 +55,23 @@ impl Manager {
 			return;
 		}
 
+		if hovered.url.is_remote() {
+			let mime = self.mimetype.get(&hovered.url).cloned();
+			self.active_mut().preview.go_remote(hovered, mime.as_deref(), opt.force);
+			return;
+		}
+
 		if let Some(mime) = self.mimetype.get(&hovered.url).cloned() {
 			self.active_mut().preview.go(hovered, &mime, opt.force);
 		} else {
-			render!(self.active_mut().preview.reset());
+			self.reset_preview();
+		}
+	}
+
+	#[inline]
+	fn reset_preview(&mut self) {
+		if self.active_mut().preview.reset() {
+			damage_regions(&[Region::Preview]);
 		}
 	}
 }
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use yazi_boot::BOOT;
+use yazi_config::t;
+use yazi_proxy::{options::{NotifyLevel, NotifyOpt}, AppProxy};
+use yazi_shared::{event::Cmd, render, PERF};
+
+use crate::manager::Manager;
+
+impl Manager {
+	// Toggles the overlay of `--debug-perf`, or writes what's been recorded to `perf.json`
+	// in the state directory, to be attached to the reports of slowness.
+	pub fn perf(&mut self, c: Cmd) {
+		if !PERF.enabled() {
+			return AppProxy::notify_warn("Performance", "Restart with `--debug-perf` to record it");
+		}
+
+		if !c.bool("dump") {
+			PERF.toggle();
+			return render!();
+		}
+
+		let path = BOOT.state_dir.join("perf.json");
+		let result = serde_json::to_vec_pretty(&PERF.report())
+			.map_err(Into::into)
+			.and_then(|b| std::fs::write(&path, b));
+
+		match result {
+			Ok(()) => AppProxy::notify(NotifyOpt {
+				title:   "Performance".to_owned(),
+				content: format!("Written to {}", path.display()),
+				level:   NotifyLevel::Info,
+				timeout: Duration::from_secs(5),
+				actions: vec![],
+			}),
+			Err(e) => AppProxy::notify_warn("Performance", &t!("Failed to write: {e}", e = e)),
+		}
+	}
+}
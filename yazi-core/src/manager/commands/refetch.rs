@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+
+use yazi_shared::{event::Cmd, render};
+
+use crate::{manager::{Manager, FETCH_CACHE}, tasks::Tasks};
+
+pub struct Opt {
+	all: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { all: c.bool("all") } }
+}
+
+impl Manager {
+	// Forgets the cached results of the fetchers for the selected or hovered files, or the whole
+	// cache with `--all`, then fetches the ones in the current directory again.
+	pub fn refetch(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let opt = opt.into() as Opt;
+		let urls: HashSet<_> = self.selected_or_hovered(false).cloned().collect();
+
+		let targets: Vec<_> =
+			self.current().files.iter().filter(|f| opt.all || urls.contains(&f.url)).cloned().collect();
+		if opt.all {
+			FETCH_CACHE.invalidate(None);
+		} else {
+			FETCH_CACHE.invalidate(Some(&urls.into_iter().collect::<Vec<_>>()));
+		}
+
+		for f in &targets {
+			self.mimetype.remove(&f.url);
+		}
+		tasks.prework_affected(self.cwd(), &targets, &self.mimetype);
+		render!();
+	}
+}
@@ -15,17 +15,24 @@ impl Manager {
 			format!("{}", self.cwd().display())
 		};
 
-		MANAGER.title_format.replace("{cwd}", &cwd)
+		MANAGER.load().title_format.replace("{cwd}", &cwd)
 	}
 
 	pub fn refresh(&mut self, _: Cmd, tasks: &Tasks) {
-		env::set_current_dir(self.cwd()).ok();
-		env::set_var("PWD", self.cwd());
+		if !self.cwd().is_remote() && !self.cwd().is_recent() {
+			env::set_current_dir(self.cwd()).ok();
+			env::set_var("PWD", self.cwd());
+		}
+		if self.cwd().is_regular() {
+			Self::read_space(self.cwd().clone());
+		}
 
-		if !MANAGER.title_format.is_empty() {
+		if !MANAGER.load().title_format.is_empty() {
 			execute!(std::io::stderr(), SetTitle(self.title())).ok();
 		}
 
+		self.local();
+		self.detach_left();
 		self.active_mut().apply_files_attrs();
 
 		if let Some(p) = self.parent() {
@@ -37,6 +44,6 @@ impl Manager {
 		self.hover(None);
 		self.update_paged((), tasks);
 
-		tasks.prework_sorted(&self.current().files);
+		tasks.prework_sizes(self.active());
 	}
 }
@@ -1,5 +1,5 @@
 use yazi_config::popup::InputCfg;
-use yazi_proxy::{InputProxy, ManagerProxy};
+use yazi_proxy::{AppProxy, InputProxy, ManagerProxy};
 use yazi_shared::{event::Cmd, fs::Url};
 
 use crate::{manager::Manager, tasks::Tasks};
@@ -38,6 +38,12 @@ impl Manager {
 			self.selected_or_hovered(false).cloned().collect()
 		};
 
+		if !opt.permanently && opt.targets.iter().any(|u| u.is_remote()) {
+			return AppProxy::notify_warn(
+				"Trash",
+				"Remote files can't be trashed, delete them permanently instead",
+			);
+		}
 		if opt.force {
 			return self.remove_do(opt, tasks);
 		}
@@ -2,16 +2,18 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use tokio::fs;
-use yazi_config::popup::InputCfg;
+use yazi_config::{popup::{InputCfg, Origin, SelectCfg}, t, INPUT};
 use yazi_dds::Pubsub;
-use yazi_proxy::{InputProxy, TabProxy, WATCHER};
-use yazi_shared::{event::Cmd, fs::{maybe_exists, ok_or_not_found, paths_to_same_file, symlink_realpath, File, FilesOp, Url}};
+use yazi_fs::{vfs, Recent};
+use yazi_proxy::{AppProxy, InputProxy, SelectProxy, TabProxy, WATCHER};
+use yazi_shared::{event::Cmd, fs::{is_case_rename, maybe_exists, ok_or_not_found, paths_to_same_file, rename_case, symlink_realpath, unique_name, File, FilesOp, Url}};
 
 use crate::manager::Manager;
 
 pub struct Opt {
 	hovered: bool,
 	force:   bool,
+	inline:  bool,
 	empty:   String,
 	cursor:  String,
 }
@@ -21,6 +23,7 @@ impl From<Cmd> for Opt {
 		Self {
 			hovered: c.bool("hovered"),
 			force:   c.bool("force"),
+			inline:  c.bool("inline"),
 			empty:   c.take_str("empty").unwrap_or_default(),
 			cursor:  c.take_str("cursor").unwrap_or_default(),
 		}
@@ -36,11 +39,20 @@ impl Manager {
 			return;
 		};
 
-		let opt = opt.into() as Opt;
+		let mut opt = opt.into() as Opt;
 		if !opt.hovered && !self.active().selected.is_empty() {
+			if Self::refuse_remote("Bulk rename", self.selected_or_hovered(false)) {
+				return;
+			}
 			return self.bulk_rename();
 		}
 
+		// Editing in the row keeps the extension out of the way by default
+		let inline = opt.inline || INPUT.load().rename_origin == Origin::Inline;
+		if inline && opt.cursor.is_empty() {
+			opt.cursor = "before_ext".to_owned();
+		}
+
 		let name = Self::empty_url_part(&hovered, &opt.empty);
 		let cursor = match opt.cursor.as_str() {
 			"start" => Some(0),
@@ -53,9 +65,14 @@ impl Manager {
 			_ => None,
 		};
 
+		let mut cfg = InputCfg::rename().with_value(name).with_cursor(cursor);
+		if let Some(r) = self.current().rect_current(&hovered).filter(|_| inline) {
+			cfg = cfg.with_inline(r.width);
+		}
+
 		let tab = self.tabs.cursor;
 		tokio::spawn(async move {
-			let mut result = InputProxy::show(InputCfg::rename().with_value(name).with_cursor(cursor));
+			let mut result = InputProxy::show(cfg);
 			let Some(Ok(name)) = result.recv().await else {
 				return;
 			};
@@ -63,6 +80,14 @@ impl Manager {
 			if name.is_empty() {
 				return;
 			}
+			let Some(name) = Self::portable_name(name).await else {
+				return;
+			};
+
+			if hovered.is_remote() {
+				let new = hovered.parent_url().unwrap().join(name);
+				return Self::rename_remote(tab, hovered, new, opt.force).await;
+			}
 
 			let new = hovered.parent().unwrap().join(name);
 			if opt.force || !maybe_exists(&new).await || paths_to_same_file(&hovered, &new).await {
@@ -70,12 +95,12 @@ impl Manager {
 				return;
 			}
 
-			let mut result = InputProxy::show(InputCfg::overwrite());
-			if let Some(Ok(choice)) = result.recv().await {
-				if choice == "y" || choice == "Y" {
-					Self::rename_do(tab, hovered, Url::from(new)).await.ok();
-				}
+			let new = match SelectProxy::show(SelectCfg::overwrite()).await {
+				Ok(0) => Url::from(new),
+				Ok(1) => unique_name(Url::from(new)).await,
+				_ => return,
 			};
+			Self::rename_do(tab, hovered, new).await.ok();
 		});
 	}
 
@@ -84,14 +109,19 @@ impl Manager {
 		let Some(p_new) = new.parent_url() else { return Ok(()) };
 		let _permit = WATCHER.acquire().await.unwrap();
 
-		let overwritten = symlink_realpath(&new).await;
-		fs::rename(&old, &new).await?;
+		if is_case_rename(&old, &new).await {
+			rename_case(&old, &new).await?;
+		} else {
+			let overwritten = symlink_realpath(&new).await;
+			fs::rename(&old, &new).await?;
 
-		if let Ok(o) = overwritten {
-			ok_or_not_found(fs::rename(&o, &new).await)?;
-			FilesOp::Deleting(p_new.clone(), vec![Url::from(o)]).emit();
+			if let Ok(o) = overwritten {
+				ok_or_not_found(fs::rename(&o, &new).await)?;
+				FilesOp::Deleting(p_new.clone(), vec![Url::from(o)]).emit();
+			}
 		}
 		Pubsub::pub_from_rename(tab, &old, &new);
+		Recent::touch([&new]);
 
 		let file = File::from(new.clone()).await?;
 		FilesOp::Deleting(p_old, vec![old]).emit();
@@ -99,6 +129,36 @@ impl Manager {
 		Ok(TabProxy::reveal(&new))
 	}
 
+	async fn rename_remote(tab: usize, old: Url, mut new: Url, force: bool) {
+		if !force && vfs::metadata(&new).await.is_ok() {
+			new = match SelectProxy::show(SelectCfg::overwrite()).await {
+				Ok(0) => new,
+				Ok(1) => vfs::unique_name(new).await,
+				_ => return,
+			};
+		}
+
+		let result = async {
+			vfs::rename(&old, &new).await?;
+			Pubsub::pub_from_rename(tab, &old, &new);
+			vfs::metadata(&new).await
+		};
+
+		match result.await {
+			Ok(cha) => {
+				let (Some(p_old), Some(p_new)) = (old.parent_url(), new.parent_url()) else { return };
+				let file = File { url: new.clone(), cha, ..Default::default() };
+				FilesOp::Deleting(p_old, vec![old]).emit();
+				FilesOp::Upserting(p_new, HashMap::from_iter([(new.clone(), file)])).emit();
+				TabProxy::reveal(&new);
+			}
+			Err(e) => AppProxy::notify_warn(
+				"Rename",
+				&t!("Failed to rename {old}: {e}", old = format!("{old:?}"), e = e),
+			),
+		}
+	}
+
 	fn empty_url_part(url: &Url, by: &str) -> String {
 		if by == "all" {
 			return String::new();
@@ -0,0 +1,37 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::Cmd;
+
+use crate::manager::{Manager, Snapshot};
+
+impl Manager {
+	// Brings back the tabs of the session that ended with a crash, the tasks can't be resumed
+	// the same way, so they're only listed for the user to start again, along with the errors
+	// that led up to it.
+	pub fn restore(&mut self, mut c: Cmd) {
+		let Some(snapshot) = c.take_any::<Snapshot>("snapshot") else { return };
+
+		for (i, tab) in snapshot.tabs.into_iter().enumerate() {
+			if i > 0 {
+				self.tabs.create(tab.cwd.clone());
+			}
+			match tab.hovered {
+				Some(hovered) => self.active_mut().reveal(hovered),
+				None => self.active_mut().cd(tab.cwd),
+			}
+		}
+		self.tabs.set_idx(snapshot.cursor.min(self.tabs.len() - 1));
+
+		if !snapshot.tasks.is_empty() {
+			AppProxy::notify_warn(
+				"Session",
+				&format!("The tasks that were unfinished:\n{}", snapshot.tasks.join("\n")),
+			);
+		}
+		if !snapshot.errors.is_empty() {
+			AppProxy::notify_error(
+				"Session",
+				&format!("The errors before the crash:\n{}", snapshot.errors.join("\n")),
+			);
+		}
+	}
+}
@@ -0,0 +1,33 @@
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+use yazi_shared::event::Cmd;
+
+use crate::manager::Manager;
+
+impl Manager {
+	pub fn rpc_state(&mut self, mut c: Cmd) {
+		let Some(tx) = c.take_any::<oneshot::Sender<Value>>("tx") else { return };
+
+		let tabs: Vec<_> = self
+			.tabs
+			.iter()
+			.map(|tab| {
+				json!({
+					"cwd": tab.current.cwd,
+					"hovered": tab.current.hovered().map(|h| &h.url),
+					"selected": tab.selected.keys().collect::<Vec<_>>(),
+				})
+			})
+			.collect();
+
+		let active = &tabs[self.tabs.cursor];
+		tx.send(json!({
+			"cwd": active["cwd"],
+			"hovered": active["hovered"],
+			"selected": active["selected"],
+			"active": self.tabs.cursor,
+			"tabs": tabs,
+		}))
+		.ok();
+	}
+}
@@ -27,7 +27,8 @@ impl Manager {
 			return render!(self.active_mut().preview.reset());
 		};
 
-		let Some(previewer) = PLUGIN.previewer(&hovered.url, mime) else {
+		let plugin = PLUGIN.load();
+		let Some(previewer) = plugin.previewer(&hovered.url, mime) else {
 			return render!(self.active_mut().preview.reset());
 		};
 
@@ -0,0 +1,108 @@
+use tokio::fs;
+use yazi_config::{popup::SelectCfg, t, MANAGER};
+use yazi_proxy::{AppProxy, SelectProxy};
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	name:    Option<String>,
+	cut:     bool,
+	targets: Option<Vec<Url>>,
+	dest:    Option<Url>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			name:    c.take_first_str(),
+			cut:     c.bool("cut"),
+			targets: c.take_any("targets"),
+			dest:    c.take_any("dest"),
+		}
+	}
+}
+
+impl Manager {
+	// Copies the selected files, or moves them with `--cut`, to one of the destinations
+	// of `send_to` in yazi.toml, given by its name or picked by its key.
+	pub fn send_to(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let opt = opt.into() as Opt;
+		if MANAGER.load().send_to.is_empty() {
+			return AppProxy::notify_warn(
+				"Send to",
+				"No destinations, add them to `send_to` under `[manager]` of yazi.toml",
+			);
+		}
+
+		let targets = opt.targets.unwrap_or_else(|| self.selected_or_hovered(false).cloned().collect());
+		if targets.is_empty() {
+			return;
+		}
+
+		let Some(name) = opt.name else {
+			return Self::send_to_pick(targets, opt.cut);
+		};
+
+		let Some(dest) = opt.dest else {
+			return Self::send_to_check(targets, &name, opt.cut);
+		};
+
+		let src: Vec<_> = targets.iter().collect();
+		if opt.cut {
+			tasks.file_cut(&src, &dest, false);
+			self.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&src, false));
+		} else {
+			tasks.file_copy(&src, &dest, false, false);
+		}
+	}
+
+	// The destination is checked off the main thread, and sent back to be copied or moved to
+	fn send_to_check(targets: Vec<Url>, name: &str, cut: bool) {
+		let Some(dest) = MANAGER.load().send_to.iter().find(|d| d.name == name).map(|d| d.url()) else {
+			return AppProxy::notify_warn("Send to", &t!("No destination named `{name}`", name = name));
+		};
+
+		let name = name.to_owned();
+		tokio::spawn(async move {
+			if dest.is_regular() && !fs::metadata(&dest).await.is_ok_and(|m| m.is_dir()) {
+				return AppProxy::notify_warn(
+					"Send to",
+					&t!("{dest} is not a directory", dest = format!("{dest:?}")),
+				);
+			}
+
+			emit!(Call(
+				Cmd::args("send_to", vec![name])
+					.with_bool("cut", cut)
+					.with_any("targets", targets)
+					.with_any("dest", dest),
+				Layer::Manager
+			));
+		});
+	}
+
+	fn send_to_pick(targets: Vec<Url>, cut: bool) {
+		// Picked by the name, as the destinations may have been reloaded by the time it's done
+		let (names, choices): (Vec<_>, Vec<_>) = MANAGER
+			.load()
+			.send_to
+			.iter()
+			.map(|d| (d.name.clone(), (d.key, format!("{}  {}", d.name, d.path))))
+			.unzip();
+
+		tokio::spawn(async move {
+			let title = if cut { "Move to" } else { "Send to" };
+			let Ok(i) = SelectProxy::show(SelectCfg::confirm(title, choices)).await else {
+				return;
+			};
+
+			emit!(Call(
+				Cmd::args("send_to", vec![names[i].clone()])
+					.with_bool("cut", cut)
+					.with_any("targets", targets),
+				Layer::Manager
+			));
+		});
+	}
+}
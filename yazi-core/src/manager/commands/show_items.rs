@@ -0,0 +1,27 @@
+use yazi_shared::{event::Cmd, fs::Url};
+
+use crate::manager::Manager;
+
+impl Manager {
+	// Shows the files that other apps asked for, in the tab already in their folder if any,
+	// otherwise in a new one.
+	pub fn show_items(&mut self, mut c: Cmd) {
+		let Some(urls) = c.take_any::<Vec<Url>>("urls") else { return };
+		let Some(first) = urls.into_iter().next() else { return };
+
+		let folders = c.bool("folders");
+		let Some(cwd) = (if folders { Some(first.clone()) } else { first.parent_url() }) else {
+			return;
+		};
+
+		if let Some(i) = self.tabs.iter().position(|t| t.current.cwd == cwd) {
+			self.tabs.set_idx(i);
+		} else {
+			self.tabs.create(cwd.clone());
+		}
+
+		if !folders {
+			self.active_mut().reveal(first);
+		}
+	}
+}
@@ -23,6 +23,9 @@ impl From<Cmd> for Opt {
 		}
 	}
 }
+impl From<Url> for Opt {
+	fn from(url: Url) -> Self { Self { url, current: false } }
+}
 
 impl Tabs {
 	pub fn create(&mut self, opt: impl Into<Opt>) {
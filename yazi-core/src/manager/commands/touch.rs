@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use yazi_config::popup::{InputCfg, SelectCfg};
+use yazi_proxy::{AppProxy, InputProxy, SelectProxy};
+use yazi_scheduler::TouchTime;
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	time:    Option<String>,
+	atime:   bool,
+	mtime:   bool,
+	targets: Option<Vec<Url>>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			time:    c.take_first_str(),
+			atime:   c.bool("atime"),
+			mtime:   c.bool("mtime"),
+			targets: c.take_any("targets"),
+		}
+	}
+}
+
+impl Manager {
+	// Sets the access and modification times of the selected files, or only one of them
+	// with `--atime` or `--mtime`, to `now`, `exif` for their capture date, or a given time.
+	pub fn touch(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let mut opt = opt.into() as Opt;
+		let targets = match opt.targets.take() {
+			Some(v) => v,
+			None if !self.active_mut().try_escape_visual() => return,
+			None => self.selected_or_hovered(false).cloned().collect(),
+		};
+		if targets.is_empty() || Self::refuse_remote("Touch", targets.iter()) {
+			return;
+		}
+
+		let Some(time) = &opt.time else {
+			return Self::touch_pick(opt, targets);
+		};
+		let time = match TouchTime::from_str(time) {
+			Ok(t) => t,
+			Err(e) => return AppProxy::notify_warn("Touch", &e.to_string()),
+		};
+
+		let both = !opt.atime && !opt.mtime;
+		tasks.file_touch(targets, time, opt.atime || both, opt.mtime || both);
+	}
+
+	fn touch_pick(opt: Opt, targets: Vec<Url>) {
+		tokio::spawn(async move {
+			let choices = vec![
+				(Some('n'), "Now".to_owned()),
+				(Some('e'), "The capture date in the EXIF".to_owned()),
+				(Some('t'), "A time typed in".to_owned()),
+			];
+			let title = format!("Set the time of {} files to", targets.len());
+			let time = match SelectProxy::show(SelectCfg::confirm(title, choices)).await {
+				Ok(0) => "now".to_owned(),
+				Ok(1) => "exif".to_owned(),
+				Ok(_) => match InputProxy::show(InputCfg::touch()).recv().await {
+					Some(Ok(s)) if !s.is_empty() => s,
+					_ => return,
+				},
+				Err(_) => return,
+			};
+
+			emit!(Call(
+				Cmd::args("touch", vec![time])
+					.with_bool("atime", opt.atime)
+					.with_bool("mtime", opt.mtime)
+					.with_any("targets", targets),
+				Layer::Manager
+			));
+		});
+	}
+}
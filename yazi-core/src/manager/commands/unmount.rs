@@ -0,0 +1,77 @@
+use std::env;
+
+use yazi_config::t;
+use yazi_fs::Volume;
+use yazi_proxy::AppProxy;
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	eject:  bool,
+	volume: Option<Volume>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { eject: c.bool("eject"), volume: c.take_any("volume") } }
+}
+
+impl Manager {
+	// Unmounts the removable volume the cwd is on, after leaving it so that it's not busy
+	pub fn unmount(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let Some(volume) = opt.volume else {
+			return self.unmount_find(opt.eject);
+		};
+
+		// The tabs are out of it by the time it's unmounted, as they're moved here on the main thread
+		let mount = volume.mount.clone().unwrap_or_default();
+		if let Some(parent) = mount.parent() {
+			env::set_current_dir(parent).ok();
+			let parent = Url::from(parent);
+			for tab in self.tabs.iter_mut() {
+				if tab.current.cwd.is_regular() && tab.current.cwd.starts_with(&mount) {
+					tab.cd(parent.clone());
+				}
+			}
+		}
+
+		tokio::spawn(async move {
+			let result = if opt.eject { volume.eject().await } else { volume.unmount().await };
+			match result {
+				Ok(()) if opt.eject => {
+					AppProxy::notify_warn(
+						"Eject",
+						&t!("{device} can be unplugged now", device = volume.device),
+					)
+				}
+				Ok(()) => {}
+				Err(e) => {
+					AppProxy::notify_warn(
+						"Unmount",
+						&t!("Failed to unmount {device}: {e}", device = volume.device, e = e),
+					)
+				}
+			}
+		});
+	}
+
+	// Finds the volume of the cwd off the main thread, and comes back with it to leave it
+	fn unmount_find(&self, eject: bool) {
+		if !self.cwd().is_regular() {
+			return;
+		}
+
+		let cwd = self.cwd().clone();
+		tokio::spawn(async move {
+			let Some(volume) = Volume::of(&cwd).await.filter(|v| v.removable) else {
+				return AppProxy::notify_warn("Unmount", "Not on a removable volume");
+			};
+
+			emit!(Call(
+				Cmd::new("unmount").with_bool("eject", eject).with_any("volume", volume),
+				Layer::Manager
+			));
+		});
+	}
+}
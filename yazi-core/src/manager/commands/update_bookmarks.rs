@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+
+use yazi_shared::{event::Cmd, fs::Url, render};
+
+use crate::manager::{BookmarkChanges, Manager};
+
+pub struct Opt {
+	marks: BTreeMap<String, Url>,
+	saved: Option<BookmarkChanges>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		if let Some(iter) = c.take_any::<yazi_dds::body::BodyBookmarkIter>("marks") {
+			Ok(Self { marks: iter.marks, saved: None })
+		} else if let Some((marks, changes)) = c.take_any("saved") {
+			Ok(Self { marks, saved: Some(changes) })
+		} else {
+			Err(())
+		}
+	}
+}
+
+impl Manager {
+	pub fn update_bookmarks(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+		render!(match opt.saved {
+			Some(changes) => self.bookmarks.saved(opt.marks, changes),
+			None => self.bookmarks.replace(opt.marks),
+		});
+	}
+}
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use yazi_shared::event::{damage_regions, Cmd, Region};
+
+use crate::{manager::Manager, vcs::Commit};
+
+pub struct Opt {
+	path:   PathBuf,
+	commit: Option<Commit>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { path: c.take_any("path").ok_or(())?, commit: c.take_any("commit").flatten() })
+	}
+}
+
+impl Manager {
+	pub fn update_commit(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+
+		self.vcs.update_commit(opt.path, opt.commit);
+		damage_regions(&[Region::Status]);
+	}
+}
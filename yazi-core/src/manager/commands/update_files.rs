@@ -1,8 +1,8 @@
 use std::borrow::Cow;
 
-use yazi_fs::Folder;
+use yazi_fs::{Folder, GITIGNORES};
 use yazi_proxy::ManagerProxy;
-use yazi_shared::{event::Cmd, fs::FilesOp, render};
+use yazi_shared::{event::Cmd, fs::{FilesOp, Url}, render};
 
 use crate::{manager::{Manager, LINKED}, tab::Tab, tasks::Tasks};
 
@@ -32,6 +32,19 @@ impl Manager {
 		for op in ops {
 			let idx = self.tabs.cursor;
 			self.yanked.apply_op(&op);
+			self.bookmarks.apply_op(&op);
+			if matches!(
+				op,
+				FilesOp::Creating(..) | FilesOp::Deleting(..) | FilesOp::Updating(..) | FilesOp::Upserting(..)
+			) {
+				self.vcs.refresh(op.url());
+				for tab in self.tabs.iter_mut() {
+					Self::forget_sizes(tab, op.url());
+				}
+			}
+			if Self::touches_gitignore(&op) {
+				GITIGNORES.lock().forget(op.url());
+			}
 
 			for (_, tab) in self.tabs.iter_mut().enumerate().filter(|(i, _)| *i != idx) {
 				Self::update_tab(tab, Cow::Borrowed(&op), tasks);
@@ -40,7 +53,26 @@ impl Manager {
 		}
 
 		render!(self.yanked.catchup_revision(false));
+		render!(self.bookmarks.catchup_revision());
+		self.tabs.iter_mut().for_each(|t| t.measure_selected());
 		self.active_mut().apply_files_attrs();
+		self.apply_vcs();
+	}
+
+	fn touches_gitignore(op: &FilesOp) -> bool {
+		let is = |u: &Url| u.file_name().is_some_and(|n| n == ".gitignore");
+		match op {
+			FilesOp::Creating(_, files) => files.iter().any(|f| is(&f.url)),
+			FilesOp::Deleting(_, urls) => urls.iter().any(is),
+			FilesOp::Updating(_, files) | FilesOp::Upserting(_, files) => files.keys().any(is),
+			_ => false,
+		}
+	}
+
+	fn forget_sizes(tab: &mut Tab, url: &Url) {
+		tab.selected.forget_sizes(url);
+		tab.current.files.forget_sizes(url);
+		tab.parent.iter_mut().chain(tab.history.values_mut()).for_each(|f| f.files.forget_sizes(url));
 	}
 
 	fn update_tab(tab: &mut Tab, op: Cow<FilesOp>, tasks: &Tasks) {
@@ -75,9 +107,12 @@ impl Manager {
 	fn update_current(tab: &mut Tab, op: Cow<FilesOp>, tasks: &Tasks) {
 		let hovered = tab.current.hovered().filter(|_| tab.current.tracing).map(|h| h.url());
 		let calc = !matches!(*op, FilesOp::Size(..) | FilesOp::Deleting(..));
+		// The sizes bump the revision only when sorted by size, but show up in the linemode anyway
+		let sized = matches!(*op, FilesOp::Size(..));
 
 		let foreign = matches!(op, Cow::Borrowed(_));
 		if !tab.current.update(op.into_owned()) {
+			render!(sized);
 			return;
 		}
 
@@ -89,7 +124,7 @@ impl Manager {
 		ManagerProxy::hover(None); // Re-hover in next loop
 		ManagerProxy::update_paged(); // Update for paged files in next loop
 		if calc {
-			tasks.prework_sorted(&tab.current.files);
+			tasks.prework_sizes(tab);
 		}
 	}
 
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use tracing::error;
 use yazi_shared::{event::Cmd, fs::Url, render};
 
-use crate::{manager::{Manager, LINKED}, tasks::Tasks};
+use crate::{manager::{Kind, Manager, FETCH_CACHE, LINKED}, tasks::Tasks};
 
 pub struct Opt {
 	updates: HashMap<String, String>,
@@ -29,6 +29,7 @@ impl Manager {
 			.into_iter()
 			.map(|(url, mime)| (Url::from(url), mime))
 			.filter(|(url, mime)| self.mimetype.get(url) != Some(mime))
+			.inspect(|(url, mime)| FETCH_CACHE.put(Kind::Mime, url, mime))
 			.fold(HashMap::new(), |mut map, (u, m)| {
 				for u in linked.from_file(&u) {
 					map.insert(u, m.clone());
@@ -56,7 +57,7 @@ impl Manager {
 		if repeek {
 			self.peek(false);
 		}
-		tasks.prework_affected(&affected, &self.mimetype);
+		tasks.prework_affected(self.cwd(), &affected, &self.mimetype);
 		render!();
 	}
 }
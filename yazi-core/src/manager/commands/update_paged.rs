@@ -31,8 +31,17 @@ impl Manager {
 			return;
 		}
 
+		tasks.prework_cancel_except(self.cwd());
+		tasks.prework_sizes(self.active()); // Including the ones cancelled when leaving it last time
+		self.apply_vcs(); // The sorting may have changed to `vcs`
+
+		// Files in the window go first, the rest of the page is loaded ahead at a lower priority
+		let (cwd, window) = (self.cwd(), self.current().window());
+		tasks.fetch_paged(cwd, window, &self.mimetype, true);
+		tasks.preload_paged(cwd, window, &self.mimetype, true);
+
 		let targets = self.current().paginate(opt.page.unwrap_or(self.current().page));
-		tasks.fetch_paged(targets, &self.mimetype);
-		tasks.preload_paged(targets, &self.mimetype);
+		tasks.fetch_paged(cwd, targets, &self.mimetype, false);
+		tasks.preload_paged(cwd, targets, &self.mimetype, false);
 	}
 }
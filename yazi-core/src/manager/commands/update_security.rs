@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use tracing::error;
+use yazi_shared::{event::Cmd, fs::Url, render};
+
+use crate::manager::{Kind, Manager, FETCH_CACHE};
+
+pub struct Opt {
+	contexts: HashMap<String, String>,
+	caps:     HashMap<String, String>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			contexts: c.take("contexts").ok_or(())?.into_dict_string(),
+			caps:     c.take("caps").map(|d| d.into_dict_string()).unwrap_or_default(),
+		})
+	}
+}
+
+impl Manager {
+	// The security context and the file capabilities of each file, with an empty string for none
+	pub fn update_security(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(mut opt) = opt.try_into() else {
+			return error!("invalid arguments for update_security");
+		};
+
+		let mut changed = false;
+		for (url, context) in opt.contexts {
+			let caps = opt.caps.remove(&url).unwrap_or_default();
+			let url = Url::from(url);
+			FETCH_CACHE.put(Kind::Security, &url, &format!("{context} {caps}"));
+
+			changed |= if context.is_empty() && caps.is_empty() {
+				self.security.remove(&url).is_some()
+			} else {
+				let new = (context, caps);
+				self.security.insert(url, new.clone()) != Some(new)
+			};
+		}
+
+		if changed {
+			render!();
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use yazi_shared::{event::Cmd, fs::Url, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	sizes: HashMap<Url, (bool, u64)>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { sizes: c.take_any("sizes").ok_or(())? })
+	}
+}
+
+impl Manager {
+	pub fn update_selected(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+
+		for tab in self.tabs.iter_mut() {
+			render!(tab.selected.update_sizes(opt.sizes.clone()));
+		}
+	}
+}
@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use yazi_fs::Space;
+use yazi_shared::{emit, event::Cmd, fs::Url, render, Layer};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	cwd:   Option<Url>,
+	space: Option<(Space, Option<String>)>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { cwd: c.take_any("cwd"), space: c.take_any("space").flatten() }
+	}
+}
+
+impl Manager {
+	// Without a CWD, reads the spaces of the tabs again, once the file operations are done
+	pub fn update_space(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let Some(cwd) = opt.cwd else {
+			let cwds: HashSet<_> = self.tabs.iter().map(|t| &t.current.cwd).collect();
+			cwds.into_iter().filter(|u| u.is_regular()).for_each(|u| Self::read_space(u.clone()));
+			return;
+		};
+
+		// Only the ones of the tabs are kept, as the status bar shows nothing else
+		let tabs = &self.tabs;
+		self.spaces.retain(|u, _| tabs.iter().any(|t| t.current.cwd == *u));
+		match opt.space {
+			Some(s) => self.spaces.insert(cwd, s),
+			None => self.spaces.remove(&cwd),
+		};
+		render!();
+	}
+
+	pub(super) fn read_space(cwd: Url) {
+		tokio::task::spawn_blocking(move || {
+			let space = yazi_fs::space(&cwd).map(|s| (s, yazi_fs::fstype(&cwd)));
+			emit!(Call(
+				Cmd::new("update_space").with_any("cwd", cwd).with_any("space", space),
+				Layer::Manager
+			));
+		});
+	}
+}
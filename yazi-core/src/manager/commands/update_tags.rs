@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use tracing::error;
+use yazi_shared::{event::Cmd, fs::Url, render};
+
+use crate::manager::{Kind, Manager, FETCH_CACHE};
+
+pub struct Opt {
+	updates: HashMap<String, String>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { updates: c.take("updates").ok_or(())?.into_dict_string() })
+	}
+}
+
+impl Manager {
+	// The colors of tags for each file, separated by commas, with an empty string for none
+	pub fn update_tags(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return error!("invalid arguments for update_tags");
+		};
+
+		let mut changed = false;
+		for (url, colors) in opt.updates {
+			let url = Url::from(url);
+			FETCH_CACHE.put(Kind::Tags, &url, &colors);
+			let colors: Vec<_> =
+				colors.split(',').filter(|s| !s.is_empty()).map(ToOwned::to_owned).collect();
+
+			changed |= if colors.is_empty() {
+				self.tags.remove(&url).is_some()
+			} else {
+				self.tags.insert(url, colors.clone()) != Some(colors)
+			};
+		}
+
+		if changed {
+			render!();
+		}
+	}
+}
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use yazi_config::{manager::SortBy, vcs::VcsProvider};
+use yazi_shared::{event::Cmd, render};
+
+use crate::{manager::Manager, vcs::Repo};
+
+pub struct Opt {
+	root:     PathBuf,
+	provider: VcsProvider,
+	repo:     Option<Repo>,
+	scope:    Vec<PathBuf>,
+	stale:    bool,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			root:     c.take_any("root").ok_or(())?,
+			provider: c.take_any("provider").ok_or(())?,
+			repo:     c.take_any("repo").flatten(),
+			scope:    c.take_any("scope").unwrap_or_default(),
+			stale:    c.bool("stale"),
+		})
+	}
+}
+
+impl Manager {
+	pub fn update_vcs(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+
+		if opt.stale {
+			return self.vcs.reload(opt.root, opt.provider);
+		}
+
+		self.vcs.update(opt.root, opt.provider, opt.repo, opt.scope);
+		self.apply_vcs();
+		render!();
+	}
+
+	// Hands the statuses over to the folders sorted by them, the others don't need them
+	pub(crate) fn apply_vcs(&mut self) {
+		for tab in self.tabs.iter_mut().filter(|t| t.conf.sort_by == SortBy::Vcs) {
+			let hovered = tab.current.hovered().filter(|h| h.is_dir()).map(|h| h.url());
+			let folders = [Some(&mut tab.current), tab.parent.as_mut()]
+				.into_iter()
+				.flatten()
+				.chain(hovered.and_then(|u| tab.history.get_mut(&u)));
+
+			for folder in folders {
+				let statuses = folder
+					.files
+					.iter()
+					.filter_map(|f| Some((f.url(), self.vcs.status(&f.url)?)))
+					.collect();
+
+				let hovered = folder.hovered().filter(|_| folder.tracing).map(|h| h.url());
+				folder.files.update_statuses(statuses);
+				if folder.files.catchup_revision() {
+					folder.repos(hovered);
+					render!();
+				}
+			}
+		}
+	}
+}
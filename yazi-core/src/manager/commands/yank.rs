@@ -1,13 +1,16 @@
+use yazi_plugin::CLIPBOARD;
+use yazi_proxy::AppProxy;
 use yazi_shared::{event::Cmd, render};
 
 use crate::manager::{Manager, Yanked};
 
 pub struct Opt {
-	cut: bool,
+	cut:    bool,
+	system: bool,
 }
 
 impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { cut: c.bool("cut") } }
+	fn from(c: Cmd) -> Self { Self { cut: c.bool("cut"), system: c.bool("system") } }
 }
 
 impl Manager {
@@ -16,9 +19,29 @@ impl Manager {
 			return;
 		}
 
-		self.yanked = Yanked::new(opt.into().cut, self.selected_or_hovered(false).cloned().collect());
+		let opt = opt.into() as Opt;
+		self.yanked = Yanked::new(opt.cut, self.selected_or_hovered(false).cloned().collect());
 		render!(self.yanked.catchup_revision(true));
 
 		self.active_mut().escape_select();
+		if opt.system {
+			self.yank_system();
+		}
+	}
+
+	// Also puts them into the system clipboard, for pasting them into the other apps
+	fn yank_system(&self) {
+		let cut = self.yanked.cut;
+		let paths: Vec<_> =
+			self.yanked.iter().filter(|u| u.is_regular()).map(|u| u.to_path_buf()).collect();
+		if paths.is_empty() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			if !CLIPBOARD.set_files(&paths, cut).await {
+				AppProxy::notify_warn("Yank", "Failed to copy the files to the system clipboard");
+			}
+		});
 	}
 }
@@ -0,0 +1,269 @@
+use std::{collections::HashMap, io::Write, mem, path::PathBuf, time::{SystemTime, UNIX_EPOCH}};
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+use tracing::error;
+use yazi_config::{MANAGER, PREVIEW};
+use yazi_shared::{emit, event::{Cmd, Data, DataKey}, fs::{File, Url}, Layer, RoCell};
+
+pub static FETCH_CACHE: RoCell<FetchCache> = RoCell::new();
+
+// The results of the fetchers, kept across sessions so re-entering a big directory doesn't run
+// them for every file again. An entry is only used while the file stays the same, and it's
+// stored as one line each in an append-only file, which is compacted to the `fetch_cache` most
+// recent entries once it's twice as long. The file is written by a task of its own, off the
+// main thread.
+pub struct FetchCache {
+	inner:  Mutex<Inner>,
+	writer: mpsc::UnboundedSender<Op>,
+}
+
+#[derive(Default)]
+struct Inner {
+	entries: HashMap<(Kind, PathBuf), Entry>,
+	// The stamps of the files being fetched, to be stored along with their results
+	pending: HashMap<(Kind, Url), (u128, u64)>,
+	seq:     u64,
+	lines:   usize,
+}
+
+struct Entry {
+	time:  u128,
+	size:  u64,
+	value: String,
+	seq:   u64,
+}
+
+enum Op {
+	Append(String),
+	Rewrite(String),
+}
+
+// The fetchers whose results are cached, by their `id`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kind {
+	Mime,
+	Tags,
+	Security,
+}
+
+impl Kind {
+	pub fn from_id(id: &str) -> Option<Self> {
+		Some(match id {
+			"mime" => Self::Mime,
+			"tags" => Self::Tags,
+			"security" => Self::Security,
+			_ => return None,
+		})
+	}
+
+	#[inline]
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::Mime => "mime",
+			Self::Tags => "tags",
+			Self::Security => "security",
+		}
+	}
+
+	// The mime type only depends on the contents, while the tags and the security context
+	// are xattrs, which only bump the status change time.
+	fn time(self, file: &File) -> Option<SystemTime> {
+		match self {
+			Self::Mime => file.cha.mtime,
+			#[cfg(unix)]
+			Self::Tags | Self::Security => file.cha.chtime,
+			#[cfg(not(unix))]
+			Self::Tags | Self::Security => None,
+		}
+	}
+}
+
+impl FetchCache {
+	pub fn load() -> Self {
+		let me = Self { inner: Default::default(), writer: Self::writer() };
+		if MANAGER.load().fetch_cache == 0 {
+			return me;
+		}
+
+		let Ok(s) = std::fs::read_to_string(Self::path()) else { return me };
+		let mut inner = me.inner.lock();
+		for line in s.lines() {
+			let mut it = line.splitn(5, '\t');
+			let (Some(kind), Some(time), Some(size), Some(value), Some(path)) =
+				(it.next(), it.next(), it.next(), it.next(), it.next())
+			else {
+				continue;
+			};
+			let (Some(kind), Ok(time), Ok(size)) = (Kind::from_id(kind), time.parse(), size.parse())
+			else {
+				continue;
+			};
+
+			inner.seq += 1;
+			let entry = Entry { time, size, value: value.to_owned(), seq: inner.seq };
+			inner.entries.insert((kind, PathBuf::from(path)), entry);
+		}
+
+		inner.lines = s.lines().count();
+		let max = MANAGER.load().fetch_cache;
+		if inner.entries.len() > max || inner.lines > max * 2 {
+			let buf = inner.compact();
+			me.send(Op::Rewrite(buf));
+		}
+		drop(inner);
+		me
+	}
+
+	pub fn get(&self, kind: Kind, file: &File) -> Option<String> {
+		let stamp = Self::stamp(kind, file)?;
+		let inner = self.inner.lock();
+		let entry = inner.entries.get(&(kind, file.url.to_path_buf()))?;
+		((entry.time, entry.size) == stamp).then(|| entry.value.clone())
+	}
+
+	#[inline]
+	pub fn pend(&self, kind: Kind, file: &File) {
+		if let Some(stamp) = Self::stamp(kind, file) {
+			self.inner.lock().pending.insert((kind, file.url.clone()), stamp);
+		}
+	}
+
+	pub fn put(&self, kind: Kind, url: &Url, value: &str) {
+		let mut inner = self.inner.lock();
+		let Some((time, size)) = inner.pending.remove(&(kind, url.clone())) else { return };
+		let Some(path) = url.to_str().filter(|s| !s.contains('\n')) else { return };
+		if value.contains(['\t', '\n']) {
+			return;
+		}
+
+		inner.seq += 1;
+		let entry = Entry { time, size, value: value.to_owned(), seq: inner.seq };
+		inner.entries.insert((kind, url.to_path_buf()), entry);
+
+		inner.lines += 1;
+		if inner.lines > MANAGER.load().fetch_cache * 2 {
+			let buf = inner.compact();
+			return self.send(Op::Rewrite(buf));
+		}
+
+		let kind = kind.as_str();
+		self.send(Op::Append(format!("{kind}\t{time}\t{size}\t{value}\t{path}\n")));
+	}
+
+	// Forgets the given files, or all of them with `None`
+	pub fn invalidate(&self, urls: Option<&[Url]>) {
+		let mut inner = self.inner.lock();
+		match urls {
+			Some(urls) => {
+				let paths: Vec<_> = urls.iter().map(|u| u.as_path()).collect();
+				inner.entries.retain(|(_, p), _| !paths.contains(&p.as_path()));
+			}
+			None => inner.entries.clear(),
+		}
+
+		let buf = inner.compact();
+		self.send(Op::Rewrite(buf));
+	}
+
+	// Hands the cached results to the manager, the same as the fetchers do
+	pub fn replay(kind: Kind, updates: HashMap<String, String>) {
+		let dict = |m: HashMap<String, String>| {
+			Data::Dict(m.into_iter().map(|(k, v)| (DataKey::String(k), Data::String(v))).collect())
+		};
+
+		let mut cmd = match kind {
+			Kind::Mime => Cmd::new("update_mimetype"),
+			Kind::Tags => Cmd::new("update_tags"),
+			Kind::Security => Cmd::new("update_security"),
+		};
+		if kind == Kind::Security {
+			let (contexts, caps): (HashMap<_, _>, HashMap<_, _>) = updates
+				.into_iter()
+				.map(|(url, v)| {
+					let (context, caps) = v.split_once(' ').unwrap_or((&v, ""));
+					((url.clone(), context.to_owned()), (url, caps.to_owned()))
+				})
+				.unzip();
+			cmd.args.insert("contexts".to_owned(), dict(contexts));
+			cmd.args.insert("caps".to_owned(), dict(caps));
+		} else {
+			cmd.args.insert("updates".to_owned(), dict(updates));
+		}
+		emit!(Call(cmd, Layer::Manager));
+	}
+
+	fn stamp(kind: Kind, file: &File) -> Option<(u128, u64)> {
+		if MANAGER.load().fetch_cache == 0 || file.url.is_remote() || file.is_dir() {
+			return None;
+		}
+		Some((kind.time(file)?.duration_since(UNIX_EPOCH).ok()?.as_nanos(), file.cha.len))
+	}
+
+	#[inline]
+	fn path() -> PathBuf { PREVIEW.load().cache_dir.join("fetch.cache") }
+
+	#[inline]
+	fn send(&self, op: Op) { _ = self.writer.send(op); }
+
+	fn writer() -> mpsc::UnboundedSender<Op> {
+		let (tx, mut rx) = mpsc::unbounded_channel();
+		tokio::spawn(async move {
+			let mut ops = Vec::new();
+			while rx.recv_many(&mut ops, 1000).await > 0 {
+				let ops = mem::take(&mut ops);
+				if let Ok(Err(e)) = tokio::task::spawn_blocking(|| Self::write(ops)).await {
+					error!("Failed to write the fetch cache: {e}");
+				}
+			}
+		});
+		tx
+	}
+
+	// Appends the new lines in one go, or after the last rewrite, which has the earlier ones already
+	fn write(ops: Vec<Op>) -> std::io::Result<()> {
+		std::fs::create_dir_all(&PREVIEW.load().cache_dir)?;
+
+		let mut lines = String::new();
+		for op in ops {
+			match op {
+				Op::Append(s) => lines.push_str(&s),
+				Op::Rewrite(buf) => {
+					lines.clear();
+					let tmp = PREVIEW.load().cache_dir.join("fetch.cache.tmp");
+					std::fs::write(&tmp, buf)?;
+					std::fs::rename(&tmp, Self::path())?;
+				}
+			}
+		}
+
+		if lines.is_empty() {
+			return Ok(());
+		}
+		let mut f = std::fs::OpenOptions::new().create(true).append(true).open(Self::path())?;
+		f.write_all(lines.as_bytes())
+	}
+}
+
+impl Inner {
+	// Keeps the most recent entries, and returns the contents of the file rewritten with them
+	fn compact(&mut self) -> String {
+		let cap = MANAGER.load().fetch_cache;
+		if self.entries.len() > cap {
+			let mut seqs: Vec<_> = self.entries.values().map(|e| e.seq).collect();
+			seqs.sort_unstable_by(|a, b| b.cmp(a));
+			let min = if cap == 0 { u64::MAX } else { seqs[cap - 1] };
+			self.entries.retain(|_, e| e.seq >= min);
+		}
+
+		let mut buf = String::new();
+		for ((kind, path), e) in &self.entries {
+			let Some(path) = path.to_str() else { continue };
+			let kind = kind.as_str();
+			buf.push_str(&format!("{kind}\t{}\t{}\t{}\t{path}\n", e.time, e.size, e.value));
+		}
+
+		self.lines = self.entries.len();
+		buf
+	}
+}
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use yazi_boot::BOOT;
+use yazi_shared::timestamp_us;
+
+// The destinations of `paste --to`
+pub static DESTINATIONS: Frecency = Frecency::new("destinations.json");
+
+const MAX: usize = 200;
+
+// The entries ranked by how often they have been used, weighted by how long ago the last time was,
+// kept in the state directory across sessions.
+pub struct Frecency {
+	name:    &'static str,
+	entries: Mutex<Option<Vec<Entry>>>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Entry {
+	path:  String,
+	count: u32,
+	last:  u64,
+}
+
+impl Frecency {
+	const fn new(name: &'static str) -> Self { Self { name, entries: Mutex::new(None) } }
+
+	pub fn bump(&self, path: &str) {
+		let now = timestamp_us() / 1_000_000;
+		let mut entries = self.entries.lock();
+		let entries = entries.get_or_insert_with(|| self.load());
+
+		match entries.iter_mut().find(|e| e.path == path) {
+			Some(e) => (e.count, e.last) = (e.count + 1, now),
+			None => entries.push(Entry { path: path.to_owned(), count: 1, last: now }),
+		}
+		if entries.len() > MAX {
+			Self::sort(entries, now);
+			entries.truncate(MAX);
+		}
+
+		if let Ok(b) = serde_json::to_vec(entries) {
+			std::fs::write(self.path(), b).ok();
+		}
+	}
+
+	pub fn ranked(&self) -> Vec<String> {
+		let mut entries = self.entries.lock();
+		let entries = entries.get_or_insert_with(|| self.load());
+
+		Self::sort(entries, timestamp_us() / 1_000_000);
+		entries.iter().map(|e| e.path.clone()).collect()
+	}
+
+	fn sort(entries: &mut [Entry], now: u64) {
+		let score = |e: &Entry| {
+			let weight = match now.saturating_sub(e.last) {
+				0..3600 => 4.0,
+				3600..86400 => 2.0,
+				86400..604800 => 0.5,
+				_ => 0.25,
+			};
+			e.count as f64 * weight
+		};
+		entries.sort_unstable_by(|a, b| score(b).total_cmp(&score(a)));
+	}
+
+	fn load(&self) -> Vec<Entry> {
+		std::fs::read(self.path())
+			.ok()
+			.and_then(|b| serde_json::from_slice(&b).ok())
+			.unwrap_or_default()
+	}
+
+	#[inline]
+	fn path(&self) -> PathBuf { BOOT.state_dir.join(self.name) }
+}
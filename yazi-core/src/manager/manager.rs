@@ -1,31 +1,91 @@
 use std::collections::HashMap;
 
-use yazi_fs::Folder;
-use yazi_shared::fs::{File, Url};
+use yazi_config::{popup::SelectCfg, MANAGER};
+use yazi_fs::{Folder, Image, Space};
+use yazi_proxy::{AppProxy, SelectProxy};
+use yazi_shared::fs::{sanitize_name, unportable_name, File, Url};
 
-use super::{Tabs, Watcher, Yanked};
-use crate::tab::Tab;
+use super::{Bookmarks, Tabs, Watcher, Yanked};
+use crate::{tab::Tab, vcs::Vcs};
 
 pub struct Manager {
-	pub tabs:   Tabs,
-	pub yanked: Yanked,
+	pub tabs:      Tabs,
+	pub yanked:    Yanked,
+	pub bookmarks: Bookmarks,
 
 	pub(super) watcher: Watcher,
 	pub mimetype:       HashMap<Url, String>,
+	pub tags:           HashMap<Url, Vec<String>>,
+	pub security:       HashMap<Url, (String, String)>,
+	pub vcs:            Vcs,
+	pub spaces:         HashMap<Url, (Space, Option<String>)>,
+	pub(super) images:  Vec<Image>,
+
+	// Stack of the named layers toggled on, the last one takes precedence
+	pub layers: Vec<String>,
 }
 
 impl Manager {
 	pub fn make() -> Self {
 		Self {
-			tabs:   Tabs::make(),
-			yanked: Default::default(),
+			tabs:      Tabs::make(),
+			yanked:    Default::default(),
+			bookmarks: Bookmarks::load(),
 
 			watcher:  Watcher::serve(),
 			mimetype: Default::default(),
+			tags:     Default::default(),
+			security: Default::default(),
+			vcs:      Default::default(),
+			spaces:   Default::default(),
+			images:   Default::default(),
+
+			layers: Default::default(),
 		}
 	}
 
 	pub fn shutdown(&mut self) { self.tabs.iter_mut().for_each(|t| t.shutdown()); }
+
+	// The paths of the remote files would point to the local ones of the same name,
+	// so the operations working on the local filesystem only refuse to take them.
+	pub(super) fn refuse_remote<'a>(title: &str, mut urls: impl Iterator<Item = &'a Url>) -> bool {
+		if !urls.any(|u| u.is_remote()) {
+			return false;
+		}
+		AppProxy::notify_warn(
+			title,
+			"Not supported for remote files, copy them to a local directory first",
+		);
+		true
+	}
+
+	// Asks whether to keep or sanitize a name invalid on Windows, if `portable_names` is on.
+	// Each component is checked since `create` takes a relative path, `None` for cancelling.
+	pub(super) async fn portable_name(name: String) -> Option<String> {
+		if !MANAGER.load().portable_names {
+			return Some(name);
+		}
+
+		let sep = |c: char| c == '/' || (cfg!(windows) && c == '\\');
+		let normal = |s: &&str| !s.is_empty() && *s != "." && *s != "..";
+		let Some(reason) = name.split(sep).filter(normal).find_map(unportable_name) else {
+			return Some(name);
+		};
+
+		let sanitized: String = name
+			.split_inclusive(sep)
+			.map(|s| {
+				let part = s.strip_suffix(sep).unwrap_or(s);
+				if normal(&part) { sanitize_name(part) + &s[part.len()..] } else { s.to_owned() }
+			})
+			.collect();
+
+		match SelectProxy::show(SelectCfg::unportable(&reason, &sanitized)).await {
+			Ok(0) => Some(sanitized),
+			Ok(1) => Some(name),
+			_ => None,
+		}
+	}
 }
 
 impl Manager {
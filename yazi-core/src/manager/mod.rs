@@ -1,12 +1,20 @@
+mod bookmarks;
 mod commands;
+mod fetch_cache;
+mod frecency;
 mod linked;
 mod manager;
+mod snapshot;
 mod tabs;
 mod watcher;
 mod yanked;
 
+pub use bookmarks::*;
+pub use fetch_cache::*;
+pub use frecency::*;
 pub use linked::*;
 pub use manager::*;
+pub use snapshot::*;
 pub use tabs::*;
 pub use watcher::*;
 pub use yanked::*;
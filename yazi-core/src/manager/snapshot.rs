@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use yazi_boot::BOOT;
+use yazi_config::popup::SelectCfg;
+use yazi_proxy::SelectProxy;
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+// The state as of the last frame, for the panic hook that can't reach the app itself
+static LAST: Mutex<Option<Snapshot>> = Mutex::new(None);
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct Snapshot {
+	pub tabs:   Vec<SnapshotTab>,
+	pub cursor: usize,
+	pub tasks:  Vec<String>,
+	pub errors: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SnapshotTab {
+	pub cwd:     Url,
+	pub hovered: Option<Url>,
+}
+
+impl Snapshot {
+	// Called after every frame, so the state is only copied over once it differs from the last one
+	pub fn record(manager: &Manager, tasks: &Tasks) {
+		let mut last = LAST.lock();
+		let last = last.get_or_insert_with(Default::default);
+
+		let unchanged = last.cursor == manager.tabs.cursor
+			&& last.tabs.len() == manager.tabs.len()
+			&& last.tabs.iter().zip(manager.tabs.iter()).all(|(a, b)| {
+				a.cwd == b.current.cwd && a.hovered.as_ref() == b.current.hovered().map(|h| &h.url)
+			});
+		if !unchanged {
+			last.cursor = manager.tabs.cursor;
+			last.tabs = manager
+				.tabs
+				.iter()
+				.map(|t| SnapshotTab {
+					cwd:     t.current.cwd.clone(),
+					hovered: t.current.hovered().map(|h| h.url()),
+				})
+				.collect();
+		}
+
+		let ongoing = tasks.ongoing().lock();
+		if !last.tasks.iter().eq(ongoing.values().map(|t| &t.name)) {
+			last.tasks = ongoing.values().map(|t| t.name.clone()).collect();
+		}
+	}
+
+	// Keeps the latest 10 errors, as they're notified
+	pub(crate) fn error(title: &str, content: &str) {
+		let mut last = LAST.lock();
+		let errors = &mut last.get_or_insert_with(Default::default).errors;
+		if errors.len() >= 10 {
+			errors.remove(0);
+		}
+		errors.push(format!("{title}: {content}"));
+	}
+
+	// Called from the panic hook, so it gives up rather than waiting for the lock
+	pub fn dump() {
+		let Some(guard) = LAST.try_lock() else { return };
+		if let Some(b) = guard.as_ref().and_then(|s| serde_json::to_vec(s).ok()) {
+			std::fs::write(Self::path(), b).ok();
+		}
+	}
+
+	// Asks whether to resume the session left by the last crash, which is offered only once
+	pub fn offer() {
+		let Some(snapshot) = Self::take() else { return };
+		tokio::spawn(async move {
+			let n = snapshot.tabs.len();
+			let cfg = SelectCfg::confirm(format!("Yazi crashed last time, reopen its {n} tab(s)?"), vec![
+				(Some('r'), "Reopen".to_owned()),
+				(Some('d'), "Discard".to_owned()),
+			]);
+			if matches!(SelectProxy::show(cfg).await, Ok(0)) {
+				emit!(Call(Cmd::new("restore").with_any("snapshot", snapshot), Layer::Manager));
+			}
+		});
+	}
+
+	fn take() -> Option<Self> {
+		let b = std::fs::read(Self::path()).ok()?;
+		std::fs::remove_file(Self::path()).ok();
+		serde_json::from_slice(&b).ok().filter(|s: &Self| !s.tabs.is_empty())
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("crash.json") }
+}
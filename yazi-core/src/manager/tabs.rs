@@ -1,6 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 use yazi_boot::BOOT;
+use yazi_dds::Pubsub;
 use yazi_proxy::ManagerProxy;
 use yazi_shared::fs::Url;
 
@@ -50,6 +51,7 @@ impl Tabs {
 		self.cursor = idx;
 		ManagerProxy::refresh();
 		ManagerProxy::peek(true);
+		Pubsub::pub_from_tab(idx);
 	}
 }
 
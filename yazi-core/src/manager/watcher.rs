@@ -1,15 +1,15 @@
-use std::{collections::{HashMap, HashSet}, time::Duration};
+use std::{collections::{HashMap, HashSet}, mem, path::Path, time::Duration};
 
 use anyhow::Result;
-use notify::{RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
+use notify::{PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
 use parking_lot::RwLock;
-use tokio::{fs, pin, sync::{mpsc::{self, UnboundedReceiver}, watch}};
+use tokio::{pin, sync::{mpsc::{self, UnboundedReceiver}, watch}};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use tracing::error;
-use yazi_fs::{Files, Folder};
-use yazi_plugin::isolate;
-use yazi_proxy::WATCHER;
-use yazi_shared::{fs::{symlink_realname, Cha, File, FilesOp, Url}, RoCell};
+use yazi_fs::{fstype, Files, Folder, DIR_SIZES};
+use yazi_plugin::{isolate, loader::LOADER};
+use yazi_proxy::{AppProxy, WATCHER};
+use yazi_shared::{emit, event::Cmd, fs::{canonicalize, symlink_realname, Cha, File, FilesOp, Url}, Debounce, Layer, RoCell, Xdg};
 
 use super::Linked;
 
@@ -26,27 +26,92 @@ impl Watcher {
 		let (in_tx, in_rx) = watch::channel(Default::default());
 		let (out_tx, out_rx) = mpsc::unbounded_channel();
 
-		let out_tx_ = out_tx.clone();
-		let watcher = RecommendedWatcher::new(
+		let handler = |tx: mpsc::UnboundedSender<Url>| {
 			move |res: Result<notify::Event, notify::Error>| {
 				let Ok(event) = res else { return };
 				if event.kind.is_access() {
 					return;
 				}
 				for path in event.paths {
-					out_tx_.send(Url::from(path)).ok();
+					tx.send(Url::from(path)).ok();
 				}
-			},
-			Default::default(),
+			}
+		};
+
+		let watcher = RecommendedWatcher::new(handler(out_tx.clone()), Default::default());
+		let poller = PollWatcher::new(
+			handler(out_tx.clone()),
+			notify::Config::default()
+				.with_poll_interval(Duration::from_secs(yazi_config::WATCHER.load().poll_interval)),
 		);
 
-		tokio::spawn(Self::fan_in(in_rx, watcher.unwrap()));
+		tokio::spawn(Self::fan_in(in_rx, watcher.unwrap(), poller.unwrap()));
 		tokio::spawn(Self::fan_out(out_rx));
 		Self { in_tx, out_tx }
 	}
 
+	// Reloads the config once the TOML files in the config directory are changed,
+	// except for the ones of the plugins, which are reloaded on their own.
+	pub fn watch_config() {
+		let (tx, rx) = mpsc::unbounded_channel();
+		let (plugin_tx, mut plugin_rx) = mpsc::unbounded_channel();
+		let dir = Xdg::config_dir();
+		let plugins = dir.join("plugins");
+
+		let watcher = RecommendedWatcher::new(
+			move |res: Result<notify::Event, notify::Error>| {
+				let Ok(event) = res else { return };
+				if event.kind.is_access() {
+					return;
+				}
+				// The plugins are reloaded on their own, on changes to their `init.lua` or `manifest.toml`
+				let plugin = |p: &Path| {
+					let mut it = p.strip_prefix(&plugins).ok()?.components();
+					let name = it.next()?.as_os_str().to_str()?.strip_suffix(".yazi")?;
+					matches!(it.as_path().to_str(), Some("init.lua" | "manifest.toml"))
+						.then(|| name.to_owned())
+				};
+
+				let mut config = false;
+				for p in &event.paths {
+					if let Some(name) = plugin(p) {
+						plugin_tx.send(name).ok();
+					} else if !p.starts_with(&plugins) && p.extension().is_some_and(|e| e == "toml") {
+						config = true;
+					}
+				}
+				if config {
+					tx.send(()).ok();
+				}
+			},
+			Default::default(),
+		);
+		let mut watcher = match watcher {
+			Ok(w) => w,
+			Err(e) => return error!("Failed to watch the config directory: {e}"),
+		};
+		if let Err(e) = watcher.watch(&dir, RecursiveMode::Recursive) {
+			return error!("Failed to watch the config directory {dir:?}: {e}");
+		}
+
+		tokio::spawn(async move {
+			let _watcher = watcher;
+			let rx = Debounce::new(UnboundedReceiverStream::new(rx), Duration::from_millis(300));
+			pin!(rx);
+			while rx.next().await.is_some() {
+				emit!(Call(Cmd::new("reload"), Layer::App));
+			}
+		});
+
+		tokio::spawn(async move {
+			while let Some(name) = plugin_rx.recv().await {
+				LOADER.reload(&name).await;
+			}
+		});
+	}
+
 	pub(super) fn watch(&mut self, mut new: HashSet<&Url>) {
-		new.retain(|&u| u.is_regular());
+		new.retain(|&u| u.is_regular() && !yazi_config::WATCHER.load().excluded(u));
 		self.in_tx.send(new.into_iter().cloned().collect()).ok();
 	}
 
@@ -57,8 +122,12 @@ impl Watcher {
 	}
 
 	pub(super) fn trigger_dirs(&self, folders: &[&Folder]) {
-		let todo: Vec<_> =
-			folders.iter().filter(|&f| f.cwd.is_regular()).map(|&f| (f.cwd.clone(), f.cha)).collect();
+		// The remote and recent ones aren't watched, so they're listed again on each refresh instead
+		let todo: Vec<_> = folders
+			.iter()
+			.filter(|&f| f.cwd.is_regular() || f.cwd.is_remote() || f.cwd.is_recent())
+			.map(|&f| (f.cwd.clone(), f.cha))
+			.collect();
 		if todo.is_empty() {
 			return;
 		}
@@ -76,22 +145,41 @@ impl Watcher {
 		});
 	}
 
-	async fn fan_in(mut rx: watch::Receiver<HashSet<Url>>, mut watcher: RecommendedWatcher) {
+	async fn fan_in(
+		mut rx: watch::Receiver<HashSet<Url>>,
+		mut watcher: RecommendedWatcher,
+		mut poller: PollWatcher,
+	) {
+		// The ones on network filesystems are polled instead, through the same update path
+		let (mut polled, mut warned) = (HashSet::new(), false);
 		loop {
 			let (mut to_unwatch, mut to_watch): (HashSet<_>, HashSet<_>) = {
 				let (new, old) = (&*rx.borrow_and_update(), &*WATCHED.read());
 				(old.difference(new).cloned().collect(), new.difference(old).cloned().collect())
 			};
 
-			to_unwatch.retain(|u| match watcher.unwatch(u) {
-				Ok(_) => true,
-				Err(e) if matches!(e.kind, notify::ErrorKind::WatchNotFound) => true,
-				Err(e) => {
-					error!("Unwatch failed: {e:?}");
-					false
+			to_unwatch.retain(|u| {
+				let result = if polled.remove(u) { poller.unwatch(u) } else { watcher.unwatch(u) };
+				match result {
+					Ok(_) => true,
+					Err(e) if matches!(e.kind, notify::ErrorKind::WatchNotFound) => true,
+					Err(e) => {
+						error!("Unwatch failed: {e:?}");
+						false
+					}
 				}
 			});
-			to_watch.retain(|u| watcher.watch(u, RecursiveMode::NonRecursive).is_ok());
+			to_watch.retain(|u| {
+				if !yazi_config::WATCHER.load().poll(u, fstype(u).as_deref()) {
+					match watcher.watch(u, RecursiveMode::NonRecursive) {
+						Ok(_) => return true,
+						Err(e) if Self::is_exhausted(&e) => Self::warn_exhausted(&mut warned),
+						Err(_) => return false,
+					}
+				}
+				let ok = poller.watch(u, RecursiveMode::NonRecursive).is_ok();
+				ok && polled.insert(u.clone())
+			});
 
 			{
 				let mut watched = WATCHED.write();
@@ -109,6 +197,32 @@ impl Watcher {
 		}
 	}
 
+	// Running out of the inotify watches fails with `ENOSPC`
+	fn is_exhausted(e: &notify::Error) -> bool {
+		match &e.kind {
+			notify::ErrorKind::MaxFilesWatch => true,
+			#[cfg(unix)]
+			notify::ErrorKind::Io(e) => e.raw_os_error() == Some(libc::ENOSPC),
+			_ => false,
+		}
+	}
+
+	fn warn_exhausted(warned: &mut bool) {
+		if mem::replace(warned, true) {
+			return;
+		}
+		AppProxy::notify_warn(
+			"Watcher",
+			&format!(
+				"The limit of inotify watches has been reached, so the directories beyond it are \
+				 refreshed every {}s instead. To raise the limit, run:\n\n  \
+				 sudo sysctl fs.inotify.max_user_watches=524288\n\n\
+				 and add `fs.inotify.max_user_watches=524288` to /etc/sysctl.conf to make it permanent.",
+				yazi_config::WATCHER.load().poll_interval
+			),
+		);
+	}
+
 	async fn fan_out(rx: UnboundedReceiver<Url>) {
 		// TODO: revert this once a new notification is implemented
 		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1000, Duration::from_millis(100));
@@ -122,6 +236,7 @@ impl Watcher {
 			let mut reload = Vec::with_capacity(urls.len());
 
 			for url in urls {
+				DIR_SIZES.invalidate(&url);
 				let Some(name) = url.file_name() else { continue };
 				let Some(parent) = url.parent_url() else { continue };
 
@@ -130,7 +245,7 @@ impl Watcher {
 					continue;
 				};
 
-				let eq = (!file.is_link() && fs::canonicalize(&url).await.is_ok_and(|p| p == *url))
+				let eq = (!file.is_link() && canonicalize(&url).await.is_ok_and(|p| p == *url))
 					|| symlink_realname(&url, &mut cached).await.is_ok_and(|s| s == name);
 
 				if !eq {
@@ -147,7 +262,7 @@ impl Watcher {
 			if reload.is_empty() {
 				continue;
 			}
-			if let Err(e) = isolate::fetch("mime", reload).await {
+			if let Err(e) = isolate::fetch("mime", reload, Default::default(), true).await {
 				error!("Fetch `mime` failed in watcher: {e}");
 			}
 		}
@@ -164,7 +279,7 @@ impl Watcher {
 
 		async fn go(todo: HashSet<Url>) {
 			for from in todo {
-				let Ok(to) = fs::canonicalize(&from).await else { continue };
+				let Ok(to) = canonicalize(&from).await else { continue };
 
 				if to != *from && WATCHED.read().contains(&from) {
 					LINKED.write().insert(from, Url::from(to));
@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use yazi_config::popup::SelectCfg;
+use yazi_plugin::CLIPBOARD;
+use yazi_proxy::{AppProxy, SelectProxy};
+use yazi_shared::{emit, event::Cmd};
+
+use crate::notify::Notify;
+
+impl Notify {
+	// Lists the past notifications, newest first, and then the actions of the chosen one,
+	// where copying the content is always available, e.g. to report an error.
+	pub fn history(&mut self, _: Cmd) {
+		if self.history.is_empty() {
+			return AppProxy::notify_warn("Notifications", "No notifications yet");
+		}
+
+		let records: Vec<_> = self.history.iter().rev().cloned().collect();
+		let items = records
+			.iter()
+			.map(|r| {
+				let line = r.content.lines().next().unwrap_or_default();
+				format!("{} {}: {line}", r.level.icon(), r.title)
+			})
+			.collect();
+
+		tokio::spawn(async move {
+			let Ok(choice) = SelectProxy::show(SelectCfg::notify(items)).await else { return };
+			let record = &records[choice];
+
+			let mut items = vec!["Copy".to_owned()];
+			items.extend(record.actions.iter().map(|a| a.desc.clone()));
+
+			let mut cfg = SelectCfg::notify(items);
+			cfg.title = format!("{}:", record.title);
+			match SelectProxy::show(cfg).await {
+				Ok(0) => CLIPBOARD.set(&record.content).await,
+				Ok(i) => match Cmd::from_str(&record.actions[i - 1].run) {
+					Ok(cmd) => emit!(Call(cmd, record.actions[i - 1].layer)),
+					Err(e) => AppProxy::notify_error("Notifications", &e.to_string()),
+				},
+				Err(_) => {}
+			}
+		});
+	}
+}
@@ -1,12 +1,29 @@
 use std::time::Instant;
 
+use yazi_proxy::options::{NotifyLevel, NotifyOpt};
 use yazi_shared::{emit, event::Cmd, Layer};
 
-use crate::notify::{Message, Notify};
+use crate::{manager::Snapshot, notify::{Message, Notify, Record}};
+
+const HISTORY: usize = 100;
 
 impl Notify {
-	pub fn push(&mut self, msg: impl Into<Message>) {
-		let mut msg = msg.into() as Message;
+	pub fn push(&mut self, opt: NotifyOpt) {
+		if matches!(opt.level, NotifyLevel::Error) {
+			Snapshot::error(&opt.title, &opt.content);
+		}
+
+		self.history.push_back(Record::from(&opt));
+		if self.history.len() > HISTORY {
+			self.history.pop_front();
+		}
+
+		// The ones without a timeout are only kept in the history, e.g. the tasks that succeeded
+		if opt.timeout.is_zero() {
+			return;
+		}
+
+		let mut msg = Message::from(opt);
 
 		let instant = Instant::now();
 		msg.timeout += instant - self.messages.first().map_or(instant, |m| m.instant);
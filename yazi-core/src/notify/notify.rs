@@ -1,7 +1,8 @@
-use std::ops::ControlFlow;
+use std::{collections::VecDeque, ops::ControlFlow};
 
 use ratatui::layout::Rect;
 use tokio::task::JoinHandle;
+use yazi_proxy::options::{NotifyAction, NotifyLevel, NotifyOpt};
 
 use super::{Message, NOTIFY_SPACING};
 
@@ -9,6 +10,28 @@ use super::{Message, NOTIFY_SPACING};
 pub struct Notify {
 	pub(super) tick_handle: Option<JoinHandle<()>>,
 	pub messages:           Vec<Message>,
+
+	// Messages are kept here after they fade out, to be reviewed later
+	pub(super) history: VecDeque<Record>,
+}
+
+#[derive(Clone)]
+pub(super) struct Record {
+	pub(super) title:   String,
+	pub(super) content: String,
+	pub(super) level:   NotifyLevel,
+	pub(super) actions: Vec<NotifyAction>,
+}
+
+impl From<&NotifyOpt> for Record {
+	fn from(opt: &NotifyOpt) -> Self {
+		Self {
+			title:   opt.title.clone(),
+			content: opt.content.clone(),
+			level:   opt.level,
+			actions: opt.actions.clone(),
+		}
+	}
 }
 
 impl Notify {
@@ -0,0 +1,3 @@
+mod recorder;
+
+pub use recorder::*;
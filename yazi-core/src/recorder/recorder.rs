@@ -0,0 +1,161 @@
+use std::{collections::{HashMap, VecDeque}, path::PathBuf, str::FromStr, time::Duration};
+
+use anyhow::Result;
+use yazi_boot::BOOT;
+use yazi_config::t;
+use yazi_proxy::{options::{NotifyLevel, NotifyOpt}, AppProxy};
+use yazi_shared::{emit, event::{Cmd, Data}, Layer};
+
+use crate::manager::Manager;
+
+// The macro recorded and replayed when no name is given
+const DEFAULT: &str = "default";
+
+// The commands that only move around, left out of a recording unless it's `--nav`
+const NAV: &[&str] = &[
+	"arrow", "leave", "enter", "back", "forward", "cd", "reveal", "find_arrow", "tab_switch", "seek",
+];
+
+#[derive(Default)]
+pub struct Recorder {
+	macros: HashMap<String, Vec<String>>,
+
+	recording: Option<Recording>,
+	// The commands coming next from a replay or a macro of yazi.toml, that were recorded as one
+	skip:      usize,
+}
+
+struct Recording {
+	name: String,
+	nav:  bool,
+	cmds: Vec<String>,
+}
+
+impl Recorder {
+	pub fn load() -> Self {
+		let macros = std::fs::read(Self::path())
+			.ok()
+			.and_then(|b| serde_json::from_slice(&b).ok())
+			.unwrap_or_default();
+
+		Self { macros, ..Default::default() }
+	}
+
+	// Starts recording the commands run from the keys into a macro, or stops and saves it
+	pub fn record(&mut self, mut c: Cmd) {
+		if let Some(r) = self.recording.take() {
+			return self.save(r);
+		}
+
+		let name = c.take_first_str().unwrap_or_else(|| DEFAULT.to_owned());
+		Self::info(format!("Recording `{name}`, run `macro_record` again to stop"));
+		self.recording = Some(Recording { name, nav: c.bool("nav"), cmds: vec![] });
+	}
+
+	pub fn push(&mut self, cmd: &Cmd) {
+		if self.skip > 0 {
+			self.skip -= 1;
+			return;
+		}
+
+		let Some(r) = &mut self.recording else { return };
+		if cmd.name.starts_with("macro_") || (!r.nav && NAV.contains(&cmd.name.as_str())) {
+			return;
+		}
+		r.cmds.push(Self::line(cmd));
+	}
+
+	#[inline]
+	pub fn skip(&mut self, n: usize) {
+		if self.recording.is_some() {
+			self.skip += n;
+		}
+	}
+
+	// Replays a macro once, or with `--each` on each of the selected files one after another,
+	// with only that file hovered and nothing selected.
+	pub fn play(&mut self, mut c: Cmd, manager: &Manager) {
+		let name = c.take_first_str().unwrap_or_else(|| DEFAULT.to_owned());
+		let Some(lines) = self.macros.get(&name) else {
+			return AppProxy::notify_warn(
+				"Macro",
+				&t!("No macro `{name}` has been recorded", name = name),
+			);
+		};
+
+		let cmds = match lines.iter().map(|s| Cmd::from_str(s)).collect::<Result<Vec<_>>>() {
+			Ok(cmds) => cmds,
+			Err(e) => return AppProxy::notify_error(&t!("Macro `{name}`", name = name), &e.to_string()),
+		};
+
+		let mut seq = VecDeque::new();
+		if c.bool("each") {
+			let targets: Vec<_> = manager.selected_or_hovered(true).cloned().collect();
+			seq.push_back(Cmd::new("escape").with_bool("visual", true).with_bool("select", true));
+			for url in targets {
+				let mut reveal = Cmd::new("reveal");
+				reveal.args.insert("0".to_owned(), Data::Url(url));
+				seq.push_back(reveal);
+				seq.extend(cmds.iter().map(Cmd::shallow_clone));
+			}
+		} else {
+			seq.extend(cmds);
+		}
+
+		self.skip(seq.len());
+		emit!(Seq(seq, Layer::Manager));
+	}
+
+	fn save(&mut self, r: Recording) {
+		if r.cmds.is_empty() {
+			let s = format!("Nothing recorded, `{}` is unchanged", r.name);
+			return AppProxy::notify_warn("Macro", &s);
+		}
+
+		let len = r.cmds.len();
+		self.macros.insert(r.name.clone(), r.cmds);
+		let result = serde_json::to_vec_pretty(&self.macros)
+			.map_err(Into::into)
+			.and_then(|b| std::fs::write(Self::path(), b));
+
+		match result {
+			Ok(()) => Self::info(format!("Saved `{}` of {len} commands", r.name)),
+			Err(e) => {
+				AppProxy::notify_warn("Macro", &t!("Failed to save `{name}`: {e}", name = r.name, e = e))
+			}
+		}
+	}
+
+	// The command as it'd be written in keymap.toml, with the positional arguments in order
+	fn line(cmd: &Cmd) -> String {
+		let mut args: Vec<_> = cmd.args.iter().collect();
+		args.sort_unstable_by_key(|&(k, _)| (k.parse::<usize>().unwrap_or(usize::MAX), k));
+
+		let mut words = vec![cmd.name.clone()];
+		for (k, v) in args {
+			match v {
+				Data::String(s) if k.parse::<usize>().is_ok() => words.push(s.clone()),
+				Data::String(s) => words.push(format!("--{k}={s}")),
+				Data::Boolean(true) => words.push(format!("--{k}")),
+				Data::Integer(i) => words.push(format!("--{k}={i}")),
+				Data::Number(n) => words.push(format!("--{k}={n}")),
+				_ => {}
+			}
+		}
+		shell_words::join(words)
+	}
+
+	#[inline]
+	fn info(s: String) {
+		AppProxy::notify(NotifyOpt {
+			title:   "Macro".to_owned(),
+			content: s,
+			level:   NotifyLevel::Info,
+			timeout: Duration::from_secs(3),
+			actions: vec![],
+		});
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("macros.json") }
+}
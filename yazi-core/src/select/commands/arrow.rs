@@ -9,10 +9,13 @@ pub struct Opt {
 impl From<Cmd> for Opt {
 	fn from(c: Cmd) -> Self { Self { step: c.first().and_then(Data::as_isize).unwrap_or(0) } }
 }
+impl From<isize> for Opt {
+	fn from(step: isize) -> Self { Self { step } }
+}
 
 impl Select {
 	fn next(&mut self, step: usize) {
-		let len = self.items.len();
+		let len = self.matches.len();
 		if len == 0 {
 			return;
 		}
@@ -41,6 +44,11 @@ impl Select {
 
 	pub fn arrow(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
+		let old = self.cursor;
 		if opt.step > 0 { self.next(opt.step as usize) } else { self.prev(opt.step.unsigned_abs()) }
+
+		if old != self.cursor {
+			self.request_preview();
+		}
 	}
 }
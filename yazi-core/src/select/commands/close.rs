@@ -15,12 +15,29 @@ impl From<bool> for Opt {
 }
 
 impl Select {
+	// The ones toggled in a multi-select, or the one under the cursor.
+	fn submitted(&self) -> anyhow::Result<Vec<usize>> {
+		if !self.selected.is_empty() {
+			return Ok(self.selected.iter().copied().collect());
+		}
+		match self.matches.get(self.cursor) {
+			Some(&i) => Ok(vec![i]),
+			None => Err(anyhow!("no match")),
+		}
+	}
+
 	pub fn close(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 		if let Some(cb) = self.callback.take() {
-			_ = cb.send(if opt.submit { Ok(self.cursor) } else { Err(anyhow!("canceled")) });
+			_ = cb.send(if opt.submit { self.submitted() } else { Err(anyhow!("canceled")) });
 		}
 
+		self.keyword.clear();
+		self.in_filter = None;
+		self.selected.clear();
+		self.previewer = None;
+		self.preview = None;
+
 		self.cursor = 0;
 		self.offset = 0;
 		self.visible = false;
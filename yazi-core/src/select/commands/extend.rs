@@ -0,0 +1,29 @@
+use yazi_shared::event::Cmd;
+
+use crate::select::Select;
+
+pub struct Opt {
+	items: Vec<String>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { items: c.take_any("items").ok_or(())? })
+	}
+}
+
+impl Select {
+	pub fn extend(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+		if !self.visible || opt.items.is_empty() {
+			return;
+		}
+
+		self.items.extend(opt.items);
+		self.rematch(true);
+	}
+}
@@ -0,0 +1,15 @@
+use yazi_config::popup::{Offset, Origin, Position};
+use yazi_shared::{event::Cmd, render};
+
+use crate::{input::Input, select::Select};
+
+impl Select {
+	pub fn filter(&mut self, _: Cmd) {
+		let mut input = Input::default();
+		input.position = Position::new(Origin::TopLeft, Offset::line());
+		input.type_str(&self.keyword);
+
+		self.in_filter = Some(input);
+		render!();
+	}
+}
@@ -1,3 +1,7 @@
 mod arrow;
 mod close;
+mod extend;
+mod filter;
+mod preview;
 mod show;
+mod toggle;
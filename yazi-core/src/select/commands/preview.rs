@@ -0,0 +1,33 @@
+use yazi_shared::{event::{Cmd, Data}, render};
+
+use crate::select::Select;
+
+pub struct Opt {
+	idx:  usize,
+	text: String,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			idx:  c.get("idx").and_then(Data::as_usize).ok_or(())?,
+			text: c.take_str("text").unwrap_or_default(),
+		})
+	}
+}
+
+impl Select {
+	pub fn preview(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		// The preview may come back after the cursor has moved on
+		if self.visible && self.matches.get(self.cursor) == Some(&opt.idx) {
+			self.preview = Some(opt.text);
+			render!();
+		}
+	}
+}
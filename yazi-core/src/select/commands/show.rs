@@ -1,19 +1,24 @@
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot};
 use yazi_config::popup::SelectCfg;
 use yazi_shared::{event::Cmd, render};
 
 use crate::select::Select;
 
 pub struct Opt {
-	cfg: SelectCfg,
-	tx:  oneshot::Sender<anyhow::Result<usize>>,
+	cfg:     SelectCfg,
+	tx:      oneshot::Sender<anyhow::Result<Vec<usize>>>,
+	preview: Option<mpsc::UnboundedSender<usize>>,
 }
 
 impl TryFrom<Cmd> for Opt {
 	type Error = ();
 
 	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
-		Ok(Self { cfg: c.take_any("cfg").ok_or(())?, tx: c.take_any("tx").ok_or(())? })
+		Ok(Self {
+			cfg:     c.take_any("cfg").ok_or(())?,
+			tx:      c.take_any("tx").ok_or(())?,
+			preview: c.take_any("preview"),
+		})
 	}
 }
 
@@ -26,10 +31,19 @@ impl Select {
 		self.close(false);
 		self.title = opt.cfg.title;
 		self.items = opt.cfg.items;
+		self.keys = opt.cfg.keys;
 		self.position = opt.cfg.position;
 
+		self.matches = (0..self.items.len()).collect();
+		self.multi = opt.cfg.multi;
+		self.previewer = opt.preview;
+		self.request_preview();
+
 		self.callback = Some(opt.tx);
 		self.visible = true;
+		if opt.cfg.filter {
+			self.filter(Cmd::default());
+		}
 		render!();
 	}
 }
@@ -0,0 +1,26 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::select::Select;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Select {
+	pub fn toggle(&mut self, _: impl Into<Opt>) {
+		let Some(&i) = self.matches.get(self.cursor).filter(|_| self.multi) else {
+			return;
+		};
+
+		if !self.selected.remove(&i) {
+			self.selected.insert(i);
+		}
+		self.arrow(1);
+		render!();
+	}
+}
@@ -1,37 +1,177 @@
+use std::{cmp::Reverse, collections::BTreeSet};
+
 use anyhow::Result;
-use tokio::sync::oneshot::Sender;
-use yazi_config::{popup::Position, SELECT};
+use crossterm::event::KeyCode;
+use tokio::sync::{mpsc::UnboundedSender, oneshot::Sender};
+use unicode_width::UnicodeWidthStr;
+use yazi_config::{keymap::Key, popup::Position, SELECT};
+use yazi_shared::{fuzzy_score, render};
+
+use crate::input::Input;
 
 #[derive(Default)]
 pub struct Select {
 	pub(super) title: String,
 	pub(super) items: Vec<String>,
+	pub(super) keys:  Vec<char>,
 	pub position:     Position,
 
+	// Filter
+	pub(super) keyword:   String,
+	pub(super) matches:   Vec<usize>,
+	pub(super) in_filter: Option<Input>,
+
+	// Multi-select
+	pub(super) multi:    bool,
+	pub(super) selected: BTreeSet<usize>,
+
+	// Preview
+	pub(super) previewer: Option<UnboundedSender<usize>>,
+	pub(super) preview:   Option<String>,
+
 	pub(super) offset:   usize,
 	pub(super) cursor:   usize,
-	pub(super) callback: Option<Sender<Result<usize>>>,
+	pub(super) callback: Option<Sender<Result<Vec<usize>>>>,
 
 	pub visible: bool,
 }
 
 impl Select {
-	#[inline]
-	pub fn window(&self) -> &[String] {
-		let end = (self.offset + self.limit()).min(self.items.len());
-		&self.items[self.offset..end]
+	pub fn type_(&mut self, key: &Key) -> bool {
+		if self.in_filter.is_none() {
+			return self.type_key(key);
+		}
+
+		let input = self.in_filter.as_mut().unwrap();
+		match key {
+			Key { code: KeyCode::Esc, shift: false, ctrl: false, alt: false, super_: false } => {
+				self.in_filter = None;
+				render!();
+				return true;
+			}
+			Key { code: KeyCode::Enter, shift: false, ctrl: false, alt: false, super_: false } => {
+				self.close(true);
+				return true;
+			}
+			Key { code: KeyCode::Up, shift: false, ctrl: false, alt: false, super_: false } => {
+				self.arrow(-1);
+				return true;
+			}
+			Key { code: KeyCode::Down, shift: false, ctrl: false, alt: false, super_: false } => {
+				self.arrow(1);
+				return true;
+			}
+			Key { code: KeyCode::Tab, shift: false, ctrl: false, alt: false, super_: false } => {
+				self.toggle(());
+				return true;
+			}
+			Key { code: KeyCode::Backspace, shift: false, ctrl: false, alt: false, super_: false } => {
+				input.backspace(false);
+			}
+			_ => {
+				input.type_(key);
+			}
+		}
+
+		self.filter_apply();
+		true
+	}
+
+	// Picks the choice bound to the key right away, for the ones shown with shortcuts.
+	fn type_key(&mut self, key: &Key) -> bool {
+		let Some(c) = key.plain() else { return false };
+		let Some(i) = self.keys.iter().position(|&k| k == c) else { return false };
+		let Some(cursor) = self.matches.iter().position(|&m| m == i) else { return false };
+
+		self.cursor = cursor;
+		self.close(true);
+		true
+	}
+
+	pub(super) fn filter_apply(&mut self) {
+		let kw = self.in_filter.as_ref().map_or("", |i| i.value());
+		if kw != self.keyword {
+			self.keyword = kw.to_owned();
+			self.rematch(false);
+		}
+	}
+
+	// Matches the items against the keyword again, optionally keeping the cursor on the same item,
+	// for the ones added while it's open.
+	pub(super) fn rematch(&mut self, keep: bool) {
+		let old = self.matches.get(self.cursor).copied();
+		let mut matches: Vec<_> = self
+			.items
+			.iter()
+			.enumerate()
+			.filter_map(|(i, s)| fuzzy_score(&self.keyword, s).map(|n| (i, n)))
+			.collect();
+
+		// Ties keep the order of the items given
+		matches.sort_by_key(|&(_, n)| Reverse(n));
+		self.matches = matches.into_iter().map(|(i, _)| i).collect();
+
+		let cursor = old.filter(|_| keep).and_then(|i| self.matches.iter().position(|&m| m == i));
+		self.cursor = cursor.unwrap_or(0);
+		let limit = self.limit().max(1);
+		self.offset = self.offset.min(self.cursor).max((self.cursor + 1).saturating_sub(limit));
+		if old != self.matches.get(self.cursor).copied() {
+			self.request_preview();
+		}
+		render!();
+	}
+
+	pub(super) fn request_preview(&self) {
+		if let (Some(tx), Some(&i)) = (&self.previewer, self.matches.get(self.cursor)) {
+			tx.send(i).ok();
+		}
 	}
 
 	#[inline]
 	pub(super) fn limit(&self) -> usize {
-		self.position.offset.height.saturating_sub(SELECT.border()) as usize
+		self.position.offset.height.saturating_sub(SELECT.load().border()) as usize
 	}
 }
 
 impl Select {
 	#[inline]
-	pub fn title(&self) -> String { self.title.clone() }
+	pub fn title(&self) -> String {
+		if self.keyword.is_empty() && self.in_filter.is_none() {
+			self.title.clone()
+		} else {
+			format!("{} {}", self.title, self.keyword)
+		}
+	}
+
+	// Original indices of the items in the window, in the order they're shown.
+	#[inline]
+	pub fn window(&self) -> &[usize] {
+		let end = (self.offset + self.limit()).min(self.matches.len());
+		&self.matches[self.offset..end]
+	}
+
+	#[inline]
+	pub fn item(&self, i: usize) -> &str { &self.items[i] }
+
+	#[inline]
+	pub fn key(&self, i: usize) -> Option<char> { self.keys.get(i).copied() }
+
+	#[inline]
+	pub fn is_selected(&self, i: usize) -> Option<bool> {
+		self.multi.then(|| self.selected.contains(&i))
+	}
+
+	#[inline]
+	pub fn previewed(&self) -> Option<&str> {
+		self.previewer.as_ref().map(|_| self.preview.as_deref().unwrap_or_default())
+	}
 
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+
+	#[inline]
+	pub fn cursor(&self) -> Option<u16> {
+		let input = self.in_filter.as_ref()?;
+		Some(self.title.width() as u16 + 1 + input.cursor())
+	}
 }
@@ -1,6 +1,6 @@
 use yazi_fs::Step;
 use yazi_proxy::ManagerProxy;
-use yazi_shared::{event::{Cmd, Data}, render};
+use yazi_shared::event::{damage_regions, Cmd, Data, Region};
 
 use crate::tab::Tab;
 
@@ -42,6 +42,6 @@ impl Tab {
 		}
 
 		ManagerProxy::hover(None);
-		render!();
+		damage_regions(&[Region::Header, Region::Current, Region::Status]);
 	}
 }
@@ -2,16 +2,18 @@ use std::{mem, time::Duration};
 
 use tokio::{fs, pin};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
-use yazi_config::popup::InputCfg;
+use yazi_config::{popup::{InputCfg, SelectCfg}, t};
 use yazi_dds::Pubsub;
-use yazi_proxy::{CompletionProxy, InputProxy, ManagerProxy, TabProxy};
-use yazi_shared::{event::{Cmd, Data}, fs::{expand_path, Url}, render, Debounce, InputError};
+use yazi_fs::Volume;
+use yazi_proxy::{AppProxy, InputProxy, ManagerProxy, SelectProxy, TabProxy};
+use yazi_shared::{event::{Cmd, Data}, fs::{expand_path, Url}, render, Debounce};
 
 use crate::tab::Tab;
 
 pub struct Opt {
 	target:      Url,
 	interactive: bool,
+	device:      bool,
 }
 
 impl From<Cmd> for Opt {
@@ -21,11 +23,11 @@ impl From<Cmd> for Opt {
 			target.set_path(expand_path(&target))
 		}
 
-		Self { target, interactive: c.bool("interactive") }
+		Self { target, interactive: c.bool("interactive"), device: c.bool("device") }
 	}
 }
 impl From<Url> for Opt {
-	fn from(target: Url) -> Self { Self { target, interactive: false } }
+	fn from(target: Url) -> Self { Self { target, interactive: false, device: false } }
 }
 
 impl Tab {
@@ -37,6 +39,8 @@ impl Tab {
 		let opt = opt.into() as Opt;
 		if opt.interactive {
 			return self.cd_interactive();
+		} else if opt.device {
+			return self.cd_device();
 		}
 
 		if self.current.cwd == opt.target {
@@ -91,12 +95,33 @@ impl Tab {
 							TabProxy::reveal(&u);
 						}
 					}
-					Err(InputError::Completed(before, ticket)) => {
-						CompletionProxy::trigger(&before, ticket);
-					}
 					_ => break,
 				}
 			}
 		});
 	}
+
+	// Picks one of the volumes, and mounts it first if it's not mounted yet
+	fn cd_device(&mut self) {
+		tokio::spawn(async move {
+			let volumes = match Volume::all().await {
+				Ok(v) if !v.is_empty() => v,
+				Ok(_) => return AppProxy::notify_warn("Devices", "No volumes found"),
+				Err(e) => return AppProxy::notify_warn("Devices", &t!("Failed to list: {e}", e = e)),
+			};
+
+			let items = volumes.iter().map(ToString::to_string).collect();
+			let Ok(i) = SelectProxy::show(SelectCfg::device(items)).await else { return };
+
+			match volumes[i].mount().await {
+				Ok(p) => TabProxy::cd(&Url::from(p)),
+				Err(e) => {
+					AppProxy::notify_warn(
+						"Mount",
+						&t!("Failed to mount {device}: {e}", device = volumes[i].device, e = e),
+					)
+				}
+			}
+		});
+	}
 }
@@ -1,16 +1,23 @@
-use std::ffi::{OsStr, OsString};
+use std::{borrow::Cow, ffi::{OsStr, OsString}};
 
+use percent_encoding::percent_encode;
 use yazi_plugin::CLIPBOARD;
-use yazi_shared::event::Cmd;
+use yazi_shared::{event::Cmd, fs::PATH_ENCODE_SET, shell};
 
 use crate::tab::Tab;
 
 pub struct Opt {
-	type_: String,
+	type_:  String,
+	format: String,
 }
 
 impl From<Cmd> for Opt {
-	fn from(mut c: Cmd) -> Self { Self { type_: c.take_first_str().unwrap_or_default() } }
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			type_:  c.take_first_str().unwrap_or_default(),
+			format: c.take_str("format").unwrap_or_else(|| "lines".to_owned()),
+		}
+	}
 }
 
 impl Tab {
@@ -20,21 +27,56 @@ impl Tab {
 			return;
 		}
 
-		let mut s = OsString::new();
-		let mut it = self.selected_or_hovered(true).peekable();
-		while let Some(u) = it.next() {
-			s.push(match opt.type_.as_str() {
+		let mut parts = vec![];
+		for u in self.selected_or_hovered(true) {
+			parts.push(match opt.type_.as_str() {
 				"path" => u.as_os_str(),
 				"dirname" => u.parent().map_or(OsStr::new(""), |p| p.as_os_str()),
 				"filename" => u.file_name().unwrap_or(OsStr::new("")),
 				"name_without_ext" => u.file_stem().unwrap_or(OsStr::new("")),
 				_ => return,
 			});
+		}
+
+		let s = match opt.format.as_str() {
+			"lines" => Self::copy_join(parts.into_iter().map(Into::into), "\n"),
+			"shell" => Self::copy_join(parts.into_iter().map(shell::escape_os_str), " "),
+			"uri" => Self::copy_join(parts.into_iter().map(|p| Self::copy_uri(p).into()), "\n"),
+			"windows" => {
+				let it = parts.into_iter().map(|p| p.to_string_lossy().replace('/', "\\"));
+				Self::copy_join(it.map(|s| OsString::from(s).into()), "\n")
+			}
+			"json" => {
+				let v: Vec<_> = parts.into_iter().map(OsStr::to_string_lossy).collect();
+				serde_json::to_string(&v).unwrap_or_default().into()
+			}
+			_ => return,
+		};
+
+		futures::executor::block_on(CLIPBOARD.set(s));
+	}
+
+	fn copy_join<'a>(parts: impl Iterator<Item = Cow<'a, OsStr>>, sep: &str) -> OsString {
+		let mut s = OsString::new();
+		let mut it = parts.peekable();
+		while let Some(p) = it.next() {
+			s.push(p);
 			if it.peek().is_some() {
-				s.push("\n");
+				s.push(sep);
 			}
 		}
+		s
+	}
 
-		futures::executor::block_on(CLIPBOARD.set(s));
+	// `file:///home/a%20b` on Unix, and `file:///C:/a%20b` on Windows
+	fn copy_uri(p: &OsStr) -> OsString {
+		let mut bytes = p.as_encoded_bytes().to_vec();
+		if cfg!(windows) {
+			bytes.iter_mut().filter(|b| **b == b'\\').for_each(|b| *b = b'/');
+		}
+		if bytes.first() != Some(&b'/') {
+			bytes.insert(0, b'/');
+		}
+		format!("file://{}", percent_encode(&bytes, PATH_ENCODE_SET)).into()
 	}
 }
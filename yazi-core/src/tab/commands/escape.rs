@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+use yazi_dds::Pubsub;
 use yazi_proxy::{AppProxy, ManagerProxy};
 use yazi_shared::{event::Cmd, render, render_and};
 
@@ -88,6 +89,8 @@ impl Tab {
 		if self.current.hovered().is_some_and(|h| h.is_dir()) {
 			ManagerProxy::peek(true);
 		}
+
+		Pubsub::pub_from_select(self.idx, self.selected.keys());
 		render_and!(true)
 	}
 
@@ -119,6 +122,8 @@ impl Tab {
 			return false;
 		}
 
+		self.measure_selected();
+		Pubsub::pub_from_select(self.idx, self.selected.keys());
 		true
 	}
 }
@@ -1,23 +1,31 @@
-use std::time::Duration;
+use std::{path::{Path, PathBuf}, time::Duration};
 
-use tokio::pin;
+use tokio::{fs, io::AsyncReadExt, pin, select, sync::mpsc};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
-use yazi_config::popup::InputCfg;
-use yazi_fs::FilterCase;
-use yazi_proxy::InputProxy;
+use yazi_config::{popup::{InputCfg, SelectCfg}, SELECT};
+use yazi_fs::{walk, FilterCase};
+use yazi_proxy::{InputProxy, SelectProxy, TabProxy};
 use yazi_shared::{emit, event::Cmd, render, Debounce, InputError, Layer};
 
 use crate::tab::{Finder, Tab};
 
+const WALK_LIMIT: usize = 200_000;
+
 pub struct Opt {
-	query: Option<String>,
-	prev:  bool,
-	case:  FilterCase,
+	query:     Option<String>,
+	prev:      bool,
+	case:      FilterCase,
+	recursive: bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
-		Self { query: c.take_first_str(), prev: c.bool("previous"), case: FilterCase::from(&c) }
+		Self {
+			query:     c.take_first_str(),
+			prev:      c.bool("previous"),
+			case:      FilterCase::from(&c),
+			recursive: c.bool("recursive"),
+		}
 	}
 }
 
@@ -32,6 +40,10 @@ impl From<Cmd> for ArrowOpt {
 impl Tab {
 	pub fn find(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
+		if opt.recursive {
+			return self.find_recursive();
+		}
+
 		tokio::spawn(async move {
 			let rx = InputProxy::show(InputCfg::find(opt.prev));
 
@@ -50,6 +62,64 @@ impl Tab {
 		});
 	}
 
+	// Walks the cwd in the background, while the paths found so far can be fuzzy-matched
+	// in the select overlay, and reveals the chosen one.
+	fn find_recursive(&self) {
+		let cwd = self.current.cwd.clone();
+		let mut walker = walk(cwd.to_path_buf(), self.conf.show_hidden, WALK_LIMIT);
+
+		tokio::spawn(async move {
+			let (tx, mut rx) = mpsc::unbounded_channel();
+			let picking = SelectProxy::pick(SelectCfg::find(), Some(tx));
+			pin!(picking);
+
+			let lines = SELECT.load().find_offset.height.saturating_sub(SELECT.load().border()) as usize;
+			let mut paths: Vec<PathBuf> = vec![];
+			let result = loop {
+				select! {
+					r = &mut picking => break r,
+					Some(batch) = walker.recv() => {
+						SelectProxy::extend(batch.iter().map(|p| p.to_string_lossy().into_owned()).collect());
+						paths.extend(batch);
+					}
+					Some(i) = rx.recv() => {
+						let Some(p) = paths.get(i) else { continue };
+						SelectProxy::preview_with(i, Self::peek_path(&cwd.join(p), lines).await);
+					}
+				}
+			};
+
+			if let Some(p) = result.ok().and_then(|v| paths.get(v[0])) {
+				TabProxy::reveal(&cwd.join(p));
+			}
+		});
+	}
+
+	// A plain text peek for the preview pane of the overlay, as the previewers are image-based.
+	async fn peek_path(path: &Path, lines: usize) -> String {
+		if let Ok(mut it) = fs::read_dir(path).await {
+			let mut names = vec![];
+			while let Ok(Some(ent)) = it.next_entry().await {
+				names.push(ent.file_name().to_string_lossy().into_owned());
+				if names.len() >= lines {
+					break;
+				}
+			}
+			names.sort_unstable();
+			return names.join("\n");
+		}
+
+		let mut buf = vec![0; 8192];
+		let n = match fs::File::open(path).await {
+			Ok(mut f) => f.read(&mut buf).await.unwrap_or(0),
+			Err(e) => return e.to_string(),
+		};
+		if buf[..n].contains(&0) {
+			return "(binary)".to_owned();
+		}
+		String::from_utf8_lossy(&buf[..n]).lines().take(lines).collect::<Vec<_>>().join("\n")
+	}
+
 	pub fn find_do(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 		let Some(query) = opt.query else {
@@ -5,10 +5,14 @@ use crate::tab::Tab;
 
 impl Tab {
 	pub fn hidden(&mut self, mut c: Cmd) {
-		self.conf.show_hidden = match c.take_first_str().as_deref() {
+		// `--ignored` toggles the files ignored by git instead of the dotfiles
+		let state =
+			if c.bool("ignored") { &mut self.conf.show_ignored } else { &mut self.conf.show_hidden };
+
+		*state = match c.take_first_str().as_deref() {
 			Some("show") => true,
 			Some("hide") => false,
-			_ => !self.conf.show_hidden,
+			_ => !*state,
 		};
 
 		let hovered = self.current.hovered().map(|f| f.url());
@@ -1,9 +1,9 @@
 use yazi_shared::{event::Cmd, render};
 
-use crate::tab::Tab;
+use crate::{tab::Tab, tasks::Tasks};
 
 impl Tab {
-	pub fn linemode(&mut self, mut c: Cmd) {
+	pub fn linemode(&mut self, mut c: Cmd, tasks: &Tasks) {
 		render!(self.conf.patch(|new| {
 			let Some(mode) = c.take_first_str() else {
 				return;
@@ -12,5 +12,7 @@ impl Tab {
 				new.linemode = mode;
 			}
 		}));
+
+		tasks.prework_sizes(self);
 	}
 }
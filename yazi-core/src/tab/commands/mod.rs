@@ -17,4 +17,5 @@ mod select;
 mod select_all;
 mod shell;
 mod sort;
+mod usage;
 mod visual_mode;
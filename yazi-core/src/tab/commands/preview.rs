@@ -1,7 +1,7 @@
 use yazi_plugin::utils::PreviewLock;
-use yazi_shared::{event::Cmd, render};
+use yazi_shared::{event::{damage_regions, Cmd, Region}, PERF};
 
-use crate::tab::Tab;
+use crate::tab::{Preview, Tab};
 
 pub struct Opt {
 	lock: PreviewLock,
@@ -17,19 +17,27 @@ impl TryFrom<Cmd> for Opt {
 
 impl Tab {
 	pub fn preview(&mut self, opt: impl TryInto<Opt>) {
-		let Some(hovered) = self.current.hovered().map(|h| &h.url) else {
-			return render!(self.preview.reset());
+		let Some(hovered) = self.current.hovered() else {
+			if self.preview.reset() {
+				damage_regions(&[Region::Preview]);
+			}
+			return;
 		};
 
 		let Ok(opt) = opt.try_into() else {
 			return;
 		};
 
-		if opt.lock.url != *hovered {
+		let lock = Preview::remote_lock(opt.lock, hovered);
+		if lock.url != hovered.url {
 			return;
 		}
 
-		self.preview.lock = Some(opt.lock);
-		render!();
+		if let Some(started) = self.preview.started.take() {
+			PERF.preview(started.elapsed());
+		}
+
+		self.preview.lock = Some(lock);
+		damage_regions(&[Region::Preview]);
 	}
 }
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use yazi_dds::Pubsub;
 use yazi_proxy::AppProxy;
 use yazi_shared::{event::{Cmd, Data}, fs::Url, render, render_and};
 
@@ -38,10 +39,13 @@ impl<'a> Tab {
 		};
 
 		if !b {
-			AppProxy::notify_warn(
+			return AppProxy::notify_warn(
 				"Select one",
 				"This file cannot be selected, due to path nesting conflict.",
 			);
 		}
+
+		self.measure_selected();
+		Pubsub::pub_from_select(self.idx, self.selected.keys());
 	}
 }
@@ -1,3 +1,4 @@
+use yazi_dds::Pubsub;
 use yazi_proxy::AppProxy;
 use yazi_shared::{event::Cmd, render};
 
@@ -32,12 +33,20 @@ impl Tab {
 		};
 
 		let same = !self.current.cwd.is_search();
-		render!(self.selected.remove_many(&removal, same) > 0);
+		let removed = self.selected.remove_many(&removal, same);
+		render!(removed > 0);
 
 		let added = self.selected.add_many(&addition, same);
 		render!(added > 0);
 
-		if added != addition.len() {
+		let partial = added != addition.len();
+		drop((removal, addition));
+		if removed > 0 || added > 0 {
+			self.measure_selected();
+			Pubsub::pub_from_select(self.idx, self.selected.keys());
+		}
+
+		if partial {
 			AppProxy::notify_warn(
 				"Select all",
 				"Some files cannot be selected, due to path nesting conflict.",
@@ -12,6 +12,7 @@ pub struct Opt {
 	orphan:      bool,
 	confirm:     bool,
 	interactive: bool,
+	each:        bool,
 }
 
 impl From<Cmd> for Opt {
@@ -22,6 +23,7 @@ impl From<Cmd> for Opt {
 			orphan:      c.bool("orphan"),
 			confirm:     c.bool("confirm"),
 			interactive: c.bool("interactive"),
+			each:        c.bool("each"),
 		}
 	}
 }
@@ -51,7 +53,11 @@ Please replace e.g. `shell` with `shell --interactive`, `shell "my-template"` wi
 			return;
 		}
 
-		let selected = self.hovered_and_selected(true).cloned().collect();
+		let selected: Vec<_> = if opt.each {
+			self.selected_or_hovered(true).cloned().collect()
+		} else {
+			self.hovered_and_selected(true).cloned().collect()
+		};
 
 		tokio::spawn(async move {
 			if !opt.confirm || opt.run.is_empty() {
@@ -62,12 +68,18 @@ Please replace e.g. `shell` with `shell --interactive`, `shell "my-template"` wi
 				}
 			}
 
+			// Once for each of the selected files, with `%f`, `%d` and `%b` in place of them
+			if opt.each {
+				return TasksProxy::shell_each(opt.run, selected);
+			}
+
 			TasksProxy::open_with(
 				selected,
 				Cow::Owned(Opener {
 					run:    opt.run,
 					block:  opt.block,
 					orphan: opt.orphan,
+					output: Default::default(),
 					desc:   Default::default(),
 					for_:   None,
 					spread: true,
@@ -21,6 +21,6 @@ impl Tab {
 		self.apply_files_attrs();
 		ManagerProxy::update_paged();
 
-		tasks.prework_sorted(&self.current.files);
+		tasks.prework_sizes(self);
 	}
 }
@@ -0,0 +1,29 @@
+use yazi_config::manager::SortBy;
+use yazi_proxy::ManagerProxy;
+use yazi_shared::{event::Cmd, render};
+
+use crate::{tab::Tab, tasks::Tasks};
+
+impl Tab {
+	// Lists the biggest entries first with their share of the cwd, like ncdu does,
+	// until toggled again, which brings back the sorting and linemode from before
+	pub fn usage(&mut self, _: Cmd, tasks: &Tasks) {
+		if let Some(conf) = self.usage.take() {
+			self.conf = conf;
+		} else {
+			self.usage = Some(self.conf.clone());
+			self.conf.patch(|new| {
+				new.sort_by = SortBy::Size;
+				new.sort_reverse = true;
+				new.sort_dir_first = false;
+				new.linemode = "usage".to_owned();
+			});
+		}
+
+		self.apply_files_attrs();
+		ManagerProxy::update_paged();
+
+		tasks.prework_sizes(self);
+		render!();
+	}
+}
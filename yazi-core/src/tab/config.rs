@@ -11,29 +11,31 @@ pub struct Config {
 	pub sort_translit:  bool,
 
 	// Display
-	pub linemode:    String,
-	pub show_hidden: bool,
+	pub linemode:     String,
+	pub show_hidden:  bool,
+	pub show_ignored: bool,
 }
 
 impl Default for Config {
 	fn default() -> Self {
 		Self {
 			// Sorting
-			sort_by:        MANAGER.sort_by,
-			sort_sensitive: MANAGER.sort_sensitive,
-			sort_reverse:   MANAGER.sort_reverse,
-			sort_dir_first: MANAGER.sort_dir_first,
-			sort_translit:  MANAGER.sort_translit,
+			sort_by:        MANAGER.load().sort_by,
+			sort_sensitive: MANAGER.load().sort_sensitive,
+			sort_reverse:   MANAGER.load().sort_reverse,
+			sort_dir_first: MANAGER.load().sort_dir_first,
+			sort_translit:  MANAGER.load().sort_translit,
 
 			// Display
-			linemode:    MANAGER.linemode.to_owned(),
-			show_hidden: MANAGER.show_hidden,
+			linemode:     MANAGER.load().linemode.to_owned(),
+			show_hidden:  MANAGER.load().show_hidden,
+			show_ignored: MANAGER.load().show_ignored,
 		}
 	}
 }
 
 impl Config {
-	pub(super) fn patch<F: FnOnce(&mut Self)>(&mut self, f: F) -> bool {
+	pub(crate) fn patch<F: FnOnce(&mut Self)>(&mut self, f: F) -> bool {
 		let old = self.clone();
 		f(self);
 		*self != old
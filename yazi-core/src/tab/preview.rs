@@ -1,12 +1,13 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use tokio::{pin, task::JoinHandle};
+use tokio::{pin, process::Command, task::JoinHandle};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use tokio_util::sync::CancellationToken;
 use yazi_adapter::ADAPTOR;
 use yazi_config::PLUGIN;
-use yazi_fs::Files;
+use yazi_fs::{vfs, Files};
 use yazi_plugin::{external::Highlighter, isolate, utils::PreviewLock};
+use yazi_proxy::ManagerProxy;
 use yazi_shared::{fs::{Cha, File, FilesOp, Url}, MIME_DIR};
 
 #[derive(Default)]
@@ -16,6 +17,10 @@ pub struct Preview {
 
 	previewer_ct:  Option<CancellationToken>,
 	folder_loader: Option<JoinHandle<()>>,
+	remote_loader: Option<JoinHandle<()>>,
+
+	// When the current previewer started, for the latency with `--debug-perf`
+	pub(super) started: Option<Instant>,
 }
 
 impl Preview {
@@ -24,12 +29,14 @@ impl Preview {
 			return;
 		}
 
-		let Some(previewer) = PLUGIN.previewer(&file.url, mime) else {
+		let plugin = PLUGIN.load();
+		let Some(previewer) = plugin.previewer(&file.url, mime) else {
 			self.reset();
 			return;
 		};
 
 		self.abort();
+		self.started = Some(Instant::now());
 		if previewer.sync {
 			isolate::peek_sync(&previewer.run, file, self.skip);
 		} else {
@@ -64,6 +71,36 @@ impl Preview {
 		}));
 	}
 
+	// Remote files are downloaded to the cache first and previewed from there, then the mimetype
+	// is detected on the local copy, which comes back here through `update_mimetype`.
+	pub fn go_remote(&mut self, file: File, mime: Option<&str>, force: bool) {
+		if !force && self.content_unchanged(&file.url, file.cha) {
+			return;
+		}
+
+		let cache = vfs::cache_path(&file.url, file.cha);
+		if let Some(mime) = mime.filter(|_| cache.exists()) {
+			return self.go(File { url: Url::from(cache), ..file }, mime, true);
+		}
+
+		self.remote_loader.take().map(|h| h.abort());
+		self.remote_loader = Some(tokio::spawn(async move {
+			let Ok(path) = vfs::download(&file.url, file.cha).await else { return };
+
+			let cmd = std::env::var_os("YAZI_FILE_ONE").unwrap_or_else(|| "file".into());
+			let Ok(output) =
+				Command::new(cmd).args(["-bL", "--mime-type"]).arg(path).kill_on_drop(true).output().await
+			else {
+				return;
+			};
+
+			let mime = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+			if output.status.success() && mime.contains('/') {
+				ManagerProxy::update_mimetype(&file.url, mime);
+			}
+		}));
+	}
+
 	#[inline]
 	pub fn abort(&mut self) {
 		self.previewer_ct.take().map(|ct| ct.cancel());
@@ -83,6 +120,15 @@ impl Preview {
 		ADAPTOR.image_hide().ok();
 	}
 
+	// Maps the lock of a remote file previewed from the cache back to the file itself
+	#[inline]
+	pub fn remote_lock(mut lock: PreviewLock, hovered: &File) -> PreviewLock {
+		if hovered.url.is_remote() && *lock.url == vfs::cache_path(&hovered.url, hovered.cha) {
+			lock.url = hovered.url.clone();
+		}
+		lock
+	}
+
 	#[inline]
 	pub fn same_url(&self, url: &Url) -> bool {
 		matches!(self.lock, Some(ref lock) if lock.url == *url)
@@ -6,6 +6,18 @@ use yazi_shared::{fs::{FilesOp, Url}, timestamp_us};
 pub struct Selected {
 	inner:   HashMap<Url, u64>,
 	parents: HashMap<Url, usize>,
+
+	// Whether each selected file is a directory and its size, `None` while it's calculated
+	sizes: HashMap<Url, Option<(bool, u64)>>,
+}
+
+#[derive(Default)]
+pub struct SelectedSummary {
+	pub count:   usize,
+	pub dirs:    usize,
+	pub size:    u64,
+	// The ones whose sizes aren't known yet, so the size is only a lower bound
+	pub pending: usize,
 }
 
 impl Deref for Selected {
@@ -79,6 +91,7 @@ impl Selected {
 
 	fn remove_same(&mut self, urls: &[impl AsRef<Url>]) -> usize {
 		let count = urls.iter().filter_map(|u| self.inner.remove(u.as_ref())).count();
+		urls.iter().for_each(|u| _ = self.sizes.remove(u.as_ref()));
 		if count == 0 {
 			return 0;
 		}
@@ -100,6 +113,45 @@ impl Selected {
 	pub fn clear(&mut self) {
 		self.inner.clear();
 		self.parents.clear();
+		self.sizes.clear();
+	}
+
+	pub fn summary(&self) -> SelectedSummary {
+		let mut summary = SelectedSummary { count: self.inner.len(), ..Default::default() };
+		for url in self.inner.keys() {
+			match self.sizes.get(url) {
+				Some(&Some((dir, n))) => {
+					summary.dirs += dir as usize;
+					summary.size += n;
+				}
+				_ => summary.pending += 1,
+			}
+		}
+		summary
+	}
+
+	// The selected files without a size yet, which are marked as being calculated
+	pub fn unmeasured(&mut self) -> Vec<Url> {
+		let urls: Vec<_> =
+			self.inner.keys().filter(|&u| !self.sizes.contains_key(u)).cloned().collect();
+		self.sizes.extend(urls.iter().map(|u| (u.clone(), None)));
+		urls
+	}
+
+	pub fn update_sizes(&mut self, sizes: HashMap<Url, (bool, u64)>) -> bool {
+		let mut b = false;
+		for (url, size) in sizes {
+			if let Some(s) = self.sizes.get_mut(&url) {
+				b |= *s != Some(size);
+				*s = Some(size);
+			}
+		}
+		b
+	}
+
+	// The selected directories containing a changed one have their sizes changed as well
+	pub fn forget_sizes(&mut self, url: &Url) {
+		self.sizes.retain(|u, _| !url.starts_with(u));
 	}
 
 	pub fn apply_op(&mut self, op: &FilesOp) {
@@ -162,6 +214,23 @@ mod tests {
 		assert!(s.parents.is_empty());
 	}
 
+	#[test]
+	fn test_summary() {
+		let mut s = Selected::default();
+
+		s.add_many(&[&Url::from("/a/b"), &Url::from("/a/c")], true);
+		assert_eq!(s.unmeasured().len(), 2);
+		assert!(s.unmeasured().is_empty());
+
+		assert!(s.update_sizes(HashMap::from([(Url::from("/a/b"), (true, 10))])));
+		let summary = s.summary();
+		assert_eq!((summary.count, summary.dirs, summary.size, summary.pending), (2, 1, 10, 1));
+
+		s.forget_sizes(&Url::from("/a/b/d"));
+		assert_eq!(s.summary().pending, 2);
+		assert_eq!(s.unmeasured(), vec![Url::from("/a/b")]);
+	}
+
 	#[test]
 	fn insert_many_success() {
 		let mut s = Selected::default();
@@ -1,8 +1,10 @@
-use std::{collections::HashMap, iter};
+use std::{collections::{HashMap, HashSet}, iter, sync::Arc};
 
 use anyhow::Result;
-use tokio::task::JoinHandle;
-use yazi_fs::{Folder, FolderStage};
+use tokio::{fs, task::JoinHandle};
+use yazi_config::{open::Open, Local, OPEN, WATCHER};
+use yazi_fs::{Folder, FolderStage, DIR_SIZES, GITIGNORES};
+use yazi_proxy::ManagerProxy;
 use yazi_shared::{fs::Url, render};
 
 use super::{Backstack, Config, Finder, Mode, Preview};
@@ -20,8 +22,13 @@ pub struct Tab {
 	pub history:   HashMap<Url, Folder>,
 	pub selected:  Selected,
 
+	// The config to go back to, once leaving the disk usage mode
+	pub usage: Option<Config>,
+
 	pub preview:       Preview,
 	pub finder:        Option<Finder>,
+	// The local config applied, and the config to go back to once leaving its subtree
+	pub local:         Option<(Arc<Local>, Config)>,
 	pub(super) search: Option<JoinHandle<Result<()>>>,
 }
 
@@ -34,6 +41,11 @@ impl Tab {
 }
 
 impl Tab {
+	#[inline]
+	pub fn open(&self) -> Arc<Open> {
+		self.local.as_ref().and_then(|(l, _)| l.open.clone()).unwrap_or_else(|| OPEN.load_full())
+	}
+
 	// --- Current
 	pub fn selected_or_hovered(&self, reorder: bool) -> Box<dyn Iterator<Item = &Url> + '_> {
 		if self.selected.is_empty() {
@@ -61,6 +73,49 @@ impl Tab {
 		}
 	}
 
+	// Calculates the sizes of the newly selected files in the background, for the summary of them,
+	// the ones in the CWD with the sizes known already are taken from it instead.
+	pub fn measure_selected(&mut self) {
+		let urls = self.selected.unmeasured();
+		if urls.is_empty() {
+			return;
+		}
+
+		let files = &self.current.files;
+		let mut known = HashMap::new();
+		let urls: HashSet<_> = urls.into_iter().collect();
+		for f in files.iter().filter(|f| urls.contains(&f.url)) {
+			if !f.is_dir() {
+				known.insert(f.url.clone(), (false, f.len));
+			} else if let Some(&n) = files.sizes.get(&f.url) {
+				known.insert(f.url.clone(), (true, n));
+			}
+		}
+
+		let rest: Vec<_> = urls.into_iter().filter(|u| !known.contains_key(u)).collect();
+		self.selected.update_sizes(known);
+		if rest.is_empty() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let (mut sizes, mut dirs) = (HashMap::new(), vec![]);
+			for url in rest {
+				match fs::symlink_metadata(&url).await {
+					Ok(m) if m.is_dir() => dirs.push(url),
+					Ok(m) => _ = sizes.insert(url, (false, m.len())),
+					Err(_) => _ = sizes.insert(url, (false, 0)),
+				}
+			}
+			ManagerProxy::update_selected(sizes);
+
+			for url in dirs {
+				let n = DIR_SIZES.calculate(&url, |p| WATCHER.load().excluded(p)).await;
+				ManagerProxy::update_selected(HashMap::from([(url, (true, n))]));
+			}
+		});
+	}
+
 	// --- History
 	#[inline]
 	pub fn history_new(&mut self, url: &Url) -> Folder {
@@ -80,7 +135,13 @@ impl Tab {
 
 			let hovered = f.hovered().filter(|_| f.tracing).map(|h| h.url());
 			f.files.set_show_hidden(self.conf.show_hidden);
-			f.files.set_sorter(self.conf.sorter());
+			f.files.set_ignored(if self.conf.show_ignored || !f.cwd.is_regular() {
+				None
+			} else {
+				GITIGNORES.lock().get(&f.cwd)
+			});
+			// The recent ones are kept in the order they were used
+			f.files.set_sorter(if f.cwd.is_recent() { Default::default() } else { self.conf.sorter() });
 
 			render!(f.files.catchup_revision());
 			render!(f.repos(hovered));
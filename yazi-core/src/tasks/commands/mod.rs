@@ -2,5 +2,8 @@ mod arrow;
 mod cancel;
 mod inspect;
 mod open_with;
+mod plugin_task;
 mod process_exec;
+mod retry;
+mod shell_each;
 mod toggle;
@@ -0,0 +1,11 @@
+use yazi_proxy::options::PluginTaskOpt;
+
+use crate::tasks::Tasks;
+
+impl Tasks {
+	pub fn plugin_task(&mut self, opt: impl TryInto<PluginTaskOpt>) {
+		if let Ok(opt) = opt.try_into() {
+			self.scheduler.plugin_task(opt);
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use yazi_shared::{event::{Cmd, Data}, render};
+
+use crate::tasks::Tasks;
+
+pub struct Opt {
+	id: Option<usize>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { id: c.first().and_then(Data::as_usize) } }
+}
+
+impl Tasks {
+	// Retries the failed task with the given id, e.g. from the actions of its notification,
+	// or the one under the cursor
+	pub fn retry(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let id = opt.id.or_else(|| self.ongoing().lock().get_id(self.cursor));
+		if id.map(|id| self.scheduler.retry(id)) != Some(true) {
+			return;
+		}
+
+		self.summaries = self.paginate();
+		self.arrow(0);
+		render!();
+	}
+}
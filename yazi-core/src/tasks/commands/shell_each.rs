@@ -0,0 +1,11 @@
+use yazi_proxy::options::ShellEachOpt;
+
+use crate::tasks::Tasks;
+
+impl Tasks {
+	pub fn shell_each(&mut self, opt: impl TryInto<ShellEachOpt>) {
+		if let Ok(opt) = opt.try_into() {
+			self.scheduler.process_each(opt.run, opt.targets);
+		}
+	}
+}
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use tracing::debug;
+use yazi_scheduler::{ImageOp, TouchTime};
 use yazi_shared::fs::Url;
 
 use super::Tasks;
@@ -39,6 +40,10 @@ impl Tasks {
 		}
 	}
 
+	pub fn file_link_many(&self, targets: Vec<Url>, dest: &Url, relative: bool, force: bool) {
+		self.scheduler.file_link_many(targets, dest.clone(), relative, force);
+	}
+
 	pub fn file_hardlink(&self, src: &HashSet<Url>, dest: &Url, force: bool, follow: bool) {
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
@@ -50,6 +55,12 @@ impl Tasks {
 		}
 	}
 
+	pub fn file_crypt(&self, targets: Vec<(Url, Url)>, passphrase: &str, encrypt: bool, gpg: bool) {
+		for (from, to) in targets {
+			self.scheduler.file_crypt(from, to, passphrase.to_owned(), encrypt, gpg);
+		}
+	}
+
 	pub fn file_remove(&self, targets: Vec<Url>, permanently: bool) {
 		for u in targets {
 			if permanently {
@@ -59,4 +70,12 @@ impl Tasks {
 			}
 		}
 	}
+
+	pub fn file_image(&self, from: Url, op: ImageOp, quality: u8) {
+		self.scheduler.file_image(from, op, quality);
+	}
+
+	pub fn file_touch(&self, targets: Vec<Url>, time: TouchTime, atime: bool, mtime: bool) {
+		self.scheduler.file_touch(targets, time, atime, mtime);
+	}
 }
@@ -1,15 +1,17 @@
-use yazi_shared::event::Data;
+use std::collections::HashMap;
+
+use yazi_shared::event::{Data, DataKey};
 
 use super::Tasks;
 
 impl Tasks {
 	#[inline]
-	pub fn plugin_micro(&self, name: String, args: Vec<Data>) {
+	pub fn plugin_micro(&self, name: String, args: HashMap<DataKey, Data>) {
 		self.scheduler.plugin_micro(name, args);
 	}
 
 	#[inline]
-	pub fn plugin_macro(&self, name: String, args: Vec<Data>) {
+	pub fn plugin_macro(&self, name: String, args: HashMap<DataKey, Data>) {
 		self.scheduler.plugin_macro(name, args);
 	}
 }
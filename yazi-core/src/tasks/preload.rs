@@ -1,56 +1,92 @@
 use std::collections::HashMap;
 
-use yazi_config::{manager::SortBy, plugin::MAX_PREWORKERS, PLUGIN};
-use yazi_fs::Files;
+use tokio::sync::oneshot;
+use yazi_config::{manager::SortBy, plugin::MAX_PREWORKERS, MANAGER, PLUGIN};
 use yazi_shared::{fs::{File, Url}, MIME_DIR};
 
 use super::Tasks;
+use crate::{manager::{FetchCache, Kind, FETCH_CACHE}, tab::Tab};
 
 impl Tasks {
-	pub fn fetch_paged(&self, paged: &[File], mimetype: &HashMap<Url, String>) {
+	// The `cwd` is the folder the files are loaded for, the work on them
+	// is cancelled once the user leaves it, see `prework_cancel_except()`.
+	pub fn fetch_paged(
+		&self,
+		cwd: &Url,
+		paged: &[File],
+		mimetype: &HashMap<Url, String>,
+		visible: bool,
+	) {
 		let mut loaded = self.scheduler.prework.loaded.lock();
 		let mut tasks: [Vec<_>; MAX_PREWORKERS as usize] = Default::default();
-		for f in paged {
+		let mut cached: HashMap<Kind, HashMap<_, _>> = HashMap::new();
+		// The fetchers and preloaders work on local files, the remote ones are handled by the VFS
+		for f in paged.iter().filter(|f| !f.url.is_remote()) {
 			let mime = if f.is_dir() { Some(MIME_DIR) } else { mimetype.get(&f.url).map(|s| &**s) };
+			let hit = if mime.is_none() { FETCH_CACHE.get(Kind::Mime, f) } else { None };
+			if let Some(m) = &hit {
+				cached.entry(Kind::Mime).or_default().insert(f.url.to_string(), m.clone());
+			}
+
+			let mime = mime.or(hit.as_deref());
 			let factors = |s: &str| match s {
 				"mime" => mime.is_some(),
 				_ => false,
 			};
 
-			for p in PLUGIN.fetchers(&f.url, mime, factors) {
+			for p in PLUGIN.load().fetchers(&f.url, mime, factors) {
 				match loaded.get_mut(&f.url) {
 					Some(n) if *n & (1 << p.idx) != 0 => continue,
 					Some(n) => *n |= 1 << p.idx,
 					None => _ = loaded.insert(f.url.clone(), 1 << p.idx),
 				}
+				if let Some(kind) = Kind::from_id(&p.id) {
+					if let Some(v) = FETCH_CACHE.get(kind, f) {
+						cached.entry(kind).or_default().insert(f.url.to_string(), v);
+						continue;
+					}
+					FETCH_CACHE.pend(kind, f);
+				}
+				if mime.is_none() {
+					FETCH_CACHE.pend(Kind::Mime, f);
+				}
 				tasks[p.idx as usize].push(f.clone());
 			}
 		}
 
 		drop(loaded);
+		for (kind, updates) in cached {
+			FetchCache::replay(kind, updates);
+		}
 		for (i, tasks) in tasks.into_iter().enumerate() {
 			if !tasks.is_empty() {
-				self.scheduler.fetch_paged(&PLUGIN.fetchers[i], tasks);
+				self.scheduler.fetch_paged(&PLUGIN.load().fetchers[i], tasks, cwd, visible);
 			}
 		}
 	}
 
-	pub fn preload_paged(&self, paged: &[File], mimetype: &HashMap<Url, String>) {
+	pub fn preload_paged(
+		&self,
+		cwd: &Url,
+		paged: &[File],
+		mimetype: &HashMap<Url, String>,
+		visible: bool,
+	) {
 		let mut loaded = self.scheduler.prework.loaded.lock();
-		for f in paged {
+		for f in paged.iter().filter(|f| !f.url.is_remote()) {
 			let mime = if f.is_dir() { Some(MIME_DIR) } else { mimetype.get(&f.url).map(|s| &**s) };
-			for p in PLUGIN.preloaders(&f.url, mime) {
+			for p in PLUGIN.load().preloaders(&f.url, mime) {
 				match loaded.get_mut(&f.url) {
 					Some(n) if *n & (1 << p.idx) != 0 => continue,
 					Some(n) => *n |= 1 << p.idx,
 					None => _ = loaded.insert(f.url.clone(), 1 << p.idx),
 				}
-				self.scheduler.preload_paged(p, f);
+				self.scheduler.preload_paged(p, f, cwd, visible);
 			}
 		}
 	}
 
-	pub fn prework_affected(&self, affected: &[File], mimetype: &HashMap<Url, String>) {
+	pub fn prework_affected(&self, cwd: &Url, affected: &[File], mimetype: &HashMap<Url, String>) {
 		{
 			let mut loaded = self.scheduler.prework.loaded.lock();
 			for f in affected {
@@ -58,12 +94,24 @@ impl Tasks {
 			}
 		}
 
-		self.fetch_paged(affected, mimetype);
-		self.preload_paged(affected, mimetype);
+		self.fetch_paged(cwd, affected, mimetype, true);
+		self.preload_paged(cwd, affected, mimetype, true);
+	}
+
+	#[inline]
+	pub fn prework_cancel_except(&self, cwd: &Url) { self.scheduler.prework.cancel_except(cwd); }
+
+	#[inline]
+	pub fn size_loading(&self, url: &Url) -> bool {
+		self.scheduler.prework.size_loading.read().contains(url)
 	}
 
-	pub fn prework_sorted(&self, targets: &Files) {
-		if targets.sorter().by != SortBy::Size {
+	// The sizes are needed for sorting by size, and for the `size` linemode with `calculate_sizes`
+	pub fn prework_sizes(&self, tab: &Tab) {
+		let targets = &tab.current.files;
+		if targets.sorter().by != SortBy::Size
+			&& !(MANAGER.load().calculate_sizes && tab.conf.linemode == "size")
+		{
 			return;
 		}
 
@@ -84,6 +132,18 @@ impl Tasks {
 			loading.insert(target.clone());
 		}
 
-		self.scheduler.prework_size(targets);
+		self.scheduler.prework_size(&tab.current.cwd, targets);
+	}
+
+	// Ignores the pastes into `dest` while the files of an earlier one are still being measured
+	pub fn prework_measure(
+		&self,
+		targets: Vec<Url>,
+		dest: &Url,
+	) -> Option<oneshot::Receiver<(u64, Option<u64>)>> {
+		if !self.scheduler.prework.measuring.lock().insert(dest.clone()) {
+			return None;
+		}
+		Some(self.scheduler.prework_measure(targets, dest.clone()))
 	}
 }
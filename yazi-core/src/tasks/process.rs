@@ -1,21 +1,26 @@
 use std::{borrow::Cow, collections::HashMap, ffi::OsString, mem};
 
-use yazi_config::{open::Opener, OPEN};
+use yazi_config::open::{Open, Opener};
 use yazi_shared::fs::Url;
 
 use super::Tasks;
 
 impl Tasks {
-	pub fn process_from_files(&self, hovered: Url, targets: Vec<(Url, String)>) {
+	pub fn process_from_files(
+		&self,
+		open: &Open,
+		hovered: Url,
+		targets: Vec<(Url, String)>,
+	) {
 		let mut openers = HashMap::new();
 		for (url, mime) in targets {
-			if let Some(opener) = OPEN.openers(&url, mime).and_then(|o| o.first().copied()) {
+			if let Some(opener) = open.openers(&url, mime).and_then(|o| o.first().copied()) {
 				openers.entry(opener).or_insert_with(|| vec![hovered.clone()]).push(url);
 			}
 		}
 		for (opener, args) in openers {
 			self.process_from_opener(
-				Cow::Borrowed(opener),
+				Cow::Owned(opener.clone()),
 				args.into_iter().map(|u| u.into_os_string()).collect(),
 			);
 		}
@@ -38,4 +43,10 @@ impl Tasks {
 			self.scheduler.process_open(opener.clone(), vec![hovered.clone(), target], None);
 		}
 	}
+
+	// Runs it once with all of the args, for the ones like `compare` that take them as `$1`, `$2`
+	#[inline]
+	pub fn process_once(&self, opener: Cow<'static, Opener>, args: Vec<OsString>) {
+		self.scheduler.process_open(opener, args, None);
+	}
 }
@@ -3,6 +3,7 @@ use std::{sync::Arc, time::Duration};
 use parking_lot::Mutex;
 use tokio::{task::JoinHandle, time::sleep};
 use yazi_adapter::Dimension;
+use yazi_dds::Pubsub;
 use yazi_scheduler::{Ongoing, Scheduler, TaskSummary};
 use yazi_shared::{emit, event::Cmd, Layer};
 
@@ -31,6 +32,7 @@ impl Tasks {
 				let new = TasksProgress::from(&*ongoing.lock());
 				if last != new {
 					last = new;
+					Pubsub::pub_from_progress(new);
 					emit!(Call(Cmd::new("update_progress").with_any("progress", new), Layer::App));
 				}
 			}
@@ -0,0 +1,47 @@
+use crossterm::event::KeyCode;
+use yazi_config::keymap::Key;
+
+// Encodes the key as what a terminal would send for it, as far as xterm is concerned
+pub(super) fn encode(key: &Key, app_cursor: bool) -> Option<Vec<u8>> {
+	let cursor = |c: u8| if app_cursor { vec![0x1b, b'O', c] } else { vec![0x1b, b'[', c] };
+
+	let mut b = match key.code {
+		KeyCode::Char(c) if key.ctrl => vec![match c.to_ascii_lowercase() {
+			c @ 'a'..='z' => c as u8 - b'a' + 1,
+			'@' | ' ' => 0,
+			'[' => 0x1b,
+			'\\' => 0x1c,
+			']' => 0x1d,
+			'^' => 0x1e,
+			'_' => 0x1f,
+			_ => return None,
+		}],
+		KeyCode::Char(c) => c.to_string().into_bytes(),
+		KeyCode::Enter => vec![b'\r'],
+		KeyCode::Tab => vec![b'\t'],
+		KeyCode::BackTab => b"\x1b[Z".to_vec(),
+		KeyCode::Backspace => vec![0x7f],
+		KeyCode::Esc => vec![0x1b],
+		KeyCode::Up => cursor(b'A'),
+		KeyCode::Down => cursor(b'B'),
+		KeyCode::Right => cursor(b'C'),
+		KeyCode::Left => cursor(b'D'),
+		KeyCode::Home => cursor(b'H'),
+		KeyCode::End => cursor(b'F'),
+		KeyCode::Insert => b"\x1b[2~".to_vec(),
+		KeyCode::Delete => b"\x1b[3~".to_vec(),
+		KeyCode::PageUp => b"\x1b[5~".to_vec(),
+		KeyCode::PageDown => b"\x1b[6~".to_vec(),
+		KeyCode::F(n @ 1..=4) => vec![0x1b, b'O', b"PQRS"[n as usize - 1]],
+		KeyCode::F(n @ 5..=12) => {
+			let code = [15, 17, 18, 19, 20, 21, 23, 24][n as usize - 5];
+			format!("\x1b[{code}~").into_bytes()
+		}
+		_ => return None,
+	};
+
+	if key.alt {
+		b.insert(0, 0x1b);
+	}
+	Some(b)
+}
@@ -0,0 +1,4 @@
+mod keys;
+mod terminal;
+
+pub use terminal::*;
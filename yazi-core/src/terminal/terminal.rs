@@ -0,0 +1,208 @@
+use std::{io::{Read, Write}, path::{Path, PathBuf}, sync::{atomic::{AtomicBool, Ordering}, Arc}};
+
+use anyhow::Result;
+use crossterm::event::KeyCode;
+use parking_lot::Mutex;
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use ratatui::layout::Rect;
+use yazi_adapter::Dimension;
+use yazi_config::{keymap::Key, t};
+use yazi_proxy::AppProxy;
+use yazi_shared::{emit, render, render_and};
+
+use super::keys;
+
+// The share of the height the pane takes, at the bottom of the screen
+const PERCENT: u16 = 40;
+
+#[derive(Default)]
+pub struct Terminal {
+	pub visible: bool,
+	pub focused: bool,
+
+	pty: Option<Pty>,
+}
+
+struct Pty {
+	master: Box<dyn MasterPty + Send>,
+	writer: Box<dyn Write + Send>,
+	child:  Box<dyn Child + Send + Sync>,
+	parser: Arc<Mutex<vt100::Parser>>,
+	alive:  Arc<AtomicBool>,
+
+	cwd:  PathBuf,
+	size: (u16, u16),
+}
+
+impl Terminal {
+	// Shows the pane with the focus on it, the shell is started the first time,
+	// and again after it has exited. The file list is resized whenever the pane comes or goes.
+	pub fn toggle(&mut self, cwd: &Path, hide: bool) {
+		if hide {
+			if self.visible {
+				emit!(Resize);
+			}
+			return (self.visible, self.focused) = (false, false);
+		}
+
+		if !self.alive() {
+			match Pty::spawn(cwd, self.inner_size()) {
+				Ok(pty) => self.pty = Some(pty),
+				Err(e) => return AppProxy::notify_error("Terminal", &t!("Failed to start: {e}", e = e)),
+			}
+		}
+
+		if self.visible {
+			render!();
+		} else {
+			emit!(Resize);
+		}
+		(self.visible, self.focused) = (true, true);
+	}
+
+	// Keys go to the shell while it's focused, except `<C-\>` that gives the focus back
+	pub fn type_(&mut self, key: &Key) -> bool {
+		if !self.alive() {
+			(self.visible, self.focused) = (false, false);
+			return false;
+		}
+		let Some(pty) = self.pty.as_mut().filter(|_| self.focused && self.visible) else {
+			return false;
+		};
+
+		if key.ctrl && key.code == KeyCode::Char('\\') {
+			self.focused = false;
+			return render_and!(true);
+		}
+
+		let app_cursor = pty.parser.lock().screen().application_cursor();
+		if let Some(b) = keys::encode(key, app_cursor) {
+			pty.write(&b);
+		}
+		true
+	}
+
+	pub fn paste(&mut self, s: &str) {
+		let Some(pty) = &mut self.pty else { return };
+		if pty.parser.lock().screen().bracketed_paste() {
+			pty.write(&[b"\x1b[200~", s.as_bytes(), b"\x1b[201~"].concat());
+		} else {
+			pty.write(s.as_bytes());
+		}
+	}
+
+	// Follows the cwd of the active tab, and the size of the screen. The `cd` waits until
+	// the shell is back in the foreground, rather than being typed into whatever runs in it.
+	pub fn sync(&mut self, cwd: &Path) {
+		let size = self.inner_size();
+		let Some(pty) = self.pty.as_mut().filter(|_| self.visible) else { return };
+
+		if pty.size != size {
+			pty.resize(size);
+		}
+		if pty.cwd != cwd && pty.idle() {
+			pty.cwd = cwd.to_owned();
+			pty.write(Pty::cd(cwd).as_bytes());
+		}
+	}
+
+	// Splits the area into the one left for the file list, and the one of the pane
+	pub fn split(&self, area: Rect) -> (Rect, Option<Rect>) {
+		if !self.alive() || !self.visible {
+			return (area, None);
+		}
+
+		let height = Self::height(area.height);
+		let top = Rect { height: area.height - height, ..area };
+		(top, Some(Rect { y: top.bottom(), height, ..area }))
+	}
+
+	#[inline]
+	pub fn alive(&self) -> bool { self.pty.as_ref().is_some_and(|p| p.alive.load(Ordering::Relaxed)) }
+
+	#[inline]
+	pub fn screen<T>(&self, f: impl FnOnce(&vt100::Screen) -> T) -> Option<T> {
+		self.pty.as_ref().map(|p| f(p.parser.lock().screen()))
+	}
+
+	// The rows and columns of the shell, inside the border on top of the pane
+	fn inner_size(&self) -> (u16, u16) {
+		let ws = Dimension::available();
+		(Self::height(ws.rows).saturating_sub(1).max(1), ws.columns.max(1))
+	}
+
+	#[inline]
+	fn height(total: u16) -> u16 { (total * PERCENT / 100).max(3).min(total) }
+}
+
+impl Pty {
+	fn spawn(cwd: &Path, (rows, cols): (u16, u16)) -> Result<Self> {
+		let pair = native_pty_system().openpty(PtySize { rows, cols, ..Default::default() })?;
+
+		let mut cmd = CommandBuilder::new_default_prog();
+		cmd.cwd(cwd);
+		let child = pair.slave.spawn_command(cmd)?;
+		drop(pair.slave);
+
+		let mut reader = pair.master.try_clone_reader()?;
+		let writer = pair.master.take_writer()?;
+
+		let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, 0)));
+		let alive = Arc::new(AtomicBool::new(true));
+		std::thread::spawn({
+			let (parser, alive) = (parser.clone(), alive.clone());
+			move || {
+				let mut buf = [0; 8192];
+				while let Ok(n @ 1..) = reader.read(&mut buf) {
+					parser.lock().process(&buf[..n]);
+					emit!(Render);
+				}
+				alive.store(false, Ordering::Relaxed);
+				emit!(Resize);
+			}
+		});
+
+		Ok(Self {
+			master: pair.master,
+			writer,
+			child,
+			parser,
+			alive,
+
+			cwd: cwd.to_owned(),
+			size: (rows, cols),
+		})
+	}
+
+	fn write(&mut self, b: &[u8]) {
+		if self.writer.write_all(b).and_then(|_| self.writer.flush()).is_err() {
+			self.alive.store(false, Ordering::Relaxed);
+		}
+	}
+
+	fn resize(&mut self, (rows, cols): (u16, u16)) {
+		self.size = (rows, cols);
+		self.master.resize(PtySize { rows, cols, ..Default::default() }).ok();
+		self.parser.lock().set_size(rows, cols);
+	}
+
+	// Whether the shell itself owns the foreground process group of the pty
+	#[cfg(unix)]
+	fn idle(&self) -> bool {
+		let leader = self.master.process_group_leader();
+		leader.is_some() && leader == self.child.process_id().map(|p| p as _)
+	}
+
+	// There's no telling what's in the foreground of a ConPTY, so it's never typed into
+	#[cfg(windows)]
+	fn idle(&self) -> bool { false }
+
+	// Whatever has been typed on the prompt is cleared with `<C-u>` first
+	fn cd(path: &Path) -> String {
+		format!("\x15 cd -- {}\r", shell_words::quote(&path.to_string_lossy()))
+	}
+}
+
+impl Drop for Pty {
+	fn drop(&mut self) { self.child.kill().ok(); }
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use anyhow::Result;
+use yazi_config::vcs::VcsProvider;
+
+use super::repo::run;
+
+// The last commit that touched a file
+#[derive(Clone, Debug)]
+pub struct Commit {
+	pub hash:    String,
+	pub author:  String,
+	pub time:    i64,
+	pub subject: String,
+}
+
+impl Commit {
+	pub async fn load(root: &Path, provider: VcsProvider, path: &Path) -> Result<Option<Self>> {
+		let Some(rel) = path.strip_prefix(root).ok().and_then(|p| p.to_str()) else {
+			return Ok(None);
+		};
+
+		let out = match provider {
+			VcsProvider::Git => {
+				run(root, "git", &["log", "-1", "--format=%h%n%an%n%at%n%s", "--", rel]).await?
+			}
+			VcsProvider::Mercurial => {
+				let template = r"--template={node|short}\n{author|person}\n{date|hgdate}\n{desc|firstline}";
+				run(root, "hg", &["log", "-l1", template, "--", rel]).await?
+			}
+			VcsProvider::Jujutsu | VcsProvider::Subversion => return Ok(None),
+		};
+
+		let out = String::from_utf8_lossy(&out);
+		let mut it = out.lines();
+		let (Some(hash), Some(author), Some(time)) = (it.next(), it.next(), it.next()) else {
+			return Ok(None);
+		};
+
+		Ok(Some(Self {
+			hash:    hash.to_owned(),
+			author:  author.to_owned(),
+			// `hgdate` is followed by the timezone offset
+			time:    time.split(' ').next().and_then(|s| s.parse().ok()).unwrap_or(0),
+			subject: it.next().unwrap_or_default().to_owned(),
+		}))
+	}
+}
@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use yazi_config::vcs::VcsProvider;
+use yazi_fs::VcsStatus;
+
+use super::{repo::run, Repo};
+
+// Goes through the directories in `scope` only if any, to be merged into the repo loaded before
+pub(super) async fn load(root: &Path, scope: &[PathBuf]) -> Result<Repo> {
+	let specs: Vec<_> =
+		scope.iter().filter_map(|p| p.strip_prefix(root).ok()).map(|p| p.to_string_lossy()).collect();
+
+	// Without the optional locks, as refreshing the index would be taken as a change of it
+	let mut args =
+		vec!["--no-optional-locks", "status", "--porcelain=v1", "-z", "--branch", "--ignored"];
+	if !specs.is_empty() {
+		args.push("--");
+		args.extend(specs.iter().map(|s| if s.is_empty() { "." } else { s }));
+	}
+
+	let out = run(root, "git", &args).await?;
+	Ok(parse(root, &out))
+}
+
+fn parse(root: &Path, out: &[u8]) -> Repo {
+	let mut repo = Repo::new(root, VcsProvider::Git);
+	let mut it = out.split(|&b| b == 0);
+	while let Some(entry) = it.next() {
+		if let Some(b) = entry.strip_prefix(b"## ") {
+			parse_branch(&mut repo, &String::from_utf8_lossy(b));
+			continue;
+		} else if entry.len() < 4 {
+			continue;
+		}
+
+		// Renames and copies are followed by the original path
+		if matches!(entry[0], b'R' | b'C') {
+			it.next();
+		}
+
+		if let Some(status) = from_xy(entry[0], entry[1]) {
+			repo.insert(&String::from_utf8_lossy(&entry[3..]), status);
+		}
+	}
+	repo
+}
+
+// `main...origin/main [ahead 1, behind 2]`, `No commits yet on main`, or `HEAD (no branch)`
+fn parse_branch(repo: &mut Repo, s: &str) {
+	let (head, counts) = s.split_once(" [").unwrap_or((s, ""));
+	for c in counts.trim_end_matches(']').split(", ") {
+		if let Some(n) = c.strip_prefix("ahead ") {
+			repo.ahead = n.parse().unwrap_or(0);
+		} else if let Some(n) = c.strip_prefix("behind ") {
+			repo.behind = n.parse().unwrap_or(0);
+		}
+	}
+
+	let head = head.strip_prefix("No commits yet on ").unwrap_or(head);
+	let head = head.split_once("...").map_or(head, |(h, _)| h);
+	repo.branch = (head != "HEAD (no branch)").then(|| head.to_owned());
+}
+
+// The `XY` columns of `git status --porcelain`
+fn from_xy(x: u8, y: u8) -> Option<VcsStatus> {
+	Some(match (x, y) {
+		(b'?', b'?') => VcsStatus::Untracked,
+		(b'!', b'!') => VcsStatus::Ignored,
+		(b'U', _) | (_, b'U') | (b'A', b'A') | (b'D', b'D') => VcsStatus::Conflicted,
+		(b'R' | b'C', _) | (_, b'R' | b'C') => VcsStatus::Renamed,
+		(b'D', _) | (_, b'D') => VcsStatus::Deleted,
+		(b'A', _) => VcsStatus::Added,
+		(b'M' | b'T', _) | (_, b'M' | b'T') => VcsStatus::Modified,
+		_ => return None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let out = b"## main...origin/main [ahead 1, behind 2]\0 M src/a.rs\0?? new/\0!! target/\0UU b.rs\0R  d.rs\0c.rs\0";
+		let repo = parse(Path::new("/r"), out);
+
+		assert_eq!(repo.branch.as_deref(), Some("main"));
+		assert_eq!((repo.ahead, repo.behind), (1, 2));
+
+		assert_eq!(repo.status(Path::new("/r/src/a.rs")), Some(VcsStatus::Modified));
+		assert_eq!(repo.status(Path::new("/r/src")), Some(VcsStatus::Modified));
+		assert_eq!(repo.status(Path::new("/r/new/deep/c.rs")), Some(VcsStatus::Untracked));
+		assert_eq!(repo.status(Path::new("/r/target/debug")), Some(VcsStatus::Ignored));
+		assert_eq!(repo.status(Path::new("/r")), Some(VcsStatus::Conflicted));
+		assert_eq!(repo.status(Path::new("/r/d.rs")), Some(VcsStatus::Renamed));
+		assert_eq!(repo.status(Path::new("/r/c.rs")), None);
+	}
+
+	#[test]
+	fn test_merge() {
+		let mut repo = parse(Path::new("/r"), b"## main\0 M a/x.rs\0 M b/y.rs\0");
+		let scoped = parse(Path::new("/r"), b"## dev\0?? a/z.rs\0");
+		repo.merge(scoped, &[PathBuf::from("/r/a")]);
+
+		assert_eq!(repo.branch.as_deref(), Some("dev"));
+		assert_eq!(repo.status(Path::new("/r/a/x.rs")), None);
+		assert_eq!(repo.status(Path::new("/r/a")), Some(VcsStatus::Untracked.rollup()));
+		assert_eq!(repo.status(Path::new("/r/b/y.rs")), Some(VcsStatus::Modified));
+	}
+}
@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use anyhow::Result;
+use yazi_config::vcs::VcsProvider;
+use yazi_fs::VcsStatus;
+
+use super::{repo::run, Repo};
+
+pub(super) async fn load(root: &Path) -> Result<Repo> {
+	let (status, branch) = tokio::try_join!(
+		run(root, "hg", &["status", "--color=never"]),
+		run(root, "hg", &["branch"]),
+	)?;
+
+	let mut repo = Repo::new(root, VcsProvider::Mercurial);
+	for line in String::from_utf8_lossy(&status).lines() {
+		let Some((code, path)) = line.split_once(' ') else { continue };
+		let status = match code {
+			"M" => VcsStatus::Modified,
+			"A" => VcsStatus::Added,
+			"R" | "!" => VcsStatus::Deleted,
+			"?" => VcsStatus::Untracked,
+			"I" => VcsStatus::Ignored,
+			_ => continue,
+		};
+		repo.insert(path, status);
+	}
+
+	let branch = String::from_utf8_lossy(&branch).trim().to_owned();
+	repo.branch = Some(branch).filter(|s| !s.is_empty());
+	Ok(repo)
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use anyhow::Result;
+use yazi_config::vcs::VcsProvider;
+use yazi_fs::VcsStatus;
+
+use super::{repo::run, Repo};
+
+const DIFF: &[&str] = &["--no-pager", "diff", "--summary"];
+const LOG: &[&str] =
+	&["--no-pager", "--ignore-working-copy", "log", "-r=@", "--no-graph", "-T=change_id.shortest(8)"];
+
+pub(super) async fn load(root: &Path) -> Result<Repo> {
+	// The log doesn't snapshot the working copy, so the two don't fight over its lock
+	let (diff, head) = tokio::try_join!(run(root, "jj", DIFF), run(root, "jj", LOG))?;
+
+	let mut repo = Repo::new(root, VcsProvider::Jujutsu);
+	for line in String::from_utf8_lossy(&diff).lines() {
+		let Some((code, path)) = line.split_once(' ') else { continue };
+		let status = match code {
+			"M" => VcsStatus::Modified,
+			"A" | "C" => VcsStatus::Added,
+			"D" => VcsStatus::Deleted,
+			"R" => VcsStatus::Renamed,
+			_ => continue,
+		};
+		repo.insert(&renamed_to(path), status);
+	}
+
+	let head = String::from_utf8_lossy(&head).trim().to_owned();
+	repo.branch = Some(head).filter(|s| !s.is_empty());
+	Ok(repo)
+}
+
+// Renames are shown as `src/{a.rs => b.rs}`, and this takes the new path out of it
+fn renamed_to(path: &str) -> String {
+	let Some((prefix, rest)) = path.split_once('{') else { return path.to_owned() };
+	let Some((inner, suffix)) = rest.split_once('}') else { return path.to_owned() };
+	let to = inner.split_once(" => ").map_or(inner, |(_, to)| to);
+	format!("{prefix}{to}{suffix}").replace("//", "/")
+}
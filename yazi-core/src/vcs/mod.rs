@@ -0,0 +1,11 @@
+mod commit;
+mod git;
+mod hg;
+mod jj;
+mod repo;
+mod svn;
+mod vcs;
+
+pub use commit::*;
+pub use repo::*;
+pub use vcs::*;
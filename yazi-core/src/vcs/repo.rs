@@ -0,0 +1,107 @@
+use std::{collections::HashMap, mem, path::{Path, PathBuf}};
+
+use anyhow::{bail, Result};
+use tokio::process::Command;
+use yazi_config::vcs::VcsProvider;
+use yazi_fs::VcsStatus;
+
+#[derive(Debug)]
+pub struct Repo {
+	pub root:     PathBuf,
+	pub provider: VcsProvider,
+	pub branch:   Option<String>,
+	pub ahead:    usize,
+	pub behind:   usize,
+
+	files: HashMap<PathBuf, VcsStatus>,
+	dirs:  HashMap<PathBuf, VcsStatus>,
+}
+
+impl Repo {
+	pub(super) fn new(root: &Path, provider: VcsProvider) -> Self {
+		Self {
+			root: root.to_owned(),
+			provider,
+			branch: None,
+			ahead: 0,
+			behind: 0,
+			files: Default::default(),
+			dirs: Default::default(),
+		}
+	}
+
+	// Loads the statuses under the directories in `scope` only, for the providers that support it,
+	// which are merged into the repo loaded before with `merge`.
+	pub async fn load(root: &Path, provider: VcsProvider, scope: &[PathBuf]) -> Result<Self> {
+		match provider {
+			VcsProvider::Git => super::git::load(root, scope).await,
+			VcsProvider::Jujutsu => super::jj::load(root).await,
+			VcsProvider::Mercurial => super::hg::load(root).await,
+			VcsProvider::Subversion => super::svn::load(root).await,
+		}
+	}
+
+	// Takes the statuses under the directories in `scope` from the repo loaded with it,
+	// keeping the others, and rolls them up to their parents again.
+	pub(super) fn merge(&mut self, other: Self, scope: &[PathBuf]) {
+		let kept = mem::take(&mut self.files)
+			.into_iter()
+			.filter(|(p, _)| !scope.iter().any(|s| p.starts_with(s)))
+			.chain(other.files)
+			.collect::<Vec<_>>();
+
+		self.dirs.clear();
+		for (path, status) in kept {
+			self.insert_path(path, status);
+		}
+
+		self.branch = other.branch;
+		(self.ahead, self.behind) = (other.ahead, other.behind);
+	}
+
+	// Records the status of a path relative to the root, and rolls it up to its parents
+	pub(super) fn insert(&mut self, rel: &str, status: VcsStatus) {
+		self.insert_path(self.root.join(rel.trim_end_matches('/')), status);
+	}
+
+	fn insert_path(&mut self, path: PathBuf, status: VcsStatus) {
+		// The ignored ones don't make their parents look changed
+		if status != VcsStatus::Ignored {
+			for p in path.ancestors().skip(1).take_while(|&p| p.starts_with(&self.root)) {
+				let s = self.dirs.entry(p.to_owned()).or_insert(status.rollup());
+				*s = s.max(status.rollup());
+			}
+		}
+		self.files.insert(path, status);
+	}
+
+	pub fn status(&self, path: &Path) -> Option<VcsStatus> {
+		if let Some(&s) = self.files.get(path).or_else(|| self.dirs.get(path)) {
+			return Some(s);
+		}
+
+		// Only the top of an untracked or ignored directory is reported
+		path
+			.ancestors()
+			.skip(1)
+			.take_while(|&p| p.starts_with(&self.root))
+			.find_map(|p| self.files.get(p).copied())
+			.filter(|&s| s == VcsStatus::Untracked || s == VcsStatus::Ignored)
+	}
+}
+
+// Runs the command of a provider in the root, and returns its stdout
+pub(super) async fn run(root: &Path, program: &str, args: &[&str]) -> Result<Vec<u8>> {
+	let output = Command::new(program)
+		.args(args)
+		.current_dir(root)
+		.env("HGPLAIN", "1") // Ignores the user's config of `hg` that changes the output
+		.kill_on_drop(true)
+		.output()
+		.await?;
+
+	if !output.status.success() {
+		bail!("`{program}` failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+	}
+	Ok(output.stdout)
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use anyhow::Result;
+use yazi_config::vcs::VcsProvider;
+use yazi_fs::VcsStatus;
+
+use super::{repo::run, Repo};
+
+pub(super) async fn load(root: &Path) -> Result<Repo> {
+	let (status, url) = tokio::try_join!(
+		run(root, "svn", &["status", "--non-interactive"]),
+		run(root, "svn", &["info", "--non-interactive", "--show-item=relative-url"]),
+	)?;
+
+	let mut repo = Repo::new(root, VcsProvider::Subversion);
+	for line in String::from_utf8_lossy(&status).lines() {
+		// The first 7 columns are the flags, followed by a space and the path
+		let (Some(flags), Some(path)) = (line.get(..7), line.get(8..)) else { continue };
+		let b = flags.as_bytes();
+		let status = match (b[0], b[1]) {
+			(b'C', _) | (_, b'C') => VcsStatus::Conflicted,
+			_ if b[6] == b'C' => VcsStatus::Conflicted,
+			(b'A', _) => VcsStatus::Added,
+			(b'D' | b'!', _) => VcsStatus::Deleted,
+			(b'M' | b'R' | b'~', _) | (_, b'M') => VcsStatus::Modified,
+			(b'?', _) => VcsStatus::Untracked,
+			(b'I', _) => VcsStatus::Ignored,
+			_ => continue,
+		};
+		repo.insert(path, status);
+	}
+
+	// `^/branches/foo`, relative to the root of the repository
+	let url = String::from_utf8_lossy(&url).trim().to_owned();
+	repo.branch = Some(url.trim_start_matches("^/").to_owned()).filter(|s| !s.is_empty());
+	Ok(repo)
+}
@@ -0,0 +1,217 @@
+use std::{collections::{hash_map::Entry, HashMap, HashSet}, path::{Path, PathBuf}, time::Duration};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::{task::JoinHandle, time::sleep};
+use tracing::error;
+use yazi_config::{vcs::VcsProvider, VCS};
+use yazi_fs::VcsStatus;
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use super::{Commit, Repo};
+
+// Changes usually come in bursts, e.g. a `git checkout`, so they're coalesced into one reload
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Default)]
+pub struct Vcs {
+	repos: HashMap<PathBuf, Repo>,
+	roots: HashMap<PathBuf, Option<(PathBuf, VcsProvider)>>,
+
+	// The directories changed while the repos were being loaded, empty for the whole repo
+	loading: HashSet<PathBuf>,
+	dirty:   HashMap<PathBuf, Vec<PathBuf>>,
+
+	// The index and `HEAD` of the git repos, which change without touching the files, e.g. a commit
+	watcher: Option<RecommendedWatcher>,
+
+	// The last commits of the files that have been hovered
+	commits: HashMap<PathBuf, Option<Commit>>,
+	peeked:  Option<PathBuf>,
+	peeking: Option<JoinHandle<()>>,
+}
+
+impl Vcs {
+	// Finds the repos of the directories, and loads the ones that haven't been loaded yet
+	pub(crate) fn watch<'a>(&mut self, dirs: impl IntoIterator<Item = &'a Url>) {
+		for dir in dirs.into_iter().filter(|u| u.is_regular()) {
+			match self.root_of(dir) {
+				Some((root, provider)) if !self.repos.contains_key(&root) => {
+					if provider == VcsProvider::Git {
+						self.watch_git(&root);
+					}
+					self.load(root, provider, vec![]);
+				}
+				_ => {}
+			}
+		}
+	}
+
+	// Reloads the statuses under the directory, after something in it has changed
+	pub(crate) fn refresh(&mut self, dir: &Url) {
+		if let Some(Some((root, provider))) = self.roots.get(dir.as_path()).cloned() {
+			self.load(root, provider, vec![dir.to_path_buf()]);
+		}
+	}
+
+	// Reloads the whole repo, after its index or `HEAD` has changed
+	#[inline]
+	pub(crate) fn reload(&mut self, root: PathBuf, provider: VcsProvider) {
+		self.load(root, provider, vec![]);
+	}
+
+	pub(crate) fn update(
+		&mut self,
+		root: PathBuf,
+		provider: VcsProvider,
+		repo: Option<Repo>,
+		scope: Vec<PathBuf>,
+	) {
+		self.loading.remove(&root);
+		match (repo, self.repos.get_mut(&root)) {
+			(Some(repo), Some(old)) if !scope.is_empty() => old.merge(repo, &scope),
+			(Some(repo), _) => _ = self.repos.insert(root.clone(), repo),
+			(None, _) => _ = self.repos.remove(&root),
+		}
+
+		// The commits may have changed along with the statuses
+		self.commits.retain(|p, _| !p.starts_with(&root));
+		if let Some(path) = self.peeked.clone().filter(|p| p.starts_with(&root)) {
+			self.peek(&path);
+		}
+
+		if let Some(scope) = self.dirty.remove(&root) {
+			self.load(root, provider, scope);
+		}
+	}
+
+	// Fetches the last commit of the hovered file, the one of the file hovered before is dropped,
+	// so that scrolling through a directory doesn't pile up the processes.
+	pub(crate) fn peek(&mut self, path: &Path) {
+		self.peeked = Some(path.to_owned());
+		if self.commits.contains_key(path) {
+			return;
+		}
+
+		let Some((root, provider)) = path.parent().and_then(|p| self.roots.get(p)).cloned().flatten()
+		else {
+			return;
+		};
+		if !self.repos.contains_key(&root) {
+			return; // Peeked again once the repo is loaded
+		}
+
+		let path = path.to_owned();
+		if let Some(h) = self.peeking.take() {
+			h.abort();
+		}
+		self.peeking = Some(tokio::spawn(async move {
+			sleep(DEBOUNCE / 2).await;
+			let commit = Commit::load(&root, provider, &path).await.ok().flatten();
+			emit!(Call(
+				Cmd::new("update_commit").with_any("path", path).with_any("commit", commit),
+				Layer::Manager
+			));
+		}));
+	}
+
+	pub(crate) fn update_commit(&mut self, path: PathBuf, commit: Option<Commit>) {
+		self.commits.insert(path, commit);
+	}
+
+	fn root_of(&mut self, dir: &Path) -> Option<(PathBuf, VcsProvider)> {
+		if let Some(root) = self.roots.get(dir) {
+			return root.clone();
+		}
+
+		let root = VCS.load().detect(dir);
+		self.roots.insert(dir.to_owned(), root.clone());
+		root
+	}
+
+	// Loads the statuses under the directories in `scope`, or the whole repo if it's empty,
+	// where only git can be told the directories, and the others are always loaded in full.
+	fn load(&mut self, root: PathBuf, provider: VcsProvider, mut scope: Vec<PathBuf>) {
+		if provider != VcsProvider::Git || !self.repos.contains_key(&root) {
+			scope.clear();
+		}
+
+		if self.loading.contains(&root) {
+			match self.dirty.entry(root) {
+				Entry::Occupied(e) if e.get().is_empty() => {}
+				Entry::Occupied(mut e) if !scope.is_empty() => e.get_mut().extend(scope),
+				Entry::Occupied(mut e) => e.get_mut().clear(),
+				Entry::Vacant(e) => _ = e.insert(scope),
+			}
+			return;
+		}
+
+		self.loading.insert(root.clone());
+		tokio::spawn(async move {
+			sleep(DEBOUNCE).await;
+			let repo = Repo::load(&root, provider, &scope).await.ok();
+			emit!(Call(
+				Cmd::new("update_vcs")
+					.with_any("root", root)
+					.with_any("provider", provider)
+					.with_any("repo", repo)
+					.with_any("scope", scope),
+				Layer::Manager
+			));
+		});
+	}
+
+	fn watch_git(&mut self, root: &Path) {
+		let git = root.join(".git");
+		if !git.is_dir() {
+			return; // A worktree or a submodule, whose `.git` is a file pointing elsewhere
+		}
+
+		if self.watcher.is_none() {
+			let watcher = RecommendedWatcher::new(
+				|res: Result<notify::Event, notify::Error>| {
+					let Ok(event) = res else { return };
+					if event.kind.is_access() {
+						return;
+					}
+
+					let changed = event.paths.iter().filter(|p| {
+						p.file_name().is_some_and(|n| n == "index" || n == "HEAD")
+					});
+					for root in changed.filter_map(|p| p.parent()?.parent()) {
+						emit!(Call(
+							Cmd::new("update_vcs")
+								.with_any("root", root.to_owned())
+								.with_any("provider", VcsProvider::Git)
+								.with_bool("stale", true),
+							Layer::Manager
+						));
+					}
+				},
+				Default::default(),
+			);
+			match watcher {
+				Ok(w) => self.watcher = Some(w),
+				Err(e) => return error!("Failed to watch the git repos: {e}"),
+			}
+		}
+
+		if let Some(Err(e)) = self.watcher.as_mut().map(|w| w.watch(&git, RecursiveMode::NonRecursive))
+		{
+			error!("Failed to watch {git:?}: {e}");
+		}
+	}
+}
+
+impl Vcs {
+	// The repo of a directory that's been watched
+	#[inline]
+	pub fn repo(&self, dir: &Path) -> Option<&Repo> {
+		self.roots.get(dir)?.as_ref().and_then(|(r, _)| self.repos.get(r))
+	}
+
+	#[inline]
+	pub fn status(&self, url: &Url) -> Option<VcsStatus> { self.repo(url.parent()?)?.status(url) }
+
+	#[inline]
+	pub fn commit(&self, path: &Path) -> Option<&Commit> { self.commits.get(path)?.as_ref() }
+}
@@ -1,6 +1,6 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{collections::HashSet, str::FromStr, sync::Arc};
 
-use yazi_config::{keymap::{Control, Key}, KEYMAP};
+use yazi_config::keymap::{Control, Key};
 use yazi_shared::{event::Cmd, render, Layer};
 
 use crate::which::{Which, WhichSorter};
@@ -42,16 +42,16 @@ impl Which {
 		render!();
 	}
 
-	pub fn show_with(&mut self, key: &Key, layer: Layer) {
+	// `ctrls` are the bindings of the layer whose conditions are satisfied
+	pub fn show_with(&mut self, key: &Key, layer: Layer, ctrls: Vec<Arc<Control>>) {
 		let mut seen = HashSet::new();
 
 		self.layer = layer;
 		self.times = 1;
-		self.cands = KEYMAP
-			.get(layer)
-			.iter()
+		self.cands = ctrls
+			.into_iter()
 			.filter(|c| c.on.len() > 1 && &c.on[0] == key)
-			.filter(|&c| seen.insert(&c.on))
+			.filter(|c| seen.insert(c.on.clone()))
 			.map(|c| c.into())
 			.collect();
 
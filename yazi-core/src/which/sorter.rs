@@ -14,10 +14,10 @@ pub struct WhichSorter {
 impl Default for WhichSorter {
 	fn default() -> Self {
 		Self {
-			by:        WHICH.sort_by,
-			sensitive: WHICH.sort_sensitive,
-			reverse:   WHICH.sort_reverse,
-			translit:  WHICH.sort_translit,
+			by:        WHICH.load().sort_by,
+			sensitive: WHICH.load().sort_sensitive,
+			reverse:   WHICH.load().sort_reverse,
+			translit:  WHICH.load().sort_translit,
 		}
 	}
 }
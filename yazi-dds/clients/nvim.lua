@@ -0,0 +1,119 @@
+-- A reference client of the editor protocol of Yazi, see `yazi-dds/src/editor.rs`, for Neovim 0.10+.
+-- Save it as `lua/yazi-dds.lua` in the config directory, then:
+--
+--   local yazi = require("yazi-dds")
+--   vim.keymap.set("n", "<leader>y", yazi.open)
+--   vim.api.nvim_create_autocmd("BufEnter", { callback = function() yazi.reveal() end })
+--
+local M = { yazi = nil, me = nil, pipe = nil, win = nil, ready = false, hovered = nil }
+
+local function now()
+	local sec, usec = vim.uv.gettimeofday()
+	return sec * 1000000 + usec
+end
+
+local function send(kind, receiver, body)
+	if M.pipe then
+		M.pipe:write(string.format("%s,%d,%d,%s\n", kind, receiver, M.me, vim.json.encode(body)))
+	end
+end
+
+local function connect(cb)
+	local pipe
+	if vim.fn.has("win32") == 1 then
+		pipe = vim.uv.new_tcp()
+		pipe:connect("127.0.0.1", 33581, cb)
+	else
+		local tmp = (os.getenv("TMPDIR") or "/tmp"):gsub("/$", "")
+		pipe = vim.uv.new_pipe(false)
+		pipe:connect(string.format("%s/.yazi_dds-%d.sock", tmp, vim.uv.getuid()), cb)
+	end
+	return pipe
+end
+
+local function on_line(line)
+	local kind, _, sender, body = line:match("^([^,]+),(%d+),(%d+),(.*)$")
+	if not kind then
+		return
+	end
+
+	body = vim.json.decode(body)
+	if kind == "hey" and not M.ready and body.peers[tostring(M.yazi)] then
+		-- Yazi is up and able to take the handshake
+		M.ready = true
+		send("editor", M.yazi, { name = "nvim", server = vim.v.servername })
+	elseif kind == "open" and tonumber(sender) == M.yazi then
+		M.close()
+		for _, url in ipairs(body.urls) do
+			vim.cmd.edit(vim.fn.fnameescape(url))
+		end
+	elseif kind == "hover" then
+		M.hovered = body.url
+	end
+end
+
+local function attach(attempt)
+	M.pipe = connect(vim.schedule_wrap(function(err)
+		if err then
+			M.pipe:close()
+			return attempt < 50 and vim.defer_fn(function() attach(attempt + 1) end, 100)
+		end
+
+		local buf = ""
+		M.pipe:read_start(vim.schedule_wrap(function(_, data)
+			buf = buf .. (data or "")
+			for line in buf:gmatch("([^\n]*)\n") do
+				on_line(line)
+			end
+			buf = buf:match("[^\n]*$")
+		end))
+
+		local hi = { abilities = { "open", "hover" }, version = "nvim" }
+		M.pipe:write(string.format("hi,0,%d,%s\n", M.me, vim.json.encode(hi)))
+	end))
+end
+
+-- Starts Yazi in a floating terminal, at the file of the current buffer
+function M.open()
+	if M.win and vim.api.nvim_win_is_valid(M.win) then
+		return vim.api.nvim_set_current_win(M.win)
+	end
+
+	M.yazi, M.me, M.ready = now(), now() + 1, false
+	local cols, rows = vim.o.columns, vim.o.lines
+	M.win = vim.api.nvim_open_win(vim.api.nvim_create_buf(false, true), true, {
+		relative = "editor",
+		width = math.floor(cols * 0.9),
+		height = math.floor(rows * 0.9),
+		col = math.floor(cols * 0.05),
+		row = math.floor(rows * 0.05),
+		border = "rounded",
+	})
+
+	vim.fn.termopen({ "yazi", "--client-id", tostring(M.yazi), vim.fn.expand("%:p") }, {
+		on_exit = function() M.close() end,
+	})
+	vim.cmd.startinsert()
+	attach(0)
+end
+
+-- Shows the file in Yazi, the one of the current buffer by default
+function M.reveal(path)
+	path = path or vim.fn.expand("%:p")
+	if M.ready and path ~= "" then
+		send("reveal", M.yazi, { urls = { path }, folders = false })
+	end
+end
+
+function M.close()
+	if M.win and vim.api.nvim_win_is_valid(M.win) then
+		vim.api.nvim_win_close(M.win, true)
+	end
+	if M.pipe and not M.pipe:is_closing() then
+		M.pipe:write(string.format("bye,0,%d,{}\n", M.me))
+		M.pipe:close()
+	end
+	M.win, M.pipe, M.ready = nil, nil, false
+end
+
+return M
@@ -2,7 +2,7 @@ use anyhow::{bail, Result};
 use mlua::{ExternalResult, IntoLua, Lua, Value};
 use serde::Serialize;
 
-use super::{BodyBulk, BodyBye, BodyCd, BodyCustom, BodyDelete, BodyHey, BodyHi, BodyHover, BodyMove, BodyRename, BodyTrash, BodyYank};
+use super::{BodyBookmark, BodyBulk, BodyBye, BodyCd, BodyCustom, BodyDelete, BodyDuplicate, BodyEditor, BodyHey, BodyHi, BodyHover, BodyMove, BodyOpDone, BodyOpProgress, BodyOpStart, BodyOpen, BodyRename, BodyReveal, BodySelect, BodyTab, BodyTrash, BodyYank};
 use crate::Payload;
 
 #[derive(Debug, Serialize)]
@@ -13,12 +13,22 @@ pub enum Body<'a> {
 	Bye(BodyBye),
 	Cd(BodyCd<'a>),
 	Hover(BodyHover<'a>),
+	Tab(BodyTab),
+	Select(BodySelect<'a>),
 	Rename(BodyRename<'a>),
 	Bulk(BodyBulk<'a>),
 	Yank(BodyYank<'a>),
+	Bookmark(BodyBookmark<'a>),
 	Move(BodyMove<'a>),
+	Duplicate(BodyDuplicate<'a>),
 	Trash(BodyTrash<'a>),
 	Delete(BodyDelete<'a>),
+	Reveal(BodyReveal<'a>),
+	Editor(BodyEditor),
+	Open(BodyOpen<'a>),
+	OpStart(BodyOpStart),
+	OpProgress(BodyOpProgress),
+	OpDone(BodyOpDone),
 	Custom(BodyCustom),
 }
 
@@ -30,12 +40,22 @@ impl Body<'static> {
 			"bye" => Self::Bye(serde_json::from_str(body)?),
 			"cd" => Self::Cd(serde_json::from_str(body)?),
 			"hover" => Self::Hover(serde_json::from_str(body)?),
+			"tab" => Self::Tab(serde_json::from_str(body)?),
+			"select" => Self::Select(serde_json::from_str(body)?),
 			"rename" => Self::Rename(serde_json::from_str(body)?),
 			"bulk" => Self::Bulk(serde_json::from_str(body)?),
 			"@yank" => Self::Yank(serde_json::from_str(body)?),
+			"@bookmark" => Self::Bookmark(serde_json::from_str(body)?),
 			"move" => Self::Move(serde_json::from_str(body)?),
+			"duplicate" => Self::Duplicate(serde_json::from_str(body)?),
 			"trash" => Self::Trash(serde_json::from_str(body)?),
 			"delete" => Self::Delete(serde_json::from_str(body)?),
+			"reveal" => Self::Reveal(serde_json::from_str(body)?),
+			"editor" => Self::Editor(serde_json::from_str(body)?),
+			"open" => Self::Open(serde_json::from_str(body)?),
+			"op-start" => Self::OpStart(serde_json::from_str(body)?),
+			"op-progress" => Self::OpProgress(serde_json::from_str(body)?),
+			"op-done" => Self::OpDone(serde_json::from_str(body)?),
 			_ => BodyCustom::from_str(kind, body)?,
 		})
 	}
@@ -53,12 +73,22 @@ impl Body<'static> {
 				| "bye"
 				| "cd"
 				| "hover"
+				| "tab"
+				| "select"
 				| "rename"
 				| "bulk"
 				| "@yank"
+				| "@bookmark"
 				| "move"
+				| "duplicate"
 				| "trash"
 				| "delete"
+				| "reveal"
+				| "editor"
+				| "open"
+				| "op-start"
+				| "op-progress"
+				| "op-done"
 		) {
 			bail!("Cannot construct system event");
 		}
@@ -84,12 +114,22 @@ impl<'a> Body<'a> {
 			Self::Bye(_) => "bye",
 			Self::Cd(_) => "cd",
 			Self::Hover(_) => "hover",
+			Self::Tab(_) => "tab",
+			Self::Select(_) => "select",
 			Self::Rename(_) => "rename",
 			Self::Bulk(_) => "bulk",
 			Self::Yank(_) => "@yank",
+			Self::Bookmark(_) => "@bookmark",
 			Self::Move(_) => "move",
+			Self::Duplicate(_) => "duplicate",
 			Self::Trash(_) => "trash",
 			Self::Delete(_) => "delete",
+			Self::Reveal(_) => "reveal",
+			Self::Editor(_) => "editor",
+			Self::Open(_) => "open",
+			Self::OpStart(_) => "op-start",
+			Self::OpProgress(_) => "op-progress",
+			Self::OpDone(_) => "op-done",
 			Self::Custom(b) => b.kind.as_str(),
 		}
 	}
@@ -111,12 +151,22 @@ impl IntoLua<'_> for Body<'static> {
 			Self::Bye(b) => b.into_lua(lua),
 			Self::Cd(b) => b.into_lua(lua),
 			Self::Hover(b) => b.into_lua(lua),
+			Self::Tab(b) => b.into_lua(lua),
+			Self::Select(b) => b.into_lua(lua),
 			Self::Rename(b) => b.into_lua(lua),
 			Self::Bulk(b) => b.into_lua(lua),
 			Self::Yank(b) => b.into_lua(lua),
+			Self::Bookmark(b) => b.into_lua(lua),
 			Self::Move(b) => b.into_lua(lua),
+			Self::Duplicate(b) => b.into_lua(lua),
 			Self::Trash(b) => b.into_lua(lua),
 			Self::Delete(b) => b.into_lua(lua),
+			Self::Reveal(b) => b.into_lua(lua),
+			Self::Editor(b) => b.into_lua(lua),
+			Self::Open(b) => b.into_lua(lua),
+			Self::OpStart(b) => b.into_lua(lua),
+			Self::OpProgress(b) => b.into_lua(lua),
+			Self::OpDone(b) => b.into_lua(lua),
 			Self::Custom(b) => b.into_lua(lua),
 		}
 	}
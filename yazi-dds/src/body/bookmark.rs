@@ -0,0 +1,58 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use mlua::{IntoLua, Lua, MetaMethod, UserData, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodyBookmark<'a> {
+	pub marks: Cow<'a, BTreeMap<String, Url>>,
+	#[serde(skip)]
+	dummy:     bool,
+}
+
+impl<'a> BodyBookmark<'a> {
+	#[inline]
+	pub fn borrowed(marks: &'a BTreeMap<String, Url>) -> Body<'a> {
+		Self { marks: Cow::Borrowed(marks), dummy: false }.into()
+	}
+}
+
+impl BodyBookmark<'static> {
+	#[inline]
+	pub fn dummy() -> Body<'static> { Self { marks: Default::default(), dummy: true }.into() }
+}
+
+impl<'a> From<BodyBookmark<'a>> for Body<'a> {
+	fn from(value: BodyBookmark<'a>) -> Self { Self::Bookmark(value) }
+}
+
+impl IntoLua<'_> for BodyBookmark<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value<'_>> {
+		if let Some(Cow::Owned(marks)) = Some(self.marks).filter(|_| !self.dummy) {
+			BodyBookmarkIter { marks }.into_lua(lua)
+		} else {
+			lua.create_table()?.into_lua(lua)
+		}
+	}
+}
+
+// --- Iterator
+pub struct BodyBookmarkIter {
+	pub marks: BTreeMap<String, Url>,
+}
+
+impl UserData for BodyBookmarkIter {
+	fn add_methods<'lua, M: mlua::UserDataMethods<'lua, Self>>(methods: &mut M) {
+		methods.add_meta_method(MetaMethod::Len, |_, me, ()| Ok(me.marks.len()));
+
+		methods.add_meta_method(MetaMethod::Index, |lua, me, name: mlua::String| {
+			match me.marks.get(name.to_str()?) {
+				Some(url) => Some(lua.create_any_userdata(url.clone())).transpose(),
+				None => Ok(None),
+			}
+		});
+	}
+}
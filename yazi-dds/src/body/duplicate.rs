@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodyDuplicate<'a> {
+	pub items: Cow<'a, Vec<BodyDuplicateItem>>,
+}
+
+impl<'a> BodyDuplicate<'a> {
+	#[inline]
+	pub fn borrowed(items: &'a Vec<BodyDuplicateItem>) -> Body<'a> {
+		Self { items: Cow::Borrowed(items) }.into()
+	}
+}
+
+impl BodyDuplicate<'static> {
+	#[inline]
+	pub fn owned(items: Vec<BodyDuplicateItem>) -> Body<'static> {
+		Self { items: Cow::Owned(items) }.into()
+	}
+}
+
+impl<'a> From<BodyDuplicate<'a>> for Body<'a> {
+	fn from(value: BodyDuplicate<'a>) -> Self { Self::Duplicate(value) }
+}
+
+impl IntoLua<'_> for BodyDuplicate<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		lua.create_table_from([("items", self.items.into_owned())])?.into_lua(lua)
+	}
+}
+
+// --- Item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BodyDuplicateItem {
+	pub from: Url,
+	pub to:   Url,
+}
+
+impl IntoLua<'_> for BodyDuplicateItem {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		lua
+			.create_table_from([
+				("from", lua.create_any_userdata(self.from)?),
+				("to", lua.create_any_userdata(self.to)?),
+			])?
+			.into_lua(lua)
+	}
+}
@@ -0,0 +1,21 @@
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+
+use super::Body;
+
+/// The handshake of an editor, with the address that it listens on, e.g. `v:servername` of Neovim
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BodyEditor {
+	pub name:   String,
+	pub server: String,
+}
+
+impl<'a> From<BodyEditor> for Body<'a> {
+	fn from(value: BodyEditor) -> Self { Self::Editor(value) }
+}
+
+impl IntoLua<'_> for BodyEditor {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		lua.create_table_from([("name", self.name), ("server", self.server)])?.into_lua(lua)
+	}
+}
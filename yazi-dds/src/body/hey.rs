@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use mlua::{ExternalResult, IntoLua, Lua, Value};
 use serde::{Deserialize, Serialize};
@@ -10,12 +10,14 @@ use crate::Peer;
 pub struct BodyHey {
 	pub peers:   HashMap<u64, Peer>,
 	pub version: String,
+	#[serde(default)]
+	pub retains: HashSet<String>,
 }
 
 impl BodyHey {
 	#[inline]
-	pub fn owned(peers: HashMap<u64, Peer>) -> Body<'static> {
-		Self { peers, version: BodyHi::version() }.into()
+	pub fn owned(peers: HashMap<u64, Peer>, retains: HashSet<String>) -> Body<'static> {
+		Self { peers, version: BodyHi::version(), retains }.into()
 	}
 }
 
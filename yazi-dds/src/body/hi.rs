@@ -11,14 +11,21 @@ pub struct BodyHi<'a> {
 	/// Specifies the kinds of events that the client can handle
 	pub abilities: HashSet<Cow<'a, str>>,
 	pub version:   String,
+	/// Asks for the last so many events retained by the server, of each kind in the abilities
+	#[serde(default)]
+	pub replay:    usize,
 }
 
 impl<'a> BodyHi<'a> {
 	#[inline]
-	pub fn borrowed(abilities: HashSet<&'a str>) -> Body<'a> {
+	pub fn borrowed(abilities: HashSet<&'a str>) -> Body<'a> { Self::replay(abilities, 0) }
+
+	#[inline]
+	pub fn replay(abilities: HashSet<&'a str>, replay: usize) -> Body<'a> {
 		Self {
 			abilities: abilities.into_iter().map(Cow::Borrowed).collect(),
 			version:   Self::version(),
+			replay,
 		}
 		.into()
 	}
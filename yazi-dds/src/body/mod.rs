@@ -1,29 +1,49 @@
 #![allow(clippy::module_inception)]
 
 mod body;
+mod bookmark;
 mod bulk;
 mod bye;
 mod cd;
 mod custom;
 mod delete;
+mod duplicate;
+mod editor;
 mod hey;
 mod hi;
 mod hover;
 mod move_;
+mod op_done;
+mod op_progress;
+mod op_start;
+mod open;
 mod rename;
+mod reveal;
+mod select;
+mod tab;
 mod trash;
 mod yank;
 
 pub use body::*;
+pub use bookmark::*;
 pub use bulk::*;
 pub use bye::*;
 pub use cd::*;
 pub use custom::*;
 pub use delete::*;
+pub use duplicate::*;
+pub use editor::*;
 pub use hey::*;
 pub use hi::*;
 pub use hover::*;
 pub use move_::*;
+pub use op_done::*;
+pub use op_progress::*;
+pub use op_start::*;
+pub use open::*;
 pub use rename::*;
+pub use reveal::*;
+pub use select::*;
+pub use tab::*;
 pub use trash::*;
 pub use yank::*;
@@ -0,0 +1,38 @@
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+// `state` is one of `succeeded`, `failed` or `canceled`, with the reasons of failures in `errors`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BodyOpDone {
+	pub id:        usize,
+	pub op:        String,
+	pub from:      Url,
+	pub to:        Option<Url>,
+	pub state:     String,
+	pub processed: u64,
+	pub errors:    Vec<String>,
+}
+
+impl<'a> From<BodyOpDone> for Body<'a> {
+	fn from(value: BodyOpDone) -> Self { Self::OpDone(value) }
+}
+
+impl IntoLua<'_> for BodyOpDone {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		let t = lua.create_table_from([
+			("id", self.id.into_lua(lua)?),
+			("op", self.op.into_lua(lua)?),
+			("from", lua.create_any_userdata(self.from)?.into_lua(lua)?),
+			("state", self.state.into_lua(lua)?),
+			("processed", self.processed.into_lua(lua)?),
+			("errors", lua.create_sequence_from(self.errors)?.into_lua(lua)?),
+		])?;
+		if let Some(to) = self.to {
+			t.raw_set("to", lua.create_any_userdata(to)?)?;
+		}
+		t.into_lua(lua)
+	}
+}
@@ -0,0 +1,33 @@
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+
+use super::Body;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BodyOpProgress {
+	pub id:        usize,
+	pub total:     u32,
+	pub succ:      u32,
+	pub fail:      u32,
+	pub found:     u64,
+	pub processed: u64,
+}
+
+impl<'a> From<BodyOpProgress> for Body<'a> {
+	fn from(value: BodyOpProgress) -> Self { Self::OpProgress(value) }
+}
+
+impl IntoLua<'_> for BodyOpProgress {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		lua
+			.create_table_from([
+				("id", self.id as u64),
+				("total", self.total as u64),
+				("succ", self.succ as u64),
+				("fail", self.fail as u64),
+				("found", self.found),
+				("processed", self.processed),
+			])?
+			.into_lua(lua)
+	}
+}
@@ -0,0 +1,39 @@
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+// A file operation of the scheduler has been queued, `to` is absent for deleting and trashing
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BodyOpStart {
+	pub id:   usize,
+	pub op:   String,
+	pub from: Url,
+	pub to:   Option<Url>,
+}
+
+impl BodyOpStart {
+	#[inline]
+	pub fn owned(id: usize, op: &str, from: &Url, to: Option<&Url>) -> Body<'static> {
+		Self { id, op: op.to_owned(), from: from.clone(), to: to.cloned() }.into()
+	}
+}
+
+impl<'a> From<BodyOpStart> for Body<'a> {
+	fn from(value: BodyOpStart) -> Self { Self::OpStart(value) }
+}
+
+impl IntoLua<'_> for BodyOpStart {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		let t = lua.create_table_from([
+			("id", self.id.into_lua(lua)?),
+			("op", self.op.into_lua(lua)?),
+			("from", lua.create_any_userdata(self.from)?.into_lua(lua)?),
+		])?;
+		if let Some(to) = self.to {
+			t.raw_set("to", lua.create_any_userdata(to)?)?;
+		}
+		t.into_lua(lua)
+	}
+}
@@ -0,0 +1,35 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+/// The files to be opened in the editor attached, instead of in Yazi
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodyOpen<'a> {
+	pub urls: Cow<'a, Vec<Url>>,
+}
+
+impl<'a> BodyOpen<'a> {
+	#[inline]
+	pub fn borrowed(urls: &'a Vec<Url>) -> Body<'a> { Self { urls: Cow::Borrowed(urls) }.into() }
+}
+
+impl<'a> From<BodyOpen<'a>> for Body<'a> {
+	fn from(value: BodyOpen<'a>) -> Self { Self::Open(value) }
+}
+
+impl IntoLua<'_> for BodyOpen<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value<'_>> {
+		let urls = lua.create_table_with_capacity(self.urls.len(), 0)?;
+
+		#[allow(clippy::unnecessary_to_owned)]
+		for (i, url) in self.urls.into_owned().into_iter().enumerate() {
+			urls.raw_set(i + 1, lua.create_any_userdata(url)?)?;
+		}
+
+		lua.create_table_from([("urls", urls)])?.into_lua(lua)
+	}
+}
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+// Asked by other apps to show the files, or the folders with `folders`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodyReveal<'a> {
+	pub urls:    Cow<'a, Vec<Url>>,
+	pub folders: bool,
+}
+
+impl BodyReveal<'static> {
+	#[inline]
+	pub fn owned(urls: Vec<Url>, folders: bool) -> Body<'static> {
+		Self { urls: Cow::Owned(urls), folders }.into()
+	}
+}
+
+impl<'a> From<BodyReveal<'a>> for Body<'a> {
+	fn from(value: BodyReveal<'a>) -> Self { Self::Reveal(value) }
+}
+
+impl IntoLua<'_> for BodyReveal<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value<'_>> {
+		let urls = lua.create_table_with_capacity(self.urls.len(), 0)?;
+
+		#[allow(clippy::unnecessary_to_owned)]
+		for (i, url) in self.urls.into_owned().into_iter().enumerate() {
+			urls.raw_set(i + 1, lua.create_any_userdata(url)?)?;
+		}
+
+		lua
+			.create_table_from([("urls", urls.into_lua(lua)?), ("folders", self.folders.into_lua(lua)?)])?
+			.into_lua(lua)
+	}
+}
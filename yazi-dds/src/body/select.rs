@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::fs::Url;
+
+use super::Body;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodySelect<'a> {
+	pub tab:  usize,
+	pub urls: Vec<Cow<'a, Url>>,
+	#[serde(skip)]
+	dummy:    bool,
+}
+
+impl<'a> BodySelect<'a> {
+	#[inline]
+	pub fn borrowed(tab: usize, urls: impl Iterator<Item = &'a Url>) -> Body<'a> {
+		Self { tab, urls: urls.map(Cow::Borrowed).collect(), dummy: false }.into()
+	}
+}
+
+impl BodySelect<'static> {
+	#[inline]
+	pub fn dummy(tab: usize) -> Body<'static> { Self { tab, urls: vec![], dummy: true }.into() }
+}
+
+impl<'a> From<BodySelect<'a>> for Body<'a> {
+	fn from(value: BodySelect<'a>) -> Self { Self::Select(value) }
+}
+
+impl IntoLua<'_> for BodySelect<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		if self.dummy {
+			return lua.create_table_from([("tab", self.tab)])?.into_lua(lua);
+		}
+
+		let urls = lua.create_table_with_capacity(self.urls.len(), 0)?;
+		for (i, u) in self.urls.into_iter().enumerate() {
+			urls.raw_set(i + 1, lua.create_any_userdata(u.into_owned())?)?;
+		}
+
+		lua
+			.create_table_from([("tab", self.tab.into_lua(lua)?), ("urls", urls.into_lua(lua)?)])?
+			.into_lua(lua)
+	}
+}
@@ -11,6 +11,8 @@ use crate::{body::{Body, BodyBye, BodyHi}, ClientReader, ClientWriter, Payload,
 
 pub(super) static ID: RoCell<u64> = RoCell::new();
 pub(super) static PEERS: RoCell<RwLock<HashMap<u64, Peer>>> = RoCell::new();
+// The kinds the server keeps for the late subscribers, sent even without a peer able to handle them
+pub(super) static RETAINS: RoCell<RwLock<HashSet<String>>> = RoCell::new();
 
 pub(super) static QUEUE_TX: RoCell<mpsc::UnboundedSender<String>> = RoCell::new();
 pub(super) static QUEUE_RX: RoCell<mpsc::UnboundedReceiver<String>> = RoCell::new();
@@ -106,16 +108,18 @@ impl Client {
 	/// sent by other yazi instances:
 	///   - If no server is running, fail right away;
 	///   - If a server is closed, attempt to reconnect forever.
-	pub async fn draw(kinds: HashSet<&str>) -> Result<()> {
-		async fn make(kinds: &HashSet<&str>) -> Result<ClientReader> {
+	///
+	/// The last `replay` messages of each kind retained by the server are received first.
+	pub async fn draw(kinds: HashSet<&str>, replay: usize) -> Result<()> {
+		async fn make(kinds: &HashSet<&str>, replay: usize) -> Result<ClientReader> {
 			let (lines, mut writer) = Stream::connect().await?;
-			let hi = Payload::new(BodyHi::borrowed(kinds.clone()));
+			let hi = Payload::new(BodyHi::replay(kinds.clone(), replay));
 			writer.write_all(format!("{hi}\n").as_bytes()).await?;
 			writer.flush().await?;
 			Ok(lines)
 		}
 
-		let mut lines = make(&kinds).await.context("No running Yazi instance found")?;
+		let mut lines = make(&kinds, replay).await.context("No running Yazi instance found")?;
 		loop {
 			match lines.next_line().await? {
 				Some(s) => {
@@ -125,7 +129,8 @@ impl Client {
 					}
 				}
 				None => loop {
-					if let Ok(new) = make(&kinds).await {
+					// Not replayed again, as they have been seen before the reconnection
+					if let Ok(new) = make(&kinds, 0).await {
 						lines = new;
 						break;
 					} else {
@@ -144,6 +149,11 @@ impl Client {
 	#[inline]
 	pub(super) fn able(&self, ability: &str) -> bool { self.abilities.contains(ability) }
 
+	// Whether a broadcast of the kind goes anywhere, i.e. to a peer, or to be retained by the server
+	pub(super) fn wanted(kind: &str) -> bool {
+		RETAINS.read().contains(kind) || PEERS.read().values().any(|p| p.able(kind))
+	}
+
 	async fn connect(server: &mut Option<JoinHandle<()>>) -> (ClientReader, ClientWriter) {
 		let mut first = true;
 		loop {
@@ -167,6 +177,7 @@ impl Client {
 
 	async fn reconnect(server: &mut Option<JoinHandle<()>>) -> (ClientReader, ClientWriter) {
 		PEERS.write().clear();
+		RETAINS.write().clear();
 
 		time::sleep(time::Duration::from_millis(500)).await;
 		Self::connect(server).await
@@ -176,6 +187,7 @@ impl Client {
 		if let Ok(Body::Hey(mut hey)) = Payload::from_str(s).map(|p| p.body) {
 			hey.peers.retain(|&id, _| id != *ID);
 			*PEERS.write() = hey.peers;
+			*RETAINS.write() = hey.retains;
 		}
 	}
 }
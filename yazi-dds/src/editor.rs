@@ -0,0 +1,38 @@
+use parking_lot::RwLock;
+use yazi_shared::fs::Url;
+
+use crate::{body::{BodyEditor, BodyOpen}, Client, PEERS};
+
+// The editor attached to this instance, and the peer ID of it
+static EDITOR: RwLock<Option<(u64, BodyEditor)>> = RwLock::new(None);
+
+// The protocol between Yazi and an editor that embeds it, on top of DDS:
+//   1. The editor connects as a peer, with `open` in the abilities of its `hi`, and sends
+//      `editor,<yazi-id>,<its-id>,{"name":"nvim","server":"<address>"}` to attach itself;
+//   2. Yazi sends `open` with the `urls` to the editor, instead of opening them by itself;
+//   3. The editor sends `reveal` with the `urls` to have them shown, e.g. its current buffer;
+//   4. `hover`, `cd` and the other events are available as usual, by adding them to the abilities.
+// The editor is detached once it leaves, which is told by the `hey` of the server.
+pub struct Editor;
+
+impl Editor {
+	pub fn attach(id: u64, editor: BodyEditor) { *EDITOR.write() = Some((id, editor)); }
+
+	// The name and the server address of the editor attached, if it's still there
+	pub fn attached() -> Option<BodyEditor> {
+		let editor = EDITOR.read();
+		let (id, editor) = editor.as_ref()?;
+		PEERS.read().get(id).is_some_and(|p| p.able("open")).then(|| editor.clone())
+	}
+
+	// Hands the files over to the editor, returns `false` if none is attached
+	pub fn open(urls: &Vec<Url>) -> bool {
+		let Some(id) = EDITOR.read().as_ref().map(|&(id, _)| id) else { return false };
+		if !PEERS.read().get(&id).is_some_and(|p| p.able("open")) {
+			return false;
+		}
+
+		Client::push(BodyOpen::borrowed(urls).with_receiver(id));
+		true
+	}
+}
@@ -0,0 +1,63 @@
+use std::{os::unix::ffi::OsStringExt, path::PathBuf};
+
+use percent_encoding::percent_decode_str;
+use tracing::error;
+use yazi_shared::fs::Url;
+use zbus::{connection::Builder, fdo::RequestNameFlags, interface};
+
+use crate::{body::BodyReveal, Pubsub};
+
+const NAME: &str = "org.freedesktop.FileManager1";
+const PATH: &str = "/org/freedesktop/FileManager1";
+
+pub(super) struct FileManager1;
+
+#[interface(name = "org.freedesktop.FileManager1")]
+impl FileManager1 {
+	async fn show_folders(&self, uris: Vec<String>, _startup_id: String) { Self::reveal(uris, true) }
+
+	async fn show_items(&self, uris: Vec<String>, _startup_id: String) { Self::reveal(uris, false) }
+
+	async fn show_item_properties(&self, uris: Vec<String>, _startup_id: String) {
+		Self::reveal(uris, false)
+	}
+}
+
+impl FileManager1 {
+	pub(super) fn serve() {
+		tokio::spawn(async {
+			if let Err(e) = Self::run().await {
+				error!("Failed to serve {NAME}: {e}");
+			}
+		});
+	}
+
+	// Each instance queues for the name, so the latest one owns it, and the one before takes
+	// over once it quits.
+	async fn run() -> zbus::Result<()> {
+		let conn = Builder::session()?.serve_at(PATH, Self)?.build().await?;
+		conn
+			.request_name_with_flags(
+				NAME,
+				RequestNameFlags::AllowReplacement | RequestNameFlags::ReplaceExisting,
+			)
+			.await?;
+
+		std::future::pending::<()>().await;
+		Ok(())
+	}
+
+	fn reveal(uris: Vec<String>, folders: bool) {
+		let urls: Vec<_> = uris
+			.iter()
+			.filter_map(|s| s.strip_prefix("file://"))
+			.map(|s| s.strip_prefix("localhost").unwrap_or(s))
+			.map(|s| PathBuf::from(std::ffi::OsString::from_vec(percent_decode_str(s).collect())))
+			.map(Url::from)
+			.collect();
+
+		if !urls.is_empty() {
+			Pubsub::pub_(BodyReveal::owned(urls, folders));
+		}
+	}
+}
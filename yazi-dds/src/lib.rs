@@ -1,22 +1,30 @@
 #![allow(clippy::option_map_unit_fn)]
 pub mod body;
 mod client;
+mod editor;
+#[cfg(all(unix, not(target_os = "macos")))]
+mod file_manager1;
 mod payload;
 mod pubsub;
 mod pump;
+mod rpc;
 mod sendable;
 mod server;
 mod state;
 mod stream;
+mod websocket;
 
 pub use client::*;
+pub use editor::*;
 pub use payload::*;
 pub use pubsub::*;
 pub use pump::*;
+use rpc::*;
 pub use sendable::*;
 use server::*;
 pub use state::*;
 use stream::*;
+use websocket::*;
 
 #[cfg(unix)]
 pub static USERS_CACHE: yazi_shared::RoCell<uzers::UsersCache> = yazi_shared::RoCell::new();
@@ -27,17 +35,22 @@ pub fn init() {
 	// Client
 	ID.init(yazi_boot::ARGS.client_id.unwrap_or(yazi_shared::timestamp_us()));
 	PEERS.with(<_>::default);
+	RETAINS.with(<_>::default);
 	QUEUE_TX.init(tx);
 	QUEUE_RX.init(rx);
 
 	// Server
 	CLIENTS.with(<_>::default);
+	RETAINED.with(<_>::default);
 	STATE.with(<_>::default);
 
 	// Pubsub
 	LOCAL.with(<_>::default);
 	REMOTE.with(<_>::default);
 
+	// Rpc
+	SUBSCRIBERS.with(<_>::default);
+
 	#[cfg(unix)]
 	USERS_CACHE.with(<_>::default);
 
@@ -55,6 +68,23 @@ pub fn init() {
 pub fn serve() {
 	Pump::serve();
 	Client::serve();
+
+	if yazi_config::DDS.rpc {
+		Rpc::serve();
+	}
+	if !yazi_config::DDS.websocket.is_empty() {
+		WebSocket::serve();
+	}
+
+	#[cfg(all(unix, not(target_os = "macos")))]
+	if yazi_config::DDS.file_manager1 {
+		file_manager1::FileManager1::serve();
+	}
 }
 
-pub async fn shutdown() { Pump::shutdown().await; }
+pub async fn shutdown() {
+	Pump::shutdown().await;
+	if yazi_config::DDS.rpc {
+		Rpc::shutdown();
+	}
+}
@@ -4,7 +4,7 @@ use anyhow::{anyhow, Result};
 use yazi_boot::BOOT;
 use yazi_shared::{emit, event::Cmd, Layer};
 
-use crate::{body::Body, ID};
+use crate::{body::Body, Rpc, ID};
 
 #[derive(Debug)]
 pub struct Payload<'a> {
@@ -46,6 +46,12 @@ impl<'a> Payload<'a> {
 impl Payload<'static> {
 	pub(super) fn emit(self) {
 		self.try_flush();
+
+		// The local system events have been notified by `Pubsub` already, with the full body
+		let local = self.sender == *ID && !matches!(self.body, Body::Custom(_));
+		if !local && Rpc::wants(self.body.kind()) {
+			Rpc::notify(&self);
+		}
 		emit!(Call(Cmd::new("accept_payload").with_any("payload", self), Layer::App));
 	}
 }
@@ -82,12 +88,22 @@ impl Display for Payload<'_> {
 			Body::Bye(b) => serde_json::to_string(b),
 			Body::Cd(b) => serde_json::to_string(b),
 			Body::Hover(b) => serde_json::to_string(b),
+			Body::Tab(b) => serde_json::to_string(b),
+			Body::Select(b) => serde_json::to_string(b),
 			Body::Rename(b) => serde_json::to_string(b),
 			Body::Bulk(b) => serde_json::to_string(b),
 			Body::Yank(b) => serde_json::to_string(b),
+			Body::Bookmark(b) => serde_json::to_string(b),
 			Body::Move(b) => serde_json::to_string(b),
+			Body::Duplicate(b) => serde_json::to_string(b),
 			Body::Trash(b) => serde_json::to_string(b),
 			Body::Delete(b) => serde_json::to_string(b),
+			Body::Reveal(b) => serde_json::to_string(b),
+			Body::Editor(b) => serde_json::to_string(b),
+			Body::Open(b) => serde_json::to_string(b),
+			Body::OpStart(b) => serde_json::to_string(b),
+			Body::OpProgress(b) => serde_json::to_string(b),
+			Body::OpDone(b) => serde_json::to_string(b),
 			Body::Custom(b) => serde_json::to_string(b),
 		};
 
@@ -1,11 +1,11 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use mlua::Function;
 use parking_lot::RwLock;
 use yazi_boot::BOOT;
 use yazi_shared::{fs::Url, RoCell};
 
-use crate::{body::{Body, BodyBulk, BodyCd, BodyDelete, BodyHi, BodyHover, BodyMove, BodyMoveItem, BodyRename, BodyTrash, BodyYank}, Client, ID, PEERS};
+use crate::{body::{Body, BodyBookmark, BodyBulk, BodyCd, BodyDelete, BodyDuplicate, BodyDuplicateItem, BodyHi, BodyHover, BodyMove, BodyMoveItem, BodyOpDone, BodyOpProgress, BodyOpStart, BodyRename, BodySelect, BodyTab, BodyTrash, BodyYank}, Client, Rpc, ID, PEERS};
 
 pub static LOCAL: RoCell<RwLock<HashMap<String, HashMap<String, Function<'static>>>>> =
 	RoCell::new();
@@ -72,68 +72,123 @@ impl Pubsub {
 			return Self::pub_(body);
 		}
 
-		let (kind, peers) = (body.kind(), PEERS.read());
-		if receiver == 0 && peers.values().any(|c| c.able(kind)) {
+		let kind = body.kind();
+		if receiver == 0 && Client::wanted(kind) {
 			Client::push(body);
-		} else if peers.get(&receiver).is_some_and(|c| c.able(kind)) {
+		} else if PEERS.read().get(&receiver).is_some_and(|c| c.able(kind)) {
 			Client::push(body.with_receiver(receiver));
 		}
 	}
 
 	pub fn pub_from_hi() -> bool {
 		let abilities = REMOTE.read().keys().cloned().collect();
-		let abilities = BOOT.remote_events.union(&abilities).map(|s| s.as_str()).collect();
+		let mut abilities: HashSet<_> =
+			BOOT.remote_events.union(&abilities).map(|s| s.as_str()).collect();
+
+		// Always handled by Yazi itself, sent by the editors that embed it
+		abilities.extend(["editor", "reveal"]);
 
 		Client::push(BodyHi::borrowed(abilities));
 		true
 	}
 
+	// Only to the RPC subscribers, as it changes too often to go through DDS
+	pub fn pub_from_progress(progress: impl serde::Serialize) {
+		if Rpc::wants("progress") {
+			if let Ok(body) = serde_json::to_value(progress) {
+				Rpc::notify_value("progress", *ID, body);
+			}
+		}
+	}
+
 	pub fn pub_from_cd(tab: usize, url: &Url) {
 		if LOCAL.read().contains_key("cd") {
 			Self::pub_(BodyCd::dummy(tab));
 		}
-		if PEERS.read().values().any(|p| p.able("cd")) {
+		if Client::wanted("cd") {
 			Client::push(BodyCd::borrowed(tab, url));
 		}
 		if BOOT.local_events.contains("cd") {
 			BodyCd::borrowed(tab, url).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("cd") {
+			Rpc::notify(&BodyCd::borrowed(tab, url).with_receiver(*ID));
+		}
 	}
 
 	pub fn pub_from_hover(tab: usize, url: Option<&Url>) {
 		if LOCAL.read().contains_key("hover") {
 			Self::pub_(BodyHover::dummy(tab));
 		}
-		if PEERS.read().values().any(|p| p.able("hover")) {
+		if Client::wanted("hover") {
 			Client::push(BodyHover::borrowed(tab, url));
 		}
 		if BOOT.local_events.contains("hover") {
 			BodyHover::borrowed(tab, url).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("hover") {
+			Rpc::notify(&BodyHover::borrowed(tab, url).with_receiver(*ID));
+		}
+	}
+
+	pub fn pub_from_tab(idx: usize) {
+		if LOCAL.read().contains_key("tab") {
+			Self::pub_(BodyTab::owned(idx));
+		}
+		if Client::wanted("tab") {
+			Client::push(BodyTab::owned(idx));
+		}
+		if BOOT.local_events.contains("tab") {
+			BodyTab::owned(idx).with_receiver(*ID).flush();
+		}
+		if Rpc::wants("tab") {
+			Rpc::notify(&BodyTab::owned(idx).with_receiver(*ID));
+		}
+	}
+
+	pub fn pub_from_select<'a>(tab: usize, urls: impl Iterator<Item = &'a Url> + Clone) {
+		if LOCAL.read().contains_key("select") {
+			Self::pub_(BodySelect::dummy(tab));
+		}
+		if Client::wanted("select") {
+			Client::push(BodySelect::borrowed(tab, urls.clone()));
+		}
+		if BOOT.local_events.contains("select") {
+			BodySelect::borrowed(tab, urls.clone()).with_receiver(*ID).flush();
+		}
+		if Rpc::wants("select") {
+			Rpc::notify(&BodySelect::borrowed(tab, urls).with_receiver(*ID));
+		}
 	}
 
 	pub fn pub_from_rename(tab: usize, from: &Url, to: &Url) {
 		if LOCAL.read().contains_key("rename") {
 			Self::pub_(BodyRename::dummy(tab, from, to));
 		}
-		if PEERS.read().values().any(|p| p.able("rename")) {
+		if Client::wanted("rename") {
 			Client::push(BodyRename::borrowed(tab, from, to));
 		}
 		if BOOT.local_events.contains("rename") {
 			BodyRename::borrowed(tab, from, to).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("rename") {
+			Rpc::notify(&BodyRename::borrowed(tab, from, to).with_receiver(*ID));
+		}
 	}
 
 	pub fn pub_from_bulk(changes: HashMap<&Url, &Url>) {
 		if LOCAL.read().contains_key("bulk") {
 			Self::pub_(BodyBulk::owned(&changes));
 		}
-		if PEERS.read().values().any(|p| p.able("bulk")) {
+		if Client::wanted("bulk") {
 			Client::push(BodyBulk::borrowed(&changes));
 		}
 		if BOOT.local_events.contains("bulk") {
 			BodyBulk::borrowed(&changes).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("bulk") {
+			Rpc::notify(&BodyBulk::borrowed(&changes).with_receiver(*ID));
+		}
 	}
 
 	pub fn pub_from_yank(cut: bool, urls: &HashSet<Url>) {
@@ -146,48 +201,120 @@ impl Pubsub {
 		if BOOT.local_events.contains("@yank") {
 			BodyYank::borrowed(cut, urls).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("@yank") {
+			Rpc::notify(&BodyYank::borrowed(cut, urls).with_receiver(*ID));
+		}
+	}
+
+	pub fn pub_from_bookmark(marks: &BTreeMap<String, Url>) {
+		if LOCAL.read().contains_key("@bookmark") {
+			Self::pub_(BodyBookmark::dummy());
+		}
+		if Self::own_static_ability("@bookmark") {
+			Client::push(BodyBookmark::borrowed(marks));
+		}
+		if BOOT.local_events.contains("@bookmark") {
+			BodyBookmark::borrowed(marks).with_receiver(*ID).flush();
+		}
+		if Rpc::wants("@bookmark") {
+			Rpc::notify(&BodyBookmark::borrowed(marks).with_receiver(*ID));
+		}
 	}
 
 	pub(super) fn pub_from_move(items: Vec<BodyMoveItem>) {
-		if PEERS.read().values().any(|p| p.able("move")) {
+		if Client::wanted("move") {
 			Client::push(BodyMove::borrowed(&items));
 		}
 		if BOOT.local_events.contains("move") {
 			BodyMove::borrowed(&items).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("move") {
+			Rpc::notify(&BodyMove::borrowed(&items).with_receiver(*ID));
+		}
 		if LOCAL.read().contains_key("move") {
 			Self::pub_(BodyMove::owned(items));
 		}
 	}
 
+	pub(super) fn pub_from_duplicate(items: Vec<BodyDuplicateItem>) {
+		if Client::wanted("duplicate") {
+			Client::push(BodyDuplicate::borrowed(&items));
+		}
+		if BOOT.local_events.contains("duplicate") {
+			BodyDuplicate::borrowed(&items).with_receiver(*ID).flush();
+		}
+		if Rpc::wants("duplicate") {
+			Rpc::notify(&BodyDuplicate::borrowed(&items).with_receiver(*ID));
+		}
+		if LOCAL.read().contains_key("duplicate") {
+			Self::pub_(BodyDuplicate::owned(items));
+		}
+	}
+
 	pub(super) fn pub_from_trash(urls: Vec<Url>) {
-		if PEERS.read().values().any(|p| p.able("trash")) {
+		if Client::wanted("trash") {
 			Client::push(BodyTrash::borrowed(&urls));
 		}
 		if BOOT.local_events.contains("trash") {
 			BodyTrash::borrowed(&urls).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("trash") {
+			Rpc::notify(&BodyTrash::borrowed(&urls).with_receiver(*ID));
+		}
 		if LOCAL.read().contains_key("trash") {
 			Self::pub_(BodyTrash::owned(urls));
 		}
 	}
 
 	pub(super) fn pub_from_delete(urls: Vec<Url>) {
-		if PEERS.read().values().any(|p| p.able("delete")) {
+		if Client::wanted("delete") {
 			Client::push(BodyDelete::borrowed(&urls));
 		}
 		if BOOT.local_events.contains("delete") {
 			BodyDelete::borrowed(&urls).with_receiver(*ID).flush();
 		}
+		if Rpc::wants("delete") {
+			Rpc::notify(&BodyDelete::borrowed(&urls).with_receiver(*ID));
+		}
 		if LOCAL.read().contains_key("delete") {
 			Self::pub_(BodyDelete::owned(urls));
 		}
 	}
 
+	pub fn pub_from_op_start(id: usize, op: &str, from: &Url, to: Option<&Url>) {
+		Self::pub_from_op("op-start", || BodyOpStart::owned(id, op, from, to));
+	}
+
+	#[inline]
+	pub fn pub_from_op_progress(body: BodyOpProgress) {
+		Self::pub_from_op("op-progress", || body.clone().into());
+	}
+
+	#[inline]
+	pub fn pub_from_op_done(body: BodyOpDone) {
+		Self::pub_from_op("op-done", || body.clone().into());
+	}
+
+	// The lifecycle of the file operations, built lazily as there's rarely a subscriber of them
+	fn pub_from_op(kind: &str, body: impl Fn() -> Body<'static>) {
+		if Client::wanted(kind) {
+			Client::push(body());
+		}
+		if BOOT.local_events.contains(kind) {
+			body().with_receiver(*ID).flush();
+		}
+		if Rpc::wants(kind) {
+			Rpc::notify(&body().with_receiver(*ID));
+		}
+		if LOCAL.read().contains_key(kind) {
+			Self::pub_(body());
+		}
+	}
+
 	#[inline]
 	fn own_static_ability(kind: &str) -> bool {
 		REMOTE.read().contains_key(kind)  // Owned abilities
-			|| PEERS.read().values().any(|p| p.able(kind))  // Remote peers' abilities
+			|| Client::wanted(kind)  // Remote peers' abilities, or the ones retained by the server
 			|| BOOT.remote_events.contains(kind) // Owned abilities from the command-line argument
 	}
 }
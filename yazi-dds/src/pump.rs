@@ -6,10 +6,11 @@ use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use tokio_util::sync::CancellationToken;
 use yazi_shared::{fs::Url, RoCell};
 
-use crate::{body::BodyMoveItem, Pubsub};
+use crate::{body::{BodyDuplicateItem, BodyMoveItem}, Pubsub};
 
 static CT: RoCell<CancellationToken> = RoCell::new();
 static MOVE_TX: Mutex<Option<mpsc::UnboundedSender<BodyMoveItem>>> = Mutex::new(None);
+static DUPLICATE_TX: Mutex<Option<mpsc::UnboundedSender<BodyDuplicateItem>>> = Mutex::new(None);
 static TRASH_TX: Mutex<Option<mpsc::UnboundedSender<Url>>> = Mutex::new(None);
 static DELETE_TX: Mutex<Option<mpsc::UnboundedSender<Url>>> = Mutex::new(None);
 
@@ -23,6 +24,13 @@ impl Pump {
 		}
 	}
 
+	#[inline]
+	pub fn push_duplicate(from: Url, to: Url) {
+		if let Some(tx) = &*DUPLICATE_TX.lock() {
+			tx.send(BodyDuplicateItem { from, to }).ok();
+		}
+	}
+
 	#[inline]
 	pub fn push_trash(target: Url) {
 		if let Some(tx) = &*TRASH_TX.lock() {
@@ -39,29 +47,35 @@ impl Pump {
 
 	pub(super) fn serve() {
 		let (move_tx, move_rx) = mpsc::unbounded_channel();
+		let (duplicate_tx, duplicate_rx) = mpsc::unbounded_channel();
 		let (trash_tx, trash_rx) = mpsc::unbounded_channel();
 		let (delete_tx, delete_rx) = mpsc::unbounded_channel();
 
 		CT.with(<_>::default);
 		MOVE_TX.lock().replace(move_tx);
+		DUPLICATE_TX.lock().replace(duplicate_tx);
 		TRASH_TX.lock().replace(trash_tx);
 		DELETE_TX.lock().replace(delete_tx);
 
 		tokio::spawn(async move {
 			let move_rx =
 				UnboundedReceiverStream::new(move_rx).chunks_timeout(1000, Duration::from_millis(500));
+			let duplicate_rx = UnboundedReceiverStream::new(duplicate_rx)
+				.chunks_timeout(1000, Duration::from_millis(500));
 			let trash_rx =
 				UnboundedReceiverStream::new(trash_rx).chunks_timeout(1000, Duration::from_millis(500));
 			let delete_rx =
 				UnboundedReceiverStream::new(delete_rx).chunks_timeout(1000, Duration::from_millis(500));
 
 			pin!(move_rx);
+			pin!(duplicate_rx);
 			pin!(trash_rx);
 			pin!(delete_rx);
 
 			loop {
 				select! {
 					Some(items) = move_rx.next() => Pubsub::pub_from_move(items),
+					Some(items) = duplicate_rx.next() => Pubsub::pub_from_duplicate(items),
 					Some(urls) = trash_rx.next() => Pubsub::pub_from_trash(urls),
 					Some(urls) = delete_rx.next() => Pubsub::pub_from_delete(urls),
 					else => {
@@ -75,6 +89,7 @@ impl Pump {
 
 	pub(super) async fn shutdown() {
 		drop(MOVE_TX.lock().take());
+		drop(DUPLICATE_TX.lock().take());
 		drop(TRASH_TX.lock().take());
 		drop(DELETE_TX.lock().take());
 		CT.cancelled().await;
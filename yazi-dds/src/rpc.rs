@@ -0,0 +1,224 @@
+use std::{collections::{HashMap, HashSet}, str::FromStr, sync::atomic::{AtomicU64, Ordering}};
+
+use anyhow::Result;
+use parking_lot::RwLock;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::{io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader}, select, sync::{mpsc, oneshot}};
+use tracing::error;
+use yazi_shared::{emit, event::Cmd, Layer, RoCell};
+
+use crate::{Payload, ID};
+
+pub(super) static SUBSCRIBERS: RoCell<RwLock<HashMap<u64, Subscriber>>> = RoCell::new();
+static CONN: AtomicU64 = AtomicU64::new(1);
+
+pub(super) struct Subscriber {
+	kinds: HashSet<String>,
+	tx:    mpsc::UnboundedSender<String>,
+}
+
+#[derive(Deserialize)]
+struct Request {
+	id:     Option<Value>,
+	method: String,
+	#[serde(default)]
+	params: Value,
+}
+
+// A JSON-RPC 2.0 server on a socket of this instance, one request or response per line,
+// also served over WebSocket by `WebSocket`:
+//   - `state`: the tabs, with the cwd, hovered and selected files of each;
+//   - `exec`: runs a command, e.g. `{ "cmd": "cd ~/Downloads", "layer": "manager" }`;
+//   - `subscribe` / `unsubscribe`: the kinds of DDS events to be notified as `event`, `*` for all.
+pub(super) struct Rpc;
+
+impl Rpc {
+	pub(super) fn serve() {
+		let path = Self::path();
+		std::env::set_var("YAZI_RPC", &path);
+
+		tokio::spawn(async move {
+			if let Err(e) = Self::listen(path).await {
+				error!("Failed to serve the RPC socket: {e}");
+			}
+		});
+	}
+
+	pub(super) fn shutdown() {
+		#[cfg(unix)]
+		std::fs::remove_file(Self::path()).ok();
+	}
+
+	#[inline]
+	pub(super) fn wants(kind: &str) -> bool {
+		SUBSCRIBERS.read().values().any(|s| s.kinds.contains(kind) || s.kinds.contains("*"))
+	}
+
+	pub(super) fn notify(payload: &Payload) {
+		if let Ok(body) = serde_json::to_value(&payload.body) {
+			Self::notify_value(payload.body.kind(), payload.sender, body);
+		}
+	}
+
+	pub(super) fn notify_value(kind: &str, sender: u64, body: Value) {
+		let params = json!({ "kind": kind, "sender": sender, "body": body });
+		let s = format!("{}\n", json!({ "jsonrpc": "2.0", "method": "event", "params": params }));
+		for sub in SUBSCRIBERS.read().values() {
+			if sub.kinds.contains(kind) || sub.kinds.contains("*") {
+				sub.tx.send(s.clone()).ok();
+			}
+		}
+	}
+
+	#[cfg(unix)]
+	async fn listen(path: String) -> Result<()> {
+		tokio::fs::remove_file(&path).await.ok();
+		let listener = tokio::net::UnixListener::bind(&path)?;
+
+		// Only the user can connect, as it runs any command they could
+		use std::os::unix::fs::PermissionsExt;
+		tokio::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).await?;
+
+		loop {
+			let (stream, _) = listener.accept().await?;
+			tokio::spawn(Self::handle(Self::next_conn(), stream));
+		}
+	}
+
+	#[cfg(windows)]
+	async fn listen(path: String) -> Result<()> {
+		use tokio::net::windows::named_pipe::ServerOptions;
+
+		let mut server = ServerOptions::new().first_pipe_instance(true).create(&path)?;
+		loop {
+			server.connect().await?;
+			let stream = std::mem::replace(&mut server, ServerOptions::new().create(&path)?);
+			tokio::spawn(Self::handle(Self::next_conn(), stream));
+		}
+	}
+
+	async fn handle(conn: u64, stream: impl AsyncRead + AsyncWrite) {
+		let (reader, mut writer) = tokio::io::split(stream);
+		let mut lines = BufReader::new(reader).lines();
+		let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+
+		loop {
+			select! {
+				Some(s) = rx.recv() => {
+					if writer.write_all(s.as_bytes()).await.is_err() {
+						break;
+					}
+				}
+				next = lines.next_line() => {
+					let Ok(Some(line)) = next else { break };
+					if line.trim().is_empty() {
+						continue;
+					}
+
+					let Some(resp) = Self::respond(conn, &line, &tx).await else { continue };
+					if writer.write_all(format!("{resp}\n").as_bytes()).await.is_err() {
+						break;
+					}
+				}
+			}
+		}
+
+		Self::leave(conn);
+	}
+
+	#[inline]
+	pub(super) fn next_conn() -> u64 { CONN.fetch_add(1, Ordering::Relaxed) }
+
+	#[inline]
+	pub(super) fn leave(conn: u64) { SUBSCRIBERS.write().remove(&conn); }
+
+	// Replies to the requests only, not to the notifications without an `id`
+	pub(super) async fn respond(
+		conn: u64,
+		line: &str,
+		tx: &mpsc::UnboundedSender<String>,
+	) -> Option<Value> {
+		let req = match serde_json::from_str::<Request>(line) {
+			Ok(req) => req,
+			Err(e) => return Some(Self::error(Value::Null, -32700, e)),
+		};
+
+		let result = match req.method.as_str() {
+			"state" => Self::state().await,
+			"exec" => Self::exec(req.params),
+			"subscribe" => Self::subscribe(conn, req.params, tx),
+			"unsubscribe" => Self::unsubscribe(conn, req.params),
+			_ => {
+				let e = format!("Method not found: {}", req.method);
+				return req.id.map(|id| Self::error(id, -32601, e));
+			}
+		};
+
+		let id = req.id?;
+		Some(match result {
+			Ok(v) => json!({ "jsonrpc": "2.0", "id": id, "result": v }),
+			Err(e) => Self::error(id, -32602, e),
+		})
+	}
+
+	async fn state() -> Result<Value> {
+		let (tx, rx) = oneshot::channel::<Value>();
+		emit!(Call(Cmd::new("rpc_state").with_any("tx", tx), Layer::Manager));
+		Ok(rx.await?)
+	}
+
+	fn exec(params: Value) -> Result<Value> {
+		#[derive(Deserialize)]
+		struct Params {
+			cmd:   String,
+			layer: Option<String>,
+		}
+
+		let p: Params = serde_json::from_value(params)?;
+		let layer = p.layer.as_deref().map_or(Ok(Layer::Manager), Layer::from_str)?;
+
+		emit!(Call(Cmd::from_str(&p.cmd)?, layer));
+		Ok(Value::Null)
+	}
+
+	fn subscribe(conn: u64, params: Value, tx: &mpsc::UnboundedSender<String>) -> Result<Value> {
+		let kinds: HashSet<String> = serde_json::from_value(params["kinds"].clone())?;
+
+		let mut subs = SUBSCRIBERS.write();
+		let sub =
+			subs.entry(conn).or_insert_with(|| Subscriber { kinds: <_>::default(), tx: tx.clone() });
+		sub.kinds.extend(kinds);
+		Ok(json!(sub.kinds))
+	}
+
+	fn unsubscribe(conn: u64, params: Value) -> Result<Value> {
+		let kinds: HashSet<String> = serde_json::from_value(params["kinds"].clone())?;
+
+		let mut subs = SUBSCRIBERS.write();
+		let Some(sub) = subs.get_mut(&conn) else { return Ok(json!([])) };
+
+		sub.kinds.retain(|k| !kinds.contains(k));
+		let left = json!(sub.kinds);
+		if sub.kinds.is_empty() {
+			subs.remove(&conn);
+		}
+		Ok(left)
+	}
+
+	fn error(id: Value, code: i32, message: impl ToString) -> Value {
+		json!({
+			"jsonrpc": "2.0",
+			"id": id,
+			"error": { "code": code, "message": message.to_string() },
+		})
+	}
+
+	#[cfg(unix)]
+	fn path() -> String {
+		std::env::temp_dir().join(format!(".yazi_rpc-{}.sock", *ID)).to_string_lossy().into_owned()
+	}
+
+	#[cfg(windows)]
+	fn path() -> String { format!(r"\\.\pipe\yazi_rpc-{}", *ID) }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use mlua::{ExternalError, Lua, MultiValue, Table, Value};
-use yazi_shared::{event::{Data, DataKey}, OrderedFloat};
+use yazi_shared::{event::{AnyData, Data, DataKey}, OrderedFloat};
 
 pub struct Sendable;
 
@@ -38,7 +38,9 @@ impl Sendable {
 				if let Ok(t) = ud.take::<yazi_shared::fs::Url>() {
 					Data::Url(t)
 				} else if let Ok(t) = ud.take::<super::body::BodyYankIter>() {
-					Data::Any(Box::new(t))
+					Data::Any(AnyData::new(t))
+				} else if let Ok(t) = ud.take::<super::body::BodyBookmarkIter>() {
+					Data::Any(AnyData::new(t))
 				} else {
 					Err("unsupported userdata included".into_lua_err())?
 				}
@@ -55,22 +57,15 @@ impl Sendable {
 			Data::Number(v) => Value::Number(v),
 			Data::String(v) => Value::String(lua.create_string(v)?),
 			Data::List(v) => Value::Table(Self::list_to_table(lua, v)?),
-			Data::Dict(t) => {
-				let seq_len = t.keys().filter(|&k| !k.is_integer()).count();
-				let table = lua.create_table_with_capacity(seq_len, t.len() - seq_len)?;
-				for (k, v) in t {
-					table.raw_set(Self::key_to_value(lua, k)?, Self::data_to_value(lua, v)?)?;
-				}
-				Value::Table(table)
-			}
+			Data::Dict(t) => Value::Table(Self::dict_to_table(lua, t)?),
 			Data::Url(v) => Value::UserData(lua.create_any_userdata(v)?),
-			Data::Any(v) => {
-				if let Ok(t) = v.downcast::<super::body::BodyYankIter>() {
-					Value::UserData(lua.create_userdata(*t)?)
-				} else {
-					Err("unsupported userdata included".into_lua_err())?
-				}
-			}
+			Data::Any(v) => match v.downcast::<super::body::BodyYankIter>() {
+				Ok(t) => Value::UserData(lua.create_userdata(*t)?),
+				Err(v) => match v.downcast::<super::body::BodyBookmarkIter>() {
+					Ok(t) => Value::UserData(lua.create_userdata(*t)?),
+					Err(_) => Err("unsupported userdata included".into_lua_err())?,
+				},
+			},
 		})
 	}
 
@@ -82,6 +77,15 @@ impl Sendable {
 		lua.create_sequence_from(vec)
 	}
 
+	pub fn dict_to_table(lua: &Lua, data: HashMap<DataKey, Data>) -> mlua::Result<Table> {
+		let seq_len = data.keys().filter(|&k| k.is_integer()).count();
+		let table = lua.create_table_with_capacity(seq_len, data.len() - seq_len)?;
+		for (k, v) in data {
+			table.raw_set(Self::key_to_value(lua, k)?, Self::data_to_value(lua, v)?)?;
+		}
+		Ok(table)
+	}
+
 	pub fn list_to_values(lua: &Lua, data: Vec<Data>) -> mlua::Result<MultiValue> {
 		let mut vec = Vec::with_capacity(data.len());
 		for v in data {
@@ -1,14 +1,28 @@
-use std::{collections::HashMap, str::FromStr, time::Duration};
+use std::{collections::{HashMap, VecDeque}, str::FromStr, sync::atomic::{AtomicU64, Ordering}, time::Duration};
 
 use anyhow::Result;
 use parking_lot::RwLock;
 use tokio::{io::{AsyncBufReadExt, AsyncWriteExt, BufReader}, select, sync::mpsc::{self, UnboundedReceiver}, task::JoinHandle, time};
 use yazi_shared::RoCell;
 
-use crate::{body::{Body, BodyBye, BodyHey}, Client, ClientWriter, Payload, Peer, Stream, STATE};
+use crate::{body::{Body, BodyBye, BodyHey, BodyHi}, Client, ClientWriter, Payload, Peer, Stream, STATE};
 
 pub(super) static CLIENTS: RoCell<RwLock<HashMap<u64, Client>>> = RoCell::new();
 
+// The last events of each kind, in the order they came through, for the late subscribers
+pub(super) static RETAINED: RoCell<RwLock<HashMap<String, Retained>>> = RoCell::new();
+
+// The sender and the payload of each event
+type Retained = VecDeque<(u64, String)>;
+
+static SEQ: AtomicU64 = AtomicU64::new(0);
+
+// Told to the peers along with the others in `hey`, so that the instances send them
+// even when nobody subscribes yet, and they can be retained.
+const RETAIN_KINDS: &[&str] =
+	&["cd", "hover", "tab", "select", "rename", "bulk", "move", "duplicate", "trash", "delete"];
+const RETAIN_MAX: usize = 20;
+
 pub(super) struct Server;
 
 impl Server {
@@ -53,6 +67,10 @@ impl Server {
 								let Some(receiver) = parts.next().and_then(|s| s.parse().ok()) else { continue };
 								let Some(sender) = parts.next().and_then(|s| s.parse::<u64>().ok()) else { continue };
 
+								if receiver == 0 && RETAIN_KINDS.contains(&kind) {
+									Self::retain(kind, &line);
+								}
+
 								let clients = CLIENTS.read();
 								let clients: Vec<_> = if receiver == 0 {
 									clients.values().filter(|c| c.able(kind)).collect()
@@ -95,6 +113,9 @@ impl Server {
 			if let Some(ref state) = *STATE.read() {
 				state.values().for_each(|s| _ = tx.send(s.clone()));
 			}
+			if hi.replay > 0 {
+				Self::replay(&hi, &tx);
+			}
 		}
 
 		let mut clients = CLIENTS.write();
@@ -109,15 +130,35 @@ impl Server {
 	}
 
 	fn handle_hey(clients: &HashMap<u64, Client>) {
-		let payload = format!(
-			"{}\n",
-			Payload::new(BodyHey::owned(
-				clients.values().map(|c| (c.id, Peer::new(&c.abilities))).collect()
-			))
-		);
+		let peers = clients.values().map(|c| (c.id, Peer::new(&c.abilities))).collect();
+		let retains = RETAIN_KINDS.iter().map(|&s| s.to_owned()).collect();
+
+		let payload = format!("{}\n", Payload::new(BodyHey::owned(peers, retains)));
 		clients.values().for_each(|c| _ = c.tx.send(payload.clone()));
 	}
 
+	fn retain(kind: &str, line: &str) {
+		let mut retained = RETAINED.write();
+		let events = retained.entry(kind.to_owned()).or_default();
+		if events.len() >= RETAIN_MAX {
+			events.pop_front();
+		}
+		events.push_back((SEQ.fetch_add(1, Ordering::Relaxed), format!("{line}\n")));
+	}
+
+	fn replay(hi: &BodyHi, tx: &mpsc::UnboundedSender<String>) {
+		let retained = RETAINED.read();
+		let mut events: Vec<_> = hi
+			.abilities
+			.iter()
+			.filter_map(|kind| retained.get(kind.as_ref()))
+			.flat_map(|events| events.iter().skip(events.len().saturating_sub(hi.replay)))
+			.collect();
+
+		events.sort_unstable_by_key(|(seq, _)| *seq);
+		events.into_iter().for_each(|(_, s)| _ = tx.send(s.clone()));
+	}
+
 	async fn handle_bye(id: u64, mut rx: UnboundedReceiver<String>, mut writer: ClientWriter) {
 		while let Ok(payload) = rx.try_recv() {
 			if writer.write_all(payload.as_bytes()).await.is_err() {
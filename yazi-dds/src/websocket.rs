@@ -0,0 +1,101 @@
+use anyhow::Result;
+use futures::{SinkExt, StreamExt};
+use percent_encoding::percent_decode_str;
+use tokio::{net::{TcpListener, TcpStream}, select, sync::mpsc};
+use tokio_tungstenite::{accept_hdr_async, tungstenite::{handshake::server::{ErrorResponse, Request, Response}, http::StatusCode, Message}, WebSocketStream};
+use tracing::error;
+use yazi_config::DDS;
+
+use crate::Rpc;
+
+// The JSON-RPC of `Rpc` over WebSocket, for the browser-based tools and remote dashboards.
+// The token is given as `?token=` in the URL, since browsers can't set the headers of it,
+// or as `Authorization: Bearer` by the others.
+pub(super) struct WebSocket;
+
+impl WebSocket {
+	pub(super) fn serve() {
+		if DDS.websocket_token.is_empty() {
+			return error!("Refused to serve WebSocket on {} without a `websocket_token`", DDS.websocket);
+		}
+
+		tokio::spawn(async {
+			if let Err(e) = Self::listen().await {
+				error!("Failed to serve WebSocket on {}: {e}", DDS.websocket);
+			}
+		});
+	}
+
+	// The response of a refused handshake is what `tungstenite` takes, large as it is
+	#[allow(clippy::result_large_err)]
+	async fn listen() -> Result<()> {
+		let listener = TcpListener::bind(&DDS.websocket).await?;
+		loop {
+			let (stream, _) = listener.accept().await?;
+			tokio::spawn(async move {
+				let ws = accept_hdr_async(stream, |req: &Request, resp: Response| {
+					if Self::authorized(req) { Ok(resp) } else { Err(Self::forbidden()) }
+				});
+				if let Ok(ws) = ws.await {
+					Self::handle(Rpc::next_conn(), ws).await;
+				}
+			});
+		}
+	}
+
+	async fn handle(conn: u64, ws: WebSocketStream<TcpStream>) {
+		let (mut sink, mut stream) = ws.split();
+		let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+
+		loop {
+			select! {
+				Some(s) = rx.recv() => {
+					if sink.send(Message::Text(s.trim_end().to_owned())).await.is_err() {
+						break;
+					}
+				}
+				next = stream.next() => {
+					let Some(Ok(msg)) = next else { break };
+					let Message::Text(text) = msg else {
+						if msg.is_close() { break } else { continue }
+					};
+
+					let Some(resp) = Rpc::respond(conn, &text, &tx).await else { continue };
+					if sink.send(Message::Text(resp.to_string())).await.is_err() {
+						break;
+					}
+				}
+			}
+		}
+
+		Rpc::leave(conn);
+	}
+
+	fn authorized(req: &Request) -> bool {
+		let token = DDS.websocket_token.as_bytes();
+		let query = req.uri().query().unwrap_or_default();
+		for kv in query.split('&') {
+			let Some(v) = kv.strip_prefix("token=") else { continue };
+			let v: Vec<_> = percent_decode_str(v).collect();
+			if Self::token_eq(&v, token) {
+				return true;
+			}
+		}
+
+		let header = req.headers().get("authorization").and_then(|v| v.to_str().ok());
+		header
+			.and_then(|v| v.strip_prefix("Bearer "))
+			.is_some_and(|v| Self::token_eq(v.as_bytes(), token))
+	}
+
+	// Compares all the bytes regardless of where they differ, so the time taken doesn't give it away
+	fn token_eq(a: &[u8], b: &[u8]) -> bool {
+		a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+	}
+
+	fn forbidden() -> ErrorResponse {
+		let mut resp = ErrorResponse::new(Some("Invalid token".to_owned()));
+		*resp.status_mut() = StatusCode::UNAUTHORIZED;
+		resp
+	}
+}
@@ -1,12 +1,15 @@
-use std::{collections::VecDeque, sync::atomic::Ordering};
+use std::{collections::VecDeque, sync::atomic::Ordering, time::Duration};
 
 use anyhow::Result;
 use crossterm::event::KeyEvent;
-use yazi_config::keymap::Key;
-use yazi_core::input::InputMode;
-use yazi_shared::{emit, event::{Cmd, Event, NEED_RENDER}, Layer};
+use yazi_boot::ARGS;
+use yazi_config::{keymap::Key, Checker, MANAGER};
+use yazi_core::{input::InputMode, manager::{Snapshot, Watcher}};
+use yazi_plugin::COMMANDS;
+use yazi_proxy::AppProxy;
+use yazi_shared::{emit, event::{damage_as, Cmd, Event, NEED_RENDER}, render, Layer, PERF};
 
-use crate::{lives::Lives, Ctx, Executor, Router, Signals, Term};
+use crate::{lives::Lives, Appearance, Ctx, Executor, Router, Signals, Term};
 
 pub(crate) struct App {
 	pub(crate) cx:      Ctx,
@@ -16,12 +19,30 @@ pub(crate) struct App {
 
 impl App {
 	pub(crate) async fn serve() -> Result<()> {
-		let term = Term::start()?;
-		let (mut rx, signals) = (Event::take(), Signals::start()?);
+		let term = if ARGS.headless { None } else { Some(Term::start()?) };
+		let (mut rx, mut signals) = (Event::take(), Signals::start()?);
+		if ARGS.headless {
+			signals.stop(None);
+		}
 
 		Lives::register()?;
-		let mut app = Self { cx: Ctx::make(), term: Some(term), signals };
+		if !ARGS.headless {
+			Appearance::watch();
+			Watcher::watch_config();
+			Self::recent_serve();
+			Self::check_config();
+		}
+
+		let mut app = Self { cx: Ctx::make(), term, signals };
 		app.render();
+		PERF.ready();
+		if !ARGS.headless {
+			Snapshot::offer();
+		}
+
+		if let Some(p) = &ARGS.script {
+			Self::headless_load(p)?;
+		}
 
 		let mut times = 0;
 		let mut events = Vec::with_capacity(200);
@@ -49,6 +70,35 @@ impl App {
 		Ok(())
 	}
 
+	// Files stop being recently modified without any event, so re-render for them periodically.
+	fn recent_serve() {
+		tokio::spawn(async {
+			let mut interval = tokio::time::interval(Duration::from_secs(30));
+			loop {
+				interval.tick().await;
+				if MANAGER.load().recent_within > 0 {
+					render!(); // Outside of any layer, so the whole screen is redrawn
+					emit!(Render);
+				}
+			}
+		});
+	}
+
+	// Surfaces the same diagnostics as `ya check-config`, but only the first few of them.
+	fn check_config() {
+		let diagnostics = Checker::check(|s| COMMANDS.get(s).is_some());
+		if diagnostics.is_empty() {
+			return;
+		}
+
+		let mut content: Vec<_> = diagnostics.iter().take(3).map(|d| d.to_string()).collect();
+		if diagnostics.len() > 3 {
+			content.push(format!("...and {} more", diagnostics.len() - 3));
+		}
+		content.push("Run `ya check-config` for details.".to_owned());
+		AppProxy::notify_warn("Config", &content.join("\n"));
+	}
+
 	#[inline]
 	fn dispatch(&mut self, event: Event) -> Result<()> {
 		match event {
@@ -70,6 +120,9 @@ impl App {
 	#[inline]
 	fn dispatch_seq(&mut self, mut cmds: VecDeque<Cmd>, layer: Layer) {
 		if let Some(cmd) = cmds.pop_front() {
+			if layer == Layer::Manager {
+				self.cx.recorder.push(&cmd);
+			}
 			Executor::new(self).execute(cmd, layer);
 		}
 		if !cmds.is_empty() {
@@ -85,10 +138,12 @@ impl App {
 
 	#[inline]
 	fn dispatch_paste(&mut self, str: String) {
-		if self.cx.input.visible {
+		if self.cx.terminal.focused && !self.cx.input.visible {
+			self.cx.terminal.paste(&str);
+		} else if self.cx.input.visible {
 			let input = &mut self.cx.input;
 			if input.mode() == InputMode::Insert {
-				input.type_str(&str);
+				damage_as(Layer::Input, || input.type_str(&str));
 			}
 		}
 	}
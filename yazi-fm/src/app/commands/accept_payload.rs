@@ -1,8 +1,8 @@
 use mlua::IntoLua;
 use tracing::error;
-use yazi_dds::{Payload, LOCAL, REMOTE};
+use yazi_dds::{body::Body, Editor, Payload, LOCAL, REMOTE};
 use yazi_plugin::LUA;
-use yazi_shared::event::Cmd;
+use yazi_shared::{emit, event::Cmd, Layer};
 
 use crate::{app::App, lives::Lives};
 
@@ -12,6 +12,18 @@ impl App {
 			return;
 		};
 
+		// Handled by Yazi itself, besides the plugins that subscribe to it
+		match &payload.body {
+			Body::Reveal(b) => emit!(Call(
+				Cmd::new("show_items")
+					.with_any("urls", b.urls.clone().into_owned())
+					.with_bool("folders", b.folders),
+				Layer::Manager
+			)),
+			Body::Editor(b) => Editor::attach(payload.sender, b.clone()),
+			_ => {}
+		}
+
 		let kind = payload.body.kind().to_owned();
 		let map = if payload.receiver == 0 || payload.receiver != payload.sender {
 			REMOTE.read()
@@ -0,0 +1,113 @@
+use std::{path::Path, str::FromStr, sync::atomic::{AtomicBool, Ordering}, time::Duration};
+
+use anyhow::Result;
+use crossterm::event::{KeyEvent, KeyModifiers};
+use mlua::{ExternalError, ExternalResult, Thread, ThreadStatus, Value};
+use yazi_config::keymap::Key;
+use yazi_plugin::LUA;
+use yazi_shared::{emit, event::{Cmd, Event}, Layer};
+
+use crate::{app::App, lives::Lives};
+
+pub(super) static FAILED: AtomicBool = AtomicBool::new(false);
+
+pub struct Opt {
+	idle: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { idle: c.bool("idle") } }
+}
+
+impl App {
+	// Loads the script as a coroutine, which yields back to the event loop
+	// whenever it calls `headless.sleep()` or `headless.idle()`.
+	pub(crate) fn headless_load(path: &Path) -> Result<()> {
+		let headless = LUA.create_table()?;
+		headless.raw_set(
+			"press",
+			LUA.create_function(|_, keys: Value| {
+				let keys = match keys {
+					Value::String(s) => vec![s.to_str()?.to_owned()],
+					Value::Table(t) => t.sequence_values().collect::<mlua::Result<_>>()?,
+					_ => Err("expected a string or a table of strings".into_lua_err())?,
+				};
+				for s in keys {
+					let key = Key::from_str(&s).into_lua_err()?;
+					Event::Key(Self::key_event(key)).emit();
+				}
+				Ok(())
+			})?,
+		)?;
+		headless.raw_set(
+			"assert",
+			LUA.create_function(|_, (cond, msg): (bool, Option<String>)| {
+				if !cond {
+					eprintln!("assertion failed: {}", msg.as_deref().unwrap_or("no message"));
+					FAILED.store(true, Ordering::Relaxed);
+				}
+				Ok(cond)
+			})?,
+		)?;
+		LUA.globals().raw_set("headless", headless)?;
+
+		LUA
+			.load(
+				r#"
+				function headless.sleep(secs) coroutine.yield(secs) end
+				function headless.idle() coroutine.yield() end
+				"#,
+			)
+			.exec()?;
+
+		let chunk = std::fs::read(path)?;
+		let f = LUA.load(chunk).set_name(path.display().to_string()).into_function()?;
+		LUA.set_named_registry_value("headless", LUA.create_thread(f)?)?;
+
+		Self::headless_wait(None, true);
+		Ok(())
+	}
+
+	pub(crate) fn headless(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if opt.idle && !self.cx.tasks.ongoing().lock().is_empty() {
+			return Self::headless_wait(Some(Duration::from_millis(100)), true);
+		}
+
+		let Ok(thread) = LUA.named_registry_value::<Thread>("headless") else {
+			return;
+		};
+
+		let result = Lives::scope(&self.cx, |_| thread.resume::<_, Option<f64>>(()));
+		match result {
+			Ok(secs) if thread.status() == ThreadStatus::Resumable => match secs {
+				Some(secs) => Self::headless_wait(Some(Duration::from_secs_f64(secs.max(0.0))), false),
+				None => Self::headless_wait(None, true),
+			},
+			Ok(_) => Event::Quit(Default::default()).emit(),
+			Err(e) => {
+				eprintln!("{e}");
+				FAILED.store(true, Ordering::Relaxed);
+				Event::Quit(Default::default()).emit();
+			}
+		}
+	}
+
+	// Waiting for idle lets the events emitted so far be processed first,
+	// then keeps polling until all the tasks are done.
+	fn headless_wait(delay: Option<Duration>, idle: bool) {
+		tokio::spawn(async move {
+			tokio::time::sleep(delay.unwrap_or(Duration::from_millis(50))).await;
+			emit!(Call(Cmd::new("headless").with_bool("idle", idle), Layer::App));
+		});
+	}
+
+	fn key_event(key: Key) -> KeyEvent {
+		let mut modifiers = KeyModifiers::empty();
+		modifiers.set(KeyModifiers::SHIFT, key.shift);
+		modifiers.set(KeyModifiers::CONTROL, key.ctrl);
+		modifiers.set(KeyModifiers::ALT, key.alt);
+		modifiers.set(KeyModifiers::SUPER, key.super_);
+		KeyEvent::new(key.code, modifiers)
+	}
+}
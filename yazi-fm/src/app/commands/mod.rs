@@ -1,8 +1,10 @@
 mod accept_payload;
+mod headless;
 mod mouse;
 mod notify;
 mod plugin;
 mod quit;
+mod reload;
 mod render;
 mod resize;
 mod resume;
@@ -25,7 +25,7 @@ impl App {
 			let area = yazi_plugin::elements::Rect::cast(&LUA, size)?;
 			let root = LUA.globals().raw_get::<_, Table>("Root")?.call_method::<_, Table>("new", area)?;
 
-			if matches!(event.kind, MouseEventKind::Down(_) if MANAGER.mouse_events.draggable()) {
+			if matches!(event.kind, MouseEventKind::Down(_) if MANAGER.load().mouse_events.draggable()) {
 				root.raw_set("_drag_start", evt.clone())?;
 			}
 
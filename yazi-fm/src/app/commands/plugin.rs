@@ -1,10 +1,12 @@
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
-use mlua::TableExt;
+use mlua::{Function, MultiValue, Table, TableExt, Value};
 use scopeguard::defer;
 use tracing::warn;
+use yazi_config::{t, MACROS};
 use yazi_dds::Sendable;
-use yazi_plugin::{loader::LOADER, RtRef, LUA};
+use yazi_plugin::{loader::LOADER, RtRef, COMMANDS, LUA};
+use yazi_proxy::{options::{NotifyLevel, NotifyOpt}, AppProxy};
 use yazi_shared::{emit, event::Cmd, Layer};
 
 use crate::{app::App, lives::Lives};
@@ -31,6 +33,24 @@ impl App {
 		});
 	}
 
+	// Non-built-in commands, either a macro defined in yazi.toml or one registered by a plugin.
+	pub(crate) fn command(&mut self, cmd: Cmd, layer: Layer) {
+		match MACROS.load().expand(&cmd) {
+			Some(Ok(seq)) => {
+				self.cx.recorder.skip(seq.len());
+				emit!(Seq(seq, layer))
+			}
+			Some(Err(e)) => {
+				AppProxy::notify_error(&t!("Macro `{name}`", name = cmd.name), &e.to_string())
+			}
+			None => {
+				if let Ok(opt) = COMMANDS.resolve(cmd) {
+					self.plugin(opt);
+				}
+			}
+		}
+	}
+
 	#[inline]
 	pub(crate) fn _plugin_do(opt: yazi_plugin::Opt) {
 		let cmd: Cmd = opt.into();
@@ -58,8 +78,40 @@ impl App {
 			if let Some(cb) = opt.cb {
 				cb(&LUA, plugin)
 			} else {
-				plugin.call_method("entry", Sendable::list_to_table(&LUA, opt.args)?)
+				plugin.call_method("entry", Sendable::dict_to_table(&LUA, opt.args)?)
 			}
 		});
 	}
+
+	pub(crate) fn plugin_reload(&mut self, mut cmd: Cmd) {
+		let Some(id) = cmd.take_first_str() else { return };
+
+		let reload = || {
+			LUA.named_registry_value::<RtRef>("rt")?.clear(&id);
+			let loaded: Table = LUA.globals().raw_get::<_, Table>("package")?.raw_get("loaded")?;
+			loaded.raw_set(id.as_str(), Value::Nil)?;
+
+			let setup =
+				LUA.named_registry_value::<Table>("setups").and_then(|t| t.raw_get::<_, Table>(id.as_str()));
+			let Ok(args) = setup else {
+				LUA.named_registry_value::<RtRef>("rt")?.push(&id);
+				defer! { _ = LUA.named_registry_value::<RtRef>("rt").map(|mut r| r.pop()) }
+				return LOADER.load(&LUA, &id).map(|_| ());
+			};
+
+			let plugin: Table = LUA.globals().raw_get::<_, Function>("require")?.call(id.as_str())?;
+			plugin.call_method("setup", args.sequence_values().collect::<mlua::Result<MultiValue>>()?)
+		};
+
+		match reload() {
+			Ok(()) => AppProxy::notify(NotifyOpt {
+				title:   "Plugin".to_owned(),
+				content: format!("Reloaded `{id}`"),
+				level:   NotifyLevel::Info,
+				timeout: Duration::from_secs(3),
+				actions: vec![],
+			}),
+			Err(e) => AppProxy::notify_error(&t!("Plugin `{id}`", id = id), &e.to_string()),
+		}
+	}
 }
@@ -1,6 +1,6 @@
-use std::ffi::OsString;
+use std::{ffi::OsString, sync::atomic::Ordering};
 
-use yazi_boot::ARGS;
+use yazi_boot::{ARGS, BOOT};
 use yazi_shared::event::EventQuit;
 
 use crate::{app::App, Term};
@@ -15,11 +15,15 @@ impl App {
 		if !opt.no_cwd_file {
 			self.cwd_to_file();
 		}
+		let chosen = opt.selected.is_some();
 		if let Some(selected) = opt.selected {
 			self.selected_to_file(selected);
 		}
 
-		Term::goodbye(|| false);
+		if ARGS.headless {
+			std::process::exit(super::headless::FAILED.load(Ordering::Relaxed) as i32);
+		}
+		Term::goodbye(|| BOOT.chooser.is_some() && !chosen);
 	}
 
 	fn cwd_to_file(&self) {
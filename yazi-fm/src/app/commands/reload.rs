@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use yazi_config::t;
+use yazi_plugin::{external::Highlighter, Config, LUA};
+use yazi_proxy::{options::{NotifyLevel, NotifyOpt}, AppProxy};
+use yazi_shared::event::Cmd;
+
+use crate::app::App;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+
+impl App {
+	pub(crate) fn reload(&mut self, _: impl Into<Opt>) {
+		let r = match yazi_config::reload() {
+			Ok(r) => r,
+			Err(e) => {
+				return AppProxy::notify_error("Config", &t!("Failed to reload: {e}", e = format!("{e:#}")));
+			}
+		};
+		if !r.yazi && !r.keymap && !r.theme {
+			return;
+		}
+
+		let installed = Config::new(&LUA).install_manager().and_then(|c| c.install_theme());
+		if let Err(e) = installed {
+			return AppProxy::notify_error("Config", &e.to_string());
+		}
+		if r.theme {
+			Highlighter::reset_theme();
+		}
+
+		self.resize(());
+		AppProxy::notify(NotifyOpt {
+			title:   "Config".to_owned(),
+			content: "Reloaded".to_owned(),
+			level:   NotifyLevel::Info,
+			timeout: Duration::from_secs(3),
+			actions: vec![],
+		});
+	}
+}
@@ -1,11 +1,20 @@
-use std::{io::{stderr, BufWriter}, sync::atomic::Ordering};
+use std::{io::{stderr, BufWriter}, sync::atomic::Ordering, time::Instant};
 
 use crossterm::{execute, queue, terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate}};
 use ratatui::{backend::{Backend, CrosstermBackend}, buffer::Buffer, CompletedFrame};
 use scopeguard::defer;
+use yazi_core::manager::Snapshot;
 use yazi_plugin::elements::COLLISION;
+use yazi_shared::{event::{DAMAGED, REGIONS}, Layer, PERF};
 
-use crate::{app::App, lives::Lives, root::Root};
+use crate::{app::App, components, lives::Lives, root::{Overlays, Root}};
+
+const OVERLAYS: u8 = 1 << Layer::Tasks as u8
+	| 1 << Layer::Select as u8
+	| 1 << Layer::Input as u8
+	| 1 << Layer::Help as u8
+	| 1 << Layer::Completion as u8
+	| 1 << Layer::Which as u8;
 
 impl App {
 	pub(crate) fn render(&mut self) {
@@ -13,18 +22,39 @@ impl App {
 			return;
 		};
 
+		let now = Instant::now();
+		self.cx.terminal.sync(&self.cx.manager.active().current.cwd);
 		queue!(stderr(), BeginSynchronizedUpdate).ok();
 		defer! { execute!(stderr(), EndSynchronizedUpdate).ok(); }
 
+		// The popups are redrawn over the last of the rest, if only they have changed since then,
+		// and so are the components of the manager layer when only some of them have
+		let (damaged, regions) =
+			(DAMAGED.swap(0, Ordering::Relaxed), REGIONS.swap(0, Ordering::Relaxed));
+		let regions = match damaged {
+			0 => u8::MAX,
+			d if d & OVERLAYS == d => 0,
+			_ => regions,
+		};
+
 		let collision = COLLISION.swap(false, Ordering::Relaxed);
 		let frame = term
-			.draw(|f| {
-				_ = Lives::scope(&self.cx, |_| Ok(f.render_widget(Root::new(&self.cx), f.size())));
-
-				if let Some((x, y)) = self.cx.cursor() {
-					f.set_cursor(x, y);
-				}
-			})
+			.draw_layered(
+				regions,
+				|f| {
+					let (area, pane) = self.cx.terminal.split(f.size());
+					_ = Lives::scope(&self.cx, |_| Ok(f.render_widget(Root::new(&self.cx), area)));
+					if let Some(pane) = pane {
+						f.render_widget(components::Terminal::new(&self.cx), pane);
+					}
+				},
+				|f| {
+					f.render_widget(Overlays::new(&self.cx), f.size());
+					if let Some((x, y)) = self.cx.cursor() {
+						f.set_cursor(x, y);
+					}
+				},
+			)
 			.unwrap();
 
 		if COLLISION.load(Ordering::Relaxed) {
@@ -38,6 +68,11 @@ impl App {
 		if collision && !COLLISION.load(Ordering::Relaxed) {
 			self.cx.manager.peek(true);
 		}
+
+		if PERF.enabled() {
+			PERF.frame(now.elapsed(), self.cx.tasks.ongoing().lock().len());
+		}
+		Snapshot::record(&self.cx.manager, &self.cx.tasks);
 	}
 
 	pub(crate) fn render_notify(&mut self) {
@@ -0,0 +1,164 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use yazi_config::THEME;
+use yazi_proxy::ManagerProxy;
+
+// 0: unknown, 1: dark, 2: light
+static BACKGROUND: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Appearance {
+	Dark,
+	Light,
+}
+
+// The command, its arguments, and the parser of its output
+type Query = (&'static str, &'static [&'static str], fn(&str) -> Option<Appearance>);
+
+impl Appearance {
+	// Parses the `OSC 11` response, e.g. `\x1b]11;rgb:1e1e/1e1e/2e2e\x1b\\`
+	pub(super) fn store_osc11(resp: &str) {
+		let Some((_, s)) = resp.split_once("\x1b]11;rgb:") else { return };
+
+		let mut rgb = s.splitn(3, '/').map(|c| {
+			let hex: String = c.chars().take_while(char::is_ascii_hexdigit).collect();
+			u16::from_str_radix(&hex, 16).ok().map(|v| v as f64 / (16f64.powi(hex.len() as i32) - 1.0))
+		});
+
+		let (Some(Some(r)), Some(Some(g)), Some(Some(b))) = (rgb.next(), rgb.next(), rgb.next()) else {
+			return;
+		};
+
+		let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+		BACKGROUND.store(if luma < 0.5 { 1 } else { 2 }, Ordering::Relaxed);
+	}
+
+	fn terminal() -> Option<Self> {
+		match BACKGROUND.load(Ordering::Relaxed) {
+			1 => Some(Self::Dark),
+			2 => Some(Self::Light),
+			_ => None,
+		}
+	}
+
+	// Applies the flavor matching the terminal background at startup, and then
+	// keeps following the OS dark mode if the platform reports one.
+	pub(super) fn watch() {
+		let (dark, light) = (THEME.load().flavor.dark.clone(), THEME.load().flavor.light.clone());
+		if dark.is_empty() && light.is_empty() {
+			return;
+		}
+
+		let apply = move |a: Self| {
+			let name = if a == Self::Dark { &dark } else { &light };
+			if !name.is_empty() {
+				ManagerProxy::flavor(name);
+			}
+		};
+
+		tokio::spawn(async move {
+			let last = Self::terminal().or(Self::os().await);
+			if let Some(a) = last {
+				apply(a);
+			}
+			Self::follow(last, apply).await;
+		});
+	}
+
+	// Follows the `color-scheme` of GNOME through the changes dconf reports to `gsettings monitor`
+	#[cfg(not(any(target_os = "macos", windows)))]
+	async fn follow(mut last: Option<Self>, apply: impl Fn(Self)) {
+		use std::process::Stdio;
+
+		use tokio::io::{AsyncBufReadExt, BufReader};
+
+		let Ok(mut child) = tokio::process::Command::new("gsettings")
+			.args(["monitor", "org.gnome.desktop.interface", "color-scheme"])
+			.stdin(Stdio::null())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null())
+			.kill_on_drop(true)
+			.spawn()
+		else {
+			return;
+		};
+		let Some(stdout) = child.stdout.take() else { return };
+
+		let mut lines = BufReader::new(stdout).lines();
+		while let Ok(Some(line)) = lines.next_line().await {
+			let now = Self::gsettings(&line).or(Self::terminal());
+			if let Some(now) = now.filter(|&n| last != Some(n)) {
+				last = Some(now);
+				apply(now);
+			}
+		}
+	}
+
+	// Neither of them reports the changes to the command line, so they're polled instead
+	#[cfg(any(target_os = "macos", windows))]
+	async fn follow(mut last: Option<Self>, apply: impl Fn(Self)) {
+		loop {
+			tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+			let Some(now) = Self::os().await else {
+				if last.is_none() {
+					break;
+				}
+				continue;
+			};
+			if last != Some(now) {
+				last = Some(now);
+				apply(now);
+			}
+		}
+	}
+
+	async fn os() -> Option<Self> {
+		#[cfg(target_os = "macos")]
+		let (cmd, args, parse): Query = ("defaults", &["read", "-g", "AppleInterfaceStyle"], |s| {
+			Some(if s.contains("Dark") { Self::Dark } else { Self::Light })
+		});
+
+		#[cfg(windows)]
+		let (cmd, args, parse): Query = (
+			"reg",
+			&[
+				"query",
+				r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+				"/v",
+				"AppsUseLightTheme",
+			],
+			|s| Some(if s.contains("0x0") { Self::Dark } else { Self::Light }),
+		);
+
+		#[cfg(not(any(target_os = "macos", windows)))]
+		let (cmd, args, parse): Query =
+			("gsettings", &["get", "org.gnome.desktop.interface", "color-scheme"], Self::gsettings);
+
+		let output = tokio::process::Command::new(cmd)
+			.args(args)
+			.stdin(std::process::Stdio::null())
+			.stderr(std::process::Stdio::null())
+			.kill_on_drop(true)
+			.output()
+			.await
+			.ok()?;
+
+		// macOS doesn't set `AppleInterfaceStyle` at all in light mode
+		if !output.status.success() {
+			return if cfg!(target_os = "macos") { Some(Self::Light) } else { None };
+		}
+		parse(&String::from_utf8_lossy(&output.stdout))
+	}
+
+	// `'default'` is no preference at all, which leaves it to the terminal background
+	#[cfg(not(any(target_os = "macos", windows)))]
+	fn gsettings(s: &str) -> Option<Self> {
+		if s.contains("prefer-dark") {
+			Some(Self::Dark)
+		} else if s.contains("prefer-light") {
+			Some(Self::Light)
+		} else {
+			None
+		}
+	}
+}
@@ -24,16 +24,16 @@ impl<'a> Widget for Completion<'a> {
 			.enumerate()
 			.map(|(i, x)| {
 				let icon = if x.ends_with(MAIN_SEPARATOR) {
-					&THEME.completion.icon_folder
+					&THEME.load().completion.icon_folder
 				} else {
-					&THEME.completion.icon_file
+					&THEME.load().completion.icon_file
 				};
 
 				let mut item = ListItem::new(format!(" {icon} {x}"));
 				if i == self.cx.completion.rel_cursor() {
-					item = item.style(THEME.completion.active);
+					item = item.style(THEME.load().completion.active);
 				} else {
-					item = item.style(THEME.completion.inactive);
+					item = item.style(THEME.load().completion.inactive);
 				}
 
 				item
@@ -58,7 +58,9 @@ impl<'a> Widget for Completion<'a> {
 		yazi_plugin::elements::Clear::default().render(area, buf);
 		List::new(items)
 			.block(
-				Block::bordered().border_type(BorderType::Rounded).border_style(THEME.completion.border),
+				Block::bordered()
+					.border_type(BorderType::Rounded)
+					.border_style(THEME.load().completion.border),
 			)
 			.render(area, buf);
 	}
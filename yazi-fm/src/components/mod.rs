@@ -1,7 +1,11 @@
 #![allow(clippy::module_inception)]
 
+mod perf;
 mod preview;
 mod progress;
+mod terminal;
 
+pub(super) use perf::*;
 pub(super) use preview::*;
 pub(super) use progress::*;
+pub(super) use terminal::*;
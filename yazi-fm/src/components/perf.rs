@@ -0,0 +1,30 @@
+use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{Block, BorderType, Paragraph, Widget}};
+use yazi_shared::{Report, PERF};
+
+pub(crate) struct Perf;
+
+impl Widget for Perf {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let report = PERF.report();
+		let mut lines = vec![Line::from(format!("startup  {:>8.1} ms", report.startup))];
+		lines.extend(
+			report.phases.iter().map(|p| Line::from(format!("  {:<7}{:>8.1} ms", p.name, p.ms))),
+		);
+
+		let (avg, max) = Report::stats(report.frames.iter().map(|f| f.ms));
+		lines.push(Line::from(format!("frame    {avg:>8.1} ms, max {max:.1}")));
+		let (avg, max) = Report::stats(report.previews.iter().copied());
+		lines.push(Line::from(format!("preview  {avg:>8.1} ms, max {max:.1}")));
+		let queue = report.frames.back().map_or(0, |f| f.queue);
+		lines.push(Line::from(format!("queue    {queue:>8}")));
+
+		let width = 36.min(area.width);
+		let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(1));
+		let rect = Rect { x: area.right() - width, y: area.y + 1, width, height };
+
+		yazi_plugin::elements::Clear::default().render(rect, buf);
+		Paragraph::new(lines)
+			.block(Block::bordered().border_type(BorderType::Rounded).title("Performance"))
+			.render(rect, buf);
+	}
+}
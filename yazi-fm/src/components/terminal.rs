@@ -0,0 +1,66 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Modifier, Style}, widgets::{Block, Borders, Widget}};
+
+use crate::Ctx;
+
+pub(crate) struct Terminal<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Terminal<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	#[inline]
+	fn color(c: vt100::Color) -> Color {
+		match c {
+			vt100::Color::Default => Color::Reset,
+			vt100::Color::Idx(i) => Color::Indexed(i),
+			vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+		}
+	}
+}
+
+impl<'a> Widget for Terminal<'a> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let term = &self.cx.terminal;
+		let title = if term.focused { " Terminal (<C-\\> to leave) " } else { " Terminal " };
+		let style = Style::default();
+		let style = if term.focused { style } else { style.add_modifier(Modifier::DIM) };
+
+		let block = Block::new().borders(Borders::TOP).border_style(style).title(title);
+		let inner = block.inner(area);
+		yazi_plugin::elements::Clear::default().render(area, buf);
+		block.render(area, buf);
+
+		term.screen(|screen| {
+			for y in 0..inner.height {
+				for x in 0..inner.width {
+					let Some(cell) = screen.cell(y, x) else { continue };
+					if cell.is_wide_continuation() {
+						continue;
+					}
+
+					let mut style =
+						Style::default().fg(Self::color(cell.fgcolor())).bg(Self::color(cell.bgcolor()));
+					if cell.bold() {
+						style = style.add_modifier(Modifier::BOLD);
+					}
+					if cell.italic() {
+						style = style.add_modifier(Modifier::ITALIC);
+					}
+					if cell.underline() {
+						style = style.add_modifier(Modifier::UNDERLINED);
+					}
+					if cell.inverse() {
+						style = style.add_modifier(Modifier::REVERSED);
+					}
+
+					let s = cell.contents();
+					buf
+						.get_mut(inner.x + x, inner.y + y)
+						.set_symbol(if s.is_empty() { " " } else { &s })
+						.set_style(style);
+				}
+			}
+		});
+	}
+}
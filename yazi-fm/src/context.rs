@@ -1,7 +1,7 @@
 use ratatui::layout::Rect;
 use yazi_adapter::Dimension;
 use yazi_config::popup::{Origin, Position};
-use yazi_core::{completion::Completion, help::Help, input::Input, manager::Manager, notify::Notify, select::Select, tasks::Tasks, which::Which};
+use yazi_core::{completion::Completion, help::Help, input::Input, manager::Manager, notify::Notify, recorder::Recorder, select::Select, tasks::Tasks, terminal::Terminal, which::Which};
 
 pub struct Ctx {
 	pub manager:    Manager,
@@ -12,6 +12,8 @@ pub struct Ctx {
 	pub completion: Completion,
 	pub which:      Which,
 	pub notify:     Notify,
+	pub terminal:   Terminal,
+	pub recorder:   Recorder,
 }
 
 impl Ctx {
@@ -25,21 +27,22 @@ impl Ctx {
 			completion: Default::default(),
 			which:      Default::default(),
 			notify:     Default::default(),
+			terminal:   Default::default(),
+			recorder:   Recorder::load(),
 		}
 	}
 
 	pub fn area(&self, position: &Position) -> Rect {
 		let ws = Dimension::available();
-		if position.origin != Origin::Hovered {
+		if !matches!(position.origin, Origin::Hovered | Origin::Inline) {
 			return position.rect(ws);
 		}
 
-		if let Some(r) =
-			self.manager.hovered().and_then(|h| self.manager.current().rect_current(&h.url))
-		{
-			Position::sticky(ws, r, position.offset)
-		} else {
-			Position::new(Origin::TopCenter, position.offset).rect(ws)
+		let r = self.manager.hovered().and_then(|h| self.manager.current().rect_current(&h.url));
+		match r {
+			Some(r) if position.origin == Origin::Inline => r,
+			Some(r) => Position::sticky(ws, r, position.offset),
+			None => Position::new(Origin::TopCenter, position.offset).rect(ws),
 		}
 	}
 
@@ -47,11 +50,22 @@ impl Ctx {
 	pub fn cursor(&self) -> Option<(u16, u16)> {
 		if self.input.visible {
 			let Rect { x, y, .. } = self.area(&self.input.position);
-			return Some((x + 1 + self.input.cursor(), y + 1));
+			let b = !self.input.is_inline() as u16;
+			return Some((x + b + self.input.cursor(), y + b));
+		}
+		if let (true, Some(c)) = (self.select.visible, self.select.cursor()) {
+			let Rect { x, y, .. } = self.area(&self.select.position);
+			return Some((x + 1 + c, y));
 		}
 		if let Some((x, y)) = self.help.cursor() {
 			return Some((x, y));
 		}
+		if self.terminal.focused {
+			let ws = Dimension::available();
+			let (_, pane) = self.terminal.split(Rect { x: 0, y: 0, width: ws.columns, height: ws.rows });
+			let (y, x) = self.terminal.screen(|s| (!s.hide_cursor()).then(|| s.cursor_position()))??;
+			return pane.map(|r| (r.x + x, r.y + 1 + y));
+		}
 		None
 	}
 }
@@ -1,5 +1,5 @@
 use yazi_core::input::InputMode;
-use yazi_shared::{event::Cmd, Layer};
+use yazi_shared::{event::{damage_as, Cmd}, Layer};
 
 use crate::app::App;
 
@@ -13,7 +13,7 @@ impl<'a> Executor<'a> {
 
 	#[inline]
 	pub(super) fn execute(&mut self, cmd: Cmd, layer: Layer) {
-		match layer {
+		damage_as(layer, || match layer {
 			Layer::App => self.app(cmd),
 			Layer::Manager => self.manager(cmd),
 			Layer::Tasks => self.tasks(cmd),
@@ -22,7 +22,7 @@ impl<'a> Executor<'a> {
 			Layer::Help => self.help(cmd),
 			Layer::Completion => self.completion(cmd),
 			Layer::Which => self.which(cmd),
-		}
+		})
 	}
 
 	fn app(&mut self, cmd: Cmd) {
@@ -35,11 +35,14 @@ impl<'a> Executor<'a> {
 		}
 
 		on!(accept_payload);
+		on!(headless);
 		on!(notify);
 		on!(plugin);
 		on!(plugin_do);
+		on!(plugin_reload);
 		on!(update_notify);
 		on!(update_progress);
+		on!(reload);
 		on!(resize);
 		on!(stop);
 		on!(resume);
@@ -47,14 +50,24 @@ impl<'a> Executor<'a> {
 
 	fn manager(&mut self, cmd: Cmd) {
 		macro_rules! on {
-			(MANAGER, $name:ident $(,$args:expr)*) => {
+			(MANAGER, $name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.manager.$name(cmd);
+				}
+			};
+			(MANAGER, $name:ident, tasks) => {
 				if cmd.name == stringify!($name) {
-					return self.app.cx.manager.$name(cmd, $($args),*);
+					return self.app.cx.manager.$name(cmd, &self.app.cx.tasks);
 				}
 			};
-			(ACTIVE, $name:ident $(,$args:expr)*) => {
+			(ACTIVE, $name:ident) => {
 				if cmd.name == stringify!($name) {
-					return self.app.cx.manager.active_mut().$name(cmd, $($args),*);
+					return self.app.cx.manager.active_mut().$name(cmd);
+				}
+			};
+			(ACTIVE, $name:ident, tasks) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.manager.active_mut().$name(cmd, &self.app.cx.tasks);
 				}
 			};
 			(TABS, $name:ident) => {
@@ -62,82 +75,29 @@ impl<'a> Executor<'a> {
 					return self.app.cx.manager.tabs.$name(cmd);
 				}
 			};
+			(OTHER, $name:ident) => {};
 		}
 
-		on!(MANAGER, update_task);
-		on!(MANAGER, update_files, &self.app.cx.tasks);
-		on!(MANAGER, update_mimetype, &self.app.cx.tasks);
-		on!(MANAGER, update_paged, &self.app.cx.tasks);
-		on!(MANAGER, update_yanked);
-		on!(MANAGER, hover);
-		on!(MANAGER, peek);
-		on!(MANAGER, seek);
-		on!(MANAGER, refresh, &self.app.cx.tasks);
-		on!(MANAGER, quit, &self.app.cx.tasks);
-		on!(MANAGER, close, &self.app.cx.tasks);
-		on!(MANAGER, suspend);
-		on!(ACTIVE, escape);
-		on!(ACTIVE, preview);
-
-		// Navigation
-		on!(ACTIVE, arrow);
-		on!(ACTIVE, leave);
-		on!(ACTIVE, enter);
-		on!(ACTIVE, back);
-		on!(ACTIVE, forward);
-		on!(ACTIVE, cd);
-		on!(ACTIVE, reveal);
-
-		// Selection
-		on!(ACTIVE, select);
-		on!(ACTIVE, select_all);
-		on!(ACTIVE, visual_mode);
-
-		// Operation
-		on!(MANAGER, open, &self.app.cx.tasks);
-		on!(MANAGER, open_do, &self.app.cx.tasks);
-		on!(MANAGER, yank);
-		on!(MANAGER, unyank);
-		on!(MANAGER, paste, &self.app.cx.tasks);
-		on!(MANAGER, link, &self.app.cx.tasks);
-		on!(MANAGER, hardlink, &self.app.cx.tasks);
-		on!(MANAGER, remove, &self.app.cx.tasks);
-		on!(MANAGER, remove_do, &self.app.cx.tasks);
-		on!(MANAGER, create);
-		on!(MANAGER, rename);
-		on!(ACTIVE, copy);
-		on!(ACTIVE, shell);
-		on!(ACTIVE, hidden);
-		on!(ACTIVE, linemode);
-		on!(ACTIVE, search);
-		on!(ACTIVE, search_do);
-
-		// Filter
-		on!(ACTIVE, filter);
-		on!(ACTIVE, filter_do);
-
-		// Find
-		on!(ACTIVE, find);
-		on!(ACTIVE, find_do);
-		on!(ACTIVE, find_arrow);
-
-		// Sorting
-		on!(ACTIVE, sort, &self.app.cx.tasks);
-
-		// Tabs
-		on!(TABS, create);
-		on!(TABS, close);
-		on!(TABS, switch);
-		on!(TABS, swap);
+		yazi_config::manager_commands!(on);
 
 		match cmd.name.as_bytes() {
 			// Tasks
 			b"tasks_show" => self.app.cx.tasks.toggle(()),
+			// Macro
+			b"macro_record" => self.app.cx.recorder.record(cmd),
+			b"macro_play" => self.app.cx.recorder.play(cmd, &self.app.cx.manager),
+			// Terminal
+			b"terminal" => {
+				let cwd = &self.app.cx.manager.active().current.cwd;
+				self.app.cx.terminal.toggle(cwd, cmd.bool("hide"))
+			}
+			// Notify
+			b"notify_history" => self.app.cx.notify.history(cmd),
 			// Help
 			b"help" => self.app.cx.help.toggle(Layer::Manager),
 			// Plugin
 			b"plugin" => self.app.plugin(cmd),
-			_ => {}
+			_ => self.app.command(cmd, Layer::Manager),
 		}
 	}
 
@@ -153,21 +113,17 @@ impl<'a> Executor<'a> {
 					return self.app.cx.tasks.$name(cmd);
 				}
 			};
+			(OTHER, $name:ident) => {};
 		}
 
-		on!(toggle, "close");
-		on!(arrow);
-		on!(inspect);
-		on!(cancel);
-		on!(open_with);
-		on!(process_exec);
+		yazi_config::tasks_commands!(on);
 
 		match cmd.name.as_str() {
 			// Help
 			"help" => self.app.cx.help.toggle(Layer::Tasks),
 			// Plugin
 			"plugin" => self.app.plugin(cmd),
-			_ => {}
+			_ => self.app.command(cmd, Layer::Tasks),
 		}
 	}
 
@@ -178,22 +134,22 @@ impl<'a> Executor<'a> {
 					return self.app.cx.select.$name(cmd);
 				}
 			};
+			(OTHER, $name:ident) => {};
 		}
 
-		on!(show);
-		on!(close);
-		on!(arrow);
+		yazi_config::select_commands!(on);
 
 		match cmd.name.as_str() {
 			// Help
 			"help" => self.app.cx.help.toggle(Layer::Select),
 			// Plugin
 			"plugin" => self.app.plugin(cmd),
-			_ => {}
+			_ => self.app.command(cmd, Layer::Select),
 		}
 	}
 
 	fn input(&mut self, cmd: Cmd) {
+		let mode = self.app.cx.input.mode();
 		macro_rules! on {
 			($name:ident) => {
 				if cmd.name == stringify!($name) {
@@ -205,14 +161,20 @@ impl<'a> Executor<'a> {
 					return self.app.cx.input.$name(cmd);
 				}
 			};
+			(NORMAL, $name:ident) => {
+				if mode == InputMode::Normal && cmd.name == stringify!($name) {
+					return self.app.cx.input.$name(cmd);
+				}
+			};
+			(INSERT, $name:ident) => {
+				if mode == InputMode::Insert && cmd.name == stringify!($name) {
+					return self.app.cx.input.$name(cmd);
+				}
+			};
+			(OTHER, $name:ident) => {};
 		}
 
-		on!(show);
-		on!(close);
-		on!(escape);
-		on!(move_, "move");
-		on!(backward);
-		on!(forward);
+		yazi_config::input_commands!(on);
 
 		if cmd.name.as_str() == "complete" {
 			return if cmd.bool("trigger") {
@@ -222,29 +184,13 @@ impl<'a> Executor<'a> {
 			};
 		}
 
-		match self.app.cx.input.mode() {
-			InputMode::Normal => {
-				on!(insert);
-				on!(visual);
-
-				on!(delete);
-				on!(yank);
-				on!(paste);
-
-				on!(undo);
-				on!(redo);
-
-				match cmd.name.as_str() {
-					// Help
-					"help" => self.app.cx.help.toggle(Layer::Input),
-					// Plugin
-					"plugin" => self.app.plugin(cmd),
-					_ => {}
-				}
-			}
-			InputMode::Insert => {
-				on!(backspace);
-				on!(kill);
+		if mode == InputMode::Normal {
+			match cmd.name.as_str() {
+				// Help
+				"help" => self.app.cx.help.toggle(Layer::Input),
+				// Plugin
+				"plugin" => self.app.plugin(cmd),
+				_ => self.app.command(cmd, Layer::Input),
 			}
 		}
 	}
@@ -256,17 +202,16 @@ impl<'a> Executor<'a> {
 					return self.app.cx.help.$name(cmd);
 				}
 			};
+			(OTHER, $name:ident) => {};
 		}
 
-		on!(escape);
-		on!(arrow);
-		on!(filter);
+		yazi_config::help_commands!(on);
 
 		match cmd.name.as_str() {
 			"close" => self.app.cx.help.toggle(Layer::Help),
 			// Plugin
 			"plugin" => self.app.plugin(cmd),
-			_ => {}
+			_ => self.app.command(cmd, Layer::Help),
 		}
 	}
 
@@ -277,12 +222,10 @@ impl<'a> Executor<'a> {
 					return self.app.cx.completion.$name(cmd);
 				}
 			};
+			(OTHER, $name:ident) => {};
 		}
 
-		on!(trigger);
-		on!(show);
-		on!(close);
-		on!(arrow);
+		yazi_config::completion_commands!(on);
 
 		match cmd.name.as_str() {
 			"close_input" => self.app.cx.input.close(cmd),
@@ -290,7 +233,7 @@ impl<'a> Executor<'a> {
 			"help" => self.app.cx.help.toggle(Layer::Completion),
 			// Plugin
 			"plugin" => self.app.plugin(cmd),
-			_ => {}
+			_ => self.app.command(cmd, Layer::Completion),
 		}
 	}
 
@@ -20,16 +20,16 @@ impl Widget for Bindings<'_> {
 
 		// On
 		let col1: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.on()).style(THEME.help.on)).collect();
+			bindings.iter().map(|c| ListItem::new(c.on()).style(THEME.load().help.on)).collect();
 
 		// Run
 		let col2: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.run()).style(THEME.help.run)).collect();
+			bindings.iter().map(|c| ListItem::new(c.run()).style(THEME.load().help.run)).collect();
 
 		// Desc
 		let col3: Vec<_> = bindings
 			.iter()
-			.map(|c| ListItem::new(c.desc().unwrap_or("-".into())).style(THEME.help.desc))
+			.map(|c| ListItem::new(c.desc().unwrap_or("-".into())).style(THEME.load().help.desc))
 			.collect();
 
 		let chunks = layout::Layout::horizontal([
@@ -42,7 +42,7 @@ impl Widget for Bindings<'_> {
 		let cursor = self.cx.help.rel_cursor() as u16;
 		buf.set_style(
 			Rect { x: area.x, y: area.y + cursor, width: area.width, height: 1 },
-			THEME.help.hovered,
+			THEME.load().help.hovered,
 		);
 
 		List::new(col1).render(chunks[0], buf);
@@ -23,9 +23,9 @@ impl<'a> Input<'a> {
 
 		let (theme, syntaxes) = futures::executor::block_on(Highlighter::init());
 		if let Some(syntax) = syntaxes.find_syntax_by_name("Bourne Again Shell (bash)") {
-			let mut h = HighlightLines::new(syntax, theme);
+			let mut h = HighlightLines::new(syntax, &theme);
 			let regions = h.highlight_line(self.cx.input.value(), syntaxes)?;
-			return Ok(Highlighter::to_line_widget(regions, &PREVIEW.indent()));
+			return Ok(Highlighter::to_line_widget(regions, &PREVIEW.load().indent()));
 		}
 		bail!("Failed to find syntax")
 	}
@@ -37,23 +37,30 @@ impl<'a> Widget for Input<'a> {
 		let area = self.cx.area(&input.position);
 
 		yazi_plugin::elements::Clear::default().render(area, buf);
-		Paragraph::new(self.highlighted_value().unwrap_or_else(|_| Line::from(input.value())))
-			.block(
+		let value = if input.obscure {
+			Line::from("•".repeat(input.value().chars().count()))
+		} else {
+			self.highlighted_value().unwrap_or_else(|_| Line::from(input.value()))
+		};
+		let mut p = Paragraph::new(value).style(THEME.load().input.value);
+		if !input.is_inline() {
+			p = p.block(
 				Block::bordered()
 					.border_type(BorderType::Rounded)
-					.border_style(THEME.input.border)
-					.title(Line::styled(&input.title, THEME.input.title)),
-			)
-			.style(THEME.input.value)
-			.render(area, buf);
+					.border_style(THEME.load().input.border)
+					.title(Line::styled(&input.title, THEME.load().input.title)),
+			);
+		}
+		p.render(area, buf);
 
 		if let Some(Range { start, end }) = input.selected() {
-			let x = win.width.min(area.x + 1 + start);
-			let y = win.height.min(area.y + 1);
+			let b = !input.is_inline() as u16;
+			let x = win.width.min(area.x + b + start);
+			let y = win.height.min(area.y + b);
 
 			buf.set_style(
 				Rect { x, y, width: (end - start).min(win.width - x), height: 1.min(win.height - y) },
-				THEME.input.selected,
+				THEME.load().input.selected,
 			)
 		}
 
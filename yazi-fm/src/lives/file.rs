@@ -41,12 +41,54 @@ impl File {
 			reg.add_method("size", |_, me, ()| {
 				Ok(if me.is_dir() { me.folder().files.sizes.get(&me.url).copied() } else { Some(me.len) })
 			});
+			reg.add_method("size_loading", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				Ok(me.is_dir() && cx.tasks.size_loading(&me.url))
+			});
 			reg.add_method("mime", |lua, me, ()| {
 				let cx = lua.named_registry_value::<CtxRef>("cx")?;
 				Ok(cx.manager.mimetype.get(&me.url).cloned())
 			});
+			reg.add_method("tags", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				Ok(cx.manager.tags.get(&me.url).cloned())
+			});
+			reg.add_method("security", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let Some((context, caps)) = cx.manager.security.get(&me.url) else {
+					return Ok(None);
+				};
+
+				Some(lua.create_table_from([
+					("context", Some(context.as_str()).filter(|s| !s.is_empty())),
+					("caps", Some(caps.as_str()).filter(|s| !s.is_empty())),
+				]))
+				.transpose()
+			});
+			reg.add_method("vcs", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				Ok(cx.manager.vcs.status(&me.url).map(|s| s.as_str()))
+			});
+			reg.add_method("commit", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let Some(c) = cx.manager.vcs.commit(&me.url) else {
+					return Ok(None);
+				};
+
+				Some(lua.create_table_from([
+					("hash", c.hash.as_str().into_lua(lua)?),
+					("author", c.author.as_str().into_lua(lua)?),
+					("time", c.time.into_lua(lua)?),
+					("subject", c.subject.as_str().into_lua(lua)?),
+				]))
+				.transpose()
+			});
 			reg.add_method("prefix", |lua, me, ()| {
-				if !me.folder().cwd.is_search() {
+				// The recent ones are from all over the place, so the whole directory is shown
+				if me.folder().cwd.is_recent() {
+					let p = me.url.parent().map(|p| p.as_os_str().as_encoded_bytes());
+					return p.map(|b| lua.create_string(b)).transpose();
+				} else if !me.folder().cwd.is_search() {
 					return Ok(None);
 				}
 
@@ -59,7 +101,7 @@ impl File {
 				let mime =
 					if me.is_dir() { Some(MIME_DIR) } else { cx.manager.mimetype.get(&me.url).map(|x| &**x) };
 
-				Ok(THEME.filetypes.iter().find(|&x| x.matches(me, mime)).map(|x| Style::from(x.style)))
+				Ok(THEME.load().filetypes.matches(me, mime).map(|x| Style::from(x.style)))
 			});
 			reg.add_method("is_hovered", |_, me, ()| Ok(me.idx == me.folder().cursor));
 			reg.add_method("is_yanked", |lua, me, ()| {
@@ -72,6 +114,10 @@ impl File {
 					1u8
 				})
 			});
+			reg.add_method("bookmark", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				Ok(cx.manager.bookmarks.name_of(&me.url).map(str::to_owned))
+			});
 			reg.add_method("is_marked", |_, me, ()| {
 				use yazi_core::tab::Mode::*;
 				if !me.tab().mode.is_visual() || me.folder().cwd != me.tab().current.cwd {
@@ -1,10 +1,10 @@
 use std::ops::{Deref, Range};
 
-use mlua::{AnyUserData, Lua, UserDataFields};
+use mlua::{AnyUserData, IntoLua, Lua, UserDataFields, UserDataMethods};
 use yazi_config::LAYOUT;
 use yazi_plugin::{bindings::Cast, url::Url};
 
-use super::{File, Files, SCOPE};
+use super::{CtxRef, File, Files, SCOPE};
 
 pub(super) struct Folder {
 	window: Range<usize>,
@@ -48,6 +48,41 @@ impl Folder {
 			reg.add_field_method_get("hovered", |_, me| {
 				me.hovered().map(|_| File::make(me.cursor, me, me.tab())).transpose()
 			});
+
+			reg.add_method("size", |_, me, ()| {
+				let files = &me.files;
+				let size = |f: &yazi_shared::fs::File| {
+					if f.is_dir() { files.sizes.get(&f.url).copied().unwrap_or(0) } else { f.len }
+				};
+				Ok(files.iter().map(size).sum::<u64>())
+			});
+			reg.add_method("repo", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let Some(repo) = cx.manager.vcs.repo(&me.cwd) else {
+					return Ok(None);
+				};
+
+				Some(lua.create_table_from([
+					("provider", repo.provider.to_string().into_lua(lua)?),
+					("branch", repo.branch.as_deref().into_lua(lua)?),
+					("ahead", repo.ahead.into_lua(lua)?),
+					("behind", repo.behind.into_lua(lua)?),
+				]))
+				.transpose()
+			});
+			reg.add_method("space", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let Some((space, fstype)) = cx.manager.spaces.get(&me.cwd) else {
+					return Ok(None);
+				};
+
+				Some(lua.create_table_from([
+					("free", space.free.into_lua(lua)?),
+					("total", space.total.into_lua(lua)?),
+					("fstype", fstype.as_deref().into_lua(lua)?),
+				]))
+				.transpose()
+			});
 		})?;
 
 		lua.register_userdata_type::<yazi_fs::FolderStage>(|reg| {
@@ -43,15 +43,14 @@ impl Lives {
 			LUA.set_named_registry_value("cx", scope.create_any_userdata_ref(cx)?)?;
 
 			let globals = LUA.globals();
-			globals.raw_set(
-				"cx",
-				LUA.create_table_from([
-					("active", super::Tab::make(cx.manager.active())?),
-					("tabs", super::Tabs::make(&cx.manager.tabs)?),
-					("tasks", super::Tasks::make(&cx.tasks)?),
-					("yanked", super::Yanked::make(&cx.manager.yanked)?),
-				])?,
-			)?;
+			let t = LUA.create_table_from([
+				("active", super::Tab::make(cx.manager.active())?),
+				("tabs", super::Tabs::make(&cx.manager.tabs)?),
+				("tasks", super::Tasks::make(&cx.tasks)?),
+				("yanked", super::Yanked::make(&cx.manager.yanked)?),
+			])?;
+			t.raw_set("layers", LUA.create_sequence_from(cx.manager.layers.iter().map(String::as_str))?)?;
+			globals.raw_set("cx", t)?;
 
 			f(scope)
 		});
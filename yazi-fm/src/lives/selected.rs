@@ -1,4 +1,4 @@
-use std::{collections::{hash_map, HashMap}, ops::Deref};
+use std::{collections::hash_map, ops::Deref};
 
 use mlua::{AnyUserData, IntoLuaMulti, Lua, MetaMethod, UserDataMethods, UserDataRefMut};
 use yazi_plugin::{bindings::Cast, url::Url};
@@ -7,20 +7,18 @@ use super::{Iter, SCOPE};
 
 #[derive(Clone, Copy)]
 pub(super) struct Selected {
-	inner: *const HashMap<yazi_shared::fs::Url, u64>,
+	inner: *const yazi_core::tab::Selected,
 }
 
 impl Deref for Selected {
-	type Target = HashMap<yazi_shared::fs::Url, u64>;
+	type Target = yazi_core::tab::Selected;
 
 	fn deref(&self) -> &Self::Target { self.inner() }
 }
 
 impl Selected {
 	#[inline]
-	pub(super) fn make(
-		inner: &HashMap<yazi_shared::fs::Url, u64>,
-	) -> mlua::Result<AnyUserData<'static>> {
+	pub(super) fn make(inner: &yazi_core::tab::Selected) -> mlua::Result<AnyUserData<'static>> {
 		SCOPE.create_any_userdata(Self { inner })
 	}
 
@@ -41,11 +39,21 @@ impl Selected {
 
 				Ok((iter, Iter::make(me.inner().keys())))
 			});
+
+			reg.add_method("summary", |lua, me, ()| {
+				let s = me.summary();
+				lua.create_table_from([
+					("count", s.count as u64),
+					("dirs", s.dirs as u64),
+					("size", s.size),
+					("pending", s.pending as u64),
+				])
+			});
 		})?;
 
 		Ok(())
 	}
 
 	#[inline]
-	fn inner(&self) -> &'static HashMap<yazi_shared::fs::Url, u64> { unsafe { &*self.inner } }
+	fn inner(&self) -> &'static yazi_core::tab::Selected { unsafe { &*self.inner } }
 }
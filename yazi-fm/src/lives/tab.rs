@@ -36,6 +36,7 @@ impl Tab {
 
 			reg.add_field_method_get("mode", |_, me| Mode::make(&me.mode));
 			reg.add_field_method_get("conf", |_, me| Config::make(&me.conf));
+			reg.add_field_method_get("usage", |_, me| Ok(me.usage.is_some()));
 			reg.add_field_method_get("current", |_, me| Folder::make(None, &me.current, me));
 			reg.add_field_method_get("parent", |_, me| {
 				me.parent.as_ref().map(|f| Folder::make(None, f, me)).transpose()
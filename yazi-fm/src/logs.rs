@@ -1,8 +1,11 @@
-use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{fmt, prelude::__tracing_subscriber_SubscriberExt, Registry};
-use yazi_shared::{RoCell, Xdg};
+use std::sync::Mutex;
 
-static _GUARD: RoCell<WorkerGuard> = RoCell::new();
+use tracing_appender::{non_blocking::WorkerGuard, rolling::{RollingFileAppender, Rotation}};
+use tracing_subscriber::{fmt, prelude::__tracing_subscriber_SubscriberExt, EnvFilter, Registry};
+use yazi_config::LOG;
+use yazi_shared::Xdg;
+
+static GUARD: Mutex<Option<WorkerGuard>> = Mutex::new(None);
 
 pub(super) struct Logs;
 
@@ -11,15 +14,37 @@ impl Logs {
 		let state_dir = Xdg::state_dir();
 		std::fs::create_dir_all(&state_dir).expect("Failed to create state directory");
 
-		let appender = tracing_appender::rolling::never(state_dir, "yazi.log");
+		let appender = RollingFileAppender::builder()
+			.rotation(Rotation::DAILY)
+			.filename_prefix("yazi")
+			.filename_suffix("log")
+			.max_log_files(LOG.keep)
+			.build(state_dir)
+			.expect("Failed to create the log file");
 		let (handle, guard) = tracing_appender::non_blocking(appender);
 
-		// let filter = EnvFilter::from_default_env();
 		let subscriber = Registry::default()
-			.with(fmt::layer().pretty().with_writer(handle).with_ansi(cfg!(debug_assertions)));
+			.with(Self::filter())
+			.with(fmt::layer().with_writer(handle).with_ansi(cfg!(debug_assertions)));
 
 		tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 
-		_GUARD.init(guard);
+		*GUARD.lock().unwrap() = Some(guard);
+	}
+
+	// Writes out what's left in the buffer, after which nothing more is logged
+	pub(super) fn flush() {
+		if let Ok(mut guard) = GUARD.lock() {
+			guard.take();
+		}
+	}
+
+	// `YAZI_LOG` takes the same directives as `RUST_LOG`, e.g. `yazi_scheduler=debug,info`
+	fn filter() -> EnvFilter {
+		match EnvFilter::try_from_env("YAZI_LOG") {
+			Ok(filter) => filter,
+			Err(_) if LOG.enabled => EnvFilter::new(&LOG.level),
+			Err(_) => EnvFilter::new("error"),
+		}
 	}
 }
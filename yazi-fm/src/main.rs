@@ -6,6 +6,7 @@
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 mod app;
+mod appearance;
 mod completion;
 mod components;
 mod context;
@@ -24,6 +25,7 @@ mod tasks;
 mod term;
 mod which;
 
+use appearance::*;
 use context::*;
 use executor::*;
 use logs::*;
@@ -33,29 +35,31 @@ use root::*;
 use router::*;
 use signals::*;
 use term::*;
+use yazi_shared::PERF;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
 	Panic::install();
-	Logs::start();
 
 	_ = fdlimit::raise_fd_limit();
 
-	yazi_shared::init();
+	PERF.phase("shared", yazi_shared::init);
+
+	PERF.phase("config", yazi_config::init)?;
 
-	yazi_config::init()?;
+	Logs::start();
 
-	yazi_adapter::init();
+	PERF.phase("adapter", yazi_adapter::init);
 
-	yazi_boot::init();
+	PERF.phase("boot", yazi_boot::init);
 
-	yazi_proxy::init();
+	PERF.phase("proxy", yazi_proxy::init);
 
-	yazi_dds::init();
+	PERF.phase("dds", yazi_dds::init);
 
-	yazi_plugin::init()?;
+	PERF.phase("plugin", yazi_plugin::init)?;
 
-	yazi_core::init();
+	PERF.phase("core", yazi_core::init);
 
 	yazi_dds::serve();
 	app::App::serve().await
@@ -1,4 +1,7 @@
-use crate::Term;
+use tracing::error;
+use yazi_core::manager::Snapshot;
+
+use crate::{Logs, Term};
 
 pub(super) struct Panic;
 
@@ -8,6 +11,9 @@ impl Panic {
 
 		let hook = std::panic::take_hook();
 		std::panic::set_hook(Box::new(move |info| {
+			error!("{info}");
+			Logs::flush();
+			Snapshot::dump();
 			Term::goodbye(|| {
 				hook(info);
 				true
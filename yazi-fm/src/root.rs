@@ -2,6 +2,7 @@ use mlua::{Table, TableExt};
 use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
 use tracing::error;
 use yazi_plugin::{bindings::Cast, elements::render_widgets, LUA};
+use yazi_shared::PERF;
 
 use super::{completion, input, select, tasks, which};
 use crate::{components, help, Ctx};
@@ -28,6 +29,23 @@ impl<'a> Widget for Root<'a> {
 		}
 
 		components::Preview::new(self.cx).render(area, buf);
+	}
+}
+
+// The popups drawn by the layers other than the manager, on top of the `Root`
+pub(super) struct Overlays<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Overlays<'a> {
+	pub(super) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+}
+
+impl<'a> Widget for Overlays<'a> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		if PERF.visible() {
+			components::Perf.render(area, buf);
+		}
 
 		if self.cx.tasks.visible {
 			tasks::Layout::new(self.cx).render(area, buf);
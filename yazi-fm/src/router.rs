@@ -1,7 +1,11 @@
-use yazi_config::{keymap::{Control, Key}, KEYMAP};
-use yazi_shared::{emit, Layer};
+use std::sync::Arc;
 
-use crate::app::App;
+use mlua::{Table, TableExt, Value};
+use yazi_config::{keymap::{Control, Key}, vcs::VcsProvider, KEYMAP};
+use yazi_plugin::LUA;
+use yazi_shared::{emit, event::damage_as, Layer, MIME_DIR};
+
+use crate::{app::App, lives::Lives, Ctx};
 
 pub(super) struct Router<'a> {
 	app: &'a mut App,
@@ -16,12 +20,19 @@ impl<'a> Router<'a> {
 		let cx = &mut self.app.cx;
 
 		if cx.which.visible {
-			return cx.which.type_(key);
+			return damage_as(Layer::Which, || cx.which.type_(key));
+		}
+		if cx.help.visible && damage_as(Layer::Help, || cx.help.type_(&key)) {
+			return true;
 		}
-		if cx.help.visible && cx.help.type_(&key) {
+		if cx.input.visible && damage_as(Layer::Input, || cx.input.type_(&key)) {
 			return true;
 		}
-		if cx.input.visible && cx.input.type_(&key) {
+		if cx.select.visible
+			&& !cx.help.visible
+			&& !cx.input.visible
+			&& damage_as(Layer::Select, || cx.select.type_(&key))
+		{
 			return true;
 		}
 
@@ -35,20 +46,52 @@ impl<'a> Router<'a> {
 			self.matches(Layer::Select, key)
 		} else if cx.tasks.visible {
 			self.matches(Layer::Tasks, key)
+		} else if cx.terminal.type_(&key) {
+			true
 		} else {
-			self.matches(Layer::Manager, key)
+			self.panel(key) || self.layers(key) || self.matches(Layer::Manager, key)
 		}
 	}
 
+	// Keys go to the focused panel of a plugin first, if any.
+	fn panel(&mut self, key: Key) -> bool {
+		let Ok(root) = LUA.globals().raw_get::<_, Table>("Root") else { return false };
+		if root.raw_get::<_, Value>("_focus").map_or(true, |v| v.is_nil()) {
+			return false;
+		}
+
+		Lives::scope(&self.app.cx, |_| root.call_method("panel_key", key.to_string()))
+			.unwrap_or(false)
+	}
+
+	// Keys go to the named layers toggled on, from the most recent one.
+	fn layers(&mut self, key: Key) -> bool {
+		let (layers, keymap) = (self.app.cx.manager.layers.clone(), KEYMAP.load());
+		layers.iter().rev().any(|name| {
+			let Some(ctrls) = keymap.layers.get(name) else { return false };
+			self.matches_in(Layer::Manager, key, || ctrls.iter().cloned())
+		})
+	}
+
 	#[inline]
 	fn matches(&mut self, layer: Layer, key: Key) -> bool {
-		for ctrl @ Control { on, .. } in KEYMAP.get(layer) {
-			if on.is_empty() || on[0] != key {
+		let keymap = KEYMAP.load();
+		self.matches_in(layer, key, || keymap.iter(layer))
+	}
+
+	fn matches_in<I>(&mut self, layer: Layer, key: Key, ctrls: impl Fn() -> I) -> bool
+	where
+		I: Iterator<Item = Arc<Control>>,
+	{
+		let cx = &self.app.cx;
+		for ctrl in ctrls() {
+			if ctrl.on.is_empty() || ctrl.on[0] != key || !Self::satisfied(cx, &ctrl) {
 				continue;
 			}
 
-			if on.len() > 1 {
-				self.app.cx.which.show_with(&key, layer);
+			if ctrl.on.len() > 1 {
+				let ctrls = ctrls().filter(|c| Self::satisfied(cx, c)).collect();
+				self.app.cx.which.show_with(&key, layer, ctrls);
 			} else {
 				emit!(Seq(ctrl.to_seq(), layer));
 			}
@@ -56,4 +99,29 @@ impl<'a> Router<'a> {
 		}
 		false
 	}
+
+	fn satisfied(cx: &Ctx, ctrl: &Control) -> bool {
+		if !ctrl.is_conditional() {
+			return true;
+		}
+
+		let tab = cx.manager.active();
+		let hovered = tab.current.hovered();
+		let repo = cx.manager.vcs.repo(tab.current.cwd.as_path());
+		let mime = hovered.map(|h| {
+			if h.is_dir() { MIME_DIR } else { cx.manager.mimetype.get(&h.url).map_or("", |s| s) }
+		});
+
+		ctrl.satisfied(hovered.zip(mime).map(|(h, m)| (h.url.as_path(), m)), |term| match term {
+			"selected" => !tab.selected.is_empty(),
+			"select" => tab.mode.is_select(),
+			"unset" => tab.mode.is_unset(),
+			"visual" => tab.mode.is_visual(),
+			"dir" => hovered.is_some_and(|h| h.is_dir()),
+			"hidden" => hovered.is_some_and(|h| h.is_hidden()),
+			"link" => hovered.is_some_and(|h| h.is_link()),
+			"git" => repo.is_some_and(|r| r.provider == VcsProvider::Git),
+			_ => false,
+		})
+	}
 }
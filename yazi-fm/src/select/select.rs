@@ -1,4 +1,4 @@
-use ratatui::{buffer::Buffer, layout::Rect, widgets::{Block, BorderType, List, ListItem, Widget}};
+use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Rect}, text::Text, widgets::{Block, BorderType, List, ListItem, Paragraph, Widget, Wrap}};
 use yazi_config::THEME;
 
 use crate::Ctx;
@@ -20,23 +20,40 @@ impl<'a> Widget for Select<'a> {
 			.window()
 			.iter()
 			.enumerate()
-			.map(|(i, v)| {
+			.map(|(i, &idx)| {
+				let mut v = select.item(idx).to_owned();
+				if let Some(k) = select.key(idx) {
+					v = format!("[{k}] {v}");
+				}
+				if let Some(b) = select.is_selected(idx) {
+					v = format!("{} {v}", if b { "●" } else { "○" });
+				}
+
 				if i != select.rel_cursor() {
-					return ListItem::new(format!("  {v}")).style(THEME.select.inactive);
+					return ListItem::new(format!("  {v}")).style(THEME.load().select.inactive);
 				}
 
-				ListItem::new(format!(" {v}")).style(THEME.select.active)
+				ListItem::new(format!(" {v}")).style(THEME.load().select.active)
 			})
 			.collect();
 
 		yazi_plugin::elements::Clear::default().render(area, buf);
-		List::new(items)
-			.block(
-				Block::bordered()
-					.title(select.title())
-					.border_type(BorderType::Rounded)
-					.border_style(THEME.select.border),
-			)
-			.render(area, buf);
+		let block = Block::bordered()
+			.title(select.title())
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.load().select.border);
+
+		let Some(preview) = select.previewed() else {
+			return List::new(items).block(block).render(area, buf);
+		};
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let [list, right] = Layout::horizontal([Constraint::Ratio(2, 5), Constraint::Ratio(3, 5)])
+			.spacing(1)
+			.areas(inner);
+		List::new(items).render(list, buf);
+		Paragraph::new(Text::raw(preview)).wrap(Wrap { trim: false }).render(right, buf);
 	}
 }
@@ -61,7 +61,7 @@ impl Signals {
 				Event::Key(key).emit()
 			}
 			CrosstermEvent::Mouse(mouse) => {
-				if MANAGER.mouse_events.contains(mouse.kind.into()) {
+				if MANAGER.load().mouse_events.contains(mouse.kind.into()) {
 					Event::Mouse(mouse).emit();
 				}
 			}
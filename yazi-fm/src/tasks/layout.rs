@@ -34,11 +34,11 @@ impl<'a> Widget for Layout<'a> {
 
 		yazi_plugin::elements::Clear::default().render(area, buf);
 		let block = Block::bordered()
-			.title(Line::styled("Tasks", THEME.tasks.title))
+			.title(Line::styled("Tasks", THEME.load().tasks.title))
 			.title_alignment(Alignment::Center)
 			.padding(Padding::symmetric(1, 1))
 			.border_type(BorderType::Rounded)
-			.border_style(THEME.tasks.border);
+			.border_style(THEME.load().tasks.border);
 		block.clone().render(area, buf);
 
 		let tasks = &self.cx.tasks;
@@ -50,7 +50,7 @@ impl<'a> Widget for Layout<'a> {
 			.map(|(i, v)| {
 				let mut item = ListItem::new(v.name.clone());
 				if i == tasks.cursor {
-					item = item.style(THEME.tasks.hovered);
+					item = item.style(THEME.load().tasks.hovered);
 				}
 				item
 			})
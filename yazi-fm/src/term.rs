@@ -3,9 +3,12 @@ use std::{io::{self, stderr, BufWriter, Stderr}, ops::{Deref, DerefMut}, sync::a
 use anyhow::Result;
 use crossterm::{event::{DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, style::Print, terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle}};
 use cursor::RestoreCursor;
-use ratatui::{backend::CrosstermBackend, buffer::Buffer, layout::Rect, CompletedFrame, Frame, Terminal};
+use ratatui::{backend::CrosstermBackend, buffer::Buffer, layout::Rect, widgets::{Clear, Widget}, CompletedFrame, Frame, Terminal};
 use yazi_adapter::Emulator;
 use yazi_config::{INPUT, MANAGER};
+use yazi_plugin::elements::{DRAWING, DRAWN};
+
+use crate::Appearance;
 
 static CSI_U: AtomicBool = AtomicBool::new(false);
 static BLINK: AtomicBool = AtomicBool::new(false);
@@ -15,6 +18,7 @@ pub(super) struct Term {
 	inner:       Terminal<CrosstermBackend<BufWriter<Stderr>>>,
 	last_area:   Rect,
 	last_buffer: Buffer,
+	last_base:   Buffer,
 }
 
 impl Term {
@@ -23,6 +27,7 @@ impl Term {
 			inner:       Terminal::new(CrosstermBackend::new(BufWriter::new(stderr())))?,
 			last_area:   Default::default(),
 			last_buffer: Default::default(),
+			last_base:   Default::default(),
 		};
 
 		enable_raw_mode()?;
@@ -30,6 +35,7 @@ impl Term {
 			BufWriter::new(stderr()),
 			Print("\x1b[?12$p"),      // Request cursor blink status (DECSET)
 			Print("\x1bP$q q\x1b\\"), // Request cursor shape (DECRQM)
+			Print("\x1b]11;?\x1b\\"), // Request background color (OSC 11)
 			Print("\x1b[?u\x1b[c"),   // Request keyboard enhancement flags (CSI u)
 			EnterAlternateScreen,
 			EnableBracketedPaste,
@@ -37,6 +43,7 @@ impl Term {
 		)?;
 
 		let da = futures::executor::block_on(Emulator::read_until_da1());
+		Appearance::store_osc11(&da);
 		CSI_U.store(da.contains("\x1b[?0u"), Ordering::Relaxed);
 		BLINK.store(da.contains("\x1b[?12;1$y"), Ordering::Relaxed);
 		SHAPE.store(
@@ -85,7 +92,7 @@ impl Term {
 			execute!(stderr(), PopKeyboardEnhancementFlags).ok();
 		}
 
-		if !MANAGER.title_format.is_empty() {
+		if !MANAGER.load().title_format.is_empty() {
 			execute!(stderr(), SetTitle("")).ok();
 		}
 
@@ -105,8 +112,37 @@ impl Term {
 		std::process::exit(f() as i32);
 	}
 
-	pub(super) fn draw(&mut self, f: impl FnOnce(&mut Frame)) -> io::Result<CompletedFrame> {
-		let last = self.inner.draw(f)?;
+	// Draws the `regions` of the `base` over the last one of it, all of it with `u8::MAX`,
+	// while the `overlays` on top of it are always drawn
+	pub(super) fn draw_layered(
+		&mut self,
+		regions: u8,
+		base: impl FnOnce(&mut Frame),
+		overlays: impl FnOnce(&mut Frame),
+	) -> io::Result<CompletedFrame> {
+		let last_base = &mut self.last_base;
+		let last = self.inner.draw(|frame| {
+			if regions == u8::MAX || last_base.area != frame.size() {
+				base(frame);
+				last_base.clone_from(frame.buffer_mut());
+			} else if regions != 0 {
+				let buf = frame.buffer_mut();
+				buf.clone_from(last_base);
+				for (i, &area) in DRAWN.lock().iter().enumerate() {
+					if regions & 1 << i != 0 {
+						Clear.render(area, buf);
+					}
+				}
+
+				DRAWING.store(regions, Ordering::Relaxed);
+				base(frame);
+				DRAWING.store(u8::MAX, Ordering::Relaxed);
+				last_base.clone_from(frame.buffer_mut());
+			} else {
+				frame.buffer_mut().clone_from(last_base);
+			}
+			overlays(frame);
+		})?;
 
 		self.last_area = last.area;
 		self.last_buffer = last.buffer.clone();
@@ -136,7 +172,7 @@ impl Term {
 	#[inline]
 	pub(super) fn set_cursor_block() -> Result<()> {
 		use crossterm::cursor::SetCursorStyle;
-		Ok(if INPUT.cursor_blink {
+		Ok(if INPUT.load().cursor_blink {
 			queue!(stderr(), SetCursorStyle::BlinkingBlock)?
 		} else {
 			queue!(stderr(), SetCursorStyle::SteadyBlock)?
@@ -146,7 +182,7 @@ impl Term {
 	#[inline]
 	pub(super) fn set_cursor_bar() -> Result<()> {
 		use crossterm::cursor::SetCursorStyle;
-		Ok(if INPUT.cursor_blink {
+		Ok(if INPUT.load().cursor_blink {
 			queue!(stderr(), SetCursorStyle::BlinkingBar)?
 		} else {
 			queue!(stderr(), SetCursorStyle::SteadyBar)?
@@ -177,7 +213,7 @@ mod mouse {
 
 	impl crossterm::Command for SetMouse {
 		fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-			if MANAGER.mouse_events.is_empty() {
+			if MANAGER.load().mouse_events.is_empty() {
 				Ok(())
 			} else if self.0 {
 				EnableMouseCapture.write_ansi(f)
@@ -188,7 +224,7 @@ mod mouse {
 
 		#[cfg(windows)]
 		fn execute_winapi(&self) -> std::io::Result<()> {
-			if MANAGER.mouse_events.is_empty() {
+			if MANAGER.load().mouse_events.is_empty() {
 				Ok(())
 			} else if self.0 {
 				EnableMouseCapture.execute_winapi()
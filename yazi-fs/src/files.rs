@@ -1,10 +1,10 @@
-use std::{collections::{HashMap, HashSet}, mem, ops::Deref, sync::atomic::Ordering};
+use std::{collections::{HashMap, HashSet}, mem, ops::Deref, sync::{atomic::Ordering, Arc}, time::SystemTime};
 
 use tokio::{fs::{self, DirEntry}, select, sync::mpsc::{self, UnboundedReceiver}};
 use yazi_config::{manager::SortBy, MANAGER};
-use yazi_shared::fs::{maybe_exists, Cha, File, FilesOp, Url, FILES_TICKET};
+use yazi_shared::fs::{maybe_exists, Cha, ChaKind, File, FilesOp, Url, FILES_TICKET};
 
-use super::{FilesSorter, Filter};
+use super::{vfs, FilesSorter, Filter, Ignored, Recent, VcsStatus};
 
 pub struct Files {
 	hidden:       Vec<File>,
@@ -13,11 +13,13 @@ pub struct Files {
 	version:      u64,
 	pub revision: u64,
 
-	pub sizes: HashMap<Url, u64>,
+	pub sizes:    HashMap<Url, u64>,
+	pub statuses: HashMap<Url, VcsStatus>,
 
 	sorter:      FilesSorter,
 	filter:      Option<Filter>,
 	show_hidden: bool,
+	ignored:     Option<Arc<Ignored>>,
 }
 
 impl Default for Files {
@@ -29,11 +31,13 @@ impl Default for Files {
 			version:  Default::default(),
 			revision: Default::default(),
 
-			sizes: Default::default(),
+			sizes:    Default::default(),
+			statuses: Default::default(),
 
 			sorter:      Default::default(),
 			filter:      Default::default(),
-			show_hidden: MANAGER.show_hidden,
+			show_hidden: MANAGER.load().show_hidden,
+			ignored:     Default::default(),
 		}
 	}
 }
@@ -46,6 +50,12 @@ impl Deref for Files {
 
 impl Files {
 	pub async fn from_dir(url: &Url) -> std::io::Result<UnboundedReceiver<File>> {
+		if url.is_remote() {
+			let (tx, rx) = mpsc::unbounded_channel();
+			vfs::read_dir(url).await?.into_iter().for_each(|f| _ = tx.send(f));
+			return Ok(rx);
+		}
+
 		let mut it = fs::read_dir(url).await?;
 		let (tx, rx) = mpsc::unbounded_channel();
 
@@ -67,12 +77,23 @@ impl Files {
 	}
 
 	pub async fn from_dir_bulk(url: &Url) -> std::io::Result<Vec<File>> {
+		if url.is_remote() {
+			return vfs::read_dir(url).await;
+		} else if url.is_recent() {
+			return Ok(Recent::files().await);
+		}
+
 		let mut it = fs::read_dir(url).await?;
 		let mut items = Vec::with_capacity(5000);
 		while let Ok(Some(item)) = it.next_entry().await {
 			items.push(item);
 		}
 
+		#[cfg(all(target_os = "linux", feature = "io-uring"))]
+		if yazi_shared::fs::uring_available() {
+			return Ok(yazi_shared::fs::uring_files(items.iter().map(|e| e.path()).collect()).await);
+		}
+
 		let (first, rest) = items.split_at(items.len() / 3);
 		let (second, third) = rest.split_at(items.len() / 3);
 		async fn go(entities: &[DirEntry]) -> Vec<File> {
@@ -97,7 +118,18 @@ impl Files {
 	}
 
 	pub async fn assert_stale(url: &Url, cha: Cha) -> Option<Cha> {
-		match fs::metadata(url).await.map(Cha::from) {
+		// Nothing tells the change of the recent ones, so they're always listed again
+		if url.is_recent() {
+			return Some(Cha { kind: ChaKind::DIR, mtime: Some(SystemTime::now()), ..Default::default() });
+		}
+
+		let result = if url.is_remote() {
+			vfs::metadata(url).await
+		} else {
+			fs::metadata(url).await.map(Cha::from)
+		};
+
+		match result {
 			Ok(c) if !c.is_dir() => {
 				// FIXME: use `ErrorKind::NotADirectory` instead once it gets stabilized
 				FilesOp::IOErr(url.clone(), std::io::ErrorKind::AlreadyExists).emit();
@@ -105,7 +137,7 @@ impl Files {
 			Ok(c) if c.hits(cha) => {}
 			Ok(c) => return Some(c),
 			Err(e) => {
-				if maybe_exists(url).await {
+				if url.is_remote() || maybe_exists(url).await {
 					FilesOp::IOErr(url.clone(), e.kind()).emit();
 				} else if let Some(p) = url.parent_url() {
 					FilesOp::Deleting(p, vec![url.clone()]).emit();
@@ -161,6 +193,26 @@ impl Files {
 		self.sizes.extend(sizes);
 	}
 
+	// The directories containing a changed one have their sizes changed as well
+	pub fn forget_sizes(&mut self, url: &Url) {
+		let len = self.sizes.len();
+		self.sizes.retain(|u, _| !url.starts_with(u));
+		if self.sizes.len() != len && self.sorter.by == SortBy::Size {
+			self.revision += 1;
+		}
+	}
+
+	pub fn update_statuses(&mut self, statuses: HashMap<Url, VcsStatus>) {
+		if self.statuses == statuses {
+			return;
+		}
+
+		if self.sorter.by == SortBy::Vcs {
+			self.revision += 1;
+		}
+		self.statuses = statuses;
+	}
+
 	pub fn update_ioerr(&mut self) {
 		self.ticket = FILES_TICKET.fetch_add(1, Ordering::Relaxed);
 		self.hidden.clear();
@@ -214,7 +266,10 @@ impl Files {
 			};
 		}
 
-		let (hidden, items) = if let Some(filter) = &self.filter {
+		let (hidden, items) = if self.ignored.is_some() {
+			// The directory-only rules can't be told apart by the URL alone
+			(urls.clone(), urls)
+		} else if let Some(filter) = &self.filter {
 			urls.into_iter().partition(|u| {
 				(!self.show_hidden && u.is_hidden()) || !u.file_name().is_some_and(|s| filter.matches(s))
 			})
@@ -281,13 +336,12 @@ impl Files {
 
 		let (mut hidden, mut items) = if let Some(filter) = &self.filter {
 			files.into_iter().partition(|(_, f)| {
-				(f.is_hidden() && !self.show_hidden)
-					|| !f.url.file_name().is_some_and(|s| filter.matches(s))
+				self.hides(f) || !f.url.file_name().is_some_and(|s| filter.matches(s))
 			})
-		} else if self.show_hidden {
+		} else if self.show_hidden && self.ignored.is_none() {
 			(HashMap::new(), files)
 		} else {
-			files.into_iter().partition(|(_, f)| f.is_hidden())
+			files.into_iter().partition(|(_, f)| self.hides(f))
 		};
 
 		if !items.is_empty() {
@@ -304,6 +358,12 @@ impl Files {
 			return;
 		}
 
+		// The changed directories get their sizes recalculated, mostly from `DIR_SIZES`
+		let stale = files.keys().filter(|&u| self.sizes.remove(u).is_some()).count();
+		if stale > 0 && self.sorter.by == SortBy::Size {
+			self.revision += 1;
+		}
+
 		let (hidden, items) = self.update_updating(files);
 		if hidden.is_empty() && items.is_empty() {
 			return;
@@ -324,22 +384,27 @@ impl Files {
 		}
 
 		self.version = self.revision;
-		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sorter.sort(&mut self.items, &self.sizes, &self.statuses);
 		true
 	}
 
 	fn split_files(&self, files: impl IntoIterator<Item = File>) -> (Vec<File>, Vec<File>) {
 		if let Some(filter) = &self.filter {
 			files.into_iter().partition(|f| {
-				(f.is_hidden() && !self.show_hidden)
-					|| !f.url.file_name().is_some_and(|s| filter.matches(s))
+				self.hides(f) || !f.url.file_name().is_some_and(|s| filter.matches(s))
 			})
-		} else if self.show_hidden {
+		} else if self.show_hidden && self.ignored.is_none() {
 			(vec![], files.into_iter().collect())
 		} else {
-			files.into_iter().partition(|f| f.is_hidden())
+			files.into_iter().partition(|f| self.hides(f))
 		}
 	}
+
+	#[inline]
+	fn hides(&self, f: &File) -> bool {
+		(f.is_hidden() && !self.show_hidden)
+			|| self.ignored.as_ref().is_some_and(|i| i.matches(&f.url, f.is_dir()))
+	}
 }
 
 impl Files {
@@ -379,14 +444,14 @@ impl Files {
 			self.hidden = hidden;
 			if !items.is_empty() {
 				self.items.extend(items);
-				self.sorter.sort(&mut self.items, &self.sizes);
+				self.sorter.sort(&mut self.items, &self.sizes, &self.statuses);
 			}
 			return true;
 		}
 
 		let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
 		(self.hidden, self.items) = self.split_files(it);
-		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sorter.sort(&mut self.items, &self.sizes, &self.statuses);
 		true
 	}
 
@@ -413,4 +478,16 @@ impl Files {
 			self.items.extend(items);
 		}
 	}
+
+	// --- Ignored
+	pub fn set_ignored(&mut self, ignored: Option<Arc<Ignored>>) {
+		if self.ignored.as_ref().map(Arc::as_ptr) == ignored.as_ref().map(Arc::as_ptr) {
+			return;
+		}
+
+		self.ignored = ignored;
+		let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
+		(self.hidden, self.items) = self.split_files(it);
+		self.revision += 1;
+	}
 }
@@ -109,7 +109,7 @@ impl Folder {
 		let len = self.files.len();
 
 		let limit = LAYOUT.load().current.height as usize;
-		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
+		let scrolloff = (limit / 2).min(MANAGER.load().scrolloff as usize);
 
 		self.cursor = step.add(self.cursor, limit).min(len.saturating_sub(1));
 		self.offset = if self.cursor >= (self.offset + limit).min(len).saturating_sub(scrolloff) {
@@ -126,7 +126,7 @@ impl Folder {
 		let max = self.files.len().saturating_sub(1);
 
 		let limit = LAYOUT.load().current.height as usize;
-		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
+		let scrolloff = (limit / 2).min(MANAGER.load().scrolloff as usize);
 
 		self.cursor = step.add(self.cursor, limit).min(max);
 		self.offset = if self.cursor < self.offset + scrolloff {
@@ -143,6 +143,12 @@ impl Folder {
 	#[inline]
 	pub fn hovered(&self) -> Option<&File> { self.files.get(self.cursor) }
 
+	pub fn window(&self) -> &[File] {
+		let len = self.files.len();
+		let limit = LAYOUT.load().current.height as usize;
+		&self.files[self.offset.min(len)..(self.offset + limit).min(len)]
+	}
+
 	pub fn paginate(&self, page: usize) -> &[File] {
 		let len = self.files.len();
 		let limit = LAYOUT.load().current.height as usize;
@@ -0,0 +1,91 @@
+use std::{collections::HashMap, path::{Path, PathBuf}, sync::Arc};
+
+use ignore::gitignore::{gitconfig_excludes_path, Gitignore, GitignoreBuilder};
+use parking_lot::Mutex;
+use yazi_shared::RoCell;
+
+pub static GITIGNORES: RoCell<Mutex<Gitignores>> = RoCell::new();
+
+// The rules that apply to a directory, from the repo root down to itself
+pub struct Ignored {
+	chain: Vec<Arc<Gitignore>>,
+}
+
+impl Ignored {
+	pub fn matches(&self, path: &Path, is_dir: bool) -> bool {
+		for rules in self.chain.iter().rev() {
+			let m = rules.matched_path_or_any_parents(path, is_dir);
+			if !m.is_none() {
+				return m.is_ignore();
+			}
+		}
+		false
+	}
+}
+
+#[derive(Default)]
+pub struct Gitignores {
+	roots: HashMap<PathBuf, Option<PathBuf>>,
+	repos: HashMap<PathBuf, Repo>,
+}
+
+#[derive(Default)]
+struct Repo {
+	levels: HashMap<PathBuf, Option<Arc<Gitignore>>>,
+	dirs:   HashMap<PathBuf, Arc<Ignored>>,
+}
+
+impl Gitignores {
+	pub fn get(&mut self, dir: &Path) -> Option<Arc<Ignored>> {
+		let root = self.root_of(dir)?;
+		let repo = self.repos.entry(root.clone()).or_default();
+		if let Some(ignored) = repo.dirs.get(dir) {
+			return Some(ignored.clone());
+		}
+
+		let mut levels: Vec<_> = dir.ancestors().take_while(|p| p.starts_with(&root)).collect();
+		levels.reverse();
+
+		let chain = levels
+			.into_iter()
+			.filter_map(|p| {
+				repo.levels.entry(p.to_owned()).or_insert_with(|| Self::build(&root, p)).clone()
+			})
+			.collect();
+
+		let ignored = Arc::new(Ignored { chain });
+		repo.dirs.insert(dir.to_owned(), ignored.clone());
+		Some(ignored)
+	}
+
+	// Drops the cached rules of the repo containing the directory, after a `.gitignore` in it changed
+	pub fn forget(&mut self, dir: &Path) {
+		if let Some(Some(root)) = self.roots.get(dir) {
+			self.repos.remove(root);
+		}
+	}
+
+	fn root_of(&mut self, dir: &Path) -> Option<PathBuf> {
+		if let Some(root) = self.roots.get(dir) {
+			return root.clone();
+		}
+
+		let root = dir.ancestors().find(|p| p.join(".git").exists()).map(Path::to_owned);
+		self.roots.insert(dir.to_owned(), root.clone());
+		root
+	}
+
+	// The global excludes and `.git/info/exclude` come first, so the `.gitignore` takes precedence
+	fn build(root: &Path, dir: &Path) -> Option<Arc<Gitignore>> {
+		let mut builder = GitignoreBuilder::new(dir);
+		if dir == root {
+			if let Some(p) = gitconfig_excludes_path() {
+				builder.add(p);
+			}
+			builder.add(root.join(".git/info/exclude"));
+		}
+		builder.add(dir.join(".gitignore"));
+
+		builder.build().ok().filter(|g| !g.is_empty()).map(Arc::new)
+	}
+}
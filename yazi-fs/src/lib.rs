@@ -3,13 +3,28 @@
 mod files;
 mod filter;
 mod folder;
+mod ignored;
+mod mounts;
+mod recent;
+mod sizes;
 mod sorter;
 mod stage;
 mod step;
+mod vcs_status;
+pub mod vfs;
+mod volume;
+mod walker;
 
 pub use files::*;
 pub use filter::*;
 pub use folder::*;
+pub use ignored::*;
+pub use mounts::*;
+pub use recent::*;
+pub use sizes::*;
 pub use sorter::*;
 pub use stage::*;
 pub use step::*;
+pub use vcs_status::*;
+pub use volume::*;
+pub use walker::*;
@@ -0,0 +1,123 @@
+use std::path::Path;
+
+// The type of the filesystem `path` is on, from the innermost mount point containing it.
+#[cfg(target_os = "linux")]
+pub fn fstype(path: &Path) -> Option<String> {
+	let mounts = std::fs::read_to_string("/proc/self/mounts").ok()?;
+	mounts
+		.lines()
+		.filter_map(|l| {
+			let mut it = l.split_ascii_whitespace().skip(1);
+			Some((unescape(it.next()?), it.next()?))
+		})
+		.filter(|(point, _)| path.starts_with(point))
+		.max_by_key(|(point, _)| point.as_os_str().len())
+		.map(|(_, ty)| ty.to_owned())
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd", target_os = "openbsd"))]
+pub fn fstype(path: &Path) -> Option<String> {
+	use std::{ffi::{CStr, CString}, os::unix::ffi::OsStrExt};
+
+	let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+	let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+	if unsafe { libc::statfs(path.as_ptr(), &mut stat) } != 0 {
+		return None;
+	}
+
+	let name = unsafe { CStr::from_ptr(stat.f_fstypename.as_ptr()) };
+	Some(name.to_string_lossy().into_owned())
+}
+
+// The name of the filesystem of the volume `path` is on, such as `NTFS` or `exFAT`
+#[cfg(windows)]
+pub fn fstype(path: &Path) -> Option<String> {
+	use std::{os::windows::ffi::OsStrExt, ptr};
+
+	use windows_sys::Win32::Storage::FileSystem::{GetVolumeInformationW, GetVolumePathNameW};
+
+	let path: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+	let mut root = [0u16; 261];
+	if unsafe { GetVolumePathNameW(path.as_ptr(), root.as_mut_ptr(), root.len() as u32) } == 0 {
+		return None;
+	}
+
+	let mut name = [0u16; 261];
+	let ok = unsafe {
+		GetVolumeInformationW(
+			root.as_ptr(),
+			ptr::null_mut(),
+			0,
+			ptr::null_mut(),
+			ptr::null_mut(),
+			ptr::null_mut(),
+			name.as_mut_ptr(),
+			name.len() as u32,
+		)
+	};
+
+	let len = name.iter().position(|&c| c == 0)?;
+	(ok != 0).then(|| String::from_utf16_lossy(&name[..len]))
+}
+
+// Unknown elsewhere, which have to rely on the configured paths.
+#[cfg(not(any(
+	target_os = "linux",
+	target_os = "macos",
+	target_os = "ios",
+	target_os = "freebsd",
+	target_os = "openbsd",
+	windows
+)))]
+pub fn fstype(_: &Path) -> Option<String> { None }
+
+// Spaces, tabs and backslashes are octal-escaped in the mount points.
+#[cfg(target_os = "linux")]
+fn unescape(s: &str) -> std::path::PathBuf {
+	std::path::PathBuf::from(s.replace("\\040", " ").replace("\\011", "\t").replace("\\134", "\\"))
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Space {
+	pub free:  u64,
+	pub total: u64,
+}
+
+pub fn readable_size(mut n: u64) -> String {
+	const UNITS: [&str; 7] = ["B", "K", "M", "G", "T", "P", "E"];
+	let mut i = 0;
+	let mut rem = 0;
+	while n >= 1024 && i < UNITS.len() - 1 {
+		(n, rem) = (n / 1024, n % 1024);
+		i += 1;
+	}
+	format!("{}.{}{}", n, rem * 10 / 1024, UNITS[i])
+}
+
+// The space of the filesystem `path` is on, where the free one is what's available to the user,
+// i.e. without the blocks reserved for root.
+#[cfg(unix)]
+pub fn space(path: &Path) -> Option<Space> {
+	use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+	let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+	let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+	if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+		return None;
+	}
+
+	let size = stat.f_frsize as u64;
+	Some(Space { free: stat.f_bavail as u64 * size, total: stat.f_blocks as u64 * size })
+}
+
+#[cfg(windows)]
+pub fn space(path: &Path) -> Option<Space> {
+	use std::{os::windows::ffi::OsStrExt, ptr};
+
+	use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+	let path: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+	let (mut free, mut total) = (0u64, 0u64);
+	let ok = unsafe { GetDiskFreeSpaceExW(path.as_ptr(), &mut free, &mut total, ptr::null_mut()) };
+	(ok != 0).then_some(Space { free, total })
+}
@@ -0,0 +1,115 @@
+use std::{cmp::Reverse, collections::HashMap, path::PathBuf, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use parking_lot::Mutex;
+use percent_encoding::percent_decode_str;
+use quick_xml::{events::Event, Reader};
+use tokio::fs;
+use yazi_config::MANAGER;
+use yazi_shared::{fs::{File, Url}, Xdg};
+
+use crate::vfs::parse_time;
+
+// The most that are kept, the oldest ones are forgotten first
+const MAX: usize = 500;
+
+// The files opened or modified in Yazi, and the last time of it in seconds
+static RECENT: Mutex<Option<HashMap<PathBuf, u64>>> = Mutex::new(None);
+
+pub struct Recent;
+
+impl Recent {
+	pub fn touch<'a>(urls: impl IntoIterator<Item = &'a Url>) {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+		let mut recent = RECENT.lock();
+		let recent = recent.get_or_insert_with(Self::load);
+		for u in urls.into_iter().filter(|u| u.is_regular()) {
+			recent.insert(u.to_path_buf(), now);
+		}
+
+		if recent.len() > MAX {
+			let mut times: Vec<_> = recent.values().copied().collect();
+			times.sort_unstable_by(|a, b| b.cmp(a));
+			let oldest = times[MAX - 1];
+			recent.retain(|_, &mut t| t >= oldest);
+		}
+
+		if let Ok(s) = serde_json::to_string(recent) {
+			tokio::task::spawn_blocking(move || std::fs::write(Self::store(), s).ok());
+		}
+	}
+
+	// The most recent first, without the ones that are gone since
+	pub async fn files() -> Vec<File> {
+		let mut times: HashMap<_, _> = RECENT
+			.lock()
+			.get_or_insert_with(Self::load)
+			.iter()
+			.map(|(p, &t)| (p.clone(), UNIX_EPOCH + Duration::from_secs(t)))
+			.collect();
+
+		if MANAGER.load().recent_xbel {
+			for (p, t) in Self::xbel().await {
+				let old = times.entry(p).or_insert(t);
+				*old = t.max(*old);
+			}
+		}
+
+		let mut times: Vec<_> = times.into_iter().collect();
+		times.sort_unstable_by_key(|&(_, t)| Reverse(t));
+		times.truncate(MAX);
+
+		let files = times.into_iter().map(|(p, _)| async move {
+			let meta = fs::symlink_metadata(&p).await.ok()?;
+			Some(File::from_meta(Url::from(p), meta).await)
+		});
+		futures::future::join_all(files).await.into_iter().flatten().collect()
+	}
+
+	fn load() -> HashMap<PathBuf, u64> {
+		std::fs::read(Self::store())
+			.ok()
+			.and_then(|b| serde_json::from_slice(&b).ok())
+			.unwrap_or_default()
+	}
+
+	// The `visited` or `modified` of each `<bookmark>` in the XDG `recently-used.xbel`
+	async fn xbel() -> Vec<(PathBuf, SystemTime)> {
+		let Some(path) = dirs::data_dir().map(|p| p.join("recently-used.xbel")) else {
+			return vec![];
+		};
+		let Ok(body) = fs::read_to_string(path).await else {
+			return vec![];
+		};
+
+		let mut reader = Reader::from_str(&body);
+		let mut out = vec![];
+		loop {
+			let e = match reader.read_event() {
+				Ok(Event::Start(e) | Event::Empty(e)) if e.local_name().as_ref() == b"bookmark" => e,
+				Ok(Event::Eof) | Err(_) => break,
+				_ => continue,
+			};
+
+			let (mut href, mut time) = (None, None);
+			for attr in e.attributes().flatten() {
+				let value = String::from_utf8_lossy(&attr.value);
+				match attr.key.as_ref() {
+					b"href" => {
+						let path = value.strip_prefix("file://");
+						href = path.map(|s| percent_decode_str(s).decode_utf8_lossy().into_owned());
+					}
+					b"visited" | b"modified" => time = time.max(parse_time(&value)),
+					_ => {}
+				}
+			}
+			if let (Some(href), Some(time)) = (href, time) {
+				out.push((PathBuf::from(href), time));
+			}
+		}
+		out
+	}
+
+	#[inline]
+	fn store() -> PathBuf { Xdg::state_dir().join("recent.json") }
+}
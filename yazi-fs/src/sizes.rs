@@ -0,0 +1,101 @@
+use std::{collections::{HashMap, VecDeque}, path::{Path, PathBuf}, sync::atomic::{AtomicU64, Ordering}, time::SystemTime};
+
+use parking_lot::RwLock;
+use tokio::fs;
+use yazi_shared::RoCell;
+
+pub static DIR_SIZES: RoCell<DirSizes> = RoCell::new();
+
+// The directories kept at most, the half used least recently is dropped once it's exceeded
+const MAX_ENTRIES: usize = 100_000;
+
+// The size of the files directly inside each directory, along with its subdirectories,
+// which stays valid as long as the mtime of the directory doesn't change.
+#[derive(Default)]
+pub struct DirSizes {
+	inner: RwLock<HashMap<PathBuf, Entry>>,
+	tick:  AtomicU64,
+}
+
+struct Entry {
+	mtime:   SystemTime,
+	own:     u64,
+	subdirs: Vec<PathBuf>,
+	used:    AtomicU64,
+}
+
+impl DirSizes {
+	// Same as `calculate_size_with()`, but only reads the directories that have changed since
+	// the last time, the unchanged ones cost a `stat` each.
+	pub async fn calculate(&self, path: &Path, skip: impl Fn(&Path) -> bool) -> u64 {
+		let mut total = 0;
+		let mut stack = VecDeque::from([path.to_path_buf()]);
+		while let Some(path) = stack.pop_front() {
+			let Ok(meta) = fs::symlink_metadata(&path).await else { continue };
+			if !meta.is_dir() {
+				total += meta.len();
+				continue;
+			}
+
+			let mtime = meta.modified().ok();
+			let cached = mtime.and_then(|t| {
+				let inner = self.inner.read();
+				let e = inner.get(&path).filter(|e| e.mtime == t)?;
+				e.used.store(self.tick.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
+				Some((e.own, e.subdirs.clone()))
+			});
+
+			let (own, subdirs) = match cached {
+				Some(v) => v,
+				None => {
+					let Some(v) = Self::read(&path).await else { continue };
+					if let Some(mtime) = mtime {
+						let used = self.tick.fetch_add(1, Ordering::Relaxed).into();
+						self.insert(path, Entry { mtime, own: v.0, subdirs: v.1.clone(), used });
+					}
+					v
+				}
+			};
+
+			total += own;
+			stack.extend(subdirs.into_iter().filter(|p| !skip(p)));
+		}
+		total
+	}
+
+	// Modifying a file in place doesn't touch the mtime of its directory,
+	// so the watcher drops both the changed path and its parent.
+	pub fn invalidate(&self, path: &Path) {
+		let mut inner = self.inner.write();
+		inner.remove(path);
+		if let Some(parent) = path.parent() {
+			inner.remove(parent);
+		}
+	}
+
+	fn insert(&self, path: PathBuf, entry: Entry) {
+		let mut inner = self.inner.write();
+		inner.insert(path, entry);
+		if inner.len() <= MAX_ENTRIES {
+			return;
+		}
+
+		let mut used: Vec<_> = inner.values().map(|e| e.used.load(Ordering::Relaxed)).collect();
+		let (_, &mut median, _) = used.select_nth_unstable(MAX_ENTRIES / 2);
+		inner.retain(|_, e| e.used.load(Ordering::Relaxed) > median);
+	}
+
+	async fn read(dir: &Path) -> Option<(u64, Vec<PathBuf>)> {
+		let mut it = fs::read_dir(dir).await.ok()?;
+		let (mut own, mut subdirs) = (0, vec![]);
+		while let Ok(Some(entry)) = it.next_entry().await {
+			let Ok(meta) = entry.metadata().await else { continue };
+			if meta.is_dir() {
+				subdirs.push(entry.path());
+			} else {
+				own += meta.len();
+			}
+		}
+		Some((own, subdirs))
+	}
+}
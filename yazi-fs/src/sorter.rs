@@ -1,8 +1,10 @@
-use std::{cmp::Ordering, collections::HashMap, mem};
+use std::{cmp::{Ordering, Reverse}, collections::HashMap, mem};
 
 use yazi_config::manager::SortBy;
 use yazi_shared::{fs::{File, Url}, natsort, LcgRng, Transliterator};
 
+use crate::VcsStatus;
+
 #[derive(Clone, Copy, Default, PartialEq)]
 pub struct FilesSorter {
 	pub by:        SortBy,
@@ -13,7 +15,12 @@ pub struct FilesSorter {
 }
 
 impl FilesSorter {
-	pub(super) fn sort(&self, items: &mut Vec<File>, sizes: &HashMap<Url, u64>) {
+	pub(super) fn sort(
+		&self,
+		items: &mut Vec<File>,
+		sizes: &HashMap<Url, u64>,
+		statuses: &HashMap<Url, VcsStatus>,
+	) {
 		if items.is_empty() {
 			return;
 		}
@@ -60,6 +67,13 @@ impl FilesSorter {
 				let ord = self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), self.promote(a, b));
 				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
 			}),
+			// The changed ones go first, the most significant of them on top
+			SortBy::Vcs => items.sort_unstable_by(|a, b| {
+				let aa = Reverse(statuses.get(&a.url).map_or(0, |s| s.priority()));
+				let bb = Reverse(statuses.get(&b.url).map_or(0, |s| s.priority()));
+				let ord = self.cmp(aa, bb, self.promote(a, b));
+				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
+			}),
 			SortBy::Random => {
 				let mut rng = LcgRng::default();
 				items.sort_unstable_by(|a, b| self.cmp(rng.next(), rng.next(), self.promote(a, b)))
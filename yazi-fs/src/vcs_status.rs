@@ -0,0 +1,50 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VcsStatus {
+	Modified,
+	Added,
+	Deleted,
+	Renamed,
+	Untracked,
+	Ignored,
+	Conflicted,
+}
+
+impl VcsStatus {
+	// The status shown on the directories that contain this one
+	#[inline]
+	pub fn rollup(self) -> Self {
+		match self {
+			Self::Deleted | Self::Renamed => Self::Modified,
+			s => s,
+		}
+	}
+
+	#[inline]
+	pub fn priority(self) -> u8 {
+		match self {
+			Self::Conflicted => 5,
+			Self::Modified | Self::Deleted | Self::Renamed => 4,
+			Self::Added => 3,
+			Self::Untracked => 2,
+			Self::Ignored => 1,
+		}
+	}
+
+	#[inline]
+	pub fn max(self, other: Self) -> Self {
+		if other.priority() > self.priority() { other } else { self }
+	}
+
+	#[inline]
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Modified => "modified",
+			Self::Added => "added",
+			Self::Deleted => "deleted",
+			Self::Renamed => "renamed",
+			Self::Untracked => "untracked",
+			Self::Ignored => "ignored",
+			Self::Conflicted => "conflicted",
+		}
+	}
+}
@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, io, sync::Arc};
+
+use futures::TryStreamExt;
+use percent_encoding::{percent_decode_str, utf8_percent_encode};
+use quick_xml::{events::Event, Reader};
+use reqwest::{header, Body, Client, Method, RequestBuilder, Response, StatusCode};
+use tokio::{io::AsyncRead, sync::Mutex};
+use tokio_util::io::{ReaderStream, StreamReader};
+use yazi_config::VFS;
+use yazi_shared::fs::{Cha, ChaKind, File, Url, UrlScheme, PATH_ENCODE_SET};
+
+use super::Upload;
+
+const PROPFIND: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+	<d:prop><d:resourcetype/><d:getcontentlength/><d:getlastmodified/></d:prop>
+</d:propfind>"#;
+
+struct Server {
+	client: Client,
+	base:   String,
+	auth:   Option<(String, String)>,
+}
+
+static SERVERS: Mutex<BTreeMap<(UrlScheme, String), Arc<Server>>> =
+	Mutex::const_new(BTreeMap::new());
+
+// The user comes from the url or the config, and the password from the config or the keyring
+fn auth(domain: &str) -> Option<(String, String)> {
+	let (user, host) = match domain.split_once('@') {
+		Some((u, h)) => (Some(u), h),
+		None => (None, domain),
+	};
+
+	let vfs = VFS.load();
+	let cred = vfs.dav_credential(host).filter(|c| user.map_or(true, |u| u == c.user));
+	let user = user.or(cred.map(|c| c.user.as_str()))?.to_owned();
+	if let Some(c) = cred.filter(|c| !c.password.is_empty()) {
+		return Some((user, c.password.clone()));
+	}
+
+	let password = keyring::Entry::new("yazi", &format!("{user}@{host}"))
+		.and_then(|e| e.get_password())
+		.unwrap_or_default();
+	Some((user, password))
+}
+
+async fn server(url: &Url) -> io::Result<Arc<Server>> {
+	let mut servers = SERVERS.lock().await;
+	let key = (url.scheme(), url.domain().to_owned());
+	if let Some(s) = servers.get(&key) {
+		return Ok(s.clone());
+	}
+
+	let domain = url.domain().to_owned();
+	let auth = tokio::task::spawn_blocking(move || auth(&domain)).await?;
+
+	let host = url.domain().rsplit('@').next().unwrap_or_default();
+	let scheme = if url.scheme() == UrlScheme::Davs { "https" } else { "http" };
+	let server = Arc::new(Server {
+		client: Client::builder().build().map_err(io::Error::other)?,
+		base: format!("{scheme}://{host}"),
+		auth,
+	});
+
+	servers.insert(key, server.clone());
+	Ok(server)
+}
+
+impl Server {
+	fn request(&self, method: Method, url: &Url) -> RequestBuilder {
+		let req = self.client.request(method, self.href(url));
+		match &self.auth {
+			Some((user, password)) => req.basic_auth(user, Some(password)),
+			None => req,
+		}
+	}
+
+	#[inline]
+	fn href(&self, url: &Url) -> String {
+		format!("{}{}", self.base, utf8_percent_encode(&url.to_string_lossy(), PATH_ENCODE_SET))
+	}
+
+	async fn send(req: RequestBuilder) -> io::Result<Response> {
+		let resp = req.send().await.map_err(io::Error::other)?;
+		match resp.status() {
+			s if s.is_success() => Ok(resp),
+			StatusCode::NOT_FOUND => Err(io::Error::from(io::ErrorKind::NotFound)),
+			StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+				Err(io::Error::new(io::ErrorKind::PermissionDenied, resp.status().to_string()))
+			}
+			s => Err(io::Error::other(s.to_string())),
+		}
+	}
+
+	async fn propfind(&self, url: &Url, depth: &str) -> io::Result<Vec<(String, Cha)>> {
+		let req = self
+			.request(Method::from_bytes(b"PROPFIND").unwrap(), url)
+			.header("Depth", depth)
+			.header(header::CONTENT_TYPE, "application/xml")
+			.body(PROPFIND);
+
+		let body = Self::send(req).await?.text().await.map_err(io::Error::other)?;
+		Self::parse(&body).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+	}
+
+	// Takes the hrefs and properties out of a `multistatus`, in whatever the namespace prefix is
+	fn parse(body: &str) -> Result<Vec<(String, Cha)>, quick_xml::Error> {
+		let mut reader = Reader::from_str(body);
+		reader.config_mut().trim_text(true);
+
+		let (mut out, mut tag, mut href, mut cha) = (vec![], vec![], String::new(), Cha::default());
+		loop {
+			match reader.read_event()? {
+				Event::Start(e) => tag = e.local_name().as_ref().to_owned(),
+				Event::Empty(e) if e.local_name().as_ref() == b"collection" => cha.kind |= ChaKind::DIR,
+				Event::Text(t) => {
+					let text = t.unescape()?;
+					match tag.as_slice() {
+						b"href" => href = percent_decode_str(&text).decode_utf8_lossy().into_owned(),
+						b"getcontentlength" => cha.len = text.parse().unwrap_or(0),
+						b"getlastmodified" => cha.mtime = httpdate::parse_http_date(&text).ok(),
+						_ => {}
+					}
+				}
+				Event::End(e) => {
+					if e.local_name().as_ref() == b"collection" {
+						cha.kind |= ChaKind::DIR;
+					} else if e.local_name().as_ref() == b"response" {
+						out.push((std::mem::take(&mut href), std::mem::take(&mut cha)));
+					}
+					tag.clear();
+				}
+				Event::Eof => break,
+				_ => {}
+			}
+		}
+		Ok(out)
+	}
+}
+
+// The hrefs may be either absolute urls or paths, with or without the trailing slash
+fn href_path(href: &str) -> &str {
+	let path = href.split_once("://").map_or(href, |(_, s)| s.find('/').map_or("/", |i| &s[i..]));
+	match path.trim_end_matches('/') {
+		"" => "/",
+		p => p,
+	}
+}
+
+pub(super) async fn read_dir(dir: &Url) -> io::Result<Vec<File>> {
+	let s = server(dir).await?;
+	let this = href_path(&dir.to_string_lossy()).to_owned();
+
+	let mut files = vec![];
+	for (href, cha) in s.propfind(dir, "1").await? {
+		let path = href_path(&href);
+		if path == this {
+			continue;
+		}
+		if let Some(name) = path.rsplit('/').next().filter(|s| !s.is_empty()) {
+			files.push(File { url: dir.join(name), cha, ..Default::default() });
+		}
+	}
+	Ok(files)
+}
+
+pub(super) async fn metadata(url: &Url) -> io::Result<Cha> {
+	let s = server(url).await?;
+	let mut it = s.propfind(url, "0").await?.into_iter();
+	it.next().map(|(_, c)| c).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+}
+
+pub(super) async fn open(url: &Url) -> io::Result<impl AsyncRead + Send + Unpin> {
+	let s = server(url).await?;
+	let resp = Server::send(s.request(Method::GET, url)).await?;
+	Ok(StreamReader::new(resp.bytes_stream().map_err(io::Error::other)))
+}
+
+pub(super) async fn create(url: &Url) -> io::Result<Upload> {
+	let req = server(url).await?.request(Method::PUT, url);
+	Ok(Upload::spawn(|rx| async move {
+		Server::send(req.body(Body::wrap_stream(ReaderStream::new(rx)))).await.map(|_| ())
+	}))
+}
+
+pub(super) async fn create_dir(url: &Url) -> io::Result<()> {
+	let s = server(url).await?;
+	Server::send(s.request(Method::from_bytes(b"MKCOL").unwrap(), url)).await.map(|_| ())
+}
+
+pub(super) async fn remove_file(url: &Url) -> io::Result<()> {
+	let s = server(url).await?;
+	Server::send(s.request(Method::DELETE, url)).await.map(|_| ())
+}
+
+// Deleting a collection takes everything in it along, so it's checked to be empty first
+pub(super) async fn remove_dir(url: &Url) -> io::Result<()> {
+	if !read_dir(url).await?.is_empty() {
+		return Err(io::Error::other("directory not empty"));
+	}
+	remove_file(url).await
+}
+
+pub(super) async fn rename(from: &Url, to: &Url) -> io::Result<()> {
+	let s = server(from).await?;
+	let req = s
+		.request(Method::from_bytes(b"MOVE").unwrap(), from)
+		.header("Destination", s.href(to))
+		.header("Overwrite", "T");
+	Server::send(req).await.map(|_| ())
+}
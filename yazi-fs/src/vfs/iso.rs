@@ -0,0 +1,171 @@
+use std::{io::{self, SeekFrom}, path::{Component, Path}, time::SystemTime};
+
+use tokio::{fs, io::{AsyncReadExt, AsyncSeekExt, Take}};
+use yazi_shared::fs::{Cha, ChaKind, File, Url};
+
+use super::civil_time;
+
+const SECTOR: u64 = 2048;
+
+// The directories larger than this are taken as a broken image, rather than being read into memory
+const MAX_DIR: u32 = 16 * 1024 * 1024;
+
+#[derive(Clone)]
+struct Record {
+	name:  String,
+	lba:   u32,
+	len:   u32,
+	dir:   bool,
+	mtime: Option<SystemTime>,
+}
+
+impl Record {
+	fn parse(b: &[u8], joliet: bool) -> Option<Self> {
+		let raw = b.get(33..33 + *b.get(32)? as usize)?;
+		let name = if joliet {
+			let wide: Vec<_> = raw.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+			String::from_utf16_lossy(&wide)
+		} else {
+			String::from_utf8_lossy(raw).into_owned()
+		};
+
+		// Without the `;1` version, and the `.` of the ones that have no extension
+		let name = name.split(';').next().unwrap_or_default();
+		Some(Self {
+			name:  name.strip_suffix('.').unwrap_or(name).to_owned(),
+			lba:   u32::from_le_bytes(b.get(2..6)?.try_into().ok()?),
+			len:   u32::from_le_bytes(b.get(10..14)?.try_into().ok()?),
+			dir:   b.get(25)? & 2 != 0,
+			mtime: Self::time(b.get(18..25)?),
+		})
+	}
+
+	// Years since 1900, month, day, hour, minute, second, and the offset from UTC in 15 minutes
+	fn time(b: &[u8]) -> Option<SystemTime> {
+		let n = |i: usize| b[i] as i64;
+		if n(1) == 0 || n(2) == 0 {
+			return None;
+		}
+		civil_time(1900 + n(0), n(1), n(2), n(3) * 3600 + n(4) * 60 + n(5) - b[6] as i8 as i64 * 900)
+	}
+
+	fn cha(&self) -> Cha {
+		let kind = if self.dir { ChaKind::DIR } else { ChaKind::empty() };
+		Cha { kind, len: self.len as u64, mtime: self.mtime, ..Default::default() }
+	}
+}
+
+// An ISO 9660 image, browsed without mounting it, for the ones lacking the privileges to do so.
+// The Joliet names are preferred when there're any, as the primary ones are upper-cased 8.3 names.
+struct Image {
+	file:   fs::File,
+	root:   Record,
+	joliet: bool,
+}
+
+impl Image {
+	async fn open(url: &Url) -> io::Result<Self> {
+		let mut file = fs::File::open(url.domain()).await?;
+		let mut buf = vec![0; SECTOR as usize];
+		let (mut primary, mut joliet) = (None, None);
+
+		for i in 16..64 {
+			file.seek(SeekFrom::Start(i * SECTOR)).await?;
+			file.read_exact(&mut buf).await?;
+			if &buf[1..6] != b"CD001" {
+				break;
+			}
+
+			match buf[0] {
+				1 => primary = Record::parse(&buf[156..190], false),
+				2 if matches!(&buf[88..91], b"%/@" | b"%/C" | b"%/E") => {
+					joliet = Record::parse(&buf[156..190], true);
+				}
+				255 => break,
+				_ => {}
+			}
+		}
+
+		let (root, joliet) = match (joliet, primary) {
+			(Some(r), _) => (r, true),
+			(None, Some(r)) => (r, false),
+			(None, None) => return Err(io::Error::other("not an ISO 9660 image")),
+		};
+		Ok(Self { file, root, joliet })
+	}
+
+	async fn records(&mut self, dir: &Record) -> io::Result<Vec<Record>> {
+		if !dir.dir {
+			return Err(io::Error::other("not a directory"));
+		} else if dir.len > MAX_DIR {
+			return Err(io::Error::other("directory too large"));
+		}
+
+		let mut buf = vec![0; dir.len as usize];
+		self.file.seek(SeekFrom::Start(dir.lba as u64 * SECTOR)).await?;
+		self.file.read_exact(&mut buf).await?;
+
+		let (mut records, mut i) = (vec![], 0);
+		while i < buf.len() {
+			// The records don't span the sectors, the rest of one is padded with zeros
+			let n = buf[i] as usize;
+			if n == 0 {
+				i = (i / SECTOR as usize + 1) * SECTOR as usize;
+				continue;
+			}
+
+			let Some(b) = buf.get(i..i + n) else { break };
+			i += n;
+
+			// The first two are `.` and `..`, named `\0` and `\1`
+			if b.get(32) == Some(&1) && b.get(33).is_some_and(|&c| c <= 1) {
+				continue;
+			}
+			records.extend(Record::parse(b, self.joliet));
+		}
+		Ok(records)
+	}
+
+	async fn lookup(&mut self, path: &Path) -> io::Result<Record> {
+		let mut cur = self.root.clone();
+		for c in path.components() {
+			let Component::Normal(name) = c else { continue };
+			let name = name.to_string_lossy();
+
+			cur = self
+				.records(&cur)
+				.await?
+				.into_iter()
+				.find(|r| r.name == name || (!self.joliet && r.name.eq_ignore_ascii_case(&name)))
+				.ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+		}
+		Ok(cur)
+	}
+}
+
+pub(super) fn read_only() -> io::Error {
+	io::Error::new(io::ErrorKind::PermissionDenied, "the ISO 9660 images are browsed read-only")
+}
+
+pub(super) async fn read_dir(dir: &Url) -> io::Result<Vec<File>> {
+	let mut image = Image::open(dir).await?;
+	let record = image.lookup(dir).await?;
+
+	let file = |r: Record| File { url: dir.join(&r.name), cha: r.cha(), ..Default::default() };
+	Ok(image.records(&record).await?.into_iter().map(file).collect())
+}
+
+pub(super) async fn metadata(url: &Url) -> io::Result<Cha> {
+	Image::open(url).await?.lookup(url).await.map(|r| r.cha())
+}
+
+pub(super) async fn open(url: &Url) -> io::Result<Take<fs::File>> {
+	let mut image = Image::open(url).await?;
+	let record = image.lookup(url).await?;
+	if record.dir {
+		return Err(io::Error::other("is a directory"));
+	}
+
+	image.file.seek(SeekFrom::Start(record.lba as u64 * SECTOR)).await?;
+	Ok(image.file.take(record.len as u64))
+}
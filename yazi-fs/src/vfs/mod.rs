@@ -0,0 +1,246 @@
+mod dav;
+mod iso;
+mod mtp;
+mod s3;
+mod sftp;
+mod upload;
+
+use std::{io, path::PathBuf, time::{Duration, Instant, SystemTime, UNIX_EPOCH}};
+
+use md5::{Digest, Md5};
+use tokio::{fs, io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt}, sync::mpsc};
+use yazi_config::{PREVIEW, VFS};
+use yazi_shared::fs::{Cha, File, Url, UrlScheme};
+
+use self::upload::Upload;
+
+fn unsupported(url: &Url) -> io::Error {
+	io::Error::new(io::ErrorKind::Unsupported, format!("not a remote url: {url}"))
+}
+
+// The time of a civil date and the seconds into that day in UTC,
+// see http://howardhinnant.github.io/date_algorithms.html
+fn civil_time(y: i64, m: i64, d: i64, secs: i64) -> Option<SystemTime> {
+	let y = if m <= 2 { y - 1 } else { y };
+	let era = y.div_euclid(400);
+	let yoe = y - era * 400;
+	let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+	let days = era * 146097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719468;
+
+	Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(days * 86400 + secs).ok()?))
+}
+
+// Parses the `2006-01-02T15:04:05.000Z` used in the S3 listings and the XDG bookmarks
+pub(crate) fn parse_time(s: &str) -> Option<SystemTime> {
+	let n = |r: std::ops::Range<usize>| s.get(r)?.parse::<i64>().ok();
+	let (y, m, d) = (n(0..4)?, n(5..7)?, n(8..10)?);
+	let (hh, mm, ss) = (n(11..13)?, n(14..16)?, n(17..19)?);
+	civil_time(y, m, d, hh * 3600 + mm * 60 + ss)
+}
+
+pub async fn read_dir(url: &Url) -> io::Result<Vec<File>> {
+	match url.scheme() {
+		UrlScheme::Sftp => sftp::read_dir(url).await,
+		UrlScheme::S3 => s3::read_dir(url).await,
+		UrlScheme::Dav | UrlScheme::Davs => dav::read_dir(url).await,
+		UrlScheme::Mtp => mtp::read_dir(url).await,
+		UrlScheme::Iso => iso::read_dir(url).await,
+		_ => Err(unsupported(url)),
+	}
+}
+
+pub async fn metadata(url: &Url) -> io::Result<Cha> {
+	match url.scheme() {
+		UrlScheme::Sftp => sftp::metadata(url).await,
+		UrlScheme::S3 => s3::metadata(url).await,
+		UrlScheme::Dav | UrlScheme::Davs => dav::metadata(url).await,
+		UrlScheme::Mtp => mtp::metadata(url).await,
+		UrlScheme::Iso => iso::metadata(url).await,
+		_ => fs::metadata(url).await.map(Cha::from),
+	}
+}
+
+pub async fn create_dir(url: &Url) -> io::Result<()> {
+	match url.scheme() {
+		UrlScheme::Sftp => sftp::create_dir(url).await,
+		UrlScheme::S3 => s3::create_dir(url).await,
+		UrlScheme::Dav | UrlScheme::Davs => dav::create_dir(url).await,
+		UrlScheme::Mtp => mtp::create_dir(url).await,
+		UrlScheme::Iso => Err(iso::read_only()),
+		_ => fs::create_dir(url).await,
+	}
+}
+
+pub async fn remove_file(url: &Url) -> io::Result<()> {
+	match url.scheme() {
+		UrlScheme::Sftp => sftp::remove_file(url).await,
+		UrlScheme::S3 => s3::remove_file(url).await,
+		UrlScheme::Dav | UrlScheme::Davs => dav::remove_file(url).await,
+		UrlScheme::Mtp => mtp::remove_file(url).await,
+		UrlScheme::Iso => Err(iso::read_only()),
+		_ => fs::remove_file(url).await,
+	}
+}
+
+pub async fn remove_dir(url: &Url) -> io::Result<()> {
+	match url.scheme() {
+		UrlScheme::Sftp => sftp::remove_dir(url).await,
+		UrlScheme::S3 => s3::remove_dir(url).await,
+		UrlScheme::Dav | UrlScheme::Davs => dav::remove_dir(url).await,
+		UrlScheme::Mtp => mtp::remove_dir(url).await,
+		UrlScheme::Iso => Err(iso::read_only()),
+		_ => fs::remove_dir(url).await,
+	}
+}
+
+// Renames within the same location, as the remote ones can't be renamed across each other
+pub async fn rename(from: &Url, to: &Url) -> io::Result<()> {
+	if from.scheme() != to.scheme() || from.domain() != to.domain() {
+		return Err(io::Error::new(io::ErrorKind::Unsupported, "cannot rename across locations"));
+	}
+
+	match from.scheme() {
+		UrlScheme::Sftp => sftp::rename(from, to).await,
+		UrlScheme::S3 => s3::rename(from, to).await,
+		UrlScheme::Dav | UrlScheme::Davs => dav::rename(from, to).await,
+		UrlScheme::Mtp => mtp::rename(from, to).await,
+		UrlScheme::Iso => Err(iso::read_only()),
+		_ => fs::rename(from, to).await,
+	}
+}
+
+// Removes the directories left empty after moving their files out, deepest first.
+pub async fn remove_dir_clean(url: &Url) {
+	if !url.is_remote() {
+		return yazi_shared::fs::remove_dir_clean(url).await;
+	}
+
+	let Ok(files) = read_dir(url).await else { return };
+	for f in files.into_iter().filter(|f| f.is_dir() && !f.is_link()) {
+		Box::pin(remove_dir_clean(&f.url)).await;
+	}
+	remove_dir(url).await.ok();
+}
+
+pub async fn unique_name(mut url: Url) -> Url {
+	if !url.is_remote() {
+		return yazi_shared::fs::unique_name(url).await;
+	}
+
+	let Some(name) = url.file_stem().map(|s| s.to_string_lossy().into_owned()) else { return url };
+	let ext = url.extension().map(|s| format!(".{}", s.to_string_lossy())).unwrap_or_default();
+
+	let mut i = 0;
+	while metadata(&url).await.is_ok() {
+		i += 1;
+		url.set_file_name(format!("{name}_{i}{ext}"));
+	}
+	url
+}
+
+async fn reader(url: &Url) -> io::Result<Box<dyn AsyncRead + Send + Unpin>> {
+	Ok(match url.scheme() {
+		UrlScheme::Sftp => Box::new(sftp::open(url).await?),
+		UrlScheme::S3 => Box::new(s3::open(url).await?),
+		UrlScheme::Dav | UrlScheme::Davs => Box::new(dav::open(url).await?),
+		UrlScheme::Mtp => Box::new(mtp::open(url).await?),
+		UrlScheme::Iso => Box::new(iso::open(url).await?),
+		_ => Box::new(fs::File::open(url).await?),
+	})
+}
+
+async fn writer(url: &Url) -> io::Result<Box<dyn AsyncWrite + Send + Unpin>> {
+	Ok(match url.scheme() {
+		UrlScheme::Sftp => Box::new(sftp::create(url).await?),
+		UrlScheme::S3 => Box::new(s3::create(url).await?),
+		UrlScheme::Dav | UrlScheme::Davs => Box::new(dav::create(url).await?),
+		UrlScheme::Mtp => Box::new(mtp::create(url).await?),
+		UrlScheme::Iso => return Err(iso::read_only()),
+		_ => Box::new(fs::File::create(url).await?),
+	})
+}
+
+// Copies a file where either side is remote, with the same protocol as the local
+// `copy_with_progress`: the bytes copied so far in chunks, then `Ok(0)` once it's done.
+pub fn copy_with_progress(from: &Url, to: &Url) -> mpsc::Receiver<io::Result<u64>> {
+	let (tx, rx) = mpsc::channel(1);
+	let (from, to) = (from.clone(), to.clone());
+
+	tokio::spawn(async move {
+		let result = async {
+			let (mut r, mut w) = (reader(&from).await?, writer(&to).await?);
+			let (mut buf, mut last, mut pending) = (vec![0; 256 * 1024], Instant::now(), 0);
+
+			loop {
+				let n = r.read(&mut buf).await?;
+				if n == 0 {
+					break;
+				}
+
+				w.write_all(&buf[..n]).await?;
+				pending += n as u64;
+				if last.elapsed() >= Duration::from_secs(1) {
+					// Stop early once the receiver is gone, i.e. the task has been canceled
+					if tx.send(Ok(pending)).await.is_err() {
+						return Err(io::Error::from(io::ErrorKind::Interrupted));
+					}
+					(last, pending) = (Instant::now(), 0);
+				}
+			}
+
+			w.shutdown().await?;
+			Ok(pending)
+		}
+		.await;
+
+		match result {
+			Ok(n) => {
+				if n > 0 {
+					tx.send(Ok(n)).await.ok();
+				}
+				tx.send(Ok(0)).await.ok();
+			}
+			Err(e) => _ = tx.send(Err(e)).await,
+		}
+	});
+	rx
+}
+
+// Where the remote file is downloaded for previewing, which changes along with the file itself.
+// The extension is kept, so that the previewers matching on the name still apply.
+pub fn cache_path(url: &Url, cha: Cha) -> PathBuf {
+	let mut digest = Md5::new_with_prefix(url.to_string());
+	digest.update(format!("//{:?}//{}", cha.mtime, cha.len));
+
+	let ext = url.extension().map(|s| format!(".{}", s.to_string_lossy())).unwrap_or_default();
+	PREVIEW.load().cache_dir.join(format!("vfs-{:x}{ext}", digest.finalize()))
+}
+
+// Downloads the remote file to the cache, unless it's too large or already there.
+pub async fn download(url: &Url, cha: Cha) -> io::Result<PathBuf> {
+	if cha.len > VFS.load().preview_max_size {
+		return Err(io::Error::other(format!("larger than {} bytes", VFS.load().preview_max_size)));
+	}
+
+	let path = cache_path(url, cha);
+	if fs::try_exists(&path).await.unwrap_or(false) {
+		return Ok(path);
+	}
+
+	// Written under a temporary name first, so that a canceled one isn't taken as complete
+	let part = path.with_extension("part");
+	let mut it = copy_with_progress(url, &Url::from(&part));
+	while let Some(res) = it.recv().await {
+		match res {
+			Ok(0) => break,
+			Ok(_) => {}
+			Err(e) => {
+				fs::remove_file(&part).await.ok();
+				return Err(e);
+			}
+		}
+	}
+
+	fs::rename(&part, &path).await?;
+	Ok(path)
+}
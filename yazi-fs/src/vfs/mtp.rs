@@ -0,0 +1,429 @@
+use std::{io, pin::Pin, process::Stdio, task::{Context, Poll}, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+#[cfg(not(windows))]
+use percent_encoding::utf8_percent_encode;
+use tokio::{io::{AsyncRead, ReadBuf}, process::{Child, ChildStdout, Command}};
+use yazi_shared::fs::{Cha, ChaKind, File, Url};
+
+use super::Upload;
+
+// The output of the process streaming a file, which is killed if it's dropped before the end
+pub(super) struct Download {
+	_child: Child,
+	stdout: ChildStdout,
+}
+
+impl AsyncRead for Download {
+	fn poll_read(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+	) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.stdout).poll_read(cx, buf)
+	}
+}
+
+fn error(err: &[u8]) -> io::Error {
+	let err = String::from_utf8_lossy(err).trim().to_owned();
+	let kind = if err.contains("No such file") || err.contains("not found") {
+		io::ErrorKind::NotFound
+	} else if err.contains("ermission denied") {
+		io::ErrorKind::PermissionDenied
+	} else {
+		io::ErrorKind::Other
+	};
+	io::Error::new(kind, err)
+}
+
+// The devices and storages are always listed again, since there's no mtime to tell the change
+#[inline]
+fn dir_cha() -> Cha {
+	Cha { kind: ChaKind::DIR, mtime: Some(SystemTime::now()), ..Default::default() }
+}
+
+#[inline]
+fn time(secs: u64) -> SystemTime { UNIX_EPOCH + Duration::from_secs(secs) }
+
+// A line of `gio list -l`, i.e. `name\tsize\t(type)` followed by the attributes asked for,
+// which the script on Windows prints the same way for each item.
+fn entry(dir: &Url, line: &str) -> Option<File> {
+	let mut it = line.split('\t');
+	let (name, len, ty) = (it.next()?, it.next()?.parse().ok()?, it.next()?);
+
+	let mut kind = ChaKind::empty();
+	if ty.starts_with("(directory)") {
+		kind |= ChaKind::DIR;
+	}
+
+	let mtime = line
+		.split_once("time::modified=")
+		.and_then(|(_, s)| s.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok())
+		.filter(|&s| s != 0)
+		.map(time);
+
+	let cha = Cha { kind, len, mtime, ..Default::default() };
+	Some(File { url: dir.join(name), cha, ..Default::default() })
+}
+
+// --- Linux and the others, through `gio` of GVfs
+// The devices mounted by us, which are left mounted for the file managers sharing them
+#[cfg(not(windows))]
+static MOUNTED: tokio::sync::Mutex<std::collections::BTreeSet<String>> =
+	tokio::sync::Mutex::const_new(std::collections::BTreeSet::new());
+
+// Goes through the `gio` of GVfs, which already talks to the devices, and handles
+// the locking of them, the "Allow access" prompt on the phone, and the reconnecting.
+#[cfg(not(windows))]
+async fn gio(args: &[&str]) -> io::Result<String> {
+	let output = match Command::new("gio").args(args).kill_on_drop(true).output().await {
+		Ok(o) => o,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => {
+			yazi_proxy::AppProxy::notify_warn(
+				"MTP",
+				"`gio` from GVfs is required to access the MTP devices",
+			);
+			return Err(e);
+		}
+		Err(e) => return Err(e),
+	};
+
+	if output.status.success() {
+		Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+	} else {
+		Err(error(&output.stderr))
+	}
+}
+
+// `mtp:///` lists the devices, and the first component of the path is the device,
+// which has to be mounted before anything on it can be reached.
+#[cfg(not(windows))]
+async fn uri(url: &Url) -> io::Result<String> {
+	let path = url.to_string_lossy();
+	let path = path.trim_start_matches('/');
+	let (device, rest) = path.split_once('/').unwrap_or((path, ""));
+	if device.is_empty() {
+		return Err(io::Error::new(io::ErrorKind::Unsupported, "not on a device"));
+	}
+
+	let root = format!("mtp://{device}/");
+	let mut mounted = MOUNTED.lock().await;
+	if !mounted.contains(device) {
+		// Only remembered once it's reachable, so a locked phone is tried again the next time
+		match gio(&["mount", &root]).await {
+			Err(e) if !e.to_string().contains("already mounted") => return Err(e),
+			_ => _ = mounted.insert(device.to_owned()),
+		}
+	}
+	Ok(format!("{root}{}", utf8_percent_encode(rest, yazi_shared::fs::PATH_ENCODE_SET)))
+}
+
+// The devices known to GVfs, mounted or not
+#[cfg(not(windows))]
+async fn devices(root: &Url) -> io::Result<Vec<File>> {
+	let out = gio(&["mount", "-li"]).await?;
+	let ids: std::collections::BTreeSet<_> = out
+		.split_whitespace()
+		.filter_map(|s| s.split_once("mtp://").map(|(_, s)| s.trim_end_matches('/')))
+		.filter(|s| !s.is_empty() && !s.contains('/'))
+		.collect();
+
+	let file = |id: &str| File { url: root.join(id), cha: dir_cha(), ..Default::default() };
+	Ok(ids.into_iter().map(file).collect())
+}
+
+#[cfg(not(windows))]
+pub(super) async fn read_dir(dir: &Url) -> io::Result<Vec<File>> {
+	if dir.parent().is_none() {
+		return devices(dir).await;
+	}
+
+	let out = gio(&["list", "-l", "-a", "time::modified", &uri(dir).await?]).await?;
+	Ok(out.lines().filter_map(|l| entry(dir, l)).collect())
+}
+
+#[cfg(not(windows))]
+pub(super) async fn metadata(url: &Url) -> io::Result<Cha> {
+	if url.parent().is_none() {
+		return Ok(dir_cha());
+	}
+
+	let attrs = "standard::type,standard::size,time::modified";
+	let out = gio(&["info", "-a", attrs, &uri(url).await?]).await?;
+	let field = |k: &str| {
+		out.lines().find_map(|l| l.trim().strip_prefix(k)?.strip_prefix(": ")?.parse::<u64>().ok())
+	};
+
+	let mut kind = ChaKind::empty();
+	if field("standard::type") == Some(2) {
+		kind |= ChaKind::DIR;
+	}
+	Ok(Cha {
+		kind,
+		len: field("standard::size").unwrap_or(0),
+		mtime: field("time::modified").map(time),
+		..Default::default()
+	})
+}
+
+#[cfg(not(windows))]
+pub(super) async fn open(url: &Url) -> io::Result<Download> {
+	let mut child = Command::new("gio")
+		.args(["cat", &uri(url).await?])
+		.stdin(Stdio::null())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.kill_on_drop(true)
+		.spawn()?;
+
+	let stdout = child.stdout.take().unwrap();
+	Ok(Download { _child: child, stdout })
+}
+
+#[cfg(not(windows))]
+pub(super) async fn create(url: &Url) -> io::Result<Upload> {
+	let mut child = Command::new("gio")
+		.args(["save", &uri(url).await?])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::null())
+		.stderr(Stdio::piped())
+		.kill_on_drop(true)
+		.spawn()?;
+
+	let mut stdin = child.stdin.take().unwrap();
+	Ok(Upload::spawn(|mut rx| async move {
+		tokio::io::copy(&mut rx, &mut stdin).await?;
+		drop(stdin);
+
+		let output = child.wait_with_output().await?;
+		if output.status.success() { Ok(()) } else { Err(error(&output.stderr)) }
+	}))
+}
+
+#[cfg(not(windows))]
+pub(super) async fn create_dir(url: &Url) -> io::Result<()> {
+	gio(&["mkdir", &uri(url).await?]).await.map(|_| ())
+}
+
+#[cfg(not(windows))]
+pub(super) async fn remove_file(url: &Url) -> io::Result<()> {
+	gio(&["remove", &uri(url).await?]).await.map(|_| ())
+}
+
+#[cfg(not(windows))]
+pub(super) async fn remove_dir(url: &Url) -> io::Result<()> {
+	gio(&["remove", &uri(url).await?]).await.map(|_| ())
+}
+
+#[cfg(not(windows))]
+pub(super) async fn rename(from: &Url, to: &Url) -> io::Result<()> {
+	gio(&["move", "-T", &uri(from).await?, &uri(to).await?]).await.map(|_| ())
+}
+
+// --- Windows, through the portable devices under "This PC" of the Shell, which is
+// what Explorer uses, so the phone asks for the access the same way.
+//
+// The paths and names are handed over in the environment, and the items are found by name
+// from "This PC" down, since the ones on the devices can't be parsed from a path.
+#[cfg(windows)]
+const PRELUDE: &str = r#"
+$ErrorActionPreference = 'Stop'
+[Console]::OutputEncoding = [Text.Encoding]::UTF8
+$shell = New-Object -ComObject Shell.Application
+function Fail($e) { [Console]::Error.Write($e); exit 1 }
+function Find($path) {
+	$script:p = $null; $script:i = $null; $script:f = $shell.NameSpace(17)
+	foreach ($n in $path.Split([char[]]'/\', [StringSplitOptions]::RemoveEmptyEntries)) {
+		if (!$script:f) { Fail 'No such file or directory' }
+		$script:p = $script:f
+		$script:i = @($script:f.Items()) |
+			Where-Object { !$_.IsFileSystem -and $_.Name -eq $n } | Select-Object -First 1
+		if (!$script:i) { Fail 'No such file or directory' }
+		$script:f = if ($script:i.IsFolder) { $script:i.GetFolder } else { $null }
+	}
+}
+function Line($c) {
+	$t = try { ([DateTimeOffset]$c.ModifyDate).ToUnixTimeSeconds() } catch { 0 }
+	$k = if ($c.IsFolder) { '(directory)' } else { '(regular)' }
+	$line = $c.Name, [long]$c.Size, $k, ('time::modified=' + $t)
+	[Console]::Out.WriteLine($line -join [char]9)
+}
+function Wait($d, $name, $len) {
+	for ($n = 0; $n -le 3000; $n++) {
+		$done = @($d.Items()) | Where-Object { $_.Name -eq $name }
+		if ($done -and ($len -lt 0 -or $done[0].Size -eq $len)) { return }
+		Start-Sleep -Milliseconds 200
+	}
+	Fail 'Timed out'
+}
+# 4: no progress, 16: yes to all, 512: no confirmation for the new folders, 1024: no error UI
+$flags = 1556
+"#;
+
+#[cfg(windows)]
+fn shell(script: &str, envs: &[(&str, &std::ffi::OsStr)]) -> Command {
+	let mut cmd = Command::new("powershell");
+	cmd
+		.args(["-NoProfile", "-NonInteractive", "-Command", &format!("{PRELUDE}{script}")])
+		.envs(envs.iter().copied())
+		.stdin(Stdio::null())
+		.kill_on_drop(true);
+	cmd
+}
+
+#[cfg(windows)]
+async fn run(script: &str, envs: &[(&str, &std::ffi::OsStr)]) -> io::Result<String> {
+	let output = shell(script, envs).output().await?;
+	if output.status.success() {
+		Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+	} else {
+		Err(error(&output.stderr))
+	}
+}
+
+#[cfg(windows)]
+pub(super) async fn read_dir(dir: &Url) -> io::Result<Vec<File>> {
+	let script = r#"
+Find $env:YAZI_MTP_PATH
+if (!$f) { Fail 'Not a directory' }
+foreach ($c in @($f.Items()) | Where-Object { !$_.IsFileSystem }) { Line $c }
+"#;
+
+	let out = run(script, &[("YAZI_MTP_PATH", dir.as_os_str())]).await?;
+	let mut files: Vec<_> = out.lines().filter_map(|l| entry(dir, l)).collect();
+	if dir.parent().is_none() {
+		files.iter_mut().for_each(|f| f.cha = dir_cha());
+	}
+	Ok(files)
+}
+
+#[cfg(windows)]
+pub(super) async fn metadata(url: &Url) -> io::Result<Cha> {
+	if url.parent().is_none() {
+		return Ok(dir_cha());
+	}
+
+	let out = run("Find $env:YAZI_MTP_PATH; Line $i", &[("YAZI_MTP_PATH", url.as_os_str())]).await?;
+	let file = out.lines().find_map(|l| entry(url, l));
+	file.map(|f| f.cha).ok_or_else(|| io::Error::other("unexpected output of the shell"))
+}
+
+// Copied to a temporary folder of its own first, as the Shell only copies between folders,
+// and streamed out from there.
+#[cfg(windows)]
+pub(super) async fn open(url: &Url) -> io::Result<Download> {
+	let script = r#"
+Find $env:YAZI_MTP_PATH
+if (!$i -or $i.IsFolder) { Fail 'Is a directory' }
+$t = Join-Path ([IO.Path]::GetTempPath()) ('yazi-mtp-' + [Guid]::NewGuid())
+New-Item -ItemType Directory $t | Out-Null
+try {
+	$shell.NameSpace($t).CopyHere($i, $flags)
+	$dst = Join-Path $t $i.Name
+	for ($n = 0; !(Test-Path -LiteralPath $dst); $n++) {
+		if ($n -gt 3000) { Fail 'Timed out' }
+		Start-Sleep -Milliseconds 200
+	}
+	# Locked by the Shell until it's copied in full
+	while ($true) {
+		try { $s = [IO.File]::Open($dst, 'Open', 'Read', 'None'); break }
+		catch { Start-Sleep -Milliseconds 200 }
+	}
+	$o = [Console]::OpenStandardOutput(); $s.CopyTo($o); $o.Flush(); $s.Close()
+} finally {
+	Remove-Item -LiteralPath $t -Recurse -Force
+}
+"#;
+
+	let mut child = shell(script, &[("YAZI_MTP_PATH", url.as_os_str())])
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()?;
+
+	let stdout = child.stdout.take().unwrap();
+	Ok(Download { _child: child, stdout })
+}
+
+// Saved to a temporary folder of its own first, and copied over under the same name from there
+#[cfg(windows)]
+pub(super) async fn create(url: &Url) -> io::Result<Upload> {
+	let script = r#"
+Find $env:YAZI_MTP_PATH
+if (!$f) { Fail 'Not a directory' }
+$src = Get-Item -LiteralPath $env:YAZI_MTP_FROM
+$f.CopyHere($src.FullName, $flags)
+Wait $f $src.Name $src.Length
+"#;
+
+	let (Some(parent), Some(name)) = (url.parent_url(), url.file_name()) else {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "not a file on a device"));
+	};
+
+	let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+	let dir = std::env::temp_dir().join(format!("yazi-mtp-{nanos}"));
+	let src = dir.join(name);
+
+	tokio::fs::create_dir(&dir).await?;
+	Ok(Upload::spawn(|mut rx| async move {
+		let result = async {
+			tokio::io::copy(&mut rx, &mut tokio::fs::File::create(&src).await?).await?;
+			let envs = [("YAZI_MTP_PATH", parent.as_os_str()), ("YAZI_MTP_FROM", src.as_os_str())];
+			run(script, &envs).await.map(|_| ())
+		};
+
+		let result = result.await;
+		tokio::fs::remove_dir_all(&dir).await.ok();
+		result
+	}))
+}
+
+#[cfg(windows)]
+pub(super) async fn create_dir(url: &Url) -> io::Result<()> {
+	let script = r#"
+Find $env:YAZI_MTP_PATH
+if (!$f) { Fail 'Not a directory' }
+$f.NewFolder($env:YAZI_MTP_NAME)
+Wait $f $env:YAZI_MTP_NAME -1
+"#;
+
+	let (Some(parent), Some(name)) = (url.parent_url(), url.file_name()) else {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "not a folder on a device"));
+	};
+	run(script, &[("YAZI_MTP_PATH", parent.as_os_str()), ("YAZI_MTP_NAME", name)]).await.map(|_| ())
+}
+
+// The same as "Delete" in the context menu of Explorer, as the Shell has no other way to do it
+#[cfg(windows)]
+pub(super) async fn remove_file(url: &Url) -> io::Result<()> {
+	let script = "Find $env:YAZI_MTP_PATH; $i.InvokeVerb('delete')";
+	run(script, &[("YAZI_MTP_PATH", url.as_os_str())]).await.map(|_| ())
+}
+
+#[cfg(windows)]
+pub(super) async fn remove_dir(url: &Url) -> io::Result<()> { remove_file(url).await }
+
+// Moved into the new folder first if it's another one, and renamed there
+#[cfg(windows)]
+pub(super) async fn rename(from: &Url, to: &Url) -> io::Result<()> {
+	let script = r#"
+Find $env:YAZI_MTP_TO; $d = $f
+Find $env:YAZI_MTP_PATH
+if (!$d) { Fail 'Not a directory' }
+if ($d.Self.Path -ne $p.Self.Path) {
+	$d.MoveHere($i, $flags)
+	Wait $d $i.Name -1
+	$i = @($d.Items()) | Where-Object { $_.Name -eq $i.Name } | Select-Object -First 1
+}
+if ($i.Name -ne $env:YAZI_MTP_NAME) { $i.Name = $env:YAZI_MTP_NAME }
+"#;
+
+	let (Some(parent), Some(name)) = (to.parent_url(), to.file_name()) else {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "not a file on a device"));
+	};
+
+	let envs = [
+		("YAZI_MTP_PATH", from.as_os_str()),
+		("YAZI_MTP_TO", parent.as_os_str()),
+		("YAZI_MTP_NAME", name),
+	];
+	run(script, &envs).await.map(|_| ())
+}
@@ -0,0 +1,148 @@
+use std::{collections::BTreeMap, io, sync::Arc, time::SystemTime};
+
+use futures::StreamExt;
+use s3::{creds::Credentials, Bucket, Region};
+use tokio::{io::AsyncRead, sync::Mutex};
+use tokio_util::io::StreamReader;
+use yazi_config::{t, VFS};
+use yazi_proxy::AppProxy;
+use yazi_shared::fs::{Cha, ChaKind, File, Url};
+
+use super::{parse_time, Upload};
+
+static BUCKETS: Mutex<BTreeMap<String, Arc<Bucket>>> = Mutex::const_new(BTreeMap::new());
+
+fn build(name: &str) -> anyhow::Result<Box<Bucket>> {
+	let vfs = VFS.load();
+	let region = if vfs.s3_endpoint.is_empty() {
+		vfs.s3_region.parse()?
+	} else {
+		Region::Custom { region: vfs.s3_region.clone(), endpoint: vfs.s3_endpoint.clone() }
+	};
+
+	let bucket = Bucket::new(name, region, Credentials::default()?)?;
+	// The compatible ones rarely support the virtual-hosted style
+	Ok(if vfs.s3_endpoint.is_empty() { bucket } else { bucket.with_path_style() })
+}
+
+async fn bucket(url: &Url) -> io::Result<Arc<Bucket>> {
+	let mut buckets = BUCKETS.lock().await;
+	if let Some(b) = buckets.get(url.domain()) {
+		return Ok(b.clone());
+	}
+
+	match build(url.domain()) {
+		Ok(b) => Ok(buckets.entry(url.domain().to_owned()).or_insert(Arc::from(b)).clone()),
+		Err(e) => {
+			AppProxy::notify_warn(
+				"S3",
+				&t!("Failed to access the bucket `{domain}`: {e}", domain = url.domain(), e = e),
+			);
+			Err(io::Error::other(e))
+		}
+	}
+}
+
+// The key of an object, or the prefix of a "directory" with the trailing slash
+fn key(url: &Url) -> String { url.to_string_lossy().trim_start_matches('/').to_owned() }
+
+fn prefix(url: &Url) -> String {
+	let key = key(url);
+	if key.is_empty() { key } else { format!("{}/", key.trim_end_matches('/')) }
+}
+
+fn object(len: u64, mtime: Option<SystemTime>) -> Cha {
+	Cha { len, mtime, ..Default::default() }
+}
+
+// There are no directories but the common prefixes, which have no mtime of their own,
+// so that they are always taken as changed and listed again.
+fn prefix_cha() -> Cha {
+	Cha { kind: ChaKind::DIR, mtime: Some(SystemTime::now()), ..Default::default() }
+}
+
+pub(super) async fn read_dir(dir: &Url) -> io::Result<Vec<File>> {
+	let b = bucket(dir).await?;
+	let (prefix, mut files) = (prefix(dir), vec![]);
+
+	// Which goes through all the pages of the listing
+	let pages = b.list(prefix.clone(), Some("/".to_owned())).await.map_err(io::Error::other)?;
+	for page in pages {
+		for p in page.common_prefixes.unwrap_or_default() {
+			let name = p.prefix.strip_prefix(prefix.as_str()).unwrap_or(&p.prefix).trim_end_matches('/');
+			files.push(File { url: dir.join(name), cha: prefix_cha(), ..Default::default() });
+		}
+		for o in page.contents {
+			let name = o.key.strip_prefix(prefix.as_str()).unwrap_or(&o.key);
+			// The placeholder of an empty "directory" itself
+			if name.is_empty() {
+				continue;
+			}
+			let cha = object(o.size, parse_time(&o.last_modified));
+			files.push(File { url: dir.join(name), cha, ..Default::default() });
+		}
+	}
+	Ok(files)
+}
+
+pub(super) async fn metadata(url: &Url) -> io::Result<Cha> {
+	let key = key(url);
+	if key.is_empty() || key.ends_with('/') {
+		return Ok(prefix_cha());
+	}
+
+	let b = bucket(url).await?;
+	if let Ok((head, 200)) = b.head_object(&key).await {
+		return Ok(object(head.content_length.unwrap_or(0) as u64, None));
+	}
+
+	let (page, _) = b
+		.list_page(prefix(url), Some("/".to_owned()), None, None, Some(1))
+		.await
+		.map_err(io::Error::other)?;
+	if page.contents.is_empty() && page.common_prefixes.unwrap_or_default().is_empty() {
+		Err(io::Error::from(io::ErrorKind::NotFound))
+	} else {
+		Ok(prefix_cha())
+	}
+}
+
+pub(super) async fn open(url: &Url) -> io::Result<impl AsyncRead + Send + Unpin> {
+	let b = bucket(url).await?;
+	let stream = b.get_object_stream(key(url)).await.map_err(io::Error::other)?;
+	Ok(StreamReader::new(stream.bytes.map(|r| r.map_err(io::Error::other))))
+}
+
+pub(super) async fn create(url: &Url) -> io::Result<Upload> {
+	let (b, key) = (bucket(url).await?, key(url));
+	Ok(Upload::spawn(|mut rx| async move {
+		b.put_object_stream(&mut rx, key).await.map(|_| ()).map_err(io::Error::other)
+	}))
+}
+
+// Directories exist as long as there are objects under them,
+// so an empty placeholder keeps the new one around until something is put in it.
+pub(super) async fn create_dir(url: &Url) -> io::Result<()> {
+	let b = bucket(url).await?;
+	b.put_object(prefix(url), &[]).await.map(|_| ()).map_err(io::Error::other)
+}
+
+pub(super) async fn remove_file(url: &Url) -> io::Result<()> {
+	let b = bucket(url).await?;
+	b.delete_object(key(url)).await.map(|_| ()).map_err(io::Error::other)
+}
+
+pub(super) async fn remove_dir(url: &Url) -> io::Result<()> {
+	let b = bucket(url).await?;
+	b.delete_object(prefix(url)).await.map(|_| ()).map_err(io::Error::other)
+}
+
+pub(super) async fn rename(from: &Url, to: &Url) -> io::Result<()> {
+	if metadata(from).await?.is_dir() {
+		return Err(io::Error::new(io::ErrorKind::Unsupported, "cannot rename a prefix in S3"));
+	}
+
+	let b = bucket(from).await?;
+	b.copy_object_internal(key(from), key(to)).await.map_err(io::Error::other)?;
+	remove_file(from).await
+}
@@ -0,0 +1,186 @@
+use std::{collections::BTreeMap, io, process::Stdio, sync::Arc};
+
+use russh_sftp::{client::{error::Error, fs::{File as SftpFile, Metadata}, SftpSession}, protocol::StatusCode};
+use tokio::{process::{Child, Command}, sync::Mutex};
+use yazi_config::{t, VFS};
+use yazi_proxy::AppProxy;
+use yazi_shared::fs::{Cha, ChaKind, File, Url};
+
+struct Session {
+	sftp:   SftpSession,
+	_child: Child,
+}
+
+// One connection per `user@host`, shared by all the tabs and tasks
+static SESSIONS: Mutex<BTreeMap<String, Arc<Session>>> = Mutex::const_new(BTreeMap::new());
+
+fn into_io(e: Error) -> io::Error {
+	match e {
+		Error::Status(s) if s.status_code == StatusCode::NoSuchFile => {
+			io::Error::new(io::ErrorKind::NotFound, s.error_message)
+		}
+		Error::Status(s) if s.status_code == StatusCode::PermissionDenied => {
+			io::Error::new(io::ErrorKind::PermissionDenied, s.error_message)
+		}
+		Error::Status(s) => io::Error::other(s.error_message),
+		e => io::Error::new(io::ErrorKind::ConnectionAborted, e.to_string()),
+	}
+}
+
+// Runs the system `ssh` as the transport, so that the hosts, keys and agents
+// configured for it are used as-is, and no password prompt can break the TUI.
+async fn connect(domain: &str) -> io::Result<Session> {
+	let (host, port) = match domain.rsplit_once(':') {
+		Some((h, p)) if p.parse::<u16>().is_ok() => (h, Some(p)),
+		_ => (domain, None),
+	};
+
+	let mut cmd = Command::new("ssh");
+	cmd.args(&VFS.load().ssh_args).args(["-o", "BatchMode=yes"]);
+	if let Some(port) = port {
+		cmd.args(["-p", port]);
+	}
+
+	let mut child = cmd
+		.args(["-s", "--", host, "sftp"])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.kill_on_drop(true)
+		.spawn()?;
+
+	let stream = tokio::io::join(child.stdout.take().unwrap(), child.stdin.take().unwrap());
+	let sftp = SftpSession::new(stream).await.map_err(into_io)?;
+	Ok(Session { sftp, _child: child })
+}
+
+async fn session(url: &Url) -> io::Result<Arc<Session>> {
+	let mut sessions = SESSIONS.lock().await;
+	if let Some(s) = sessions.get(url.domain()) {
+		return Ok(s.clone());
+	}
+
+	let s = match connect(url.domain()).await {
+		Ok(s) => Arc::new(s),
+		Err(e) => {
+			AppProxy::notify_warn(
+				"SFTP",
+				&t!("Failed to connect to `{domain}`: {e}", domain = url.domain(), e = e),
+			);
+			return Err(e);
+		}
+	};
+	sessions.insert(url.domain().to_owned(), s.clone());
+	Ok(s)
+}
+
+// Drops the connection once it's broken, so the next call reconnects
+async fn check<T>(url: &Url, result: Result<T, Error>) -> io::Result<T> {
+	let result = result.map_err(into_io);
+	if matches!(result, Err(ref e) if e.kind() == io::ErrorKind::ConnectionAborted) {
+		SESSIONS.lock().await.remove(url.domain());
+	}
+	result
+}
+
+#[inline]
+fn path(url: &Url) -> String { url.to_string_lossy().into_owned() }
+
+fn cha(m: &Metadata) -> Cha {
+	let mut kind = ChaKind::empty();
+	if m.is_dir() {
+		kind |= ChaKind::DIR;
+	}
+
+	Cha {
+		kind,
+		len:    m.size.unwrap_or(0),
+		atime:  m.accessed().ok(),
+		ctime:  None,
+		mtime:  m.modified().ok(),
+		#[cfg(unix)]
+		chtime: None,
+		#[cfg(unix)]
+		perm:   m.permissions.unwrap_or(0) as _,
+		#[cfg(unix)]
+		uid:    m.uid.unwrap_or(0) as _,
+		#[cfg(unix)]
+		gid:    m.gid.unwrap_or(0) as _,
+		#[cfg(unix)]
+		nlink:  1,
+		#[cfg(windows)]
+		attrs:  0,
+		#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+		flags:  0,
+	}
+}
+
+pub(super) async fn read_dir(dir: &Url) -> io::Result<Vec<File>> {
+	let s = session(dir).await?;
+	let it = check(dir, s.sftp.read_dir(path(dir)).await).await?;
+
+	let mut files = vec![];
+	for entry in it {
+		let url = dir.join(entry.file_name());
+		let meta = entry.metadata();
+		if !meta.is_symlink() {
+			files.push(File { cha: cha(&meta), url, ..Default::default() });
+			continue;
+		}
+
+		// The entries come with the attributes of the links themselves, follow them like `stat`
+		let mut c = s.sftp.metadata(path(&url)).await.map(|m| cha(&m)).unwrap_or_else(|_| {
+			let mut c = cha(&meta);
+			c.kind |= ChaKind::ORPHAN;
+			c
+		});
+		c.kind |= ChaKind::LINK;
+
+		let link_to = s.sftp.read_link(path(&url)).await.ok().map(|p| dir.join(p));
+		files.push(File { cha: c, url, link_to, ..Default::default() });
+	}
+	Ok(files)
+}
+
+pub(super) async fn metadata(url: &Url) -> io::Result<Cha> {
+	let s = session(url).await?;
+	check(url, s.sftp.metadata(path(url)).await).await.map(|m| cha(&m))
+}
+
+pub(super) async fn open(url: &Url) -> io::Result<SftpFile> {
+	let s = session(url).await?;
+	check(url, s.sftp.open(path(url)).await).await
+}
+
+pub(super) async fn create(url: &Url) -> io::Result<SftpFile> {
+	let s = session(url).await?;
+	check(url, s.sftp.create(path(url)).await).await
+}
+
+pub(super) async fn create_dir(url: &Url) -> io::Result<()> {
+	let s = session(url).await?;
+	check(url, s.sftp.create_dir(path(url)).await).await
+}
+
+pub(super) async fn remove_file(url: &Url) -> io::Result<()> {
+	let s = session(url).await?;
+	check(url, s.sftp.remove_file(path(url)).await).await
+}
+
+pub(super) async fn remove_dir(url: &Url) -> io::Result<()> {
+	let s = session(url).await?;
+	check(url, s.sftp.remove_dir(path(url)).await).await
+}
+
+// SFTPv3 doesn't overwrite on renaming, so the existing one is removed first
+pub(super) async fn rename(from: &Url, to: &Url) -> io::Result<()> {
+	let s = session(from).await?;
+	if s.sftp.rename(path(from), path(to)).await.is_ok() {
+		return Ok(());
+	}
+
+	if metadata(to).await.is_ok_and(|c| !c.is_dir()) {
+		remove_file(to).await?;
+	}
+	check(from, s.sftp.rename(path(from), path(to)).await).await
+}
@@ -0,0 +1,42 @@
+use std::{future::Future, io, pin::Pin, task::{ready, Context, Poll}};
+
+use tokio::{io::{AsyncWrite, DuplexStream}, task::JoinHandle};
+
+// Streams what's written into an upload running in the background, which completes on shutdown,
+// for the protocols that take the body as a reader instead of being written to.
+pub(super) struct Upload {
+	pipe: DuplexStream,
+	task: JoinHandle<io::Result<()>>,
+}
+
+impl Upload {
+	pub(super) fn spawn<F>(f: impl FnOnce(DuplexStream) -> F) -> Self
+	where
+		F: Future<Output = io::Result<()>> + Send + 'static,
+	{
+		let (pipe, rx) = tokio::io::duplex(1024 * 1024);
+		Self { pipe, task: tokio::spawn(f(rx)) }
+	}
+}
+
+impl AsyncWrite for Upload {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		Pin::new(&mut self.pipe).poll_write(cx, buf)
+	}
+
+	fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.pipe).poll_flush(cx)
+	}
+
+	fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		ready!(Pin::new(&mut self.pipe).poll_shutdown(cx))?;
+		match ready!(Pin::new(&mut self.task).poll(cx)) {
+			Ok(result) => Poll::Ready(result),
+			Err(e) => Poll::Ready(Err(io::Error::other(e))),
+		}
+	}
+}
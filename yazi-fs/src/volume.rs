@@ -0,0 +1,359 @@
+use std::{fmt::{self, Display}, io, path::{Path, PathBuf}};
+
+use tokio::process::Command;
+
+use crate::{readable_size, space, Space};
+
+#[derive(Clone, Debug)]
+pub struct Volume {
+	pub device:    String,
+	pub label:     String,
+	pub mount:     Option<PathBuf>,
+	pub removable: bool,
+	pub space:     Option<Space>,
+}
+
+impl Display for Volume {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = if self.label.is_empty() {
+			self.device.rsplit(['/', '\\']).next().unwrap_or(&self.device)
+		} else {
+			&self.label
+		};
+
+		write!(f, "{name} ({})", self.device)?;
+		match (&self.mount, self.space) {
+			(Some(p), Some(s)) => {
+				write!(f, "  {}, {} free of {}", p.display(), readable_size(s.free), readable_size(s.total))
+			}
+			(Some(p), None) => write!(f, "  {}", p.display()),
+			(None, _) => write!(f, "  not mounted"),
+		}
+	}
+}
+
+impl Volume {
+	// The mounted volumes, along with the removable ones that can be mounted on demand
+	pub async fn all() -> io::Result<Vec<Self>> {
+		let mut volumes = list().await?;
+		for v in &mut volumes {
+			v.space = v.mount.as_deref().and_then(space);
+		}
+		Ok(volumes)
+	}
+
+	// The innermost mounted volume containing `path`
+	pub async fn of(path: &Path) -> Option<Self> {
+		Self::all()
+			.await
+			.ok()?
+			.into_iter()
+			.filter(|v| v.mount.as_ref().is_some_and(|p| path.starts_with(p)))
+			.max_by_key(|v| v.mount.as_ref().map(|p| p.as_os_str().len()))
+	}
+
+	// Mounts it unless it's mounted already, and returns where it's mounted to
+	pub async fn mount(&self) -> io::Result<PathBuf> {
+		if let Some(p) = &self.mount {
+			return Ok(p.clone());
+		}
+
+		mount(&self.device).await?;
+		Self::all()
+			.await?
+			.into_iter()
+			.find(|v| v.device == self.device)
+			.and_then(|v| v.mount)
+			.ok_or_else(|| io::Error::other("mounted, but the mount point isn't found"))
+	}
+
+	pub async fn unmount(&self) -> io::Result<()> { unmount(&self.device).await }
+
+	// Unmounts it, and powers off the drive so that it can be unplugged safely
+	pub async fn eject(&self) -> io::Result<()> { eject(&self.device).await }
+}
+
+// A disk image attached and mounted by us, to be detached once it's left
+#[derive(Clone, Debug)]
+pub struct Image {
+	pub path:  PathBuf,
+	pub mount: PathBuf,
+	device:    String,
+	volume:    String,
+}
+
+impl Image {
+	pub async fn attach(path: &Path) -> io::Result<Self> { attach(path).await }
+
+	pub async fn detach(&self) -> io::Result<()> { detach(self).await }
+}
+
+async fn run(program: &str, args: &[&str]) -> io::Result<String> {
+	let output = Command::new(program).args(args).kill_on_drop(true).output().await?;
+	if output.status.success() {
+		Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+	} else {
+		Err(io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_owned()))
+	}
+}
+
+// --- Linux, through `lsblk` for the listing and udisks2 for the rest, which needs no root
+#[cfg(target_os = "linux")]
+async fn list() -> io::Result<Vec<Volume>> {
+	let out = run("lsblk", &["-P", "-o", "PATH,LABEL,MOUNTPOINT,RM,HOTPLUG,FSTYPE"]).await?;
+	let re = regex::Regex::new(r#"(\w+)="([^"]*)""#).unwrap();
+
+	let mut volumes = vec![];
+	for line in out.lines() {
+		let field = |k: &str| {
+			re.captures_iter(line).find(|c| &c[1] == k).map(|c| unescape(&c[2])).unwrap_or_default()
+		};
+
+		// Without a filesystem, or one that can't be browsed, e.g. a partition table or LUKS
+		let fstype = field("FSTYPE");
+		if matches!(fstype.as_str(), "" | "swap" | "crypto_LUKS" | "LVM2_member") {
+			continue;
+		}
+
+		let mount = field("MOUNTPOINT");
+		volumes.push(Volume {
+			device:    field("PATH"),
+			label:     field("LABEL"),
+			mount:     Some(mount).filter(|s| s.starts_with('/')).map(PathBuf::from),
+			removable: field("RM") == "1" || field("HOTPLUG") == "1",
+			space:     None,
+		});
+	}
+	Ok(volumes)
+}
+
+// `lsblk -P` escapes the unsafe chars as `\xNN`
+#[cfg(target_os = "linux")]
+fn unescape(s: &str) -> String {
+	let mut bytes = Vec::with_capacity(s.len());
+	let mut it = s.as_bytes().iter().copied();
+	while let Some(b) = it.next() {
+		if b != b'\\' {
+			bytes.push(b);
+			continue;
+		}
+
+		let rest: Vec<_> = it.by_ref().take(3).collect();
+		match std::str::from_utf8(&rest).ok().and_then(|s| s.strip_prefix('x')) {
+			Some(h) if h.len() == 2 => bytes.push(u8::from_str_radix(h, 16).unwrap_or(b'?')),
+			_ => bytes.extend([b'\\'].iter().chain(&rest)),
+		}
+	}
+	String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(target_os = "linux")]
+async fn mount(device: &str) -> io::Result<()> {
+	run("udisksctl", &["mount", "--no-user-interaction", "-b", device]).await.map(|_| ())
+}
+
+#[cfg(target_os = "linux")]
+async fn unmount(device: &str) -> io::Result<()> {
+	run("udisksctl", &["unmount", "--no-user-interaction", "-b", device]).await.map(|_| ())
+}
+
+#[cfg(target_os = "linux")]
+async fn eject(device: &str) -> io::Result<()> {
+	unmount(device).await?;
+	run("udisksctl", &["power-off", "--no-user-interaction", "-b", device]).await.map(|_| ())
+}
+
+// Set up as a read-only loop device, and the first volume in it is mounted
+#[cfg(target_os = "linux")]
+async fn attach(path: &Path) -> io::Result<Image> {
+	let file = path.to_string_lossy();
+	let out = run("udisksctl", &["loop-setup", "-r", "--no-user-interaction", "-f", &file]).await?;
+	let device = out
+		.split_whitespace()
+		.find(|s| s.starts_with("/dev/loop"))
+		.map(|s| s.trim_end_matches('.').to_owned())
+		.ok_or_else(|| io::Error::other("no loop device set up"))?;
+
+	// Either the loop device itself, or the first partition of it like `/dev/loop0p1`
+	let part = format!("{device}p");
+	let volume =
+		Volume::all().await?.into_iter().find(|v| v.device == device || v.device.starts_with(&part));
+	let result = match volume {
+		Some(v) => v.mount().await.map(|mount| (v.device, mount)),
+		None => Err(io::Error::other("no filesystem found in the image")),
+	};
+
+	match result {
+		Ok((volume, mount)) => Ok(Image { path: path.to_owned(), mount, device, volume }),
+		Err(e) => {
+			run("udisksctl", &["loop-delete", "--no-user-interaction", "-b", &device]).await.ok();
+			Err(e)
+		}
+	}
+}
+
+#[cfg(target_os = "linux")]
+async fn detach(image: &Image) -> io::Result<()> {
+	unmount(&image.volume).await?;
+	run("udisksctl", &["loop-delete", "--no-user-interaction", "-b", &image.device]).await.map(|_| ())
+}
+
+// --- macOS, where DiskArbitration mounts the volumes as they're plugged in,
+// so only the mounted ones are listed, and `diskutil` does the rest.
+#[cfg(target_os = "macos")]
+async fn list() -> io::Result<Vec<Volume>> {
+	let out = run("df", &["-kP"]).await?;
+
+	let mut volumes = vec![];
+	for line in out.lines().skip(1) {
+		let Some(device) = line.split_whitespace().next().filter(|s| s.starts_with("/dev/")) else {
+			continue;
+		};
+		let Some(mount) = line.split_once("% ").map(|(_, s)| s.trim_start()) else { continue };
+		if mount.starts_with("/System/Volumes/") {
+			continue;
+		}
+
+		let mount = PathBuf::from(mount);
+		let label = mount.file_name().map(|s| s.to_string_lossy().into_owned());
+		volumes.push(Volume {
+			device:    device.to_owned(),
+			label:     label.unwrap_or_else(|| "Macintosh HD".to_owned()),
+			removable: mount.starts_with("/Volumes"),
+			mount:     Some(mount),
+			space:     None,
+		});
+	}
+	Ok(volumes)
+}
+
+#[cfg(target_os = "macos")]
+async fn mount(device: &str) -> io::Result<()> {
+	run("diskutil", &["mount", device]).await.map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+async fn unmount(device: &str) -> io::Result<()> {
+	run("diskutil", &["unmount", device]).await.map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+async fn eject(device: &str) -> io::Result<()> {
+	run("diskutil", &["eject", device]).await.map(|_| ())
+}
+
+// The first line is the whole disk, and the ones with a mount point are its volumes
+#[cfg(target_os = "macos")]
+async fn attach(path: &Path) -> io::Result<Image> {
+	let file = path.to_string_lossy();
+	let out = run("hdiutil", &["attach", "-readonly", "-nobrowse", &file]).await?;
+
+	let device = out.split_whitespace().next().unwrap_or_default().to_owned();
+	let mount = out
+		.lines()
+		.filter_map(|l| l.rsplit('\t').next().map(str::trim))
+		.find(|s| s.starts_with('/') && !s.starts_with("/dev/"))
+		.map(PathBuf::from);
+
+	match mount {
+		Some(mount) => Ok(Image { path: path.to_owned(), mount, volume: device.clone(), device }),
+		None => {
+			run("hdiutil", &["detach", &device]).await.ok();
+			Err(io::Error::other("no filesystem found in the image"))
+		}
+	}
+}
+
+#[cfg(target_os = "macos")]
+async fn detach(image: &Image) -> io::Result<()> {
+	run("hdiutil", &["detach", &image.device]).await.map(|_| ())
+}
+
+// --- Windows, where each volume is a drive letter mounted by the system itself
+#[cfg(windows)]
+async fn list() -> io::Result<Vec<Volume>> {
+	use windows_sys::Win32::Storage::FileSystem::{GetDriveTypeW, GetLogicalDrives};
+
+	// The `DRIVE_*` returned by `GetDriveTypeW`
+	const REMOVABLE: u32 = 2;
+	const CDROM: u32 = 5;
+
+	let drives = unsafe { GetLogicalDrives() };
+	let volume = |i: u8| {
+		let root = format!("{}:\\", (b'A' + i) as char);
+		let wide: Vec<u16> = root.encode_utf16().chain(Some(0)).collect();
+		let ty = unsafe { GetDriveTypeW(wide.as_ptr()) };
+		Volume {
+			device:    root[..2].to_owned(),
+			label:     String::new(),
+			mount:     Some(PathBuf::from(root)),
+			removable: ty == REMOVABLE || ty == CDROM,
+			space:     None,
+		}
+	};
+	Ok((0..26u8).filter(|i| drives & (1 << i) != 0).map(volume).collect())
+}
+
+#[cfg(windows)]
+async fn mount(_: &str) -> io::Result<()> {
+	Err(io::Error::new(io::ErrorKind::Unsupported, "the drives are mounted by Windows itself"))
+}
+
+#[cfg(windows)]
+async fn unmount(device: &str) -> io::Result<()> { eject(device).await }
+
+// The same as "Eject" in the context menu of Explorer, which flushes and dismounts it
+#[cfg(windows)]
+async fn eject(device: &str) -> io::Result<()> {
+	let script = format!(
+		"(New-Object -ComObject Shell.Application).Namespace(17).ParseName('{device}')\
+		 .InvokeVerb('Eject')"
+	);
+	run("powershell", &["-NoProfile", "-Command", &script]).await.map(|_| ())
+}
+
+#[cfg(windows)]
+async fn attach(path: &Path) -> io::Result<Image> {
+	let file = path.to_string_lossy().replace('\'', "''");
+	let script = format!("(Mount-DiskImage -ImagePath '{file}' -PassThru | Get-Volume).DriveLetter");
+	let out = run("powershell", &["-NoProfile", "-Command", &script]).await?;
+
+	let Some(letter) = out.trim().chars().next().filter(char::is_ascii_alphabetic) else {
+		return Err(io::Error::other("no drive letter assigned to the image"));
+	};
+	Ok(Image {
+		path:   path.to_owned(),
+		mount:  PathBuf::from(format!("{letter}:\\")),
+		device: file,
+		volume: format!("{letter}:"),
+	})
+}
+
+#[cfg(windows)]
+async fn detach(image: &Image) -> io::Result<()> {
+	let script = format!("Dismount-DiskImage -ImagePath '{}'", image.device);
+	run("powershell", &["-NoProfile", "-Command", &script]).await.map(|_| ())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn unsupported() -> io::Error {
+	io::Error::new(io::ErrorKind::Unsupported, "not supported on this platform")
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+async fn list() -> io::Result<Vec<Volume>> { Err(unsupported()) }
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+async fn mount(_: &str) -> io::Result<()> { Err(unsupported()) }
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+async fn unmount(_: &str) -> io::Result<()> { Err(unsupported()) }
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+async fn eject(_: &str) -> io::Result<()> { Err(unsupported()) }
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+async fn attach(_: &Path) -> io::Result<Image> { Err(unsupported()) }
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+async fn detach(_: &Image) -> io::Result<()> { Err(unsupported()) }
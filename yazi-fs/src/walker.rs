@@ -0,0 +1,33 @@
+use std::{mem, path::PathBuf, time::{Duration, Instant}};
+
+use ignore::WalkBuilder;
+use tokio::sync::mpsc;
+
+const BATCH: usize = 1000;
+const INTERVAL: Duration = Duration::from_millis(100);
+
+// Walks `root` recursively on a blocking thread, respecting `.gitignore` and `.ignore`, and
+// sends the paths relative to it in batches. The walk stops once the receiver is dropped.
+pub fn walk(root: PathBuf, hidden: bool, limit: usize) -> mpsc::Receiver<Vec<PathBuf>> {
+	let (tx, rx) = mpsc::channel(4);
+	tokio::task::spawn_blocking(move || {
+		let (mut batch, mut last) = (Vec::with_capacity(BATCH), Instant::now());
+		for entry in WalkBuilder::new(&root).hidden(!hidden).build().flatten().skip(1).take(limit) {
+			let Ok(p) = entry.path().strip_prefix(&root) else { continue };
+			batch.push(p.to_owned());
+
+			if batch.len() < BATCH && last.elapsed() < INTERVAL {
+				continue;
+			}
+			if tx.blocking_send(mem::take(&mut batch)).is_err() {
+				return;
+			}
+			last = Instant::now();
+		}
+
+		if !batch.is_empty() {
+			tx.blocking_send(batch).ok();
+		}
+	});
+	rx
+}
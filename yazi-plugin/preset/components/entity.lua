@@ -6,6 +6,7 @@ Entity = {
 		{ "highlights", id = 3, order = 3000 },
 		{ "found", id = 4, order = 4000 },
 		{ "symlink", id = 5, order = 5000 },
+		{ "vcs", id = 6, order = 6000 },
 	},
 }
 
@@ -70,7 +71,23 @@ function Entity:symlink()
 	end
 
 	local to = self._file.link_to
-	return ui.Line(to and { ui.Span(" -> " .. tostring(to)):italic() } or {})
+	if not to then
+		return ui.Line {}
+	elseif self._file.cha.is_orphan then
+		return ui.Line { ui.Span(" -> " .. tostring(to) .. " (broken)"):style(THEME.manager.symlink_broken) }
+	else
+		return ui.Line { ui.Span(" -> " .. tostring(to)):style(THEME.manager.symlink_target) }
+	end
+end
+
+function Entity:vcs()
+	local status = self._file:vcs()
+	if not status then
+		return ui.Line {}
+	end
+
+	local sign = ui.Span(" " .. THEME.vcs["sign_" .. status])
+	return ui.Line { self._file:is_hovered() and sign or sign:style(THEME.vcs[status]) }
 end
 
 function Entity:render()
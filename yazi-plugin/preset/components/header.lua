@@ -26,7 +26,7 @@ function Header:cwd()
 		return ui.Span("")
 	end
 
-	local s = ya.readable_path(tostring(self._tab.current.cwd)) .. self:flags()
+	local s = ya.readable_path(tostring(self._tab.current.cwd)) .. self:flags() .. self:usage()
 	return ui.Span(ya.truncate(s, { max = max, rtl = true })):style(THEME.manager.cwd)
 end
 
@@ -44,6 +44,13 @@ function Header:flags()
 	end
 end
 
+function Header:usage()
+	if not self._tab.usage then
+		return ""
+	end
+	return string.format(" [usage: %s]", ya.readable_size(self._tab.current:size()))
+end
+
 function Header:count()
 	local yanked = #cx.yanked
 
@@ -65,10 +72,20 @@ function Header:count()
 
 	return ui.Line {
 		ui.Span(string.format(" %d ", count)):style(style),
+		ui.Span(yanked > 0 and self:target() or ""),
 		ui.Span(" "),
 	}
 end
 
+-- The hovered directory, if `smart_paste` pastes the yanked files into it instead of the cwd
+function Header:target()
+	local h = self._tab.current.hovered
+	if not MANAGER.smart_paste or not h or not h.cha.is_dir or h:is_yanked() ~= 0 then
+		return ""
+	end
+	return " → " .. ya.truncate(h.name, { max = 20 })
+end
+
 function Header:tabs()
 	local tabs = #cx.tabs
 	if tabs == 1 then
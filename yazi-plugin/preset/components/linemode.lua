@@ -26,7 +26,24 @@ end
 
 function Linemode:size()
 	local size = self._file:size()
-	return ui.Line(size and ya.readable_size(size) or "")
+	if size then
+		return ui.Line(ya.readable_size(size))
+	end
+	return ui.Line(self._file:size_loading() and "…" or "")
+end
+
+function Linemode:usage()
+	local size = self._file:size()
+	if not size then
+		return ui.Line(self._file:size_loading() and "…" or "")
+	end
+
+	local total = cx.active.current:size()
+	local ratio = total > 0 and size / total or 0
+	local n = math.floor(ratio * 10 + 0.5)
+	return ui.Line(
+		string.format("%s %5.1f%% [%s%s]", ya.readable_size(size), ratio * 100, ("#"):rep(n), (" "):rep(10 - n))
+	)
 end
 
 function Linemode:ctime()
@@ -59,6 +76,14 @@ function Linemode:owner()
 	return ui.Line(string.format("%s:%s", user or "-", group or "-"))
 end
 
+function Linemode:vcs()
+	local status = self._file:vcs()
+	if not status then
+		return ui.Line("")
+	end
+	return ui.Line(THEME.vcs["sign_" .. status]):style(THEME.vcs[status])
+end
+
 function Linemode:render()
 	local lines = {}
 	for _, c in ipairs(self._children) do
@@ -1,5 +1,16 @@
 Marker = {
 	_id = "marker",
+
+	-- The colors of Finder tags
+	TAG_COLORS = {
+		red = "red",
+		orange = "#ff9500",
+		yellow = "yellow",
+		green = "green",
+		blue = "blue",
+		purple = "magenta",
+		gray = "gray",
+	},
 }
 
 function Marker:new(area, folder)
@@ -61,6 +72,15 @@ function Marker:style(file)
 	elseif yanked == 2 then
 		return THEME.manager.marker_cut
 	end
+
+	if file:bookmark() then
+		return THEME.manager.marker_bookmarked
+	end
+
+	local tags = file:tags()
+	if tags then
+		return ui.Style():fg(self.TAG_COLORS[tags[1]] or "gray")
+	end
 end
 
 -- Mouse events
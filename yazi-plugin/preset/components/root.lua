@@ -1,6 +1,14 @@
 Root = {
+	TOP = 0,
+	BOTTOM = 1,
+	LEFT = 2,
+	RIGHT = 3,
+
 	_id = "root",
 	_drag_start = ui.Rect.default,
+	_inc = 1000,
+	_panels = {},
+	_focus = nil,
 }
 
 function Root:new(area)
@@ -11,14 +19,35 @@ function Root:new(area)
 end
 
 function Root:layout()
-	self._chunks = ui.Layout()
-		:direction(ui.Layout.VERTICAL)
-		:constraints({
-			ui.Constraint.Length(1),
-			ui.Constraint.Fill(1),
-			ui.Constraint.Length(1),
-		})
-		:split(self._area)
+	local top, bottom = self:panels_of(self.TOP), self:panels_of(self.BOTTOM)
+	local left, right = self:panels_of(self.LEFT), self:panels_of(self.RIGHT)
+
+	local vertical = ya.list_merge({ ui.Constraint.Length(1) }, self:panels_constraints(top))
+	vertical[#vertical + 1] = ui.Constraint.Fill(1)
+	ya.list_merge(vertical, self:panels_constraints(bottom))
+	vertical[#vertical + 1] = ui.Constraint.Length(1)
+
+	local horizontal = self:panels_constraints(left)
+	horizontal[#horizontal + 1] = ui.Constraint.Fill(1)
+	ya.list_merge(horizontal, self:panels_constraints(right))
+
+	local rows = ui.Layout():direction(ui.Layout.VERTICAL):constraints(vertical):split(self._area)
+	local cols = ui.Layout():direction(ui.Layout.HORIZONTAL):constraints(horizontal):split(rows[#top + 2])
+
+	self._chunks = { rows[1], cols[#left + 1], rows[#rows] }
+	self._mounted = {}
+	for i, p in ipairs(top) do
+		self._mounted[#self._mounted + 1] = { p, rows[i + 1] }
+	end
+	for i, p in ipairs(bottom) do
+		self._mounted[#self._mounted + 1] = { p, rows[#top + 2 + i] }
+	end
+	for i, p in ipairs(left) do
+		self._mounted[#self._mounted + 1] = { p, cols[i] }
+	end
+	for i, p in ipairs(right) do
+		self._mounted[#self._mounted + 1] = { p, cols[#left + 1 + i] }
+	end
 end
 
 function Root:build()
@@ -27,6 +56,11 @@ function Root:build()
 		Tab:new(self._chunks[2], cx.active),
 		Status:new(self._chunks[3], cx.active),
 	}
+	for _, m in ipairs(self._mounted) do
+		local panel = m[1].panel
+		local child = setmetatable({ _id = panel._id or "panel", _area = m[2] }, { __index = panel })
+		self._children[#self._children + 1] = child
+	end
 end
 
 function Root:render()
@@ -41,19 +75,78 @@ end
 -- Mouse events
 function Root:click(event, up)
 	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
-	return c and c:click(event, up)
+	return c and c.click and c:click(event, up)
 end
 
 function Root:scroll(event, step)
 	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
-	return c and c:scroll(event, step)
+	return c and c.scroll and c:scroll(event, step)
 end
 
 function Root:touch(event, step)
 	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
-	return c and c:touch(event, step)
+	return c and c.touch and c:touch(event, step)
 end
 
 function Root:move(event) end
 
 function Root:drag(event) end
+
+-- Panels
+function Root:panel_add(panel, side, size, order)
+	self._inc = self._inc + 1
+	self._panels[#self._panels + 1] = {
+		panel = panel,
+		side = side,
+		size = size,
+		id = self._inc,
+		order = order or self._inc,
+	}
+	table.sort(self._panels, function(a, b) return a.order < b.order end)
+
+	ya.render()
+	return self._inc
+end
+
+function Root:panel_remove(id)
+	for i, p in ipairs(self._panels) do
+		if p.id == id then
+			table.remove(self._panels, i)
+			break
+		end
+	end
+
+	if self._focus == id then
+		self._focus = nil
+	end
+	ya.render()
+end
+
+function Root:panel_focus(id) self._focus = id end
+
+function Root:panel_key(key)
+	for _, p in ipairs(self._panels) do
+		if p.id == self._focus then
+			return p.panel.key and p.panel:key(key) or false
+		end
+	end
+	return false
+end
+
+function Root:panels_of(side)
+	local panels = {}
+	for _, p in ipairs(self._panels) do
+		if p.side == side then
+			panels[#panels + 1] = p
+		end
+	end
+	return panels
+end
+
+function Root:panels_constraints(panels)
+	local constraints = {}
+	for i, p in ipairs(panels) do
+		constraints[i] = ui.Constraint.Length(p.size)
+	end
+	return constraints
+end
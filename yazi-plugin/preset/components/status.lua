@@ -6,10 +6,16 @@ Status = {
 	_inc = 1000,
 	_left = {
 		{ "mode", id = 1, order = 1000 },
+		{ "chooser", id = 10, order = 1500 },
+		{ "selection", id = 12, order = 1800 },
 		{ "size", id = 2, order = 2000 },
 		{ "name", id = 3, order = 3000 },
+		{ "vcs", id = 7, order = 4000 },
+		{ "commit", id = 8, order = 5000 },
 	},
 	_right = {
+		{ "space", id = 9, order = 500 },
+		{ "security", id = 11, order = 800 },
 		{ "permissions", id = 4, order = 1000 },
 		{ "percentage", id = 5, order = 2000 },
 		{ "position", id = 6, order = 3000 },
@@ -35,6 +41,9 @@ end
 
 function Status:mode()
 	local mode = tostring(self._tab.mode):sub(1, 3):upper()
+	if #cx.layers > 0 then
+		mode = mode .. " " .. cx.layers[#cx.layers]:upper()
+	end
 
 	local style = self:style()
 	return ui.Line {
@@ -44,6 +53,47 @@ function Status:mode()
 	}
 end
 
+-- What's returned on `open` in the chooser mode
+function Status:chooser()
+	local c = BOOT.chooser
+	if not c then
+		return ui.Line {}
+	end
+
+	local n, h = #self._tab.selected, self._tab.current.hovered
+	local s
+	if n > 0 then
+		s = string.format("%d selected", n)
+	elseif c.dir and not (h and h.cha.is_dir) then
+		s = tostring(self._tab.current.cwd)
+	else
+		s = h and h.name or ""
+	end
+
+	if c.multiple then
+		s = string.format("%s (at most %d)", s, c.multiple)
+	end
+	local what = c.dir and "dir" or "file"
+	return ui.Line(string.format(" Choose %s: %s ", what, s)):style(THEME.status.mode_select)
+end
+
+-- The count and the total size of the selected files, the ones still being calculated aside
+function Status:selection()
+	if #self._tab.selected == 0 or BOOT.chooser then
+		return ui.Line {}
+	end
+
+	local s = self._tab.selected:summary()
+	local text = string.format(" %d selected, %d dirs, %s", s.count, s.dirs, ya.readable_size(s.size))
+	local spans = { ui.Span(text):style(THEME.status.selection) }
+	if s.pending > 0 then
+		local pending = string.format(" (%d pending)", s.pending)
+		spans[#spans + 1] = ui.Span(pending):style(THEME.status.selection_pending)
+	end
+	spans[#spans + 1] = ui.Span(" ")
+	return ui.Line(spans)
+end
+
 function Status:size()
 	local h = self._tab.current.hovered
 	if not h then
@@ -66,6 +116,62 @@ function Status:name()
 	return ui.Line(" " .. h.name)
 end
 
+function Status:vcs()
+	local repo = self._tab.current:repo()
+	if not repo or not repo.branch then
+		return ui.Line {}
+	end
+
+	local spans = { ui.Span("  " .. repo.branch):style(THEME.vcs.branch) }
+	if repo.ahead > 0 then
+		spans[#spans + 1] = ui.Span(" ↑" .. repo.ahead):style(THEME.vcs.ahead)
+	end
+	if repo.behind > 0 then
+		spans[#spans + 1] = ui.Span(" ↓" .. repo.behind):style(THEME.vcs.behind)
+	end
+	return ui.Line(spans)
+end
+
+function Status:commit()
+	local h = self._tab.current.hovered
+	local c = h and h:commit()
+	if not c then
+		return ui.Line {}
+	end
+
+	return ui.Line {
+		ui.Span("  " .. c.hash):style(THEME.vcs.hash),
+		ui.Span(string.format(" %s %s: %s", os.date("%Y-%m-%d", c.time), c.author, c.subject)),
+	}
+end
+
+function Status:space()
+	local s = self._tab.current:space()
+	if not s then
+		return ui.Line {}
+	end
+
+	local free, total = ya.readable_size(s.free), ya.readable_size(s.total)
+	local text = string.format("%s free of %s", free, total)
+	if s.fstype then
+		text = text .. ", " .. s.fstype
+	end
+	return ui.Line(text .. " ")
+end
+
+function Status:security()
+	local h = self._tab.current.hovered
+	local s = h and h:security()
+	if not s then
+		return ui.Line {}
+	end
+
+	local parts = {}
+	parts[#parts + 1] = s.context
+	parts[#parts + 1] = s.caps
+	return ui.Line(table.concat(parts, " ") .. " "):style(THEME.status.permissions_s)
+end
+
 function Status:permissions()
 	local h = self._tab.current.hovered
 	if not h then
@@ -92,6 +198,13 @@ function Status:permissions()
 		end
 		spans[i] = ui.Span(c):style(style)
 	end
+
+	-- The `chflags` flags on macOS and FreeBSD, e.g. `uchg,hidden`
+	local os = ya.target_os()
+	local flags = (os == "macos" or os == "freebsd") and h.cha:flags() or {}
+	if #flags > 0 then
+		spans[#spans + 1] = ui.Span(" " .. table.concat(flags, ",")):style(THEME.status.permissions_t)
+	end
 	return ui.Line(spans)
 end
 
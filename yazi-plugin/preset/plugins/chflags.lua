@@ -0,0 +1,92 @@
+-- The `chflags` flags on macOS and FreeBSD, as `plugin chflags`, picking the ones to toggle
+-- for the selected files, in the state of the first one of them.
+local M = {}
+
+local FLAGS = {
+	{ "uchg", "User immutable" },
+	{ "uappnd", "User append-only" },
+	{ "hidden", "Hidden from the GUI" },
+	{ "nodump", "Skipped by dump" },
+	{ "opaque", "Opaque in a union mount" },
+	{ "arch", "Archived, needs root" },
+	{ "schg", "System immutable, needs root" },
+	{ "sappnd", "System append-only, needs root" },
+}
+
+local selected = ya.sync(function()
+	local urls = {}
+	for _, u in pairs(cx.active.selected) do
+		urls[#urls + 1] = u
+	end
+	if #urls == 0 and cx.active.current.hovered then
+		urls[1] = cx.active.current.hovered.url
+	end
+	return urls
+end)
+
+local function fail(s, ...) ya.notify { title = "Flags", content = string.format(s, ...), timeout = 5, level = "error" } end
+
+local function flags_of(url)
+	local cha, err = fs.cha(url)
+	if not cha then
+		return nil, err
+	end
+
+	local set = {}
+	for _, f in ipairs(cha:flags()) do
+		set[f] = true
+	end
+	return set
+end
+
+function M:entry()
+	local os = ya.target_os()
+	if os ~= "macos" and os ~= "freebsd" then
+		return fail("File flags are only available on macOS and FreeBSD")
+	end
+
+	local urls = selected()
+	if #urls == 0 then
+		return
+	end
+
+	local first, err = flags_of(urls[1])
+	if not first then
+		return fail("Failed to read `%s`, error code %s", urls[1]:name(), err)
+	end
+
+	local items = {}
+	for i, f in ipairs(FLAGS) do
+		items[i] = string.format("[%s] %-7s %s", first[f[1]] and "x" or " ", f[1], f[2])
+	end
+
+	local picked = ya.pick {
+		title = #urls == 1 and "Toggle flags of " .. urls[1]:name() or string.format("Toggle flags of %d files", #urls),
+		items = items,
+		position = { "center", w = 50 },
+		multi = true,
+	}
+	if not picked or #picked == 0 then
+		return
+	end
+
+	for _, url in ipairs(urls) do
+		local set = flags_of(url) or {}
+		for _, i in ipairs(picked) do
+			local f = FLAGS[i][1]
+			set[f] = not first[f] or nil
+		end
+
+		local list = {}
+		for f in pairs(set) do
+			list[#list + 1] = f
+		end
+
+		local ok, code = fs.set_flags(url, list)
+		if not ok then
+			return fail("Failed to change the flags of `%s`, error code %s", url:name(), code)
+		end
+	end
+end
+
+return M
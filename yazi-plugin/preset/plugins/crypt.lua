@@ -0,0 +1,84 @@
+local M = {}
+
+local STANZAS = {
+	["scrypt"] = "Passphrase (scrypt, work factor 2^%s)",
+	["X25519"] = "X25519 recipient",
+	["ssh-ed25519"] = "SSH Ed25519 key %s",
+	["ssh-rsa"] = "SSH RSA key %s",
+}
+
+function M:peek()
+	local lines
+	if (self.file.url:name() or ""):lower():match("%.age$") then
+		lines = self:age()
+	else
+		lines = self:gpg()
+	end
+
+	ya.preview_widgets(self, {
+		ui.Paragraph.parse(self.area, table.concat(lines, "\n")):wrap(ui.Paragraph.WRAP),
+	})
+end
+
+function M:seek() end
+
+-- The header of an age file is plain text, one stanza for each recipient, ending with `---`
+function M:age()
+	local f = io.open(tostring(self.file.url), "rb")
+	if not f then
+		return { "Failed to open the file" }
+	end
+
+	local lines = { "----- age -----", "" }
+	local first = f:read("*l") or ""
+	if first:find("-----BEGIN AGE ENCRYPTED FILE-----", 1, true) then
+		f:close()
+		lines[#lines + 1] = "ASCII armored, the recipients are inside the armor"
+		return lines
+	elseif first ~= "age-encryption.org/v1" then
+		f:close()
+		lines[#lines + 1] = "Not an age file, or an unknown version of it"
+		return lines
+	end
+
+	lines[#lines + 1] = "Version: v1"
+	lines[#lines + 1] = "Recipients:"
+	for _ = 1, 64 do
+		local line = f:read("*l")
+		if not line or line:sub(1, 3) == "---" then
+			break
+		end
+
+		local kind, arg = line:match("^%-> (%S+)%s*(%S*)")
+		if kind then
+			local desc = STANZAS[kind] or ("Plugin or unknown (" .. kind .. ")")
+			lines[#lines + 1] = "  - " .. desc:format(kind == "scrypt" and line:match("(%d+)$") or arg)
+		end
+	end
+
+	f:close()
+	return lines
+end
+
+-- The packets are listed without decrypting, which fails without a passphrase but is expected
+function M:gpg()
+	local output, code = Command("gpg")
+		:args({ "--batch", "--list-packets", "--pinentry-mode", "cancel", tostring(self.file.url) })
+		:stdout(Command.PIPED)
+		:stderr(Command.NULL)
+		:output()
+
+	if not output then
+		return { string.format("Spawn `gpg` command returns %s", code) }
+	end
+
+	local lines = { "----- gpg --list-packets -----", "" }
+	for line in output.stdout:gmatch("[^\n]+") do
+		if not line:find("^# off=") then
+			lines[#lines + 1] = line
+		end
+	end
+	return lines
+end
+
+return M
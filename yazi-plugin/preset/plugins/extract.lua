@@ -2,15 +2,6 @@ local function fail(s, ...) error(string.format(s, ...)) end
 
 local M = {}
 
-function M:setup()
-	ps.sub_remote("extract", function(args)
-		local noisy = #args == 1 and " --noisy" or ""
-		for _, arg in ipairs(args) do
-			ya.manager_emit("plugin", { self._id, args = ya.quote(arg, true) .. noisy })
-		end
-	end)
-end
-
 function M:entry(args)
 	if not args[1] then
 		fail("No URL provided")
@@ -0,0 +1,84 @@
+-- The SELinux or SMACK context and the file capabilities on Linux, as `plugin security --args=<action>`:
+--   - `copy-context` or `copy-caps`: copies them of the hovered file;
+--   - `chcon`: changes the context of the selected files, which usually needs root.
+local M = {}
+
+local selected = ya.sync(function()
+	local urls = {}
+	for _, u in pairs(cx.active.selected) do
+		urls[#urls + 1] = u
+	end
+	if #urls == 0 and cx.active.current.hovered then
+		urls[1] = cx.active.current.hovered.url
+	end
+	return urls
+end)
+
+local function fail(s, ...) ya.notify { title = "Security", content = string.format(s, ...), timeout = 5, level = "error" } end
+
+function M:fetch()
+	if ya.target_os() ~= "linux" then
+		return 3
+	end
+
+	local contexts, caps = {}, {}
+	for _, file in ipairs(self.files) do
+		local s = fs.security(file.url) or {}
+		contexts[tostring(file.url)] = s.context or ""
+		caps[tostring(file.url)] = s.caps or ""
+	end
+
+	ya.manager_emit("update_security", { contexts = contexts, caps = caps })
+	return 3
+end
+
+function M:entry(args)
+	if ya.target_os() ~= "linux" then
+		return fail("Security contexts and capabilities are only available on Linux")
+	end
+
+	local action, urls = args[1], selected()
+	if #urls == 0 then
+		return
+	elseif action == "chcon" then
+		return M.chcon(urls)
+	end
+
+	local s, err = fs.security(urls[1])
+	if not s then
+		return fail("Failed to read `%s`, error code %s", urls[1]:name(), err)
+	end
+
+	local value = action == "copy-caps" and s.caps or s.context
+	if not value then
+		return ya.notify { title = "Security", content = "Nothing to copy for " .. urls[1]:name(), timeout = 3 }
+	end
+	ya.clipboard_set(value)
+end
+
+function M.chcon(urls)
+	local s = fs.security(urls[1]) or {}
+	local value, event = ya.input {
+		title = "Security context:",
+		value = s.context or "",
+		position = { "top-center", y = 3, w = 50 },
+	}
+	if event ~= 1 or value == "" then
+		return
+	end
+
+	local contexts, caps = {}, {}
+	for _, url in ipairs(urls) do
+		local ok, err = fs.set_context(url, value)
+		if not ok then
+			return fail("Failed to change the context of `%s`, error code %s", url:name(), err)
+		end
+
+		local new = fs.security(url) or {}
+		contexts[tostring(url)], caps[tostring(url)] = new.context or "", new.caps or ""
+	end
+
+	ya.manager_emit("update_security", { contexts = contexts, caps = caps })
+end
+
+return M
@@ -2,6 +2,9 @@ local function setup(_, opts)
 	if opts.sync_yanked then
 		ps.sub_remote("@yank", function(body) ya.manager_emit("update_yanked", { cut = body.cut, urls = body }) end)
 	end
+	if opts.sync_bookmarks then
+		ps.sub_remote("@bookmark", function(body) ya.manager_emit("update_bookmarks", { marks = body }) end)
+	end
 end
 
 return { setup = setup }
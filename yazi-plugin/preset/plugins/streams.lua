@@ -0,0 +1,104 @@
+-- The NTFS alternate data streams of the hovered file on Windows, as `plugin streams`,
+-- picking one of them with a preview of its content, to export it next to the file or delete it.
+local M = {}
+
+local PREVIEW_BYTES = 4096
+
+local hovered = ya.sync(function()
+	local h = cx.active.current.hovered
+	return h and h.url
+end)
+
+local function fail(s, ...) ya.notify { title = "Streams", content = string.format(s, ...), timeout = 5, level = "error" } end
+
+local function read(path, limit)
+	local f = io.open(path, "rb")
+	if not f then
+		return nil
+	end
+
+	local s = f:read(limit or "*a")
+	f:close()
+	return s or ""
+end
+
+function M:entry()
+	if ya.target_family() ~= "windows" then
+		return fail("Alternate data streams are only available on Windows")
+	end
+
+	local url = hovered()
+	if not url then
+		return
+	end
+
+	local streams, err = fs.streams(url)
+	if not streams then
+		return fail("Failed to list the streams of `%s`, error code %s", url:name(), err)
+	elseif #streams == 0 then
+		return ya.notify { title = "Streams", content = url:name() .. " has no alternate data streams", timeout = 3 }
+	end
+
+	local items = {}
+	for i, s in ipairs(streams) do
+		items[i] = string.format("%s  (%s)", s.name, ya.readable_size(s.size))
+	end
+
+	local idx = ya.pick {
+		title = "Streams of " .. url:name(),
+		items = items,
+		position = { "center", w = 70 },
+		preview = function(i)
+			local s = read(string.format("%s:%s", url, streams[i].name), PREVIEW_BYTES)
+			return s and s:gsub("[%z\1-\8\11\12\14-\31]", ".") or "Failed to read the stream"
+		end,
+	}
+	if not idx then
+		return
+	end
+
+	local stream = streams[idx]
+	local action = ya.which {
+		cands = {
+			{ on = "e", desc = "Export to a file next to it" },
+			{ on = "d", desc = "Delete the stream" },
+		},
+	}
+	if action == 1 then
+		M.export(url, stream.name)
+	elseif action == 2 then
+		M.delete(url, stream.name)
+	end
+end
+
+function M.export(url, name)
+	local data = read(string.format("%s:%s", url, name))
+	if not data then
+		return fail("Failed to read the stream `%s`", name)
+	end
+
+	local to = fs.unique_name(Url(string.format("%s.%s.txt", url, (name:gsub("[\\/]", "_")))))
+	local ok, err = fs.write(to, data)
+	if not ok then
+		return fail("Failed to export the stream `%s`, error code %s", name, err)
+	end
+	ya.notify { title = "Streams", content = "Exported to " .. to:name(), timeout = 3 }
+end
+
+function M.delete(url, name)
+	local yes = ya.confirm {
+		title = string.format("Delete the stream `%s` of %s?", name, url:name()),
+		choices = { { on = "y", desc = "Yes" }, { on = "n", desc = "No" } },
+	}
+	if yes ~= 1 then
+		return
+	end
+
+	local ok, err = fs.remove("file", Url(string.format("%s:%s", url, name)))
+	if not ok then
+		return fail("Failed to delete the stream `%s`, error code %s", name, err)
+	end
+	ya.notify { title = "Streams", content = "Deleted the stream " .. name, timeout = 3 }
+end
+
+return M
@@ -0,0 +1,120 @@
+-- Finder tags and the quarantine attribute on macOS, as `plugin tags --args=<action>`:
+--   - `red`, `orange`, `yellow`, `green`, `blue`, `purple` or `gray`: toggles the tag of that color;
+--   - `clear`: removes all the tags;
+--   - `quarantine`: shows where the hovered file was downloaded from;
+--   - `unquarantine`: clears the quarantine of the files, so they can be opened without a prompt.
+local M = {}
+
+local selected = ya.sync(function()
+	local urls = {}
+	for _, u in pairs(cx.active.selected) do
+		urls[#urls + 1] = u
+	end
+	if #urls == 0 and cx.active.current.hovered then
+		urls[1] = cx.active.current.hovered.url
+	end
+	return urls
+end)
+
+local function fail(s, ...) ya.notify { title = "Tags", content = string.format(s, ...), timeout = 5, level = "error" } end
+
+local function colors(tags)
+	local s = {}
+	for _, t in ipairs(tags or {}) do
+		if t.color ~= "none" then
+			s[#s + 1] = t.color
+		end
+	end
+	return table.concat(s, ",")
+end
+
+function M:fetch()
+	if ya.target_os() ~= "macos" then
+		return 3
+	end
+
+	local updates = {}
+	for _, file in ipairs(self.files) do
+		updates[tostring(file.url)] = colors(fs.tags(file.url))
+	end
+
+	ya.manager_emit("update_tags", { updates = updates })
+	return 3
+end
+
+function M:entry(args)
+	if ya.target_os() ~= "macos" then
+		return fail("Finder tags are only available on macOS")
+	end
+
+	local action, urls = args[1], selected()
+	if action == "quarantine" then
+		return M.show_quarantine(urls[1])
+	end
+
+	local updates = {}
+	for _, url in ipairs(urls) do
+		local ok, err
+		if action == "unquarantine" then
+			ok, err = fs.clear_quarantine(url)
+		elseif action == "clear" then
+			ok, err = fs.set_tags(url, {})
+		else
+			ok, err = M.toggle(url, action)
+		end
+
+		if not ok then
+			return fail("Failed to update `%s`, error code %s", url:name(), err)
+		end
+		updates[tostring(url)] = colors(fs.tags(url))
+	end
+
+	ya.manager_emit("update_tags", { updates = updates })
+end
+
+function M.toggle(url, color)
+	local tags, err = fs.tags(url)
+	if not tags then
+		return false, err
+	end
+
+	local kept = {}
+	for _, t in ipairs(tags) do
+		if t.color ~= color then
+			kept[#kept + 1] = t
+		end
+	end
+	if #kept == #tags then
+		kept[#kept + 1] = { name = color:sub(1, 1):upper() .. color:sub(2), color = color }
+	end
+	return fs.set_tags(url, kept)
+end
+
+-- The value is `flags;timestamp;agent;uuid`, with the timestamp in hex
+function M.show_quarantine(url)
+	if not url then
+		return
+	end
+
+	local q, err = fs.quarantine(url)
+	if err then
+		return fail("Failed to read the quarantine of `%s`, error code %s", url:name(), err)
+	elseif not q then
+		return ya.notify { title = "Quarantine", content = url:name() .. " is not quarantined", timeout = 3 }
+	end
+
+	local _, time, agent = q:match("^(%x*);(%x*);([^;]*)")
+	local when = tonumber(time or "", 16)
+	ya.notify {
+		title = "Quarantine",
+		content = string.format(
+			"%s was downloaded by %s on %s",
+			url:name(),
+			agent ~= "" and agent or "an unknown app",
+			when and os.date("%Y-%m-%d %H:%M", when) or "an unknown date"
+		),
+		timeout = 5,
+	}
+end
+
+return M
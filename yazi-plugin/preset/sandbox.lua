@@ -0,0 +1,41 @@
+-- The parts of `io` and `os` that write files or spawn processes go through the same permissions
+-- as the `fs` and `Command` APIs, or any plugin could use them to get around the prompts.
+local pending, ask = ...
+
+local function gate(perm, f)
+	return function(...)
+		if pending(perm) then
+			ask(perm)
+		end
+		return f(...)
+	end
+end
+
+local open, output = io.open, io.output
+
+io.open = function(path, mode)
+	if type(mode) == "string" and mode:find("[wa+]") and pending("fs-write") then
+		ask("fs-write")
+	end
+	return open(path, mode)
+end
+
+io.output = function(file)
+	if type(file) == "string" and pending("fs-write") then
+		ask("fs-write")
+	end
+	return output(file)
+end
+
+io.popen = gate("spawn", io.popen)
+
+os.execute = gate("spawn", os.execute)
+os.remove = gate("fs-write", os.remove)
+os.rename = gate("fs-write", os.rename)
+
+-- Crafted bytecode can break out of the Lua VM, so only the source code can be loaded
+local load, loadfile = load, loadfile
+
+_G.load = function(chunk, name, _, ...) return load(chunk, name, "t", ...) end
+_G.loadfile = function(file, _, ...) return loadfile(file, "t", ...) end
+_G.dofile = function(file) return assert(loadfile(file, "t"))() end
@@ -1,5 +1,12 @@
 os.setlocale("")
 package.path = BOOT.plugin_dir .. "/?.yazi/init.lua;" .. package.path
 
-require("dds"):setup()
-require("extract"):setup()
+-- The preset plugins are subscribed here, and only loaded once they have something to handle
+ps.sub_remote("dds-cd", function(url) ya.manager_emit("cd", { url }) end)
+ps.sub_remote("dds-flavor", function(name) ya.manager_emit("flavor", { name }) end)
+ps.sub_remote("extract", function(args)
+	local noisy = #args == 1 and " --noisy" or ""
+	for _, arg in ipairs(args) do
+		ya.manager_emit("plugin", { "extract", args = ya.quote(arg, true) .. noisy })
+	end
+end)
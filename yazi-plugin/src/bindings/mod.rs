@@ -7,6 +7,8 @@ mod mouse;
 mod permit;
 mod position;
 mod range;
+mod task;
+mod token;
 mod window;
 
 pub use bindings::*;
@@ -16,4 +18,6 @@ pub use mouse::*;
 pub use permit::*;
 pub use position::*;
 pub use range::*;
+pub use task::*;
+pub use token::*;
 pub use window::*;
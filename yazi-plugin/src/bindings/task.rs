@@ -0,0 +1,25 @@
+use mlua::{prelude::LuaUserDataMethods, UserData};
+use tokio::sync::mpsc;
+use yazi_proxy::options::PluginTaskProg;
+
+pub struct Task {
+	prog: mpsc::UnboundedSender<PluginTaskProg>,
+}
+
+impl Task {
+	pub fn new(prog: mpsc::UnboundedSender<PluginTaskProg>) -> Self { Self { prog } }
+}
+
+impl UserData for Task {
+	fn add_methods<'lua, M: LuaUserDataMethods<'lua, Self>>(methods: &mut M) {
+		methods.add_method("total", |_, me, total: u32| {
+			Ok(me.prog.send(PluginTaskProg::Total(total)).is_ok())
+		});
+		methods.add_method("advance", |_, me, succ: Option<u32>| {
+			Ok(me.prog.send(PluginTaskProg::Adv(succ.unwrap_or(1))).is_ok())
+		});
+		methods.add_method("log", |_, me, line: String| {
+			Ok(me.prog.send(PluginTaskProg::Log(line)).is_ok())
+		});
+	}
+}
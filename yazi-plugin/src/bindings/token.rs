@@ -0,0 +1,16 @@
+use mlua::{prelude::LuaUserDataMethods, UserData};
+use tokio_util::sync::CancellationToken;
+
+pub struct Token {
+	inner: CancellationToken,
+}
+
+impl Token {
+	pub fn new(inner: CancellationToken) -> Self { Self { inner } }
+}
+
+impl UserData for Token {
+	fn add_methods<'lua, M: LuaUserDataMethods<'lua, Self>>(methods: &mut M) {
+		methods.add_method("cancelled", |_, me, ()| Ok(me.inner.is_cancelled()));
+	}
+}
@@ -28,6 +28,13 @@ impl Cha {
 				reg.add_field_method_get("gid", |_, me| Ok((!me.is_dummy()).then_some(me.gid)));
 				reg.add_field_method_get("nlink", |_, me| Ok((!me.is_dummy()).then_some(me.nlink)));
 			}
+			#[cfg(windows)]
+			{
+				reg.add_field_method_get("is_system", |_, me| Ok(me.attrs & 0x4 != 0));
+				reg.add_field_method_get("is_readonly", |_, me| Ok(me.attrs & 0x1 != 0));
+			}
+			#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+			reg.add_method("flags", |_, me, ()| Ok(yazi_shared::fs::file_flags(me.flags)));
 
 			reg.add_field_method_get("length", |_, me| Ok(me.len));
 			reg.add_field_method_get("created", |_, me| {
@@ -44,7 +51,7 @@ impl Cha {
 					#[cfg(unix)]
 					Some(yazi_shared::fs::permissions(_me.perm, _me.is_dummy())),
 					#[cfg(windows)]
-					None::<String>,
+					Some(yazi_shared::fs::attributes(_me.attrs, _me.is_dummy())),
 				)
 			});
 		})?;
@@ -75,6 +82,8 @@ impl Cha {
 					ctime: parse_time(t.raw_get("ctime").ok())?,
 					mtime: parse_time(t.raw_get("mtime").ok())?,
 					#[cfg(unix)]
+					chtime: None,
+					#[cfg(unix)]
 					perm: t.raw_get("permissions").unwrap_or_default(),
 					#[cfg(unix)]
 					uid: t.raw_get("uid").unwrap_or_default(),
@@ -82,6 +91,10 @@ impl Cha {
 					gid: t.raw_get("gid").unwrap_or_default(),
 					#[cfg(unix)]
 					nlink: t.raw_get("nlink").unwrap_or_default(),
+					#[cfg(windows)]
+					attrs: t.raw_get("attributes").unwrap_or_default(),
+					#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+					flags: t.raw_get("flags").unwrap_or_default(),
 				})
 			})?,
 		)
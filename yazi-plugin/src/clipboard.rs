@@ -1,4 +1,4 @@
-use std::ffi::OsString;
+use std::{ffi::OsString, path::PathBuf};
 
 use parking_lot::Mutex;
 use yazi_shared::RoCell;
@@ -58,9 +58,20 @@ impl Clipboard {
 
 		use crossterm::execute;
 		use tokio::{io::AsyncWriteExt, process::Command};
+		use yazi_config::MANAGER;
+		use yazi_shared::{env_exists, in_ssh_connection};
 
 		s.as_ref().clone_into(&mut self.content.lock());
-		execute!(BufWriter::new(stderr()), osc52::SetClipboard::new(s.as_ref())).ok();
+		let osc52 = || {
+			if MANAGER.load().osc52 {
+				execute!(BufWriter::new(stderr()), osc52::SetClipboard::new(s.as_ref())).ok();
+			}
+		};
+
+		// Without a display over SSH, the clipboard tools can't reach the one of the local machine
+		if in_ssh_connection() && !env_exists("DISPLAY") && !env_exists("WAYLAND_DISPLAY") {
+			return osc52();
+		}
 
 		let all = [
 			("pbcopy", &[][..]),
@@ -87,9 +98,10 @@ impl Clipboard {
 			drop(stdin);
 
 			if child.wait().await.map(|s| s.success()).unwrap_or_default() {
-				break;
+				return;
 			}
 		}
+		osc52();
 	}
 
 	#[cfg(windows)]
@@ -103,6 +115,220 @@ impl Clipboard {
 			.await
 			.ok();
 	}
+
+	// Reads the content of the given MIME type, e.g. `image/png`,
+	// which only the backends that support multiple targets can provide.
+	#[cfg(unix)]
+	pub async fn get_as(&self, mime: &str) -> Option<Vec<u8>> {
+		use yazi_shared::in_ssh_connection;
+
+		if mime.starts_with("text/") {
+			return Some(self.get().await.into_encoded_bytes());
+		} else if in_ssh_connection() {
+			return None;
+		}
+		Self::get_typed(mime).await
+	}
+
+	#[cfg(unix)]
+	async fn get_typed(mime: &str) -> Option<Vec<u8>> {
+		use tokio::process::Command;
+
+		let all = [
+			("wl-paste", vec!["--no-newline", "--type", mime]),
+			("xclip", vec!["-o", "-selection", "clipboard", "-t", mime]),
+		];
+
+		for (bin, args) in all {
+			let Ok(output) = Command::new(bin).args(args).kill_on_drop(true).output().await else {
+				continue;
+			};
+			if output.status.success() {
+				return Some(output.stdout);
+			}
+		}
+		None
+	}
+
+	#[cfg(windows)]
+	pub async fn get_as(&self, mime: &str) -> Option<Vec<u8>> {
+		if mime.starts_with("text/") {
+			Some(self.get().await.into_encoded_bytes())
+		} else {
+			None
+		}
+	}
+
+	#[cfg(unix)]
+	pub async fn set_as(&self, data: &[u8], mime: &str) -> bool {
+		if mime.starts_with("text/") {
+			self.set(String::from_utf8_lossy(data).as_ref()).await;
+			return true;
+		}
+		Self::set_typed(data, mime).await
+	}
+
+	#[cfg(unix)]
+	async fn set_typed(data: &[u8], mime: &str) -> bool {
+		use std::process::Stdio;
+
+		use tokio::{io::AsyncWriteExt, process::Command};
+
+		let all = [
+			("wl-copy", vec!["--type", mime]),
+			("xclip", vec!["-selection", "clipboard", "-t", mime, "-i"]),
+		];
+
+		for (bin, args) in all {
+			let cmd = Command::new(bin)
+				.args(args)
+				.stdin(Stdio::piped())
+				.stdout(Stdio::null())
+				.stderr(Stdio::null())
+				.kill_on_drop(true)
+				.spawn();
+
+			let Ok(mut child) = cmd else { continue };
+
+			let mut stdin = child.stdin.take().unwrap();
+			if stdin.write_all(data).await.is_err() {
+				continue;
+			}
+			drop(stdin);
+
+			if child.wait().await.map(|s| s.success()).unwrap_or_default() {
+				return true;
+			}
+		}
+		false
+	}
+
+	#[cfg(windows)]
+	pub async fn set_as(&self, data: &[u8], mime: &str) -> bool {
+		if mime.starts_with("text/") {
+			self.set(String::from_utf8_lossy(data).as_ref()).await;
+			true
+		} else {
+			false
+		}
+	}
+
+	// The files in the format of the file managers, i.e. `text/uri-list` for a copy, and
+	// `x-special/gnome-copied-files` for a cut, which is the only one of them that can tell it
+	#[cfg(all(unix, not(target_os = "macos")))]
+	pub async fn set_files(&self, paths: &[PathBuf], cut: bool) -> bool {
+		if yazi_shared::in_ssh_connection() {
+			return false;
+		}
+
+		let uris: Vec<_> = paths.iter().map(|p| file_uri(p)).collect();
+		let (data, mime) = if cut {
+			(format!("cut\n{}", uris.join("\n")), "x-special/gnome-copied-files")
+		} else {
+			(format!("{}\r\n", uris.join("\r\n")), "text/uri-list")
+		};
+		Self::set_typed(data.as_bytes(), mime).await
+	}
+
+	// Whether they're cut, and the files
+	#[cfg(all(unix, not(target_os = "macos")))]
+	pub async fn get_files(&self) -> Option<(bool, Vec<PathBuf>)> {
+		if yazi_shared::in_ssh_connection() {
+			return None;
+		}
+
+		if let Some(b) = Self::get_typed("x-special/gnome-copied-files").await {
+			let s = String::from_utf8_lossy(&b);
+			let mut lines = s.lines();
+			let cut = lines.next() == Some("cut");
+			let paths: Vec<_> = lines.filter_map(file_path).collect();
+			return Some((cut, paths)).filter(|(_, v)| !v.is_empty());
+		}
+
+		let b = Self::get_typed("text/uri-list").await?;
+		let cut = Self::get_typed("application/x-kde-cutselection").await.is_some_and(|b| b == b"1");
+		let paths: Vec<_> = String::from_utf8_lossy(&b).lines().filter_map(file_path).collect();
+		Some((cut, paths)).filter(|(_, v)| !v.is_empty())
+	}
+
+	// Through the `NSPasteboard` of JXA, where the file URLs also provide the legacy
+	// `NSFilenamesPboardType`. Finder decides a move on pasting instead, so they're never cut.
+	#[cfg(target_os = "macos")]
+	pub async fn set_files(&self, paths: &[PathBuf], _cut: bool) -> bool {
+		const JS: &str = r#"function run(argv) {
+			ObjC.import("AppKit");
+			const pb = $.NSPasteboard.generalPasteboard;
+			pb.clearContents;
+			pb.writeObjects($(argv.map(p => $.NSURL.fileURLWithPath(p))));
+		}"#;
+
+		let output = tokio::process::Command::new("osascript")
+			.args(["-l", "JavaScript", "-e", JS, "--"])
+			.args(paths)
+			.kill_on_drop(true)
+			.output()
+			.await;
+		output.is_ok_and(|o| o.status.success())
+	}
+
+	#[cfg(target_os = "macos")]
+	pub async fn get_files(&self) -> Option<(bool, Vec<PathBuf>)> {
+		const JS: &str = r#"function run() {
+			ObjC.import("AppKit");
+			const list = $.NSPasteboard.generalPasteboard.propertyListForType("NSFilenamesPboardType");
+			return list.isNil() ? "" : ObjC.deepUnwrap(list).join("\n");
+		}"#;
+
+		let output = tokio::process::Command::new("osascript")
+			.args(["-l", "JavaScript", "-e", JS])
+			.kill_on_drop(true)
+			.output()
+			.await
+			.ok()?;
+
+		let s = String::from_utf8_lossy(&output.stdout);
+		let paths: Vec<_> = s.lines().filter(|l| !l.is_empty()).map(PathBuf::from).collect();
+		Some((false, paths)).filter(|(_, v)| !v.is_empty())
+	}
+
+	// As a `CF_HDROP`, without the `Preferred DropEffect` of a cut that only Explorer reads
+	#[cfg(windows)]
+	pub async fn set_files(&self, paths: &[PathBuf], _cut: bool) -> bool {
+		use clipboard_win::{formats, set_clipboard};
+
+		let paths: Vec<_> = paths.iter().map(|p| p.to_string_lossy().into_owned()).collect();
+		let result = tokio::task::spawn_blocking(move || {
+			set_clipboard(formats::FileList, paths.as_slice()).is_ok()
+		});
+		result.await.unwrap_or(false)
+	}
+
+	#[cfg(windows)]
+	pub async fn get_files(&self) -> Option<(bool, Vec<PathBuf>)> {
+		use clipboard_win::{formats, get_clipboard};
+
+		let result =
+			tokio::task::spawn_blocking(|| get_clipboard::<Vec<PathBuf>, _>(formats::FileList));
+		let paths = result.await.ok()?.ok()?;
+		Some((false, paths)).filter(|(_, v)| !v.is_empty())
+	}
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn file_uri(path: &std::path::Path) -> String {
+	let bytes = path.as_os_str().as_encoded_bytes();
+	format!("file://{}", percent_encoding::percent_encode(bytes, yazi_shared::fs::PATH_ENCODE_SET))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn file_path(uri: &str) -> Option<PathBuf> {
+	use std::os::unix::ffi::OsStringExt;
+
+	// Either `file:///path` or `file://localhost/path`, the comments start with a `#`
+	let s = uri.trim().strip_prefix("file://")?;
+	let s = s.strip_prefix("localhost").unwrap_or(s);
+	let bytes: Vec<u8> = percent_encoding::percent_decode_str(s).collect();
+	Some(PathBuf::from(std::ffi::OsString::from_vec(bytes)))
 }
 
 #[cfg(unix)]
@@ -110,6 +336,11 @@ mod osc52 {
 	use std::ffi::OsStr;
 
 	use base64::{engine::general_purpose, Engine};
+	use yazi_adapter::TMUX;
+	use yazi_shared::env_exists;
+
+	// GNU Screen drops a DCS longer than its 768-byte buffer, so the payload goes in pieces
+	const SCREEN_CHUNK: usize = 76;
 
 	#[derive(Debug)]
 	pub struct SetClipboard {
@@ -124,7 +355,21 @@ mod osc52 {
 
 	impl crossterm::Command for SetClipboard {
 		fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-			write!(f, "\x1b]52;c;{}\x1b\\", self.content)
+			if *TMUX {
+				return write!(f, "\x1bPtmux;\x1b\x1b]52;c;{}\x07\x1b\\", self.content);
+			} else if !env_exists("STY") {
+				return write!(f, "\x1b]52;c;{}\x1b\\", self.content);
+			}
+
+			write!(f, "\x1bP\x1b]52;c;")?;
+			for (i, chunk) in self.content.as_bytes().chunks(SCREEN_CHUNK).enumerate() {
+				if i > 0 {
+					write!(f, "\x1b\\\x1bP")?;
+				}
+				// The base64 alphabet is ASCII, so any chunk of it is valid UTF-8
+				f.write_str(std::str::from_utf8(chunk).unwrap_or_default())?;
+			}
+			write!(f, "\x07\x1b\\")
 		}
 	}
 }
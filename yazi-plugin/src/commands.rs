@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use parking_lot::RwLock;
+use yazi_shared::{event::{Cmd, Data, DataKey}, RoCell};
+
+use crate::Opt;
+
+pub static COMMANDS: RoCell<Commands> = RoCell::new();
+
+#[derive(Default)]
+pub struct Commands {
+	inner: RwLock<HashMap<String, Command>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Command {
+	pub plugin: String,
+	pub sync:   bool,
+	pub desc:   Option<String>,
+}
+
+impl Commands {
+	#[inline]
+	pub fn register(&self, name: String, command: Command) { self.inner.write().insert(name, command); }
+
+	#[inline]
+	pub fn get(&self, name: &str) -> Option<Command> { self.inner.read().get(name).cloned() }
+
+	// Turns a command registered by a plugin into a call to its `entry()`,
+	// with positional arguments as the sequence and named ones as the fields,
+	// e.g. `my-cmd foo --bar=baz --qux-quux` becomes `{ "foo", bar = "baz", qux_quux = true }`.
+	pub fn resolve(&self, cmd: Cmd) -> Result<Opt, Cmd> {
+		let Some(command) = self.get(&cmd.name) else {
+			return Err(cmd);
+		};
+
+		let mut args = HashMap::with_capacity(cmd.args.len());
+		for (k, v) in cmd.args {
+			if matches!(v, Data::Any(_)) {
+				continue;
+			} else if let Ok(i) = k.parse::<i64>() {
+				args.insert(DataKey::Integer(i + 1), v);
+			} else {
+				args.insert(DataKey::String(k.replace('-', "_")), v);
+			}
+		}
+
+		Ok(Opt { id: command.plugin, sync: command.sync, args, cb: None })
+	}
+}
@@ -18,17 +18,17 @@ impl<'a> Config<'a> {
 	}
 
 	pub fn install_manager(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("MANAGER", self.lua.to_value_with(&*MANAGER, OPTIONS)?)?;
+		self.lua.globals().raw_set("MANAGER", self.lua.to_value_with(&*MANAGER.load(), OPTIONS)?)?;
 		Ok(self)
 	}
 
 	pub fn install_theme(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("THEME", self.lua.to_value_with(&*THEME, OPTIONS)?)?;
+		self.lua.globals().raw_set("THEME", self.lua.to_value_with(&*THEME.load(), OPTIONS)?)?;
 		Ok(self)
 	}
 
 	pub fn install_preview(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("PREVIEW", self.lua.to_value_with(&*PREVIEW, OPTIONS)?)?;
+		self.lua.globals().raw_set("PREVIEW", self.lua.to_value_with(&*PREVIEW.load(), OPTIONS)?)?;
 		Ok(self)
 	}
 }
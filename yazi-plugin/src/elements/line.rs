@@ -2,6 +2,7 @@ use std::mem;
 
 use ansi_to_tui::IntoText;
 use mlua::{AnyUserData, ExternalError, ExternalResult, FromLua, IntoLua, Lua, Table, UserData, UserDataMethods, Value};
+use yazi_shared::str_width;
 
 use super::{Span, Style};
 
@@ -82,7 +83,9 @@ impl Line {
 
 impl UserData for Line {
 	fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
-		methods.add_function("width", |_, ud: AnyUserData| Ok(ud.borrow_mut::<Self>()?.0.width()));
+		methods.add_function("width", |_, ud: AnyUserData| {
+			Ok(ud.borrow::<Self>()?.0.spans.iter().map(|s| str_width(&s.content)).sum::<usize>())
+		});
 		methods.add_function("style", |_, (ud, value): (AnyUserData, Value)| {
 			{
 				let mut me = ud.borrow_mut::<Self>()?;
@@ -13,6 +13,7 @@ mod padding;
 mod paragraph;
 mod position;
 mod rect;
+mod region;
 mod span;
 mod style;
 
@@ -29,5 +30,6 @@ pub use padding::*;
 pub use paragraph::*;
 pub use position::*;
 pub use rect::*;
+pub use region::*;
 pub use span::*;
 pub use style::*;
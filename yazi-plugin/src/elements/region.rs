@@ -0,0 +1,10 @@
+use std::sync::atomic::AtomicU8;
+
+use parking_lot::Mutex;
+use ratatui::layout::Rect;
+
+// The components to draw in the frame being rendered, as the bits of `yazi_shared::event::Region`,
+// and the areas they were drawn in the last time, which are cleared before they're drawn again
+// over the last frame, while the rest of it is kept as it is.
+pub static DRAWING: AtomicU8 = AtomicU8::new(u8::MAX);
+pub static DRAWN: Mutex<[Rect; 5]> = Mutex::new([Rect::ZERO; 5]);
@@ -1,14 +1,18 @@
-use std::{io::Cursor, mem, path::{Path, PathBuf}, sync::atomic::{AtomicUsize, Ordering}};
+use std::{io::Cursor, mem, path::{Path, PathBuf}, sync::{atomic::{AtomicUsize, Ordering}, Arc}};
 
 use anyhow::{anyhow, Result};
+use parking_lot::RwLock;
 use ratatui::text::{Line, Span, Text};
 use syntect::{dumps, easy::HighlightLines, highlighting::{self, Theme, ThemeSet}, parsing::{SyntaxReference, SyntaxSet}, LoadingError};
 use tokio::{fs::File, io::{AsyncBufReadExt, BufReader}, sync::OnceCell};
 use yazi_config::{PREVIEW, THEME};
 use yazi_shared::PeekError;
 
+use super::{Lines, HUGE_FILE};
+
 static INCR: AtomicUsize = AtomicUsize::new(0);
-static SYNTECT: OnceCell<(Theme, SyntaxSet)> = OnceCell::const_new();
+static SYNTAXES: OnceCell<SyntaxSet> = OnceCell::const_new();
+static SYNTECT_THEME: RwLock<Option<Arc<Theme>>> = RwLock::new(None);
 
 pub struct Highlighter {
 	path: PathBuf,
@@ -18,26 +22,38 @@ impl Highlighter {
 	#[inline]
 	pub fn new(path: &Path) -> Self { Self { path: path.to_owned() } }
 
-	pub async fn init() -> (&'static Theme, &'static SyntaxSet) {
+	pub async fn init() -> (Arc<Theme>, &'static SyntaxSet) {
 		let fut = async {
 			tokio::task::spawn_blocking(|| {
-				let theme = std::fs::File::open(&THEME.manager.syntect_theme)
-					.map_err(LoadingError::Io)
-					.and_then(|f| ThemeSet::load_from_reader(&mut std::io::BufReader::new(f)))
-					.or_else(|_| ThemeSet::load_from_reader(&mut Cursor::new(yazi_prebuild::ansi_theme())));
-
-				let syntaxes = dumps::from_uncompressed_data(yazi_prebuild::syntaxes());
-
-				(theme.unwrap(), syntaxes.unwrap())
+				dumps::from_uncompressed_data(yazi_prebuild::syntaxes()).unwrap()
 			})
 			.await
 			.unwrap()
 		};
 
-		let r = SYNTECT.get_or_init(|| fut).await;
-		(&r.0, &r.1)
+		let syntaxes = SYNTAXES.get_or_init(|| fut).await;
+		if let Some(theme) = SYNTECT_THEME.read().clone() {
+			return (theme, syntaxes);
+		}
+
+		let theme = tokio::task::spawn_blocking(|| {
+			std::fs::File::open(&THEME.load().manager.syntect_theme)
+				.map_err(LoadingError::Io)
+				.and_then(|f| ThemeSet::load_from_reader(&mut std::io::BufReader::new(f)))
+				.or_else(|_| ThemeSet::load_from_reader(&mut Cursor::new(yazi_prebuild::ansi_theme())))
+				.unwrap()
+		})
+		.await
+		.unwrap();
+
+		(SYNTECT_THEME.write().get_or_insert(Arc::new(theme)).clone(), syntaxes)
 	}
 
+	// Loads the theme again on the next highlight, after the flavor has changed,
+	// and the old one is dropped once the highlights still using it are done.
+	#[inline]
+	pub fn reset_theme() { SYNTECT_THEME.write().take(); }
+
 	async fn find_syntax(path: &Path) -> Result<&'static SyntaxReference> {
 		let (_, syntaxes) = Self::init().await;
 		let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
@@ -57,7 +73,11 @@ impl Highlighter {
 	}
 
 	pub async fn highlight(&self, skip: usize, limit: usize) -> Result<Text<'static>, PeekError> {
-		let mut reader = BufReader::new(File::open(&self.path).await?);
+		let file = File::open(&self.path).await?;
+		if file.metadata().await?.len() > HUGE_FILE {
+			return self.highlight_huge(skip, limit).await;
+		}
+		let mut reader = BufReader::new(file);
 
 		let syntax = Self::find_syntax(&self.path).await;
 		let mut plain = syntax.is_err() as u8;
@@ -102,14 +122,26 @@ impl Highlighter {
 		if plain == 0 {
 			Self::highlight_with(before, after, syntax.unwrap()).await
 		} else if plain == 1 {
-			Ok(Text::from(after.join("").replace('\t', &PREVIEW.indent())))
+			Ok(Text::from(after.join("").replace('\t', &PREVIEW.load().indent())))
 		} else if plain == 2 {
-			Ok(Text::from(after.join("").replace('\x1b', "^[").replace('\t', &PREVIEW.indent())))
+			Ok(Text::from(after.join("").replace('\x1b', "^[").replace('\t', &PREVIEW.load().indent())))
 		} else {
 			unreachable!()
 		}
 	}
 
+	// Huge files are shown as plain text, since highlighting needs the lines before the window
+	async fn highlight_huge(&self, skip: usize, limit: usize) -> Result<Text<'static>, PeekError> {
+		let path = self.path.clone();
+		let (lines, total) =
+			tokio::task::spawn_blocking(move || Lines::window(&path, skip, limit)).await??;
+
+		if skip > 0 && skip + limit > total {
+			return Err(PeekError::Exceed(total.saturating_sub(limit)));
+		}
+		Ok(Text::from(lines.join("").replace('\x1b', "^[").replace('\t', &PREVIEW.load().indent())))
+	}
+
 	async fn highlight_with(
 		before: Vec<String>,
 		after: Vec<String>,
@@ -119,7 +151,7 @@ impl Highlighter {
 		let (theme, syntaxes) = Self::init().await;
 
 		tokio::task::spawn_blocking(move || {
-			let mut h = HighlightLines::new(syntax, theme);
+			let mut h = HighlightLines::new(syntax, &theme);
 			for line in before {
 				if ticket != INCR.load(Ordering::Relaxed) {
 					return Err("Highlighting cancelled".into());
@@ -127,7 +159,7 @@ impl Highlighter {
 				h.highlight_line(&line, syntaxes).map_err(|e| anyhow!(e))?;
 			}
 
-			let indent = PREVIEW.indent();
+			let indent = PREVIEW.load().indent();
 			let mut lines = Vec::with_capacity(after.len());
 			for line in after {
 				if ticket != INCR.load(Ordering::Relaxed) {
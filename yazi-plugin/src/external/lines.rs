@@ -0,0 +1,116 @@
+use std::{collections::HashMap, fs::File, io::{self, BufRead, BufReader, Read, Seek, SeekFrom}, path::{Path, PathBuf}, sync::Arc, time::SystemTime};
+
+use parking_lot::Mutex;
+
+// The files over this size are previewed through a line index of them,
+// instead of being read from the start on each peek
+pub const HUGE_FILE: u64 = 32 << 20;
+
+// The offset of every `STRIDE`th line is kept, so reaching any line reads at most this many
+const STRIDE: usize = 1024;
+
+const CHUNK: usize = 1 << 20;
+
+static INDEXES: Mutex<Option<HashMap<PathBuf, Arc<Index>>>> = Mutex::new(None);
+
+struct Index {
+	len:   u64,
+	mtime: Option<SystemTime>,
+	marks: Vec<u64>,
+	total: usize,
+}
+
+pub struct Lines;
+
+impl Lines {
+	// Reads `limit` lines from the `skip`th of a file, along with the total number of them.
+	// The index is built on the first peek beyond the beginning and reused afterwards,
+	// which makes jumping to the end as cheap as any other window.
+	pub fn window(path: &Path, skip: usize, limit: usize) -> io::Result<(Vec<String>, usize)> {
+		let mut file = File::open(path)?;
+		let meta = file.metadata()?;
+		let (len, mtime) = (meta.len(), meta.modified().ok());
+
+		let index = match Self::cached(path, len, mtime) {
+			Some(index) => index,
+			None if skip + limit <= STRIDE => {
+				let lines = Self::read(file, 0, skip, limit)?;
+				Self::spawn_index(path.to_owned());
+				return Ok((lines, usize::MAX));
+			}
+			None => Self::index(path, &mut file, len, mtime)?,
+		};
+
+		let mark = (skip / STRIDE).min(index.marks.len() - 1);
+		Ok((Self::read(file, index.marks[mark], skip - mark * STRIDE, limit)?, index.total))
+	}
+
+	fn read(mut file: File, start: u64, skip: usize, limit: usize) -> io::Result<Vec<String>> {
+		file.seek(SeekFrom::Start(start))?;
+		let mut it = BufReader::new(file).split(b'\n').skip(skip);
+
+		let mut lines = Vec::with_capacity(limit);
+		while lines.len() < limit {
+			let Some(line) = it.next().transpose()? else { break };
+			let line = line.strip_suffix(b"\r").unwrap_or(&line);
+			lines.push(String::from_utf8_lossy(line).into_owned() + "\n");
+		}
+		Ok(lines)
+	}
+
+	fn cached(path: &Path, len: u64, mtime: Option<SystemTime>) -> Option<Arc<Index>> {
+		let indexes = INDEXES.lock();
+		indexes.as_ref()?.get(path).filter(|i| i.len == len && i.mtime == mtime).cloned()
+	}
+
+	// Scans the file in chunks for the line breaks, without holding the whole of it in memory
+	fn index(
+		path: &Path,
+		file: &mut File,
+		len: u64,
+		mtime: Option<SystemTime>,
+	) -> io::Result<Arc<Index>> {
+		let mut marks = Vec::with_capacity(len as usize / 64 / STRIDE + 1);
+		marks.push(0);
+
+		file.seek(SeekFrom::Start(0))?;
+		let (mut buf, mut offset, mut total, mut last) = (vec![0; CHUNK], 0, 0, b'\n');
+		loop {
+			let n = match file.read(&mut buf) {
+				Ok(0) => break,
+				Ok(n) => n,
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+				Err(e) => return Err(e),
+			};
+			for i in memchr::memchr_iter(b'\n', &buf[..n]) {
+				total += 1;
+				if total % STRIDE == 0 {
+					marks.push(offset + i as u64 + 1);
+				}
+			}
+			(offset, last) = (offset + n as u64, buf[n - 1]);
+		}
+		if last != b'\n' {
+			total += 1;
+		}
+
+		let index = Arc::new(Index { len, mtime, marks, total });
+		let mut indexes = INDEXES.lock();
+		let indexes = indexes.get_or_insert_with(HashMap::new);
+		if indexes.len() >= 8 {
+			indexes.clear();
+		}
+		indexes.insert(path.to_owned(), index.clone());
+		Ok(index)
+	}
+
+	fn spawn_index(path: PathBuf) {
+		tokio::task::spawn_blocking(move || {
+			let Ok(mut file) = File::open(&path) else { return };
+			let Ok(meta) = file.metadata() else { return };
+			if Self::cached(&path, meta.len(), meta.modified().ok()).is_none() {
+				Self::index(&path, &mut file, meta.len(), meta.modified().ok()).ok();
+			}
+		});
+	}
+}
@@ -1,7 +1,9 @@
 mod fd;
 mod highlighter;
+mod lines;
 mod rg;
 
 pub use fd::*;
 pub use highlighter::*;
+pub use lines::*;
 pub use rg::*;
@@ -1,9 +1,10 @@
 use globset::GlobBuilder;
-use mlua::{ExternalError, ExternalResult, IntoLuaMulti, Lua, Table, Value};
+use mlua::{ExternalError, ExternalResult, IntoLua, IntoLuaMulti, Lua, Table, Value};
 use tokio::fs;
 use yazi_shared::fs::remove_dir_clean;
 
-use crate::{bindings::Cast, cha::Cha, file::File, url::{Url, UrlRef}};
+use super::{Security, Streams, Tag, Tags, TAG_COLORS};
+use crate::{bindings::Cast, cha::Cha, file::File, url::{Url, UrlRef}, Perm, PERMS};
 
 pub fn install(lua: &Lua) -> mlua::Result<()> {
 	lua.globals().raw_set(
@@ -27,6 +28,7 @@ pub fn install(lua: &Lua) -> mlua::Result<()> {
 			(
 				"write",
 				lua.create_async_function(|lua, (url, data): (UrlRef, mlua::String)| async move {
+					PERMS.check(lua, Perm::FsWrite).await?;
 					match fs::write(&*url, data).await {
 						Ok(_) => (true, Value::Nil).into_lua_multi(lua),
 						Err(e) => (false, e.raw_os_error()).into_lua_multi(lua),
@@ -36,6 +38,7 @@ pub fn install(lua: &Lua) -> mlua::Result<()> {
 			(
 				"remove",
 				lua.create_async_function(|lua, (type_, url): (mlua::String, UrlRef)| async move {
+					PERMS.check(lua, Perm::FsWrite).await?;
 					let result = match type_.to_str()? {
 						"file" => fs::remove_file(&*url).await,
 						"dir" => fs::remove_dir(&*url).await,
@@ -116,6 +119,149 @@ pub fn install(lua: &Lua) -> mlua::Result<()> {
 					Url::cast(lua, yazi_shared::fs::unique_name(url.clone()).await)
 				})?,
 			),
+			(
+				"tags",
+				lua.create_async_function(|lua, url: UrlRef| async move {
+					let path = url.to_path_buf();
+					match tokio::task::spawn_blocking(move || Tags::get(&path)).await.into_lua_err()? {
+						Ok(tags) => {
+							let tbl = lua.create_table_with_capacity(tags.len(), 0)?;
+							for t in tags {
+								let (color, name) = (t.color_name().into_lua(lua)?, t.name.into_lua(lua)?);
+								tbl.raw_push(lua.create_table_from([("name", name), ("color", color)])?)?;
+							}
+							(tbl, Value::Nil).into_lua_multi(lua)
+						}
+						Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"set_tags",
+				lua.create_async_function(|lua, (url, tags): (UrlRef, Table)| async move {
+					PERMS.check(lua, Perm::FsWrite).await?;
+
+					let mut v = Vec::with_capacity(tags.raw_len());
+					for t in tags.sequence_values::<Table>() {
+						let t = t?;
+						let color: Option<mlua::String> = t.raw_get("color")?;
+						let color = match color.as_ref().map(|s| s.to_str()).transpose()? {
+							Some(c) => TAG_COLORS.iter().position(|&s| s == c).ok_or_else(|| {
+								format!("Tag color must be one of {}", TAG_COLORS.join(", ")).into_lua_err()
+							})? as u8,
+							None => 0,
+						};
+						v.push(Tag { name: t.raw_get("name")?, color });
+					}
+
+					let path = url.to_path_buf();
+					match tokio::task::spawn_blocking(move || Tags::set(&path, &v)).await.into_lua_err()? {
+						Ok(_) => (true, Value::Nil).into_lua_multi(lua),
+						Err(e) => (false, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"quarantine",
+				lua.create_async_function(|lua, url: UrlRef| async move {
+					let path = url.to_path_buf();
+					let result = tokio::task::spawn_blocking(move || Tags::quarantine(&path));
+					match result.await.into_lua_err()? {
+						Ok(q) => (q, Value::Nil).into_lua_multi(lua),
+						Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"clear_quarantine",
+				lua.create_async_function(|lua, url: UrlRef| async move {
+					PERMS.check(lua, Perm::FsWrite).await?;
+
+					let path = url.to_path_buf();
+					let result = tokio::task::spawn_blocking(move || Tags::clear_quarantine(&path));
+					match result.await.into_lua_err()? {
+						Ok(_) => (true, Value::Nil).into_lua_multi(lua),
+						Err(e) => (false, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"security",
+				lua.create_async_function(|lua, url: UrlRef| async move {
+					let path = url.to_path_buf();
+					let result = tokio::task::spawn_blocking(move || {
+						Ok::<_, std::io::Error>((Security::context(&path)?, Security::caps(&path)?))
+					});
+					match result.await.into_lua_err()? {
+						Ok((context, caps)) => {
+							let (context, caps) = (context.into_lua(lua)?, caps.into_lua(lua)?);
+							let tbl = lua.create_table_from([("context", context), ("caps", caps)])?;
+							(tbl, Value::Nil).into_lua_multi(lua)
+						}
+						Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"set_context",
+				lua.create_async_function(|lua, (url, context): (UrlRef, String)| async move {
+					PERMS.check(lua, Perm::FsWrite).await?;
+
+					let path = url.to_path_buf();
+					let result = tokio::task::spawn_blocking(move || Security::set_context(&path, &context));
+					match result.await.into_lua_err()? {
+						Ok(_) => (true, Value::Nil).into_lua_multi(lua),
+						Err(e) => (false, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"set_flags",
+				lua.create_async_function(|lua, (url, flags): (UrlRef, Vec<String>)| async move {
+					PERMS.check(lua, Perm::FsWrite).await?;
+
+					#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+					let result = {
+						use yazi_shared::fs::{set_file_flags, FILE_FLAGS};
+
+						let mut bits = 0;
+						for f in flags {
+							let Some(&(_, v)) = FILE_FLAGS.iter().find(|(n, _)| *n == f) else {
+								return Err(format!("Unknown file flag: {f}").into_lua_err());
+							};
+							bits |= v;
+						}
+						set_file_flags(url.to_path_buf(), bits).await
+					};
+					#[cfg(not(any(target_os = "macos", target_os = "freebsd")))]
+					let result: std::io::Result<()> = {
+						_ = (url, flags);
+						Err(std::io::ErrorKind::Unsupported.into())
+					};
+
+					match result {
+						Ok(_) => (true, Value::Nil).into_lua_multi(lua),
+						Err(e) => (false, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
+			(
+				"streams",
+				lua.create_async_function(|lua, url: UrlRef| async move {
+					let path = url.to_path_buf();
+					match tokio::task::spawn_blocking(move || Streams::list(&path)).await.into_lua_err()? {
+						Ok(streams) => {
+							let tbl = lua.create_table_with_capacity(streams.len(), 0)?;
+							for s in streams {
+								let (name, size) = (s.name.into_lua(lua)?, s.size.into_lua(lua)?);
+								tbl.raw_push(lua.create_table_from([("name", name), ("size", size)])?)?;
+							}
+							(tbl, Value::Nil).into_lua_multi(lua)
+						}
+						Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
+					}
+				})?,
+			),
 		])?,
 	)
 }
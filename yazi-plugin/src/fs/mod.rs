@@ -1,5 +1,13 @@
 #![allow(clippy::module_inception)]
 
 mod fs;
+mod security;
+mod streams;
+mod tags;
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+mod xattr;
 
 pub use fs::*;
+pub use security::*;
+pub use streams::*;
+pub use tags::*;
@@ -0,0 +1,101 @@
+use std::{io, path::Path};
+
+// The names of capabilities by their bits, as in `linux/capability.h`
+#[cfg(target_os = "linux")]
+const CAPS: [&str; 41] = [
+	"chown", "dac_override", "dac_read_search", "fowner", "fsetid", "kill", "setgid", "setuid",
+	"setpcap", "linux_immutable", "net_bind_service", "net_broadcast", "net_admin", "net_raw",
+	"ipc_lock", "ipc_owner", "sys_module", "sys_rawio", "sys_chroot", "sys_ptrace", "sys_pacct",
+	"sys_admin", "sys_boot", "sys_nice", "sys_resource", "sys_time", "sys_tty_config", "mknod",
+	"lease", "audit_write", "audit_control", "setfcap", "mac_override", "mac_admin", "syslog",
+	"wake_alarm", "block_suspend", "audit_read", "perfmon", "bpf", "checkpoint_restore",
+];
+
+#[cfg(target_os = "linux")]
+const SELINUX: &str = "security.selinux";
+#[cfg(target_os = "linux")]
+const SMACK: &str = "security.SMACK64";
+#[cfg(target_os = "linux")]
+const CAPABILITY: &str = "security.capability";
+
+// The security context of SELinux or SMACK, and the file capabilities, on Linux
+pub struct Security;
+
+impl Security {
+	#[cfg(target_os = "linux")]
+	pub fn context(path: &Path) -> io::Result<Option<String>> {
+		let b = match super::xattr::get(path, SELINUX)? {
+			Some(b) => b,
+			None => match super::xattr::get(path, SMACK)? {
+				Some(b) => b,
+				None => return Ok(None),
+			},
+		};
+		Ok(Some(String::from_utf8_lossy(b.strip_suffix(b"\0").unwrap_or(&b)).into_owned()))
+	}
+
+	// The one of SMACK is changed if it's already there, otherwise the one of SELinux
+	#[cfg(target_os = "linux")]
+	pub fn set_context(path: &Path, context: &str) -> io::Result<()> {
+		let name = if super::xattr::get(path, SMACK)?.is_some() { SMACK } else { SELINUX };
+		super::xattr::set(path, name, context.as_bytes())
+	}
+
+	#[cfg(target_os = "linux")]
+	pub fn caps(path: &Path) -> io::Result<Option<String>> {
+		Ok(super::xattr::get(path, CAPABILITY)?.and_then(|b| Self::parse_caps(&b)))
+	}
+
+	// The `vfs_cap_data`, in the text form of `getcap`, e.g. `cap_net_admin,cap_net_raw+ep`
+	#[cfg(target_os = "linux")]
+	fn parse_caps(b: &[u8]) -> Option<String> {
+		let u32_at = |i: usize| Some(u32::from_le_bytes(b.get(i..i + 4)?.try_into().ok()?));
+
+		let magic = u32_at(0)?;
+		let words = match magic & 0xff000000 {
+			0x01000000 => 1,
+			0x02000000 | 0x03000000 => 2,
+			_ => return None,
+		};
+		let effective = magic & 1 != 0;
+
+		let (mut permitted, mut inheritable) = (0u64, 0u64);
+		for i in 0..words {
+			permitted |= (u32_at(4 + i * 8)? as u64) << (i * 32);
+			inheritable |= (u32_at(8 + i * 8)? as u64) << (i * 32);
+		}
+
+		// Grouped by the flags, in the order of their first appearance
+		let mut groups: Vec<(String, Vec<String>)> = vec![];
+		for bit in 0..64 {
+			let (p, i) = ((permitted >> bit) & 1 != 0, (inheritable >> bit) & 1 != 0);
+			if !p && !i {
+				continue;
+			}
+
+			let flags = format!(
+				"{}{}{}",
+				if effective { "e" } else { "" },
+				if i { "i" } else { "" },
+				if p { "p" } else { "" }
+			);
+			let name = CAPS.get(bit).map_or_else(|| format!("cap_{bit}"), |s| format!("cap_{s}"));
+			match groups.iter_mut().find(|(f, _)| *f == flags) {
+				Some((_, names)) => names.push(name),
+				None => groups.push((flags, vec![name])),
+			}
+		}
+
+		let s: Vec<_> = groups.into_iter().map(|(f, n)| format!("{}+{f}", n.join(","))).collect();
+		Some(s.join(" ")).filter(|s| !s.is_empty())
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	pub fn context(_: &Path) -> io::Result<Option<String>> { Err(io::ErrorKind::Unsupported.into()) }
+
+	#[cfg(not(target_os = "linux"))]
+	pub fn set_context(_: &Path, _: &str) -> io::Result<()> { Err(io::ErrorKind::Unsupported.into()) }
+
+	#[cfg(not(target_os = "linux"))]
+	pub fn caps(_: &Path) -> io::Result<Option<String>> { Err(io::ErrorKind::Unsupported.into()) }
+}
@@ -0,0 +1,53 @@
+use std::{io, path::Path};
+
+#[derive(Clone, Debug)]
+pub struct Stream {
+	pub name: String,
+	pub size: u64,
+}
+
+// The alternate data streams of a file on NTFS, e.g. the `Zone.Identifier` of the downloads.
+// Each of them can be opened or removed as an ordinary file with the path `file:name`.
+pub struct Streams;
+
+impl Streams {
+	#[cfg(windows)]
+	pub fn list(path: &Path) -> io::Result<Vec<Stream>> {
+		use std::os::windows::ffi::OsStrExt;
+
+		use windows_sys::Win32::{Foundation::{ERROR_HANDLE_EOF, INVALID_HANDLE_VALUE}, Storage::FileSystem::{FindClose, FindFirstStreamW, FindNextStreamW, FindStreamInfoStandard, WIN32_FIND_STREAM_DATA}};
+
+		let path = yazi_shared::fs::long_path(path);
+		let wide: Vec<u16> = path.as_os_str().encode_wide().chain([0]).collect();
+
+		let mut data: WIN32_FIND_STREAM_DATA = unsafe { std::mem::zeroed() };
+		let ptr = &mut data as *mut WIN32_FIND_STREAM_DATA;
+		let handle = unsafe { FindFirstStreamW(wide.as_ptr(), FindStreamInfoStandard, ptr.cast(), 0) };
+		if handle == INVALID_HANDLE_VALUE {
+			// Directories have no streams by default, not even the unnamed one
+			let e = io::Error::last_os_error();
+			return if e.raw_os_error() == Some(ERROR_HANDLE_EOF as i32) { Ok(vec![]) } else { Err(e) };
+		}
+
+		let mut streams = vec![];
+		loop {
+			// In the form of `:name:$DATA`, and the content of the file itself is `::$DATA`
+			let len = data.cStreamName.iter().position(|&c| c == 0).unwrap_or(data.cStreamName.len());
+			let s = String::from_utf16_lossy(&data.cStreamName[..len]);
+			if let Some(name) = s.strip_prefix(':').and_then(|s| s.strip_suffix(":$DATA")) {
+				if !name.is_empty() {
+					streams.push(Stream { name: name.to_owned(), size: data.StreamSize as u64 });
+				}
+			}
+			if unsafe { FindNextStreamW(handle, ptr.cast()) } == 0 {
+				break;
+			}
+		}
+
+		unsafe { FindClose(handle) };
+		Ok(streams)
+	}
+
+	#[cfg(not(windows))]
+	pub fn list(_: &Path) -> io::Result<Vec<Stream>> { Err(io::ErrorKind::Unsupported.into()) }
+}
@@ -0,0 +1,82 @@
+use std::{io, path::Path};
+
+// The colors of Finder tags, in the order of their indices in `_kMDItemUserTags`
+pub const TAG_COLORS: [&str; 8] =
+	["none", "gray", "green", "purple", "blue", "yellow", "red", "orange"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tag {
+	pub name:  String,
+	pub color: u8,
+}
+
+impl Tag {
+	// Encoded as `Name\nColor`, where the color is omitted if it's none
+	#[cfg(target_os = "macos")]
+	fn parse(s: &str) -> Self {
+		match s.rsplit_once('\n') {
+			Some((name, c)) => Self { name: name.to_owned(), color: c.parse().unwrap_or(0) % 8 },
+			None => Self { name: s.to_owned(), color: 0 },
+		}
+	}
+
+	#[cfg(target_os = "macos")]
+	fn encode(&self) -> String {
+		if self.color == 0 { self.name.clone() } else { format!("{}\n{}", self.name, self.color) }
+	}
+
+	#[inline]
+	pub fn color_name(&self) -> &'static str { TAG_COLORS[self.color as usize % 8] }
+}
+
+// The Finder tags and quarantine of files, both are extended attributes only meaningful on macOS
+pub struct Tags;
+
+#[cfg(target_os = "macos")]
+const USER_TAGS: &str = "com.apple.metadata:_kMDItemUserTags";
+#[cfg(target_os = "macos")]
+const QUARANTINE: &str = "com.apple.quarantine";
+
+impl Tags {
+	#[cfg(target_os = "macos")]
+	pub fn get(path: &Path) -> io::Result<Vec<Tag>> {
+		let Some(b) = super::xattr::get(path, USER_TAGS)? else { return Ok(vec![]) };
+		let tags: Vec<String> = plist::from_bytes(&b).map_err(io::Error::other)?;
+		Ok(tags.iter().map(|s| Tag::parse(s)).collect())
+	}
+
+	#[cfg(target_os = "macos")]
+	pub fn set(path: &Path, tags: &[Tag]) -> io::Result<()> {
+		if tags.is_empty() {
+			return super::xattr::remove(path, USER_TAGS);
+		}
+
+		let tags: Vec<_> = tags.iter().map(Tag::encode).collect();
+		let mut b = vec![];
+		plist::to_writer_binary(&mut b, &tags).map_err(io::Error::other)?;
+		super::xattr::set(path, USER_TAGS, &b)
+	}
+
+	// In the form of `flags;timestamp;agent;uuid`, e.g. `0083;66a4e5a1;Safari;`
+	#[cfg(target_os = "macos")]
+	pub fn quarantine(path: &Path) -> io::Result<Option<String>> {
+		Ok(super::xattr::get(path, QUARANTINE)?.map(|b| String::from_utf8_lossy(&b).into_owned()))
+	}
+
+	#[cfg(target_os = "macos")]
+	pub fn clear_quarantine(path: &Path) -> io::Result<()> { super::xattr::remove(path, QUARANTINE) }
+
+	#[cfg(not(target_os = "macos"))]
+	pub fn get(_: &Path) -> io::Result<Vec<Tag>> { Err(io::ErrorKind::Unsupported.into()) }
+
+	#[cfg(not(target_os = "macos"))]
+	pub fn set(_: &Path, _: &[Tag]) -> io::Result<()> { Err(io::ErrorKind::Unsupported.into()) }
+
+	#[cfg(not(target_os = "macos"))]
+	pub fn quarantine(_: &Path) -> io::Result<Option<String>> {
+		Err(io::ErrorKind::Unsupported.into())
+	}
+
+	#[cfg(not(target_os = "macos"))]
+	pub fn clear_quarantine(_: &Path) -> io::Result<()> { Err(io::ErrorKind::Unsupported.into()) }
+}
@@ -0,0 +1,72 @@
+use std::{ffi::{c_char, c_void, CString}, io, os::unix::ffi::OsStrExt, path::Path};
+
+// The extended attributes, with the differences in signatures between macOS and Linux
+#[cfg(target_os = "macos")]
+const ENOATTR: i32 = libc::ENOATTR;
+#[cfg(target_os = "linux")]
+const ENOATTR: i32 = libc::ENODATA;
+
+fn cstr(s: &[u8]) -> io::Result<CString> {
+	CString::new(s).map_err(|_| io::ErrorKind::InvalidInput.into())
+}
+
+pub(super) fn get(path: &Path, name: &str) -> io::Result<Option<Vec<u8>>> {
+	let (p, n) = (cstr(path.as_os_str().as_bytes())?, cstr(name.as_bytes())?);
+	loop {
+		let len = unsafe { getxattr(p.as_ptr(), n.as_ptr(), std::ptr::null_mut(), 0) };
+		if len < 0 {
+			let e = io::Error::last_os_error();
+			return if e.raw_os_error() == Some(ENOATTR) { Ok(None) } else { Err(e) };
+		}
+
+		let mut buf = vec![0u8; len as usize];
+		let got = unsafe { getxattr(p.as_ptr(), n.as_ptr(), buf.as_mut_ptr().cast(), buf.len()) };
+		if got >= 0 {
+			buf.truncate(got as usize);
+			return Ok(Some(buf));
+		}
+
+		// Retry if it's grown between the two calls
+		let e = io::Error::last_os_error();
+		if e.raw_os_error() != Some(libc::ERANGE) {
+			return Err(e);
+		}
+	}
+}
+
+pub(super) fn set(path: &Path, name: &str, value: &[u8]) -> io::Result<()> {
+	let (p, n) = (cstr(path.as_os_str().as_bytes())?, cstr(name.as_bytes())?);
+	let r = unsafe { setxattr(p.as_ptr(), n.as_ptr(), value.as_ptr().cast(), value.len()) };
+	if r < 0 { Err(io::Error::last_os_error()) } else { Ok(()) }
+}
+
+#[cfg(target_os = "macos")]
+pub(super) fn remove(path: &Path, name: &str) -> io::Result<()> {
+	let (p, n) = (cstr(path.as_os_str().as_bytes())?, cstr(name.as_bytes())?);
+	if unsafe { libc::removexattr(p.as_ptr(), n.as_ptr(), 0) } == 0 {
+		return Ok(());
+	}
+
+	let e = io::Error::last_os_error();
+	if e.raw_os_error() == Some(ENOATTR) { Ok(()) } else { Err(e) }
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn getxattr(p: *const c_char, n: *const c_char, v: *mut c_void, size: usize) -> isize {
+	libc::getxattr(p, n, v, size, 0, 0)
+}
+
+#[cfg(target_os = "linux")]
+unsafe fn getxattr(p: *const c_char, n: *const c_char, v: *mut c_void, size: usize) -> isize {
+	libc::getxattr(p, n, v, size)
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn setxattr(p: *const c_char, n: *const c_char, v: *const c_void, size: usize) -> i32 {
+	libc::setxattr(p, n, v, size, 0, 0)
+}
+
+#[cfg(target_os = "linux")]
+unsafe fn setxattr(p: *const c_char, n: *const c_char, v: *const c_void, size: usize) -> i32 {
+	libc::setxattr(p, n, v, size, 0)
+}
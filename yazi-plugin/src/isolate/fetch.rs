@@ -1,21 +1,25 @@
-use mlua::{ExternalError, ExternalResult, Table, TableExt};
-use tokio::runtime::Handle;
+use mlua::{ExternalError, ExternalResult, TableExt};
+use tokio::{runtime::Handle, select};
+use tokio_util::sync::CancellationToken;
 use yazi_config::LAYOUT;
 
 use super::slim_lua;
-use crate::{bindings::Cast, elements::Rect, file::File, loader::LOADER};
+use crate::{bindings::{Cast, Token}, elements::Rect, file::File, loader::LOADER};
 
-pub async fn fetch(name: &str, files: Vec<yazi_shared::fs::File>) -> mlua::Result<u8> {
+// The job is dropped as soon as the token is cancelled, plugins that run for a long time
+// can also check `self.token:cancelled()` to stop early by themselves.
+pub async fn fetch(
+	name: &str,
+	files: Vec<yazi_shared::fs::File>,
+	token: CancellationToken,
+	visible: bool,
+) -> mlua::Result<u8> {
 	LOADER.ensure(name).await.into_lua_err()?;
 
 	let name = name.to_owned();
 	tokio::task::spawn_blocking(move || {
 		let lua = slim_lua(&name)?;
-		let plugin: Table = if let Some(b) = LOADER.read().get(&name) {
-			lua.load(b.as_ref()).set_name(name).call(())?
-		} else {
-			return Err("unloaded plugin".into_lua_err());
-		};
+		let plugin = LOADER.call(&lua, &name)?;
 
 		let files = files.into_iter().filter_map(|f| File::cast(&lua, f).ok()).collect::<Vec<_>>();
 		if files.is_empty() {
@@ -25,8 +29,15 @@ pub async fn fetch(name: &str, files: Vec<yazi_shared::fs::File>) -> mlua::Resul
 		plugin.raw_set("skip", 0)?;
 		plugin.raw_set("area", Rect::cast(&lua, LAYOUT.load().preview)?)?;
 		plugin.raw_set("files", files)?;
+		plugin.raw_set("visible", visible)?;
+		plugin.raw_set("token", lua.create_userdata(Token::new(token.clone()))?)?;
 
-		Handle::current().block_on(plugin.call_async_method("fetch", ()))
+		Handle::current().block_on(async {
+			select! {
+				r = plugin.call_async_method("fetch", ()) => r,
+				_ = token.cancelled() => Ok(0),
+			}
+		})
 	})
 	.await
 	.into_lua_err()?
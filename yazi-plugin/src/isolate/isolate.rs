@@ -5,6 +5,7 @@ use crate::{elements, runtime::Runtime};
 pub fn slim_lua(name: &str) -> mlua::Result<Lua> {
 	let lua = Lua::new();
 	lua.set_named_registry_value("rt", Runtime::new(name))?;
+	crate::perm::install(&lua)?;
 
 	// Base
 	crate::bindings::Icon::register(&lua)?;
@@ -1,4 +1,4 @@
-use mlua::{ExternalError, ExternalResult, HookTriggers, Table, TableExt};
+use mlua::{ExternalError, ExternalResult, HookTriggers, TableExt};
 use tokio::{runtime::Handle, select};
 use tokio_util::sync::CancellationToken;
 use tracing::error;
@@ -25,11 +25,7 @@ pub fn peek(cmd: &Cmd, file: yazi_shared::fs::File, skip: usize) -> Cancellation
 				},
 			);
 
-			let plugin: Table = if let Some(b) = LOADER.read().get(&name) {
-				lua.load(b.as_ref()).set_name(name).call(())?
-			} else {
-				return Err("unloaded plugin".into_lua_err());
-			};
+			let plugin = LOADER.call(&lua, &name)?;
 			plugin.raw_set("file", File::cast(&lua, file)?)?;
 			plugin.raw_set("skip", skip)?;
 			plugin.raw_set("area", Rect::cast(&lua, LAYOUT.load().preview)?)?;
@@ -1,27 +1,36 @@
-use mlua::{ExternalError, ExternalResult, Table, TableExt};
-use tokio::runtime::Handle;
+use mlua::{ExternalResult, TableExt};
+use tokio::{runtime::Handle, select};
+use tokio_util::sync::CancellationToken;
 use yazi_config::LAYOUT;
 
 use super::slim_lua;
-use crate::{bindings::Cast, elements::Rect, file::File, loader::LOADER};
+use crate::{bindings::{Cast, Token}, elements::Rect, file::File, loader::LOADER};
 
-pub async fn preload(name: &str, file: yazi_shared::fs::File) -> mlua::Result<u8> {
+pub async fn preload(
+	name: &str,
+	file: yazi_shared::fs::File,
+	token: CancellationToken,
+	visible: bool,
+) -> mlua::Result<u8> {
 	LOADER.ensure(name).await.into_lua_err()?;
 
 	let name = name.to_owned();
 	tokio::task::spawn_blocking(move || {
 		let lua = slim_lua(&name)?;
-		let plugin: Table = if let Some(b) = LOADER.read().get(&name) {
-			lua.load(b.as_ref()).set_name(name).call(())?
-		} else {
-			return Err("unloaded plugin".into_lua_err());
-		};
+		let plugin = LOADER.call(&lua, &name)?;
 
 		plugin.raw_set("skip", 0)?;
 		plugin.raw_set("area", Rect::cast(&lua, LAYOUT.load().preview)?)?;
 		plugin.raw_set("file", File::cast(&lua, file)?)?;
+		plugin.raw_set("visible", visible)?;
+		plugin.raw_set("token", lua.create_userdata(Token::new(token.clone()))?)?;
 
-		Handle::current().block_on(plugin.call_async_method("preload", ()))
+		Handle::current().block_on(async {
+			select! {
+				r = plugin.call_async_method("preload", ()) => r,
+				_ = token.cancelled() => Ok(0),
+			}
+		})
 	})
 	.await
 	.into_lua_err()?
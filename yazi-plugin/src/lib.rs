@@ -4,6 +4,7 @@ pub mod bindings;
 mod cast;
 pub mod cha;
 mod clipboard;
+mod commands;
 mod config;
 pub mod elements;
 pub mod external;
@@ -13,21 +14,31 @@ pub mod isolate;
 pub mod loader;
 mod lua;
 mod opt;
+mod perm;
 pub mod process;
 pub mod pubsub;
 mod runtime;
+mod store;
+mod types;
 pub mod url;
 pub mod utils;
 
 pub use cast::*;
 pub use clipboard::*;
+pub use commands::*;
 pub use config::*;
 pub use lua::*;
 pub use opt::*;
+pub use perm::*;
 pub use runtime::*;
+pub use store::*;
+pub use types::*;
 
 pub fn init() -> anyhow::Result<()> {
 	CLIPBOARD.with(<_>::default);
+	COMMANDS.with(<_>::default);
+	PERMS.with(<_>::default);
+	STORE.with(<_>::default);
 
 	crate::loader::init();
 	crate::init_lua()?;
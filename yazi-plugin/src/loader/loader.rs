@@ -1,19 +1,26 @@
-use std::{borrow::Cow, collections::HashMap, ops::Deref};
+use std::{borrow::Cow, collections::HashMap, ops::Deref, sync::Arc};
 
 use anyhow::Result;
-use mlua::{ExternalError, Lua, Table};
+use md5::{Digest, Md5};
+use mlua::{ChunkMode, ExternalError, Lua, Table};
 use parking_lot::RwLock;
 use tokio::fs;
 use yazi_boot::BOOT;
-use yazi_shared::RoCell;
+use yazi_shared::{emit, event::Cmd, Layer, RoCell};
+
+use crate::PERMS;
 
 pub static LOADER: RoCell<Loader> = RoCell::new();
 
 #[derive(Default)]
 pub struct Loader {
-	cache: RwLock<HashMap<String, Cow<'static, [u8]>>>,
+	cache:    RwLock<HashMap<String, Cow<'static, [u8]>>>,
+	bytecode: RwLock<HashMap<String, Compiled>>,
 }
 
+// The digest of the source the bytecode was compiled from, along with it
+type Compiled = (String, Arc<[u8]>);
+
 impl Loader {
 	pub async fn ensure(&self, name: &str) -> Result<()> {
 		if self.cache.read().contains_key(name) {
@@ -22,8 +29,9 @@ impl Loader {
 
 		let preset = match name {
 			"archive" => &include_bytes!("../../preset/plugins/archive.lua")[..],
+			"chflags" => include_bytes!("../../preset/plugins/chflags.lua"),
 			"code" => include_bytes!("../../preset/plugins/code.lua"),
-			"dds" => include_bytes!("../../preset/plugins/dds.lua"),
+			"crypt" => include_bytes!("../../preset/plugins/crypt.lua"),
 			"extract" => include_bytes!("../../preset/plugins/extract.lua"),
 			"file" => include_bytes!("../../preset/plugins/file.lua"),
 			"folder" => include_bytes!("../../preset/plugins/folder.lua"),
@@ -35,7 +43,10 @@ impl Loader {
 			"mime" => include_bytes!("../../preset/plugins/mime.lua"),
 			"noop" => include_bytes!("../../preset/plugins/noop.lua"),
 			"pdf" => include_bytes!("../../preset/plugins/pdf.lua"),
+			"security" => include_bytes!("../../preset/plugins/security.lua"),
 			"session" => include_bytes!("../../preset/plugins/session.lua"),
+			"streams" => include_bytes!("../../preset/plugins/streams.lua"),
+			"tags" => include_bytes!("../../preset/plugins/tags.lua"),
 			"video" => include_bytes!("../../preset/plugins/video.lua"),
 			"zoxide" => include_bytes!("../../preset/plugins/zoxide.lua"),
 			_ => b"",
@@ -51,21 +62,86 @@ impl Loader {
 		Ok(())
 	}
 
+	// Called by the watcher of the config directory on the changes to a plugin, which is only
+	// reloaded if it's a user plugin that has been loaded and whose source is no longer the same.
+	pub async fn reload(&self, name: &str) {
+		PERMS.forget(name);
+		if !matches!(self.read().get(name), Some(Cow::Owned(_))) {
+			return;
+		}
+
+		let Ok(b) = fs::read(BOOT.plugin_dir.join(format!("{name}.yazi/init.lua"))).await else {
+			return;
+		};
+		if self.read().get(name).is_some_and(|old| **old == *b) {
+			return;
+		}
+
+		self.write().insert(name.to_owned(), Cow::Owned(b));
+		emit!(Call(Cmd::args("plugin_reload", vec![name.to_owned()]), Layer::App));
+	}
+
 	pub fn load<'a>(&self, lua: &'a Lua, id: &str) -> mlua::Result<Table<'a>> {
 		let loaded: Table = lua.globals().raw_get::<_, Table>("package")?.raw_get("loaded")?;
 		if let Ok(t) = loaded.raw_get::<_, Table>(id) {
 			return Ok(t);
 		}
 
-		let t: Table = match self.read().get(id) {
-			Some(b) => lua.load(b.as_ref()).set_name(id).call(())?,
-			None => Err(format!("plugin `{id}` not found").into_lua_err())?,
-		};
-
+		let t = self.call(lua, id)?;
 		t.raw_set("_id", lua.create_string(id)?)?;
 		loaded.raw_set(id, t.clone())?;
 		Ok(t)
 	}
+
+	// Runs the chunk of the plugin, which has to be `ensure()`d first, from the bytecode
+	// compiled once per change of its source, and kept in the state directory for the next starts.
+	pub fn call<'a>(&self, lua: &'a Lua, name: &str) -> mlua::Result<Table<'a>> {
+		let digest = match self.read().get(name) {
+			Some(b) => format!("{:x}", Md5::new_with_prefix(b).finalize()),
+			None => Err(format!("plugin `{name}` not found").into_lua_err())?,
+		};
+
+		let cached =
+			self.bytecode.read().get(name).filter(|(d, _)| *d == digest).map(|(_, b)| b.clone());
+		let b = match cached {
+			Some(b) => b,
+			None => self.compile(lua, name, digest)?,
+		};
+
+		lua.load(&b[..]).set_name(name).set_mode(ChunkMode::Binary).call(())
+	}
+
+	// The bytecode file starts with the digest of both the source it was compiled from and itself,
+	// so that it's never mistaken for that of another source, or taken from a file cut short,
+	// and the source is compiled as text only, so no bytecode from the plugins can get in.
+	fn compile(&self, lua: &Lua, name: &str, digest: String) -> mlua::Result<Arc<[u8]>> {
+		let path = BOOT.state_dir.join(format!("bytecode/{name}.luac"));
+		let sum = |b: &[u8]| format!("{:x}", Md5::new_with_prefix(&digest).chain_update(b).finalize());
+
+		let b = match std::fs::read(&path) {
+			Ok(mut b) if b.len() > 32 && b[..32] == *sum(&b[32..]).as_bytes() => b.split_off(32),
+			_ => {
+				let b = match self.read().get(name) {
+					Some(src) => {
+						lua.load(src.as_ref()).set_name(name).set_mode(ChunkMode::Text).into_function()?
+					}
+					None => Err(format!("plugin `{name}` not found").into_lua_err())?,
+				}
+				.dump(false);
+
+				let tmp = path.with_extension(format!("luac.{}", std::process::id()));
+				std::fs::create_dir_all(BOOT.state_dir.join("bytecode"))
+					.and_then(|_| std::fs::write(&tmp, [sum(&b).as_bytes(), &b].concat()))
+					.and_then(|_| std::fs::rename(&tmp, &path))
+					.ok();
+				b
+			}
+		};
+
+		let b: Arc<[u8]> = b.into();
+		self.bytecode.write().insert(name.to_owned(), (digest, b.clone()));
+		Ok(b)
+	}
 }
 
 impl Deref for Loader {
@@ -6,7 +6,9 @@ mod require;
 pub use loader::*;
 use require::*;
 
-pub(super) fn init() { LOADER.with(<_>::default); }
+pub(super) fn init() {
+	LOADER.with(<_>::default);
+}
 
 pub(super) fn install(lua: &mlua::Lua) -> mlua::Result<()> { Require::install(lua) }
 
@@ -60,6 +60,19 @@ impl Require {
 		Ok(ts)
 	}
 
+	// Keep the arguments of `setup()`, so it can be called again when the plugin is reloaded.
+	fn remember_setup(lua: &Lua, id: &str, args: &MultiValue) -> mlua::Result<()> {
+		let setups = match lua.named_registry_value::<Table>("setups") {
+			Ok(t) => t,
+			Err(_) => {
+				let t = lua.create_table()?;
+				lua.set_named_registry_value("setups", t.clone())?;
+				t
+			}
+		};
+		setups.raw_set(id, lua.create_sequence_from(args.iter().cloned())?)
+	}
+
 	fn create_wrapper<'a>(
 		lua: &'a Lua,
 		id: Arc<str>,
@@ -70,6 +83,10 @@ impl Require {
 
 		if sync {
 			lua.create_function(move |lua, (ts, args): (Table, MultiValue)| {
+				if &*f == "setup" {
+					Self::remember_setup(lua, &id, &args)?;
+				}
+
 				let mod_: Table = ts.raw_get::<_, Table>("_mod")?;
 				lua.named_registry_value::<RtRef>("rt")?.push(&id);
 				let result = mod_.call_method::<_, MultiValue>(&f, args);
@@ -21,6 +21,7 @@ fn stage_1(lua: &'static Lua) -> Result<()> {
 
 	// Base
 	lua.set_named_registry_value("rt", Runtime::default())?;
+	crate::perm::install(lua)?;
 	lua.load(include_str!("../preset/ya.lua")).set_name("ya.lua").exec()?;
 	crate::bindings::Icon::register(lua)?;
 	crate::bindings::MouseEvent::register(lua)?;
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
+
 use anyhow::bail;
 use mlua::{Lua, Table};
-use yazi_shared::event::{Cmd, Data};
+use yazi_shared::event::{Cmd, Data, DataKey};
 
 pub(super) type OptCallback = Box<dyn FnOnce(&Lua, Table) -> mlua::Result<()> + Send>;
 
@@ -8,7 +10,7 @@ pub(super) type OptCallback = Box<dyn FnOnce(&Lua, Table) -> mlua::Result<()> +
 pub struct Opt {
 	pub id:   String,
 	pub sync: bool,
-	pub args: Vec<Data>,
+	pub args: HashMap<DataKey, Data>,
 	pub cb:   Option<OptCallback>,
 }
 
@@ -21,9 +23,13 @@ impl TryFrom<Cmd> for Opt {
 		};
 
 		let args = if let Some(s) = c.str("args") {
-			shell_words::split(s)?.into_iter().map(Data::String).collect()
+			shell_words::split(s)?
+				.into_iter()
+				.enumerate()
+				.map(|(i, s)| (DataKey::Integer(i as i64 + 1), Data::String(s)))
+				.collect()
 		} else {
-			c.take_any::<Vec<Data>>("args").unwrap_or_default()
+			c.take_any::<HashMap<DataKey, Data>>("args").unwrap_or_default()
 		};
 
 		Ok(Self { id, sync: c.bool("sync"), args, cb: c.take_any("callback") })
@@ -0,0 +1,145 @@
+use std::{borrow::Cow, collections::{HashMap, HashSet}, fmt::Display, fs, path::PathBuf};
+
+use mlua::{ExternalError, FromLua, Lua, LuaSerdeExt, Value};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use yazi_boot::BOOT;
+use yazi_config::popup::InputCfg;
+use yazi_proxy::InputProxy;
+use yazi_shared::RoCell;
+
+use crate::{loader::LOADER, RtRef};
+
+pub static PERMS: RoCell<Perms> = RoCell::new();
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Perm {
+	FsWrite,
+	Network,
+	Spawn,
+}
+
+impl Perm {
+	#[inline]
+	fn desc(self) -> &'static str {
+		match self {
+			Self::FsWrite => "write files",
+			Self::Network => "access the network",
+			Self::Spawn => "spawn processes",
+		}
+	}
+}
+
+impl FromLua<'_> for Perm {
+	fn from_lua(value: Value, lua: &Lua) -> mlua::Result<Self> { lua.from_value(value) }
+}
+
+impl Display for Perm {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::FsWrite => "fs-write",
+			Self::Network => "network",
+			Self::Spawn => "spawn",
+		})
+	}
+}
+
+#[derive(Default, Deserialize)]
+struct Manifest {
+	#[serde(default)]
+	permissions: HashSet<Perm>,
+}
+
+#[derive(Default)]
+pub struct Perms {
+	declared: Mutex<HashMap<String, Option<HashSet<Perm>>>>,
+	granted:  Mutex<Option<HashMap<String, HashSet<Perm>>>>,
+}
+
+impl Perms {
+	// Plugins have to declare the permissions they need in `manifest.toml`,
+	// and the user is asked to grant each of them the first time it's used.
+	pub async fn check(&self, lua: &Lua, perm: Perm) -> mlua::Result<()> {
+		match self.pending(lua, perm)? {
+			Some(id) => self.ask(&id, perm).await,
+			None => Ok(()),
+		}
+	}
+
+	pub(crate) async fn ask(&self, id: &str, perm: Perm) -> mlua::Result<()> {
+		let mut rx = InputProxy::show(InputCfg::permit(id, perm.desc()));
+		if matches!(rx.recv().await, Some(Ok(s)) if s == "y" || s == "Y") {
+			self.grant(id, perm);
+			Ok(())
+		} else {
+			Err(format!("`{perm}` permission denied for plugin `{id}`").into_lua_err())
+		}
+	}
+
+	// Returns the plugin that hasn't been granted the permission yet.
+	pub(crate) fn pending(&self, lua: &Lua, perm: Perm) -> mlua::Result<Option<String>> {
+		let Some(id) = lua.named_registry_value::<RtRef>("rt")?.current().map(ToOwned::to_owned) else {
+			return Ok(None);
+		};
+
+		// Preset plugins and the user's `init.lua` are trusted
+		if matches!(LOADER.read().get(&id), Some(Cow::Borrowed(_)) | None) {
+			return Ok(None);
+		}
+
+		// Plugins without a manifest declare nothing, but as they predate the permissions,
+		// they're asked for each of them once instead of being refused
+		if self.declared(&id).is_some_and(|d| !d.contains(&perm)) {
+			return Err(
+				format!("plugin `{id}` didn't declare the `{perm}` permission in its manifest")
+					.into_lua_err(),
+			);
+		}
+
+		let mut granted = self.granted.lock();
+		let granted = granted.get_or_insert_with(Self::load);
+		Ok(if granted.get(&id).is_some_and(|s| s.contains(&perm)) { None } else { Some(id) })
+	}
+
+	// The manifest is read again the next time, as the plugin may have changed what it declares
+	pub fn forget(&self, id: &str) { self.declared.lock().remove(id); }
+
+	fn declared(&self, id: &str) -> Option<HashSet<Perm>> {
+		self
+			.declared
+			.lock()
+			.entry(id.to_owned())
+			.or_insert_with(|| {
+				let s = fs::read_to_string(BOOT.plugin_dir.join(format!("{id}.yazi/manifest.toml"))).ok()?;
+				Some(toml::from_str::<Manifest>(&s).unwrap_or_default().permissions)
+			})
+			.clone()
+	}
+
+	fn grant(&self, id: &str, perm: Perm) {
+		let mut granted = self.granted.lock();
+		let granted = granted.get_or_insert_with(Self::load);
+		granted.entry(id.to_owned()).or_default().insert(perm);
+
+		if let Ok(s) = serde_json::to_string(granted) {
+			let tmp = Self::path().with_extension("json.tmp");
+			fs::write(&tmp, s).and_then(|_| fs::rename(tmp, Self::path())).ok();
+		}
+	}
+
+	fn load() -> HashMap<String, HashSet<Perm>> {
+		fs::read(Self::path()).ok().and_then(|b| serde_json::from_slice(&b).ok()).unwrap_or_default()
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("permissions.json") }
+}
+
+pub(super) fn install(lua: &Lua) -> mlua::Result<()> {
+	let pending = lua.create_function(|lua, perm: Perm| Ok(PERMS.pending(lua, perm)?.is_some()))?;
+	let ask =
+		lua.create_async_function(|lua, perm: Perm| async move { PERMS.check(lua, perm).await })?;
+
+	lua.load(include_str!("../preset/sandbox.lua")).set_name("sandbox.lua").call((pending, ask))
+}
@@ -4,6 +4,7 @@ use mlua::{AnyUserData, ExternalError, IntoLuaMulti, Lua, Table, UserData, Value
 use tokio::process::{ChildStderr, ChildStdin, ChildStdout};
 
 use super::{output::Output, Child};
+use crate::{Perm, PERMS};
 
 pub struct Command {
 	inner: tokio::process::Command,
@@ -102,11 +103,15 @@ impl UserData for Command {
 			ud.borrow_mut::<Self>()?.inner.stderr(make_stdio(stdio)?);
 			Ok(ud)
 		});
-		methods.add_method_mut("spawn", |lua, me, ()| match me.inner.spawn() {
-			Ok(child) => (Child::new(child), Value::Nil).into_lua_multi(lua),
-			Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
+		methods.add_async_method_mut("spawn", |lua, me, ()| async move {
+			PERMS.check(lua, Perm::Spawn).await?;
+			match me.inner.spawn() {
+				Ok(child) => (Child::new(child), Value::Nil).into_lua_multi(lua),
+				Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
+			}
 		});
 		methods.add_async_method_mut("output", |lua, me, ()| async move {
+			PERMS.check(lua, Perm::Spawn).await?;
 			match me.inner.output().await {
 				Ok(output) => (Output::new(output), Value::Nil).into_lua_multi(lua),
 				Err(e) => (Value::Nil, e.raw_os_error()).into_lua_multi(lua),
@@ -31,6 +31,8 @@ impl Runtime {
 
 	pub fn current(&self) -> Option<&str> { self.frames.back().map(|f| f.id.as_str()) }
 
+	pub fn clear(&mut self, id: &str) { self.blocks.remove(id); }
+
 	pub fn next_block(&mut self) -> Option<usize> {
 		self.frames.back_mut().map(|f| {
 			f.calls += 1;
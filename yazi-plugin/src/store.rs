@@ -0,0 +1,66 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::{bail, Result};
+use parking_lot::Mutex;
+use serde_json::{Map, Value};
+use yazi_boot::BOOT;
+use yazi_shared::RoCell;
+
+pub static STORE: RoCell<Store> = RoCell::new();
+
+// Upper limit of the serialized size of each plugin's store.
+const QUOTA: usize = 1 << 20;
+
+#[derive(Default)]
+pub struct Store {
+	inner: Mutex<HashMap<String, Map<String, Value>>>,
+}
+
+impl Store {
+	pub fn get(&self, ns: &str, key: &str) -> Option<Value> {
+		let mut inner = self.inner.lock();
+		Self::load(&mut inner, ns).get(key).cloned()
+	}
+
+	pub fn set(&self, ns: &str, key: &str, value: Option<Value>) -> Result<()> {
+		let mut inner = self.inner.lock();
+		let map = Self::load(&mut inner, ns);
+
+		let old = match value {
+			Some(v) => map.insert(key.to_owned(), v),
+			None => map.remove(key),
+		};
+
+		let s = serde_json::to_string(map)?;
+		if s.len() > QUOTA {
+			match old {
+				Some(v) => map.insert(key.to_owned(), v),
+				None => map.remove(key),
+			};
+			bail!("store of `{ns}` exceeds the quota of {QUOTA} bytes");
+		}
+
+		fs::create_dir_all(Self::dir())?;
+		let tmp = Self::path(ns).with_extension("json.tmp");
+		fs::write(&tmp, s)?;
+		Ok(fs::rename(tmp, Self::path(ns))?)
+	}
+
+	fn load<'a>(
+		inner: &'a mut HashMap<String, Map<String, Value>>,
+		ns: &str,
+	) -> &'a mut Map<String, Value> {
+		inner.entry(ns.to_owned()).or_insert_with(|| {
+			fs::read(Self::path(ns))
+				.ok()
+				.and_then(|b| serde_json::from_slice(&b).ok())
+				.unwrap_or_default()
+		})
+	}
+
+	#[inline]
+	fn dir() -> PathBuf { BOOT.state_dir.join("store") }
+
+	#[inline]
+	fn path(ns: &str) -> PathBuf { Self::dir().join(format!("{ns}.json")) }
+}
@@ -0,0 +1,188 @@
+use std::{collections::{BTreeMap, HashSet}, fmt::Write};
+
+use anyhow::Result;
+use mlua::{Lua, Table, Value};
+
+use crate::{isolate::slim_lua, LUA};
+
+// Signatures of the functions that can't be inferred from the bindings themselves,
+// the remaining ones are emitted as `fun(...): any`.
+const SIGNATURES: &[(&str, &str)] = &[
+	("Url", "fun(url: string): Url"),
+	("ya.bind", "fun(opts: { on: string|string[], run: string|string[], desc?: string, layer?: string })"),
+	("ya.clipboard_get", "fun(mime?: string): string|nil"),
+	("ya.clipboard_set", "fun(data: string, mime?: string): boolean"),
+	("ya.command", "fun(name: string, opts?: { sync?: boolean, desc?: string })"),
+	("ya.confirm", "fun(opts: { title: string, choices: { on?: string, desc: string }[], position?: table }): integer|nil"),
+	("ya.dbg", "fun(...: any)"),
+	("ya.err", "fun(...: any)"),
+	("ya.file_cache", "fun(opts: { file: File, skip: integer }): Url|nil"),
+	("ya.hide", "fun(): Permit"),
+	("ya.http", "fun(opts: { url: string, method?: string, headers?: table<string, string>, body?: string, timeout?: number, proxy?: string, output?: Url }): ({ status: integer, headers: table<string, string>, body?: string }|nil), string|nil"),
+	("ya.manager_emit", "fun(name: string, args: table)"),
+	("ya.notify", "fun(opts: { title: string, content: string, timeout: number, level?: \"info\"|\"warn\"|\"error\", actions?: { desc: string, run: string }[] })"),
+	("ya.pick", "fun(opts: { title: string, items: string[], position: table, multi?: boolean, preview?: fun(idx: integer): string }): integer|integer[]|nil"),
+	("ya.preview_widgets", "fun(opts: table, widgets: table[])"),
+	("ya.quote", "fun(s: string): string"),
+	("ya.sleep", "fun(secs: number)"),
+	("ya.sync", "fun(f: fun(state: table, ...: any): any): fun(...: any): any"),
+	("ya.target_family", "fun(): \"unix\"|\"windows\"|\"wasm\""),
+	("ya.target_os", "fun(): string"),
+	("ya.task", "fun(name: string, f: fun(task: Task): any): any"),
+	("ya.time", "fun(): number"),
+	("ya.truncate", "fun(s: string, max: integer): string"),
+	("ya.which", "fun(opts: { cands: { on: string|string[], desc?: string }[], silent?: boolean }): integer|nil"),
+	("ps.pub", "fun(kind: string, value: any)"),
+	("ps.pub_to", "fun(receiver: integer, kind: string, value: any)"),
+	("ps.sub", "fun(kind: string, callback: fun(body: any))"),
+	("ps.sub_remote", "fun(kind: string, callback: fun(body: any))"),
+	("ps.unsub", "fun(kind: string)"),
+	("ps.unsub_remote", "fun(kind: string)"),
+];
+
+// `cx` only exists while rendering or running a sync plugin,
+// so its shape has to be declared here instead of being walked.
+const CX: &str = r#"
+---@class cx
+---@field active cx.tab
+---@field tabs { idx: integer, [integer]: cx.tab }
+---@field tasks { progress: { total: integer, succ: integer, fail: integer, found: integer, processed: integer } }
+---@field yanked { is_cut: boolean, [integer]: Url }
+---@field layers string[]
+cx = {}
+
+---@class cx.tab
+---@field mode { is_select: boolean, is_unset: boolean, is_visual: boolean }
+---@field conf { sort_by: string, sort_sensitive: boolean, sort_reverse: boolean, sort_dir_first: boolean, sort_translit: boolean, linemode: string, show_hidden: boolean, show_ignored: boolean }
+---@field current cx.folder
+---@field parent cx.folder|nil
+---@field selected Url[]|{ summary: fun(self): { count: integer, dirs: integer, size: integer, pending: integer } }
+---@field preview { skip: integer, folder: cx.folder|nil }
+---@field name fun(self: cx.tab): string
+
+---@class cx.folder
+---@field cwd Url
+---@field files cx.file[]
+---@field window cx.file[]
+---@field offset integer
+---@field cursor integer
+---@field hovered cx.file|nil
+---@field repo fun(self: cx.folder): { provider: "git"|"jj"|"hg"|"svn", branch: string|nil, ahead: integer, behind: integer }|nil
+---@field space fun(self: cx.folder): { free: integer, total: integer, fstype: string|nil }|nil
+
+---@class cx.file: File
+---@field idx integer
+---@field is_hovered boolean
+---@field size fun(self: cx.file): integer|nil
+---@field mime fun(self: cx.file): string|nil
+---@field tags fun(self: cx.file): ("red"|"orange"|"yellow"|"green"|"blue"|"purple"|"gray")[]|nil
+---@field security fun(self: cx.file): { context: string|nil, caps: string|nil }|nil
+---@field commit fun(self: cx.file): { hash: string, author: string, time: integer, subject: string }|nil
+---@field vcs fun(self: cx.file): "modified"|"added"|"deleted"|"renamed"|"untracked"|"ignored"|"conflicted"|nil
+---@field prefix fun(self: cx.file): string|nil
+---@field icon fun(self: cx.file): { text: string, style: table }|nil
+---@field style fun(self: cx.file): table|nil
+---@field is_yanked fun(self: cx.file): integer
+---@field is_selected fun(self: cx.file): boolean
+---@field bookmark fun(self: cx.file): string|nil
+---@field found fun(self: cx.file): integer[]|nil
+---@field highlights fun(self: cx.file): integer[][]|nil
+"#;
+
+pub struct Types {
+	out:  String,
+	seen: HashSet<usize>,
+}
+
+impl Types {
+	// Walks the globals of both the main and the isolated runtimes,
+	// and emits the ones that aren't part of the Lua standard library.
+	pub fn emit() -> Result<String> {
+		let mut me = Self { out: "---@meta\n".to_owned(), seen: Default::default() };
+
+		let builtin: HashSet<String> =
+			Lua::new().globals().pairs::<String, Value>().filter_map(|r| r.ok().map(|(k, _)| k)).collect();
+
+		let isolate = slim_lua("types")?;
+		let mut globals = BTreeMap::new();
+		for lua in [&*LUA, &isolate] {
+			for pair in lua.globals().pairs::<String, Value>() {
+				let (k, v) = pair?;
+				if !builtin.contains(&k) && k.chars().next().is_some_and(|c| c.is_ascii_alphabetic()) {
+					globals.entry(k).or_insert(v);
+				}
+			}
+		}
+
+		for (name, value) in globals {
+			me.global(&name, value)?;
+		}
+
+		me.out.push_str(CX);
+		Ok(me.out)
+	}
+
+	fn global(&mut self, name: &str, value: Value) -> Result<()> {
+		match value {
+			Value::Table(t) => {
+				self.class(name, t)?;
+				writeln!(self.out, "{name} = {{}}")?;
+			}
+			v => {
+				writeln!(self.out, "\n---@type {}", Self::ty(name, &v))?;
+				writeln!(self.out, "{name} = nil")?;
+			}
+		}
+		Ok(())
+	}
+
+	fn class(&mut self, name: &str, t: Table) -> Result<()> {
+		self.seen.insert(t.to_pointer() as usize);
+
+		let mut fields = BTreeMap::new();
+		for pair in t.pairs::<Value, Value>() {
+			if let (Value::String(k), v) = pair? {
+				fields.insert(k.to_str()?.to_owned(), v);
+			}
+		}
+
+		let mut nested = vec![];
+		writeln!(self.out, "\n---@class {name}")?;
+		for (k, v) in fields {
+			let path = format!("{name}.{k}");
+			if let Value::Table(t) = &v {
+				if !self.seen.contains(&(t.to_pointer() as usize)) {
+					writeln!(self.out, "---@field {k} {path}")?;
+					nested.push((path, t.clone()));
+					continue;
+				}
+			}
+			writeln!(self.out, "---@field {k} {}", Self::ty(&path, &v))?;
+		}
+
+		for (path, t) in nested {
+			self.class(&path, t)?;
+		}
+		Ok(())
+	}
+
+	fn ty(path: &str, value: &Value) -> String {
+		match value {
+			Value::Nil => "nil".to_owned(),
+			Value::Boolean(_) => "boolean".to_owned(),
+			Value::Integer(_) => "integer".to_owned(),
+			Value::Number(_) => "number".to_owned(),
+			Value::String(_) => "string".to_owned(),
+			Value::Table(_) => "table".to_owned(),
+			Value::Function(_) => SIGNATURES
+				.iter()
+				.find(|&&(p, _)| p == path)
+				.map_or_else(|| "fun(...: any): any".to_owned(), |&(_, s)| s.to_owned()),
+			Value::UserData(ud) => ud
+				.get_metatable()
+				.and_then(|m| m.get::<String>("__name"))
+				.unwrap_or_else(|_| "userdata".to_owned()),
+			_ => "any".to_owned(),
+		}
+	}
+}
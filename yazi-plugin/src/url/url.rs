@@ -12,7 +12,10 @@ impl Url {
 			reg.add_method("frag", |lua, me, ()| lua.create_string(me.frag()));
 			reg.add_field_method_get("is_regular", |_, me| Ok(me.is_regular()));
 			reg.add_field_method_get("is_search", |_, me| Ok(me.is_search()));
+			reg.add_field_method_get("is_recent", |_, me| Ok(me.is_recent()));
 			reg.add_field_method_get("is_archive", |_, me| Ok(me.is_archive()));
+			reg.add_field_method_get("is_remote", |_, me| Ok(me.is_remote()));
+			reg.add_method("domain", |lua, me, ()| lua.create_string(me.domain()));
 
 			reg.add_method("name", |lua, me, ()| {
 				me.file_name().map(|s| lua.create_string(s.as_encoded_bytes())).transpose()
@@ -11,7 +11,7 @@ impl Utils {
 			"file_cache",
 			lua.create_function(|lua, t: Table| {
 				let file: FileRef = t.raw_get("file")?;
-				if file.url.parent() == Some(&PREVIEW.cache_dir) {
+				if file.url.parent() == Some(&PREVIEW.load().cache_dir) {
 					return Ok(None);
 				}
 
@@ -21,7 +21,7 @@ impl Utils {
 					format!("{:x}", digest.finalize())
 				};
 
-				Some(Url::cast(lua, PREVIEW.cache_dir.join(hex))).transpose()
+				Some(Url::cast(lua, PREVIEW.load().cache_dir.join(hex))).transpose()
 			})?,
 		)?;
 
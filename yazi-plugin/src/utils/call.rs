@@ -1,13 +1,13 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::{atomic::Ordering, Arc}};
 
 use mlua::{ExternalError, Lua, Table, TableExt, Value};
 use tracing::error;
 use yazi_config::LAYOUT;
 use yazi_dds::Sendable;
-use yazi_shared::{emit, event::{Cmd, Data}, render, Layer};
+use yazi_shared::{emit, event::{Cmd, Data, Region}, render, Layer};
 
 use super::Utils;
-use crate::elements::RectRef;
+use crate::{elements::{RectRef, DRAWING, DRAWN}, Perm, PERMS};
 
 impl Utils {
 	fn parse_args(t: Table) -> mlua::Result<HashMap<String, Data>> {
@@ -58,6 +58,15 @@ impl Utils {
 					_ => {}
 				}
 
+				if let Some(r) = Region::from_id(id) {
+					if let Ok(area) = c.raw_get::<_, RectRef>("_area") {
+						DRAWN.lock()[r as usize] = *area;
+					}
+					if DRAWING.load(Ordering::Relaxed) & 1 << r as u8 == 0 {
+						return lua.create_table();
+					}
+				}
+
 				match c.call_method::<_, Table>("render", ()) {
 					Err(e) => {
 						error!("Failed to `render()` the `{id}` component:\n{e}");
@@ -78,8 +87,27 @@ impl Utils {
 
 		ya.raw_set(
 			"manager_emit",
-			lua.create_function(|_, (name, args): (String, Table)| {
-				emit!(Call(Cmd { name, args: Self::parse_args(args)? }, Layer::Manager));
+			lua.create_function(|lua, (name, args): (String, Table)| {
+				let cmd = Cmd { name, args: Self::parse_args(args)? };
+
+				// These do what the `fs` and `Command` APIs would ask for the permissions to do
+				let perm = match cmd.name.as_str() {
+					"shell" => Some(Perm::Spawn),
+					"remove" | "remove_do" | "rename" => Some(Perm::FsWrite),
+					_ => None,
+				};
+				if let Some(perm) = perm {
+					if let Some(id) = PERMS.pending(lua, perm)? {
+						tokio::spawn(async move {
+							if PERMS.ask(&id, perm).await.is_ok() {
+								emit!(Call(cmd, Layer::Manager));
+							}
+						});
+						return Ok(());
+					}
+				}
+
+				emit!(Call(cmd, Layer::Manager));
 				Ok(())
 			})?,
 		)?;
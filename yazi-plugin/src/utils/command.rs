@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use mlua::{ExternalError, ExternalResult, Lua, Table, Value};
+use yazi_config::{keymap::Control, KEYMAP};
+use yazi_shared::{event::Cmd, Layer};
+
+use super::Utils;
+use crate::{Command, RtRef, COMMANDS};
+
+impl Utils {
+	fn parse_run(value: Value) -> mlua::Result<Vec<Cmd>> {
+		Ok(match value {
+			Value::String(s) => vec![Cmd::from_str(s.to_str()?).into_lua_err()?],
+			Value::Table(t) => {
+				let mut v = Vec::with_capacity(t.raw_len());
+				for s in t.sequence_values::<mlua::String>() {
+					v.push(Cmd::from_str(s?.to_str()?).into_lua_err()?);
+				}
+				v
+			}
+			_ => Err("invalid `run`".into_lua_err())?,
+		})
+	}
+
+	pub(super) fn command(lua: &Lua, ya: &Table) -> mlua::Result<()> {
+		ya.raw_set(
+			"command",
+			lua.create_function(|lua, (name, t): (mlua::String, Option<Table>)| {
+				let name = name.to_str()?;
+				if name.is_empty() || name.contains(char::is_whitespace) {
+					return Err(format!("invalid command name `{name}`").into_lua_err());
+				}
+
+				let rt = lua.named_registry_value::<RtRef>("rt")?;
+				let Some(plugin) = rt.current().map(ToOwned::to_owned) else {
+					return Err("`ya.command()` must be called within a plugin".into_lua_err());
+				};
+
+				COMMANDS.register(name.to_owned(), Command {
+					plugin,
+					sync: t.as_ref().and_then(|t| t.raw_get("sync").ok()).unwrap_or_default(),
+					desc: t.as_ref().and_then(|t| t.raw_get("desc").ok()),
+				});
+				Ok(())
+			})?,
+		)?;
+
+		ya.raw_set(
+			"bind",
+			lua.create_function(|_, t: Table| {
+				let layer = match t.raw_get::<_, Option<mlua::String>>("layer")? {
+					Some(s) => Layer::from_str(s.to_str()?).into_lua_err()?,
+					None => Layer::Manager,
+				};
+				if matches!(layer, Layer::App | Layer::Which) {
+					return Err(format!("cannot bind keys to the `{layer}` layer").into_lua_err());
+				}
+
+				let run = Self::parse_run(t.raw_get("run")?)?;
+				if run.is_empty() {
+					return Err("`run` cannot be empty".into_lua_err());
+				}
+
+				let desc = t
+					.raw_get::<_, Option<String>>("desc")?
+					.or_else(|| COMMANDS.get(&run[0].name).and_then(|c| c.desc));
+
+				KEYMAP.load().register(layer, Control {
+					on: Self::parse_keys(t.raw_get("on")?)?,
+					run,
+					desc,
+					..Default::default()
+				});
+				Ok(())
+			})?,
+		)?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,102 @@
+use std::{sync::OnceLock, time::Duration};
+
+use anyhow::{bail, Context, Result};
+use futures::StreamExt;
+use mlua::{ExternalResult, IntoLuaMulti, Lua, Table, Value};
+use reqwest::{Client, Method, Proxy, Response};
+use tokio::{fs::File, io::AsyncWriteExt};
+use yazi_shared::fs::Url;
+
+use super::Utils;
+use crate::{url::UrlRef, Perm, PERMS};
+
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+impl Utils {
+	pub(super) fn http(lua: &Lua, ya: &Table) -> mlua::Result<()> {
+		ya.raw_set(
+			"http",
+			lua.create_async_function(|lua, t: Table| async move {
+				PERMS.check(lua, Perm::Network).await?;
+				let output = t.raw_get::<_, Option<UrlRef>>("output")?.map(|u| (*u).clone());
+				let resp = match Self::http_send(&t).await.into_lua_err()? {
+					Ok(resp) => resp,
+					Err(e) => return (Value::Nil, e.to_string()).into_lua_multi(lua),
+				};
+
+				let headers = lua.create_table_with_capacity(0, resp.headers().len())?;
+				for (k, v) in resp.headers() {
+					headers.raw_set(k.as_str(), lua.create_string(v.as_bytes())?)?;
+				}
+
+				let status = resp.status().as_u16();
+				let body = match output {
+					Some(url) => Self::http_save(resp, &url).await.map(|_| Value::Nil),
+					None => match resp.bytes().await {
+						Ok(b) => Ok(Value::String(lua.create_string(&b)?)),
+						Err(e) => Err(e.into()),
+					},
+				};
+
+				match body {
+					Ok(body) => (
+						lua.create_table_from([
+							("status", Value::Integer(status as i64)),
+							("headers", Value::Table(headers)),
+							("body", body),
+						])?,
+						Value::Nil,
+					)
+						.into_lua_multi(lua),
+					Err(e) => (Value::Nil, e.to_string()).into_lua_multi(lua),
+				}
+			})?,
+		)?;
+
+		Ok(())
+	}
+
+	// The outer `Result` is for invalid arguments, which are raised as Lua errors,
+	// and the inner one is for failed requests, which are returned to the caller.
+	async fn http_send(t: &Table<'_>) -> Result<Result<Response>> {
+		let url: String = t.raw_get("url")?;
+		let method = match t.raw_get::<_, Option<mlua::String>>("method")? {
+			Some(s) => Method::from_bytes(s.to_str()?.to_ascii_uppercase().as_bytes())?,
+			None => Method::GET,
+		};
+
+		let client = match t.raw_get::<_, Option<String>>("proxy")? {
+			Some(p) => Client::builder().proxy(Proxy::all(p)?).build()?,
+			None => CLIENT.get_or_init(Client::new).clone(),
+		};
+
+		let mut req = client.request(method, url);
+		if let Some(headers) = t.raw_get::<_, Option<Table>>("headers")? {
+			for pair in headers.pairs::<String, mlua::String>() {
+				let (k, v) = pair?;
+				req = req.header(k, v.as_bytes());
+			}
+		}
+		if let Some(body) = t.raw_get::<_, Option<mlua::String>>("body")? {
+			req = req.body(body.as_bytes().to_vec());
+		}
+		if let Some(secs) = t.raw_get::<_, Option<f64>>("timeout")? {
+			if secs <= 0.0 {
+				bail!("non-positive timeout");
+			}
+			req = req.timeout(Duration::from_secs_f64(secs));
+		}
+
+		Ok(req.send().await.map_err(Into::into))
+	}
+
+	async fn http_save(resp: Response, url: &Url) -> Result<()> {
+		let mut file = File::create(url).await.with_context(|| format!("cannot create {url:?}"))?;
+
+		let mut stream = resp.bytes_stream();
+		while let Some(chunk) = stream.next().await {
+			file.write_all(&chunk?).await?;
+		}
+		Ok(file.flush().await?)
+	}
+}
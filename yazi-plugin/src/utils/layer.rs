@@ -1,17 +1,17 @@
 use std::{str::FromStr, time::Duration};
 
-use mlua::{ExternalError, ExternalResult, IntoLuaMulti, Lua, Table, Value};
+use mlua::{ExternalError, ExternalResult, Function, IntoLua, IntoLuaMulti, Lua, Table, Value};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use yazi_config::{keymap::{Control, Key}, popup::InputCfg};
-use yazi_proxy::{AppProxy, InputProxy};
+use yazi_config::{keymap::{Control, Key}, popup::{InputCfg, SelectCfg}};
+use yazi_proxy::{AppProxy, InputProxy, SelectProxy};
 use yazi_shared::{emit, event::Cmd, Debounce, Layer};
 
 use super::Utils;
 use crate::bindings::{InputRx, Position};
 
 impl Utils {
-	fn parse_keys(value: Value) -> mlua::Result<Vec<Key>> {
+	pub(super) fn parse_keys(value: Value) -> mlua::Result<Vec<Key>> {
 		Ok(match value {
 			Value::String(s) => {
 				vec![Key::from_str(s.to_str()?).into_lua_err()?]
@@ -40,6 +40,7 @@ impl Utils {
 						on:   Self::parse_keys(cand.raw_get("on")?)?,
 						run:  vec![Cmd::args("callback", vec![i.to_string()]).with_any("tx", tx.clone())],
 						desc: cand.raw_get("desc").ok(),
+						..Default::default()
 					});
 				}
 
@@ -66,8 +67,10 @@ impl Utils {
 					cursor: None, // TODO
 					position: Position::try_from(t.raw_get::<_, Table>("position")?)?.into(),
 					realtime,
-					completion: false,
+					completion: Default::default(),
 					highlight: false,
+					history: "",
+					obscure: false,
 				}));
 
 				if !realtime {
@@ -86,6 +89,68 @@ impl Utils {
 			})?,
 		)?;
 
+		ya.raw_set(
+			"confirm",
+			lua.create_async_function(|_, t: Table| async move {
+				let mut choices = vec![];
+				for choice in t.raw_get::<_, Table>("choices")?.sequence_values::<Table>() {
+					let choice = choice?;
+					let on = choice.raw_get::<_, Option<String>>("on")?;
+					if on.as_ref().is_some_and(|s| s.chars().count() != 1) {
+						return Err("`on` of a choice must be a single character".into_lua_err());
+					}
+					choices.push((on.and_then(|s| s.chars().next()), choice.raw_get("desc")?));
+				}
+
+				let mut cfg = SelectCfg::confirm(t.raw_get::<_, String>("title")?, choices);
+				if let Ok(p) = t.raw_get::<_, Table>("position") {
+					cfg.position = Position::try_from(p)?.into();
+				}
+				Ok(SelectProxy::show(cfg).await.ok().map(|i| i + 1))
+			})?,
+		)?;
+
+		ya.raw_set(
+			"pick",
+			lua.create_async_function(|lua, t: Table| async move {
+				let multi = t.raw_get("multi").unwrap_or_default();
+				let cfg = SelectCfg {
+					title: t.raw_get("title")?,
+					items: t.raw_get::<_, Table>("items")?.sequence_values().collect::<mlua::Result<_>>()?,
+					multi,
+					position: Position::try_from(t.raw_get::<_, Table>("position")?)?.into(),
+					..Default::default()
+				};
+
+				let preview: Option<Function> = t.raw_get("preview")?;
+				let (tx, mut rx) = mpsc::unbounded_channel();
+				let picking = SelectProxy::pick(cfg, preview.is_some().then_some(tx));
+				tokio::pin!(picking);
+
+				let result = loop {
+					tokio::select! {
+						r = &mut picking => break r,
+						Some(i) = rx.recv() => {
+							let Some(f) = &preview else { continue };
+							let text = match f.call_async::<_, String>(i + 1).await {
+								Ok(s) => s,
+								Err(e) => e.to_string(),
+							};
+							SelectProxy::preview_with(i, text);
+						}
+					}
+				};
+
+				match result {
+					Ok(v) if multi => {
+						lua.create_sequence_from(v.into_iter().map(|i| i + 1))?.into_lua(lua)
+					}
+					Ok(v) => (v[0] + 1).into_lua(lua),
+					Err(_) => Ok(Value::Nil),
+				}
+			})?,
+		)?;
+
 		ya.raw_set(
 			"notify",
 			lua.create_function(|_, t: Table| {
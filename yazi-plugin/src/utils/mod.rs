@@ -3,12 +3,16 @@
 mod app;
 mod cache;
 mod call;
+mod command;
+mod http;
 mod image;
 mod layer;
 mod log;
 mod preview;
+mod store;
 mod sync;
 mod target;
+mod task;
 mod text;
 mod time;
 mod user;
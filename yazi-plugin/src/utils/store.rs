@@ -0,0 +1,65 @@
+use mlua::{ExternalError, ExternalResult, Lua, Table, Value};
+use yazi_dds::Sendable;
+use yazi_shared::event::Data;
+
+use super::Utils;
+use crate::{RtRef, STORE};
+
+impl Utils {
+	fn store_ns(lua: &Lua) -> mlua::Result<String> {
+		let rt = lua.named_registry_value::<RtRef>("rt")?;
+		match rt.current() {
+			Some(id) => Ok(id.to_owned()),
+			None => Err("`ya.store` must be used within a plugin".into_lua_err()),
+		}
+	}
+
+	fn storable(data: &Data) -> bool {
+		match data {
+			Data::Nil | Data::Boolean(_) | Data::Integer(_) | Data::Number(_) | Data::String(_) => true,
+			Data::List(l) => l.iter().all(Self::storable),
+			Data::Dict(d) => d.values().all(Self::storable),
+			Data::Url(_) | Data::Any(_) => false,
+		}
+	}
+
+	pub(super) fn store(lua: &Lua, ya: &Table) -> mlua::Result<()> {
+		ya.raw_set(
+			"store",
+			lua.create_table_from([
+				(
+					"get",
+					lua.create_function(|lua, key: mlua::String| {
+						let Some(value) = STORE.get(&Self::store_ns(lua)?, key.to_str()?) else {
+							return Ok(Value::Nil);
+						};
+						Sendable::data_to_value(lua, serde_json::from_value(value).into_lua_err()?)
+					})?,
+				),
+				(
+					"set",
+					lua.create_function(|lua, (key, value): (mlua::String, Value)| {
+						let data = Sendable::value_to_data(value)?;
+						if !Self::storable(&data) {
+							return Err("cannot store userdata".into_lua_err());
+						}
+
+						let value = match data {
+							Data::Nil => None,
+							data => Some(serde_json::to_value(data).into_lua_err()?),
+						};
+						STORE.set(&Self::store_ns(lua)?, key.to_str()?, value).into_lua_err()
+					})?,
+				),
+				(
+					"delete",
+					lua.create_function(|lua, key: mlua::String| {
+						STORE.set(&Self::store_ns(lua)?, key.to_str()?, None).into_lua_err()
+					})?,
+				),
+			])?,
+		)?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,33 @@
+use mlua::{Function, Lua, MultiValue, Table};
+use tokio::{select, sync::{mpsc, oneshot}};
+use yazi_proxy::{options::{PluginTaskOpt, PluginTaskProg}, TasksProxy};
+
+use super::Utils;
+use crate::bindings::Task;
+
+impl Utils {
+	pub(super) fn task(lua: &Lua, ya: &Table) -> mlua::Result<()> {
+		ya.raw_set(
+			"task",
+			lua.create_async_function(|lua, (name, f): (String, Function)| async move {
+				let (prog_tx, prog_rx) = mpsc::unbounded_channel();
+				let (cancel_tx, cancel_rx) = oneshot::channel();
+				TasksProxy::plugin_task(PluginTaskOpt { name, prog: prog_rx, cancel: cancel_tx });
+
+				let task = lua.create_userdata(Task::new(prog_tx.clone()))?;
+				select! {
+					result = f.call_async::<_, MultiValue>(task) => {
+						match &result {
+							Ok(_) => prog_tx.send(PluginTaskProg::Succ).ok(),
+							Err(e) => prog_tx.send(PluginTaskProg::Fail(e.to_string())).ok(),
+						};
+						result
+					}
+					Ok(()) = cancel_rx => Ok(MultiValue::new()),
+				}
+			})?,
+		)?;
+
+		Ok(())
+	}
+}
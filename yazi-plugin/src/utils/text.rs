@@ -2,7 +2,8 @@ use std::ops::ControlFlow;
 
 use md5::{Digest, Md5};
 use mlua::{Lua, Table};
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use yazi_shared::grapheme_width;
 
 use super::Utils;
 use crate::CLIPBOARD;
@@ -34,9 +35,9 @@ impl Utils {
 				let (max, text) = (t.raw_get("max")?, text.to_string_lossy());
 
 				Ok(if t.raw_get("rtl").unwrap_or(false) {
-					Self::truncate(text.chars().rev(), max).into_iter().rev().collect()
+					Self::truncate(text.graphemes(true).rev(), max).into_iter().rev().collect()
 				} else {
-					Self::truncate(text.chars(), max).into_iter().collect::<String>()
+					Self::truncate(text.graphemes(true), max).into_iter().collect::<String>()
 				})
 			})?,
 		)?;
@@ -53,15 +54,30 @@ impl Utils {
 			})?,
 		)?;
 
+		ya.raw_set(
+			"clipboard_get",
+			lua.create_async_function(|lua, mime: Option<String>| async move {
+				let mime = mime.as_deref().unwrap_or("text/plain");
+				CLIPBOARD.get_as(mime).await.map(|b| lua.create_string(b)).transpose()
+			})?,
+		)?;
+
+		ya.raw_set(
+			"clipboard_set",
+			lua.create_async_function(|_, (data, mime): (mlua::String, Option<String>)| async move {
+				Ok(CLIPBOARD.set_as(data.as_bytes(), mime.as_deref().unwrap_or("text/plain")).await)
+			})?,
+		)?;
+
 		Ok(())
 	}
 
-	fn truncate(mut chars: impl Iterator<Item = char>, max: usize) -> Vec<char> {
+	fn truncate<'a>(mut graphemes: impl Iterator<Item = &'a str>, max: usize) -> Vec<&'a str> {
 		let mut width = 0;
-		let flow = chars.try_fold(Vec::with_capacity(max), |mut v, c| {
-			width += c.width().unwrap_or(0);
+		let flow = graphemes.try_fold(Vec::with_capacity(max), |mut v, g| {
+			width += grapheme_width(g);
 			if width < max {
-				v.push(c);
+				v.push(g);
 				ControlFlow::Continue(v)
 			} else {
 				ControlFlow::Break(v)
@@ -9,12 +9,16 @@ pub fn install(lua: &'static mlua::Lua) -> mlua::Result<()> {
 	Utils::app(lua, &ya)?;
 	Utils::cache(lua, &ya)?;
 	Utils::call(lua, &ya)?;
+	Utils::command(lua, &ya)?;
+	Utils::http(lua, &ya)?;
 	Utils::image(lua, &ya)?;
 	Utils::layer(lua, &ya)?;
 	Utils::log(lua, &ya)?;
 	Utils::preview(lua, &ya)?;
+	Utils::store(lua, &ya)?;
 	Utils::sync(lua, &ya)?;
 	Utils::target(lua, &ya)?;
+	Utils::task(lua, &ya)?;
 	Utils::text(lua, &ya)?;
 	Utils::time(lua, &ya)?;
 	Utils::user(lua, &ya)?;
@@ -28,12 +32,15 @@ pub fn install_isolate(lua: &mlua::Lua) -> mlua::Result<()> {
 	Utils::app(lua, &ya)?;
 	Utils::cache(lua, &ya)?;
 	Utils::call(lua, &ya)?;
+	Utils::http(lua, &ya)?;
 	Utils::image(lua, &ya)?;
 	Utils::layer(lua, &ya)?;
 	Utils::log(lua, &ya)?;
 	Utils::preview(lua, &ya)?;
+	Utils::store(lua, &ya)?;
 	Utils::sync_isolate(lua, &ya)?;
 	Utils::target(lua, &ya)?;
+	Utils::task(lua, &ya)?;
 	Utils::text(lua, &ya)?;
 	Utils::time(lua, &ya)?;
 	Utils::user(lua, &ya)?;
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use tokio::sync::oneshot;
+use yazi_config::LOCALE;
 use yazi_shared::{emit, event::Cmd, Layer};
 
 use crate::options::{NotifyLevel, NotifyOpt};
@@ -25,14 +26,17 @@ impl AppProxy {
 		emit!(Call(Cmd::new("notify").with_any("option", opt), Layer::App));
 	}
 
+	// The title and content are translated as they are, so the formatted ones
+	// have to be translated with `t!` by the callers, before they're filled in.
 	#[inline]
 	pub fn notify_warn(title: &str, content: &str) {
 		emit!(Call(
 			Cmd::new("notify").with_any("option", NotifyOpt {
-				title:   title.to_owned(),
-				content: content.to_owned(),
+				title:   LOCALE.load().get(title).to_owned(),
+				content: LOCALE.load().get(content).to_owned(),
 				level:   NotifyLevel::Warn,
 				timeout: Duration::from_secs(5),
+				actions: vec![],
 			}),
 			Layer::App
 		));
@@ -42,10 +46,11 @@ impl AppProxy {
 	pub fn notify_error(title: &str, content: &str) {
 		emit!(Call(
 			Cmd::new("notify").with_any("option", NotifyOpt {
-				title:   title.to_owned(),
-				content: content.to_owned(),
+				title:   LOCALE.load().get(title).to_owned(),
+				content: LOCALE.load().get(content).to_owned(),
 				level:   NotifyLevel::Error,
 				timeout: Duration::from_secs(10),
+				actions: vec![],
 			}),
 			Layer::App
 		));
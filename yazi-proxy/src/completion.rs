@@ -8,11 +8,13 @@ impl CompletionProxy {
 		emit!(Call(Cmd::new("close"), Layer::Completion));
 	}
 
+	// The candidates are read from the file system unless `cands` are given.
 	#[inline]
-	pub fn trigger(word: &str, ticket: usize) {
-		emit!(Call(
-			Cmd::args("trigger", vec![word.to_owned()]).with("ticket", ticket),
-			Layer::Completion
-		));
+	pub fn trigger(word: &str, ticket: usize, cands: Option<Vec<String>>) {
+		let mut cmd = Cmd::args("trigger", vec![word.to_owned()]).with("ticket", ticket);
+		if let Some(cands) = cands {
+			cmd = cmd.with_any("cands", cands);
+		}
+		emit!(Call(cmd, Layer::Completion));
 	}
 }
@@ -1,4 +1,6 @@
-use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+use std::collections::HashMap;
+
+use yazi_shared::{emit, event::{Cmd, Data, DataKey}, fs::Url, Layer};
 
 use crate::options::OpenDoOpt;
 
@@ -41,6 +43,41 @@ impl ManagerProxy {
 		emit!(Call(Cmd::new("update_task").with_any("url", url.clone()), Layer::Manager));
 	}
 
+	#[inline]
+	pub fn flavor(name: &str) {
+		emit!(Call(Cmd::args("flavor", vec![name.to_owned()]), Layer::Manager));
+	}
+
+	#[inline]
+	pub fn update_mimetype(url: &Url, mime: String) {
+		let mut cmd = Cmd::new("update_mimetype");
+		cmd.args.insert(
+			"updates".to_owned(),
+			Data::Dict(HashMap::from([(DataKey::String(url.to_string()), Data::String(mime))])),
+		);
+		emit!(Call(cmd, Layer::Manager));
+	}
+
+	#[inline]
+	pub fn update_mimetypes(updates: HashMap<String, String>) {
+		let mut cmd = Cmd::new("update_mimetype");
+		cmd.args.insert(
+			"updates".to_owned(),
+			Data::Dict(updates.into_iter().map(|(k, v)| (DataKey::String(k), Data::String(v))).collect()),
+		);
+		emit!(Call(cmd, Layer::Manager));
+	}
+
+	#[inline]
+	pub fn update_space() {
+		emit!(Call(Cmd::new("update_space"), Layer::Manager));
+	}
+
+	#[inline]
+	pub fn update_selected(sizes: HashMap<Url, (bool, u64)>) {
+		emit!(Call(Cmd::new("update_selected").with_any("sizes", sizes), Layer::Manager));
+	}
+
 	#[inline]
 	pub fn update_paged() {
 		emit!(Call(Cmd::new("update_paged"), Layer::Manager));
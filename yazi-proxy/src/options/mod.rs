@@ -2,8 +2,10 @@ mod notify;
 mod open;
 mod process;
 mod search;
+mod task;
 
 pub use notify::*;
 pub use open::*;
 pub use process::*;
 pub use search::*;
+pub use task::*;
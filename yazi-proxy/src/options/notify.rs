@@ -3,13 +3,23 @@ use std::{str::FromStr, time::Duration};
 use anyhow::bail;
 use mlua::{ExternalError, ExternalResult};
 use yazi_config::THEME;
-use yazi_shared::{event::Cmd, theme::Style};
+use yazi_shared::{event::Cmd, theme::Style, Layer};
 
 pub struct NotifyOpt {
 	pub title:   String,
 	pub content: String,
 	pub level:   NotifyLevel,
 	pub timeout: Duration,
+	pub actions: Vec<NotifyAction>,
+}
+
+// An action offered for the notification in the history, `run` is executed on the `layer`,
+// which is always the manager layer for the ones from the plugins.
+#[derive(Clone, Debug)]
+pub struct NotifyAction {
+	pub desc:  String,
+	pub run:   String,
+	pub layer: Layer,
 }
 
 impl TryFrom<Cmd> for NotifyOpt {
@@ -33,11 +43,24 @@ impl<'a> TryFrom<mlua::Table<'a>> for NotifyOpt {
 			Default::default()
 		};
 
+		let mut actions = vec![];
+		if let Ok(seq) = t.raw_get::<_, mlua::Table>("actions") {
+			for a in seq.sequence_values::<mlua::Table>() {
+				let a = a?;
+				actions.push(NotifyAction {
+					desc:  a.raw_get("desc")?,
+					run:   a.raw_get("run")?,
+					layer: Layer::Manager,
+				});
+			}
+		}
+
 		Ok(Self {
 			title: t.raw_get("title")?,
 			content: t.raw_get("content")?,
 			level,
 			timeout: Duration::from_secs_f64(timeout),
+			actions,
 		})
 	}
 }
@@ -52,20 +75,22 @@ pub enum NotifyLevel {
 
 impl NotifyLevel {
 	#[inline]
-	pub fn icon(self) -> &'static str {
+	pub fn icon(self) -> String {
+		let notify = &THEME.load().notify;
 		match self {
-			Self::Info => &THEME.notify.icon_info,
-			Self::Warn => &THEME.notify.icon_warn,
-			Self::Error => &THEME.notify.icon_error,
+			Self::Info => notify.icon_info.clone(),
+			Self::Warn => notify.icon_warn.clone(),
+			Self::Error => notify.icon_error.clone(),
 		}
 	}
 
 	#[inline]
-	pub fn style(self) -> &'static Style {
+	pub fn style(self) -> Style {
+		let notify = &THEME.load().notify;
 		match self {
-			Self::Info => &THEME.notify.title_info,
-			Self::Warn => &THEME.notify.title_warn,
-			Self::Error => &THEME.notify.title_error,
+			Self::Info => notify.title_info,
+			Self::Warn => notify.title_warn,
+			Self::Error => notify.title_error,
 		}
 	}
 }
@@ -2,7 +2,7 @@ use std::{borrow::Cow, ffi::OsString};
 
 use tokio::sync::oneshot;
 use yazi_config::open::Opener;
-use yazi_shared::event::Cmd;
+use yazi_shared::{event::Cmd, fs::Url};
 
 // --- Exec
 pub struct ProcessExecOpt {
@@ -16,3 +16,15 @@ impl TryFrom<Cmd> for ProcessExecOpt {
 
 	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_any("option").ok_or(()) }
 }
+
+// --- Shell each
+pub struct ShellEachOpt {
+	pub run:     String,
+	pub targets: Vec<Url>,
+}
+
+impl TryFrom<Cmd> for ShellEachOpt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_any("option").ok_or(()) }
+}
@@ -0,0 +1,28 @@
+use tokio::sync::{mpsc, oneshot};
+use yazi_shared::event::Cmd;
+
+// --- Plugin
+pub struct PluginTaskOpt {
+	pub name:   String,
+	pub prog:   mpsc::UnboundedReceiver<PluginTaskProg>,
+	pub cancel: oneshot::Sender<()>,
+}
+
+impl TryFrom<Cmd> for PluginTaskOpt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_any("option").ok_or(()) }
+}
+
+#[derive(Debug)]
+pub enum PluginTaskProg {
+	// total
+	Total(u32),
+	// succ
+	Adv(u32),
+	// line
+	Log(String),
+	Succ,
+	// reason
+	Fail(String),
+}
@@ -1,4 +1,6 @@
-use tokio::sync::oneshot;
+use std::future::Future;
+
+use tokio::sync::{mpsc, oneshot};
 use yazi_config::popup::SelectCfg;
 use yazi_shared::{emit, event::Cmd, Layer};
 
@@ -7,8 +9,34 @@ pub struct SelectProxy;
 impl SelectProxy {
 	#[inline]
 	pub async fn show(cfg: SelectCfg) -> anyhow::Result<usize> {
+		Ok(Self::pick(cfg, None).await?[0])
+	}
+
+	// Returns all the items toggled in a multi-select. While it's open, the index of the item
+	// under the cursor is sent to `preview`, which is expected to reply with `preview_with()`.
+	//
+	// It's shown right away rather than on the first poll, so anything `extend()`ed after this
+	// call lands in it, and in the order it was sent.
+	pub fn pick(
+		cfg: SelectCfg,
+		preview: Option<mpsc::UnboundedSender<usize>>,
+	) -> impl Future<Output = anyhow::Result<Vec<usize>>> {
 		let (tx, rx) = oneshot::channel();
-		emit!(Call(Cmd::new("show").with_any("tx", tx).with_any("cfg", cfg), Layer::Select));
-		rx.await?
+		let mut cmd = Cmd::new("show").with_any("tx", tx).with_any("cfg", cfg);
+		if let Some(preview) = preview {
+			cmd = cmd.with_any("preview", preview);
+		}
+		emit!(Call(cmd, Layer::Select));
+		async move { rx.await? }
+	}
+
+	#[inline]
+	pub fn extend(items: Vec<String>) {
+		emit!(Call(Cmd::new("extend").with_any("items", items), Layer::Select));
+	}
+
+	#[inline]
+	pub fn preview_with(idx: usize, text: String) {
+		emit!(Call(Cmd::new("preview").with("idx", idx).with("text", text), Layer::Select));
 	}
 }
@@ -4,7 +4,7 @@ use tokio::sync::oneshot;
 use yazi_config::open::Opener;
 use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
 
-use crate::options::{OpenWithOpt, ProcessExecOpt};
+use crate::options::{OpenWithOpt, PluginTaskOpt, ProcessExecOpt, ShellEachOpt};
 
 pub struct TasksProxy;
 
@@ -26,4 +26,17 @@ impl TasksProxy {
 		));
 		rx.await.ok();
 	}
+
+	#[inline]
+	pub fn shell_each(run: String, targets: Vec<Url>) {
+		emit!(Call(
+			Cmd::new("shell_each").with_any("option", ShellEachOpt { run, targets }),
+			Layer::Tasks
+		));
+	}
+
+	#[inline]
+	pub fn plugin_task(opt: PluginTaskOpt) {
+		emit!(Call(Cmd::new("plugin_task").with_any("option", opt), Layer::Tasks));
+	}
 }
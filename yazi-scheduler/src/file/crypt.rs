@@ -0,0 +1,62 @@
+use std::{io::{self, BufReader}, path::PathBuf, process::Stdio};
+
+use age::{armor::ArmoredReader, secrecy::{ExposeSecret, SecretString}, Decryptor, Encryptor};
+use anyhow::{anyhow, bail, Result};
+use tokio::{io::AsyncWriteExt, process::Command};
+
+use super::FileOpCrypt;
+
+// Built in, with a passphrase only, as the recipients would need a key management of their own.
+// Both the binary and the armored ones can be decrypted.
+pub(super) async fn age(task: FileOpCrypt) -> Result<()> {
+	let FileOpCrypt { from, to, passphrase, encrypt, .. } = task;
+	let (from, to) = (from.to_path_buf(), to.to_path_buf());
+
+	tokio::task::spawn_blocking(move || age_sync(from, to, passphrase, encrypt)).await?
+}
+
+fn age_sync(from: PathBuf, to: PathBuf, passphrase: SecretString, encrypt: bool) -> Result<()> {
+	let mut input = BufReader::new(std::fs::File::open(from)?);
+	let mut output = std::fs::File::create(to)?;
+
+	if encrypt {
+		let mut writer = Encryptor::with_user_passphrase(passphrase).wrap_output(output)?;
+		io::copy(&mut input, &mut writer)?;
+		writer.finish()?;
+		return Ok(());
+	}
+
+	let reader = match Decryptor::new(ArmoredReader::new(input))? {
+		Decryptor::Passphrase(d) => d.decrypt(&passphrase, None)?,
+		_ => bail!("Encrypted to recipients rather than with a passphrase"),
+	};
+	io::copy(&mut BufReader::new(reader), &mut output)?;
+	Ok(())
+}
+
+// Goes through the `gpg` installed, with the passphrase fed from stdin instead of the pinentry,
+// which would fight over the terminal with Yazi.
+pub(super) async fn gpg(task: FileOpCrypt) -> Result<()> {
+	let mut child = Command::new("gpg")
+		.args(["--batch", "--yes", "--pinentry-mode", "loopback", "--passphrase-fd", "0", "-o"])
+		.arg(&task.to)
+		.arg(if task.encrypt { "--symmetric" } else { "--decrypt" })
+		.arg(&task.from)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::null())
+		.stderr(Stdio::piped())
+		.kill_on_drop(true)
+		.spawn()
+		.map_err(|e| anyhow!("Failed to spawn `gpg`: {e}"))?;
+
+	let mut stdin = child.stdin.take().unwrap();
+	stdin.write_all(task.passphrase.expose_secret().as_bytes()).await?;
+	stdin.write_all(b"\n").await?;
+	drop(stdin);
+
+	let output = child.wait_with_output().await?;
+	if !output.status.success() {
+		bail!("`gpg` failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+	}
+	Ok(())
+}
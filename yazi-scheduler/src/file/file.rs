@@ -1,13 +1,15 @@
 use std::{borrow::Cow, collections::VecDeque, fs::Metadata, path::{Path, PathBuf}};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use tokio::{fs, io::{self, ErrorKind::{AlreadyExists, NotFound}}, sync::mpsc};
 use tracing::warn;
-use yazi_config::TASKS;
-use yazi_shared::fs::{calculate_size, copy_with_progress, maybe_exists, ok_or_not_found, path_relative_to, Url};
+use yazi_config::{t, TASKS};
+use yazi_fs::{vfs, Files};
+use yazi_proxy::AppProxy;
+use yazi_shared::fs::{calculate_size, canonicalize, copy_with_progress, is_case_rename, max_common_root, maybe_exists, ok_or_not_found, path_relative_to, rename_case, unique_name, Url};
 
-use super::{FileOp, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash};
-use crate::{TaskOp, TaskProg, LOW, NORMAL};
+use super::{crypt, touch, transform, FileOp, FileOpCrypt, FileOpDelete, FileOpHardlink, FileOpImage, FileOpLink, FileOpLinkMany, FileOpPaste, FileOpTouch, FileOpTransfer, FileOpTrash};
+use crate::{TaskOp, TaskProg, FAILED_LINES, LOW, NORMAL};
 
 pub struct File {
 	macro_: async_priority_channel::Sender<TaskOp, u8>,
@@ -44,7 +46,7 @@ impl File {
 						// Operation not permitted (os error 1)
 						// Attribute not found (os error 93)
 						Err(e)
-							if task.retry < TASKS.bizarre_retry
+							if task.retry < TASKS.load().bizarre_retry
 								&& matches!(e.raw_os_error(), Some(1) | Some(93)) =>
 						{
 							task.retry += 1;
@@ -74,7 +76,7 @@ impl File {
 				};
 
 				let src = if task.relative {
-					path_relative_to(&src, &fs::canonicalize(task.to.parent().unwrap()).await?)
+					path_relative_to(&src, &canonicalize(task.to.parent().unwrap()).await?)
 				} else {
 					src
 				};
@@ -102,7 +104,7 @@ impl File {
 				let meta = task.meta.as_ref().unwrap();
 				let src = if !task.follow {
 					Cow::Borrowed(task.from.as_path())
-				} else if let Ok(p) = fs::canonicalize(&task.from).await {
+				} else if let Ok(p) = canonicalize(&task.from).await {
 					Cow::Owned(p)
 				} else {
 					Cow::Borrowed(task.from.as_path())
@@ -119,8 +121,8 @@ impl File {
 				self.prog.send(TaskProg::Adv(task.id, 1, meta.len()))?;
 			}
 			FileOp::Delete(task) => {
-				if let Err(e) = fs::remove_file(&task.target).await {
-					if e.kind() != NotFound && maybe_exists(&task.target).await {
+				if let Err(e) = vfs::remove_file(&task.target).await {
+					if e.kind() != NotFound && (task.target.is_remote() || maybe_exists(&task.target).await) {
 						self.fail(task.id, format!("Delete task failed: {:?}, {e}", task))?;
 						Err(e)?
 					}
@@ -145,11 +147,47 @@ impl File {
 				.await??;
 				self.prog.send(TaskProg::Adv(task.id, 1, task.length))?;
 			}
+			FileOp::Transfer(task) => {
+				let mut it = vfs::copy_with_progress(&task.from, &task.to);
+				while let Some(res) = it.recv().await {
+					match res {
+						Ok(0) => {
+							if task.cut {
+								vfs::remove_file(&task.from).await.ok();
+							}
+							break;
+						}
+						Ok(n) => self.prog.send(TaskProg::Adv(task.id, 0, n))?,
+						Err(e) => Err(e)?,
+					}
+				}
+				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
+			}
+			FileOp::Crypt(task) => {
+				let (id, to) = (task.id, task.to.clone());
+				let result = if task.gpg { crypt::gpg(task).await } else { crypt::age(task).await };
+
+				// Nothing is left behind for a wrong passphrase, or a file that's not encrypted
+				if let Err(e) = result {
+					fs::remove_file(&to).await.ok();
+					Err(e)?
+				}
+				self.prog.send(TaskProg::Adv(id, 1, 0))?;
+			}
+			FileOp::Image(task) => {
+				let to = transform::apply(&task).await?;
+				self.log(task.id, format!("Saved as {to:?}"))?;
+				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
+			}
 		}
 		Ok(())
 	}
 
 	pub async fn paste(&self, mut task: FileOpPaste) -> Result<()> {
+		if task.cut && is_case_rename(&task.from, &task.to).await {
+			rename_case(&task.from, &task.to).await?;
+			return self.succ(task.id);
+		}
 		if task.cut && ok_or_not_found(fs::rename(&task.from, &task.to).await).is_ok() {
 			return self.succ(task.id);
 		}
@@ -218,6 +256,114 @@ impl File {
 		self.succ(task.id)
 	}
 
+	// Either side of it is remote, so all the files go through the VFS, and the symlinks are skipped
+	pub async fn transfer(&self, mut task: FileOpTransfer) -> Result<()> {
+		if task.cha.is_none() {
+			task.cha = Some(vfs::metadata(&task.from).await?);
+		}
+
+		// A cut within the same location is a rename, rather than copying everything over
+		if task.cut
+			&& task.from.scheme() == task.to.scheme()
+			&& task.from.domain() == task.to.domain()
+			&& vfs::rename(&task.from, &task.to).await.is_ok()
+		{
+			self.prog.send(TaskProg::New(task.id, 0))?;
+			return self.succ(task.id);
+		}
+
+		let cha = task.cha.unwrap();
+		if !cha.is_dir() {
+			let id = task.id;
+			self.prog.send(TaskProg::New(id, cha.len))?;
+			self.queue(FileOp::Transfer(task), LOW).await?;
+			return self.succ(id);
+		}
+
+		macro_rules! continue_unless_ok {
+			($result:expr) => {
+				match $result {
+					Ok(v) => v,
+					Err(e) => {
+						self.prog.send(TaskProg::New(task.id, 0))?;
+						self.fail(task.id, format!("An error occurred while transferring: {e}"))?;
+						continue;
+					}
+				}
+			};
+		}
+
+		let mut dirs = VecDeque::from([(task.from.clone(), task.to.clone())]);
+		while let Some((src, dest)) = dirs.pop_front() {
+			continue_unless_ok!(match vfs::create_dir(&dest).await {
+				Err(e) if !vfs::metadata(&dest).await.is_ok_and(|c| c.is_dir()) => Err(e),
+				_ => Ok(()),
+			});
+
+			for file in continue_unless_ok!(Files::from_dir_bulk(&src).await) {
+				if file.is_link() {
+					continue;
+				}
+
+				let to = dest.join(file.url.file_name().unwrap());
+				if file.is_dir() {
+					dirs.push_back((file.url, to));
+					continue;
+				}
+
+				self.prog.send(TaskProg::New(task.id, file.len))?;
+				self.queue(FileOp::Transfer(task.spawn(file.url, to, file.cha)), LOW).await?;
+			}
+		}
+		self.succ(task.id)
+	}
+
+	pub async fn crypt(&self, task: FileOpCrypt) -> Result<()> {
+		let id = task.id;
+		self.prog.send(TaskProg::New(id, fs::metadata(&task.from).await?.len()))?;
+		self.queue(FileOp::Crypt(task), LOW).await?;
+		self.succ(id)
+	}
+
+	pub async fn image(&self, task: FileOpImage) -> Result<()> {
+		let id = task.id;
+		self.prog.send(TaskProg::New(id, 0))?;
+		self.queue(FileOp::Image(task), LOW).await?;
+		self.succ(id)
+	}
+
+	pub async fn touch(&self, task: FileOpTouch) -> Result<()> {
+		let (id, total) = (task.id, task.targets.len());
+		for _ in 0..total {
+			self.prog.send(TaskProg::New(id, 0))?;
+		}
+
+		let mut failed = vec![];
+		for url in &task.targets {
+			let Err(e) = touch::apply(&task, url.to_path_buf()).await else {
+				self.prog.send(TaskProg::Adv(id, 1, 0))?;
+				continue;
+			};
+
+			let name = url.file_name().unwrap_or(url.as_os_str()).to_string_lossy();
+			self.fail(id, format!("{name}: {e}"))?;
+			failed.push(format!("{name}: {e}"));
+		}
+
+		if failed.is_empty() {
+			return self.succ(id);
+		}
+
+		let title = t!("Failed to touch {n} of {total} files", n = failed.len(), total = total);
+		if failed.len() > FAILED_LINES {
+			let more = failed.len() - FAILED_LINES;
+			failed.truncate(FAILED_LINES);
+			failed.push(t!("... and {more} more", more = more));
+		}
+		AppProxy::notify_warn(&title, &failed.join("\n"));
+		Ok(())
+	}
+
 	pub async fn link(&self, mut task: FileOpLink) -> Result<()> {
 		let id = task.id;
 		if task.meta.is_none() {
@@ -229,6 +375,31 @@ impl File {
 		self.succ(id)
 	}
 
+	pub async fn link_many(&self, task: FileOpLinkMany) -> Result<()> {
+		let FileOpLinkMany { id, targets, dest, relative, force } = task;
+		for _ in 0..targets.len() {
+			self.prog.send(TaskProg::New(id, 0))?;
+		}
+
+		let root = match canonicalize(&dest).await {
+			Ok(p) => p,
+			Err(e) => return self.fail(id, format!("Link many task failed: {dest:?}, {e}")),
+		};
+
+		let mut ok = true;
+		for from in targets {
+			match Self::link_into(&from, &dest, &root, relative, force).await {
+				Ok(()) => self.prog.send(TaskProg::Adv(id, 1, 0))?,
+				Err(e) => {
+					let name = from.file_name().unwrap_or(from.as_os_str()).to_string_lossy();
+					self.fail(id, format!("{name}: {e}"))?;
+					ok = false;
+				}
+			}
+		}
+		if ok { self.succ(id) } else { Ok(()) }
+	}
+
 	pub async fn hardlink(&self, mut task: FileOpHardlink) -> Result<()> {
 		if task.meta.is_none() {
 			task.meta = Some(Self::metadata(&task.from, task.follow).await?);
@@ -285,6 +456,10 @@ impl File {
 	}
 
 	pub async fn delete(&self, mut task: FileOpDelete) -> Result<()> {
+		if task.target.is_remote() {
+			return self.delete_remote(task).await;
+		}
+
 		let meta = fs::symlink_metadata(&task.target).await?;
 		if !meta.is_dir() {
 			let id = task.id;
@@ -315,6 +490,36 @@ impl File {
 		self.succ(task.id)
 	}
 
+	// The directory links are removed as files, instead of walking into them
+	async fn delete_remote(&self, mut task: FileOpDelete) -> Result<()> {
+		let cha = vfs::metadata(&task.target).await?;
+		if !cha.is_dir() || cha.is_link() {
+			let id = task.id;
+			task.length = cha.len;
+			self.prog.send(TaskProg::New(id, cha.len))?;
+			self.queue(FileOp::Delete(task), NORMAL).await?;
+			return self.succ(id);
+		}
+
+		let mut dirs = VecDeque::from([task.target.clone()]);
+		while let Some(target) = dirs.pop_front() {
+			let Ok(files) = Files::from_dir_bulk(&target).await else { continue };
+
+			for file in files {
+				if file.is_dir() && !file.is_link() {
+					dirs.push_front(file.url);
+					continue;
+				}
+
+				task.length = file.len;
+				task.target = file.url;
+				self.prog.send(TaskProg::New(task.id, task.length))?;
+				self.queue(FileOp::Delete(task.clone()), NORMAL).await?;
+			}
+		}
+		self.succ(task.id)
+	}
+
 	pub async fn trash(&self, mut task: FileOpTrash) -> Result<()> {
 		let id = task.id;
 		task.length = calculate_size(&task.target).await;
@@ -324,6 +529,47 @@ impl File {
 		self.succ(id)
 	}
 
+	// Links to the file itself even if it's a symlink, relative to the real parents of both,
+	// as `..` walks up from where the link really is, or absolutely if all they share is the root.
+	async fn link_into(
+		from: &Url,
+		dest: &Url,
+		root: &Path,
+		relative: bool,
+		force: bool,
+	) -> Result<()> {
+		let name = from.file_name().ok_or_else(|| anyhow!("No file name"))?;
+		let to = if force { dest.join(name) } else { unique_name(dest.join(name)).await };
+		if to == *from {
+			bail!("Can't be replaced with a link to itself");
+		}
+
+		let src = match from.parent() {
+			Some(p) if relative => canonicalize(p).await?.join(name),
+			_ => from.to_path_buf(),
+		};
+		let src = if relative && max_common_root(&[&src, &root.join(name)]).parent().is_some() {
+			path_relative_to(&src, root).into_owned()
+		} else {
+			src
+		};
+
+		ok_or_not_found(fs::remove_file(&to).await)?;
+		#[cfg(unix)]
+		{
+			fs::symlink(src, &to).await?;
+		}
+		#[cfg(windows)]
+		{
+			if fs::metadata(from).await.is_ok_and(|m| m.is_dir()) {
+				fs::symlink_dir(src, &to).await?;
+			} else {
+				fs::symlink_file(src, &to).await?;
+			}
+		}
+		Ok(())
+	}
+
 	#[inline]
 	async fn metadata(path: &Path, follow: bool) -> io::Result<Metadata> {
 		if !follow {
@@ -1,7 +1,12 @@
 #![allow(clippy::module_inception)]
 
+mod crypt;
 mod file;
 mod op;
+mod touch;
+mod transform;
 
 pub use file::*;
 pub use op::*;
+pub use touch::*;
+pub use transform::*;
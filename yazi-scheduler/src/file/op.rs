@@ -1,6 +1,9 @@
 use std::fs::Metadata;
 
-use yazi_shared::fs::Url;
+use age::secrecy::SecretString;
+use yazi_shared::fs::{Cha, Url};
+
+use super::{ImageOp, TouchTime};
 
 #[derive(Debug)]
 pub enum FileOp {
@@ -9,6 +12,9 @@ pub enum FileOp {
 	Hardlink(FileOpHardlink),
 	Delete(FileOpDelete),
 	Trash(FileOpTrash),
+	Transfer(FileOpTransfer),
+	Crypt(FileOpCrypt),
+	Image(FileOpImage),
 }
 
 impl FileOp {
@@ -19,6 +25,9 @@ impl FileOp {
 			Self::Hardlink(op) => op.id,
 			Self::Delete(op) => op.id,
 			Self::Trash(op) => op.id,
+			Self::Transfer(op) => op.id,
+			Self::Crypt(op) => op.id,
+			Self::Image(op) => op.id,
 		}
 	}
 }
@@ -75,6 +84,16 @@ impl From<FileOpPaste> for FileOpLink {
 	}
 }
 
+// --- Link many
+#[derive(Debug)]
+pub struct FileOpLinkMany {
+	pub id:       usize,
+	pub targets:  Vec<Url>,
+	pub dest:     Url,
+	pub relative: bool,
+	pub force:    bool,
+}
+
 // --- Hardlink
 #[derive(Clone, Debug)]
 pub struct FileOpHardlink {
@@ -106,3 +125,49 @@ pub struct FileOpTrash {
 	pub target: Url,
 	pub length: u64,
 }
+
+// --- Transfer
+#[derive(Clone, Debug)]
+pub struct FileOpTransfer {
+	pub id:   usize,
+	pub from: Url,
+	pub to:   Url,
+	pub cha:  Option<Cha>,
+	pub cut:  bool,
+}
+
+impl FileOpTransfer {
+	pub(super) fn spawn(&self, from: Url, to: Url, cha: Cha) -> Self {
+		Self { id: self.id, from, to, cha: Some(cha), cut: self.cut }
+	}
+}
+
+// --- Crypt
+#[derive(Debug)]
+pub struct FileOpCrypt {
+	pub id:         usize,
+	pub from:       Url,
+	pub to:         Url,
+	pub passphrase: SecretString,
+	pub encrypt:    bool,
+	pub gpg:        bool,
+}
+
+// --- Image
+#[derive(Debug)]
+pub struct FileOpImage {
+	pub id:      usize,
+	pub from:    Url,
+	pub op:      ImageOp,
+	pub quality: u8,
+}
+
+// --- Touch
+#[derive(Debug)]
+pub struct FileOpTouch {
+	pub id:      usize,
+	pub targets: Vec<Url>,
+	pub time:    TouchTime,
+	pub atime:   bool,
+	pub mtime:   bool,
+}
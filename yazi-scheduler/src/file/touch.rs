@@ -0,0 +1,102 @@
+use std::{fs::FileTimes, io::BufReader, path::{Path, PathBuf}, str::FromStr, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone};
+use exif::{In, Tag, Value};
+
+use super::FileOpTouch;
+
+#[derive(Clone, Copy, Debug)]
+pub enum TouchTime {
+	At(SystemTime),
+	// The capture date in the EXIF of each file
+	Exif,
+}
+
+impl FromStr for TouchTime {
+	type Err = anyhow::Error;
+
+	// `now`, `exif`, `@<seconds since the epoch>`, or a local time as `YYYY-MM-DD[ HH:MM[:SS]]`
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+		match s {
+			"now" => return Ok(Self::At(SystemTime::now())),
+			"exif" => return Ok(Self::Exif),
+			_ => {}
+		}
+
+		if let Some(secs) = s.strip_prefix('@') {
+			return Ok(Self::At(UNIX_EPOCH + Duration::from_secs(secs.parse()?)));
+		}
+
+		let naive = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+			.iter()
+			.find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+			.or_else(|| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))
+			.ok_or_else(|| anyhow!("Expected `now`, `exif`, `@<seconds>` or `YYYY-MM-DD HH:MM:SS`"))?;
+
+		match Local.from_local_datetime(&naive).earliest() {
+			Some(t) => Ok(Self::At(t.into())),
+			None => bail!("`{s}` doesn't exist in the local time zone"),
+		}
+	}
+}
+
+pub(super) async fn apply(task: &FileOpTouch, target: PathBuf) -> Result<()> {
+	let FileOpTouch { time, atime, mtime, .. } = *task;
+
+	tokio::task::spawn_blocking(move || {
+		let time = match time {
+			TouchTime::At(t) => t,
+			TouchTime::Exif => exif_time(&target)?,
+		};
+
+		let mut times = FileTimes::new();
+		if atime {
+			times = times.set_accessed(time);
+		}
+		if mtime {
+			times = times.set_modified(time);
+		}
+
+		// Directories can't be opened for writing on Unix, where any descriptor will do
+		#[cfg(unix)]
+		let file = std::fs::File::open(&target)?;
+		#[cfg(windows)]
+		let file = {
+			use std::os::windows::fs::OpenOptionsExt;
+			// FILE_FLAG_BACKUP_SEMANTICS, to open the directories too
+			std::fs::File::options().write(true).custom_flags(0x02000000).open(&target)?
+		};
+		Ok(file.set_times(times)?)
+	})
+	.await?
+}
+
+// `DateTimeOriginal`, or `DateTime` if the former is missing, in its own offset if it has one
+fn exif_time(path: &Path) -> Result<SystemTime> {
+	let mut reader = BufReader::new(std::fs::File::open(path)?);
+	let exif = exif::Reader::new().read_from_container(&mut reader)?;
+
+	let field = [Tag::DateTimeOriginal, Tag::DateTime]
+		.into_iter()
+		.find_map(|t| exif.get_field(t, In::PRIMARY))
+		.ok_or_else(|| anyhow!("No capture date in the EXIF"))?;
+	let Value::Ascii(ref v) = field.value else { bail!("Invalid capture date in the EXIF") };
+
+	let mut dt = exif::DateTime::from_ascii(v.first().map_or(&[], |b| b.as_slice()))?;
+	let offset = exif.get_field(Tag::OffsetTimeOriginal, In::PRIMARY).map(|f| &f.value);
+	if let Some(b) = offset.and_then(|v| if let Value::Ascii(v) = v { v.first() } else { None }) {
+		dt.parse_offset(b).ok();
+	}
+
+	let naive = NaiveDate::from_ymd_opt(dt.year as _, dt.month as _, dt.day as _)
+		.and_then(|d| d.and_hms_opt(dt.hour as _, dt.minute as _, dt.second as _))
+		.ok_or_else(|| anyhow!("Invalid capture date in the EXIF"))?;
+
+	let time = match dt.offset.and_then(|m| FixedOffset::east_opt(m as i32 * 60)) {
+		Some(tz) => tz.from_local_datetime(&naive).earliest().map(Into::into),
+		None => Local.from_local_datetime(&naive).earliest().map(Into::into),
+	};
+	time.ok_or_else(|| anyhow!("Invalid capture date in the EXIF"))
+}
@@ -0,0 +1,104 @@
+use std::{ffi::OsString, fs::OpenOptions, io::{BufWriter, ErrorKind::AlreadyExists, Write}, path::{Path, PathBuf}};
+
+use anyhow::{anyhow, Result};
+use image::{codecs::{jpeg::JpegEncoder, webp::WebPEncoder}, imageops::FilterType, DynamicImage, ImageFormat};
+use yazi_adapter::Image;
+
+use super::FileOpImage;
+
+#[derive(Clone, Copy, Debug)]
+pub enum ImageOp {
+	// By 90°, clockwise if true
+	Rotate(bool),
+	// Down to fit in a square of the size, with the aspect ratio kept
+	Resize(u32),
+	// Into JPEG, WebP or PNG, of which only JPEG has a quality, as the WebP encoder is lossless
+	Convert(ImageFormat),
+}
+
+impl ImageOp {
+	pub fn convert(ext: &str) -> Option<Self> {
+		match ImageFormat::from_extension(ext)? {
+			f @ (ImageFormat::Jpeg | ImageFormat::WebP | ImageFormat::Png) => Some(Self::Convert(f)),
+			_ => None,
+		}
+	}
+}
+
+// Writes the result next to the original, which is never touched, as `<stem>_<n>.<ext>`
+// if the name is taken, the same way the pasted ones are named.
+pub(super) async fn apply(task: &FileOpImage) -> Result<PathBuf> {
+	let FileOpImage { op, quality, .. } = *task;
+	let from = task.from.to_path_buf();
+
+	let format = match op {
+		ImageOp::Convert(f) => f,
+		_ => ImageFormat::from_path(&from)?,
+	};
+	let img = Image::upright(&from).await?;
+
+	tokio::task::spawn_blocking(move || {
+		let img = match op {
+			ImageOp::Rotate(true) => img.rotate90(),
+			ImageOp::Rotate(false) => img.rotate270(),
+			ImageOp::Resize(max) if img.width() > max || img.height() > max => {
+				img.resize(max, max, FilterType::Lanczos3)
+			}
+			_ => img,
+		};
+
+		let (to, file) = create(&from, format)?;
+		match encode(img, format, quality, file) {
+			Ok(()) => Ok(to),
+			Err(e) => {
+				std::fs::remove_file(&to).ok();
+				Err(e)
+			}
+		}
+	})
+	.await?
+}
+
+// Takes the name atomically, so neither the existing files nor the other tasks are overwritten
+fn create(from: &Path, format: ImageFormat) -> Result<(PathBuf, std::fs::File)> {
+	let stem = from.file_stem().ok_or_else(|| anyhow!("No file name"))?;
+	let ext = match from.extension() {
+		Some(e) if ImageFormat::from_extension(e) == Some(format) => e.to_owned(),
+		_ => format.extensions_str().first().copied().unwrap_or_default().into(),
+	};
+
+	let mut name = OsString::from(stem);
+	name.push(".");
+	name.push(&ext);
+
+	let mut i = 1u64;
+	loop {
+		let to = from.with_file_name(&name);
+		match OpenOptions::new().write(true).create_new(true).open(&to) {
+			Ok(file) => return Ok((to, file)),
+			Err(e) if e.kind() == AlreadyExists => {}
+			Err(e) => Err(e)?,
+		}
+
+		name = OsString::from(stem);
+		name.push(format!("_{i}."));
+		name.push(&ext);
+		i += 1;
+	}
+}
+
+fn encode(img: DynamicImage, format: ImageFormat, quality: u8, file: std::fs::File) -> Result<()> {
+	let mut w = BufWriter::new(file);
+	match format {
+		ImageFormat::Jpeg => {
+			let img = DynamicImage::ImageRgb8(img.into_rgb8());
+			img.write_with_encoder(JpegEncoder::new_with_quality(&mut w, quality))?;
+		}
+		ImageFormat::WebP => {
+			let img = DynamicImage::ImageRgba8(img.into_rgba8());
+			img.write_with_encoder(WebPEncoder::new_lossless(&mut w))?;
+		}
+		_ => img.write_to(&mut w, format)?,
+	}
+	Ok(w.flush()?)
+}
@@ -9,6 +9,7 @@ mod process;
 mod scheduler;
 mod task;
 
+pub use file::{ImageOp, TouchTime};
 pub use ongoing::*;
 pub use op::*;
 pub use scheduler::*;
@@ -17,3 +18,6 @@ pub use task::*;
 const LOW: u8 = yazi_config::Priority::Low as u8;
 const NORMAL: u8 = yazi_config::Priority::Normal as u8;
 const HIGH: u8 = yazi_config::Priority::High as u8;
+
+// The most failures listed in a notification, e.g. of `touch`
+const FAILED_LINES: usize = 20;
@@ -2,8 +2,10 @@ use std::collections::HashMap;
 
 use futures::future::BoxFuture;
 use yazi_config::TASKS;
+use yazi_dds::Pubsub;
+use yazi_shared::fs::Url;
 
-use super::{Task, TaskStage};
+use super::{Task, TaskStage, TaskTarget};
 use crate::TaskKind;
 
 #[derive(Default)]
@@ -21,6 +23,28 @@ impl Ongoing {
 		self.incr
 	}
 
+	pub fn add_op(&mut self, name: String, op: &'static str, from: &Url, to: Option<&Url>) -> usize {
+		let id = self.add(TaskKind::User, name);
+		if let Some(task) = self.all.get_mut(&id) {
+			task.target = Some(TaskTarget { op, from: from.clone(), to: to.cloned() });
+		}
+
+		Pubsub::pub_from_op_start(id, op, from, to);
+		id
+	}
+
+	// The destination of an operation is only settled once `unique_name()` has resolved it
+	pub fn retarget(&mut self, id: usize, to: &Url) {
+		if let Some(target) = self.all.get_mut(&id).and_then(|t| t.target.as_mut()) {
+			target.to = Some(to.clone());
+		}
+	}
+
+	#[inline]
+	pub fn target_to(&self, id: usize) -> Option<Url> {
+		self.all.get(&id).and_then(|t| t.target.as_ref()?.to.clone())
+	}
+
 	#[inline]
 	pub fn get(&self, id: usize) -> Option<&Task> { self.all.get(&id) }
 
@@ -32,7 +56,7 @@ impl Ongoing {
 
 	#[inline]
 	pub fn len(&self) -> usize {
-		if TASKS.suppress_preload {
+		if TASKS.load().suppress_preload {
 			self.all.values().filter(|t| t.kind != TaskKind::Preload).count()
 		} else {
 			self.all.len()
@@ -44,7 +68,7 @@ impl Ongoing {
 
 	#[inline]
 	pub fn values(&self) -> Box<dyn Iterator<Item = &Task> + '_> {
-		if TASKS.suppress_preload {
+		if TASKS.load().suppress_preload {
 			Box::new(self.all.values().filter(|t| t.kind != TaskKind::Preload))
 		} else {
 			Box::new(self.all.values())
@@ -64,6 +88,9 @@ impl Ongoing {
 				TaskStage::Pending => return None,
 				TaskStage::Dispatched => {
 					if task.succ < task.total {
+						if task.succ + task.fail >= task.total {
+							task.report_failed();
+						}
 						return None;
 					}
 					if let Some(hook) = self.hooks.remove(&id) {
@@ -73,7 +100,7 @@ impl Ongoing {
 				TaskStage::Hooked => {}
 			}
 
-			self.all.remove(&id);
+			self.all.remove(&id).map(Task::report_done);
 		}
 		None
 	}
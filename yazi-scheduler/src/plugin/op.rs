@@ -1,4 +1,6 @@
-use yazi_shared::event::Data;
+use std::collections::HashMap;
+
+use yazi_shared::event::{Data, DataKey};
 
 #[derive(Debug)]
 pub enum PluginOp {
@@ -17,5 +19,5 @@ impl PluginOp {
 pub struct PluginOpEntry {
 	pub id:   usize,
 	pub name: String,
-	pub args: Vec<Data>,
+	pub args: HashMap<DataKey, Data>,
 }
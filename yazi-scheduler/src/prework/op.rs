@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
 use yazi_config::plugin::{FetcherProps, PreloaderProps};
 use yazi_shared::{fs::Url, Throttle};
 
@@ -8,6 +10,7 @@ pub enum PreworkOp {
 	Fetch(PreworkOpFetch),
 	Load(PreworkOpLoad),
 	Size(PreworkOpSize),
+	Measure(PreworkOpMeasure),
 }
 
 impl PreworkOp {
@@ -16,6 +19,7 @@ impl PreworkOp {
 			Self::Fetch(op) => op.id,
 			Self::Load(op) => op.id,
 			Self::Size(op) => op.id,
+			Self::Measure(op) => op.id,
 		}
 	}
 }
@@ -25,18 +29,31 @@ pub struct PreworkOpFetch {
 	pub id:      usize,
 	pub plugin:  FetcherProps,
 	pub targets: Vec<yazi_shared::fs::File>,
+	pub token:   CancellationToken,
+	pub visible: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct PreworkOpLoad {
-	pub id:     usize,
-	pub plugin: PreloaderProps,
-	pub target: yazi_shared::fs::File,
+	pub id:      usize,
+	pub plugin:  PreloaderProps,
+	pub target:  yazi_shared::fs::File,
+	pub token:   CancellationToken,
+	pub visible: bool,
 }
 
 #[derive(Debug)]
 pub struct PreworkOpSize {
 	pub id:       usize,
 	pub target:   Url,
-	pub throttle: Arc<Throttle<(Url, u64)>>,
+	pub throttle: Arc<Throttle<(Url, Option<u64>)>>,
+	pub token:    CancellationToken,
+}
+
+#[derive(Debug)]
+pub struct PreworkOpMeasure {
+	pub id:      usize,
+	pub targets: Vec<Url>,
+	pub dest:    Url,
+	pub done:    oneshot::Sender<(u64, Option<u64>)>,
 }
@@ -2,14 +2,16 @@ use std::collections::{HashMap, HashSet};
 
 use anyhow::{anyhow, Result};
 use parking_lot::{Mutex, RwLock};
-use tokio::sync::mpsc;
+use tokio::{fs, select, sync::mpsc};
+use tokio_util::sync::CancellationToken;
 use tracing::error;
-use yazi_config::Priority;
+use yazi_config::{Priority, WATCHER};
+use yazi_fs::DIR_SIZES;
 use yazi_plugin::isolate;
-use yazi_shared::fs::{calculate_size, FilesOp, Url};
+use yazi_shared::fs::{FilesOp, Url};
 
-use super::{PreworkOp, PreworkOpFetch, PreworkOpLoad, PreworkOpSize};
-use crate::{TaskOp, TaskProg, HIGH, NORMAL};
+use super::{PreworkOp, PreworkOpFetch, PreworkOpLoad, PreworkOpMeasure, PreworkOpSize};
+use crate::{TaskOp, TaskProg, HIGH, LOW, NORMAL};
 
 pub struct Prework {
 	macro_: async_priority_channel::Sender<TaskOp, u8>,
@@ -17,6 +19,8 @@ pub struct Prework {
 
 	pub loaded:       Mutex<HashMap<Url, u32>>,
 	pub size_loading: RwLock<HashSet<Url>>,
+	pub measuring:    Mutex<HashSet<Url>>,
+	tokens:           Mutex<HashMap<Url, CancellationToken>>,
 }
 
 impl Prework {
@@ -24,14 +28,45 @@ impl Prework {
 		macro_: async_priority_channel::Sender<TaskOp, u8>,
 		prog: mpsc::UnboundedSender<TaskProg>,
 	) -> Self {
-		Self { macro_, prog, loaded: Default::default(), size_loading: Default::default() }
+		Self {
+			macro_,
+			prog,
+			loaded: Default::default(),
+			size_loading: Default::default(),
+			measuring: Default::default(),
+			tokens: Default::default(),
+		}
+	}
+
+	// Fetchers and preloaders of the same directory share a token,
+	// which gets cancelled once the user navigates away from it.
+	pub fn token(&self, dir: &Url) -> CancellationToken {
+		self.tokens.lock().entry(dir.clone()).or_default().clone()
+	}
+
+	pub fn cancel_except(&self, dir: &Url) {
+		self.tokens.lock().retain(|k, token| {
+			if k == dir {
+				return true;
+			}
+			token.cancel();
+			false
+		});
 	}
 
 	pub async fn work(&self, op: PreworkOp) -> Result<()> {
 		match op {
 			PreworkOp::Fetch(task) => {
 				let urls: Vec<_> = task.targets.iter().map(|f| f.url()).collect();
-				let result = isolate::fetch(&task.plugin.name, task.targets).await;
+				let result = if task.token.is_cancelled() {
+					Ok(0)
+				} else {
+					isolate::fetch(&task.plugin.name, task.targets, task.token.clone(), task.visible).await
+				};
+				if task.token.is_cancelled() {
+					self.unload(&urls, task.plugin.id);
+					return Ok(self.prog.send(TaskProg::Adv(task.id, 1, 0))?);
+				}
 				if let Err(e) = result {
 					self.fail(
 						task.id,
@@ -62,7 +97,15 @@ impl Prework {
 			}
 			PreworkOp::Load(task) => {
 				let url = task.target.url();
-				let result = isolate::preload(&task.plugin.name, task.target).await;
+				let result = if task.token.is_cancelled() {
+					Ok(0)
+				} else {
+					isolate::preload(&task.plugin.name, task.target, task.token.clone(), task.visible).await
+				};
+				if task.token.is_cancelled() {
+					self.unload(&[url], task.plugin.id);
+					return Ok(self.prog.send(TaskProg::Adv(task.id, 1, 0))?);
+				}
 				if let Err(e) = result {
 					self.fail(
 						task.id,
@@ -82,7 +125,11 @@ impl Prework {
 				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
 			}
 			PreworkOp::Size(task) => {
-				let length = calculate_size(&task.target).await;
+				// Leaving the directory drops the walk, while the parts done so far stay cached
+				let length = select! {
+					_ = task.token.cancelled() => None,
+					n = DIR_SIZES.calculate(&task.target, |p| WATCHER.load().excluded(p)) => Some(n),
+				};
 				task.throttle.done((task.target, length), |buf| {
 					{
 						let mut loading = self.size_loading.write();
@@ -92,10 +139,32 @@ impl Prework {
 					}
 
 					let parent = buf[0].0.parent_url().unwrap();
-					FilesOp::Size(parent, HashMap::from_iter(buf)).emit();
+					let sizes: HashMap<_, _> =
+						buf.into_iter().filter_map(|(url, n)| Some((url, n?))).collect();
+					if !sizes.is_empty() {
+						FilesOp::Size(parent, sizes).emit();
+					}
 				});
 				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
 			}
+			PreworkOp::Measure(task) => {
+				let mut size = 0;
+				for url in &task.targets {
+					size += match fs::symlink_metadata(url).await {
+						Ok(m) if m.is_dir() => DIR_SIZES.calculate(url, |p| WATCHER.load().excluded(p)).await,
+						Ok(m) => m.len(),
+						Err(_) => 0,
+					};
+				}
+
+				let dest = task.dest.clone();
+				let space = tokio::task::spawn_blocking(move || yazi_fs::space(&dest)).await;
+				let free = space.ok().flatten().map(|s| s.free);
+
+				self.measuring.lock().remove(&task.dest);
+				task.done.send((size, free)).ok();
+				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
+			}
 		}
 		Ok(())
 	}
@@ -104,10 +173,13 @@ impl Prework {
 		let id = task.id;
 		self.prog.send(TaskProg::New(id, 0))?;
 
-		match task.plugin.prio {
-			Priority::Low => self.queue(PreworkOp::Fetch(task), NORMAL).await?,
-			Priority::Normal => self.queue(PreworkOp::Fetch(task), HIGH).await?,
-			Priority::High => self.work(PreworkOp::Fetch(task)).await?,
+		match (task.plugin.prio, task.visible) {
+			(Priority::Low, false) => self.queue(PreworkOp::Fetch(task), LOW).await?,
+			(Priority::Low, true) => self.queue(PreworkOp::Fetch(task), NORMAL).await?,
+			(Priority::Normal, false) => self.queue(PreworkOp::Fetch(task), NORMAL).await?,
+			(Priority::Normal, true) => self.queue(PreworkOp::Fetch(task), HIGH).await?,
+			(Priority::High, false) => self.queue(PreworkOp::Fetch(task), HIGH).await?,
+			(Priority::High, true) => self.work(PreworkOp::Fetch(task)).await?,
 		}
 		self.succ(id)
 	}
@@ -116,10 +188,13 @@ impl Prework {
 		let id = task.id;
 		self.prog.send(TaskProg::New(id, 0))?;
 
-		match task.plugin.prio {
-			Priority::Low => self.queue(PreworkOp::Load(task), NORMAL).await?,
-			Priority::Normal => self.queue(PreworkOp::Load(task), HIGH).await?,
-			Priority::High => self.work(PreworkOp::Load(task)).await?,
+		match (task.plugin.prio, task.visible) {
+			(Priority::Low, false) => self.queue(PreworkOp::Load(task), LOW).await?,
+			(Priority::Low, true) => self.queue(PreworkOp::Load(task), NORMAL).await?,
+			(Priority::Normal, false) => self.queue(PreworkOp::Load(task), NORMAL).await?,
+			(Priority::Normal, true) => self.queue(PreworkOp::Load(task), HIGH).await?,
+			(Priority::High, false) => self.queue(PreworkOp::Load(task), HIGH).await?,
+			(Priority::High, true) => self.work(PreworkOp::Load(task)).await?,
 		}
 		self.succ(id)
 	}
@@ -131,6 +206,14 @@ impl Prework {
 		self.work(PreworkOp::Size(task)).await?;
 		self.succ(id)
 	}
+
+	pub async fn measure(&self, task: PreworkOpMeasure) -> Result<()> {
+		let id = task.id;
+
+		self.prog.send(TaskProg::New(id, 0))?;
+		self.work(PreworkOp::Measure(task)).await?;
+		self.succ(id)
+	}
 }
 
 impl Prework {
@@ -142,6 +225,14 @@ impl Prework {
 		Ok(self.prog.send(TaskProg::Fail(id, reason))?)
 	}
 
+	// Clears the loaded bits, so that the files can be worked on again once revisited.
+	fn unload(&self, urls: &[Url], id: u8) {
+		let mut loaded = self.loaded.lock();
+		for url in urls {
+			loaded.get_mut(url).map(|x| *x &= !(1 << id));
+		}
+	}
+
 	#[inline]
 	async fn queue(&self, op: impl Into<TaskOp>, priority: u8) -> Result<()> {
 		self.macro_.send(op.into(), priority).await.map_err(|_| anyhow!("Failed to send task"))
@@ -1,6 +1,8 @@
 use std::ffi::OsString;
 
 use tokio::sync::mpsc;
+use yazi_config::open::OpenerOutput;
+use yazi_shared::fs::Url;
 
 use super::ShellOpt;
 
@@ -35,6 +37,7 @@ pub struct ProcessOpBg {
 	pub id:     usize,
 	pub cmd:    OsString,
 	pub args:   Vec<OsString>,
+	pub output: OpenerOutput,
 	pub cancel: mpsc::Receiver<()>,
 }
 
@@ -43,3 +46,11 @@ impl From<ProcessOpBg> for ShellOpt {
 		Self { cmd: op.cmd, args: op.args, piped: true, orphan: false }
 	}
 }
+
+#[derive(Debug)]
+pub struct ProcessOpEach {
+	pub id:      usize,
+	pub run:     String,
+	pub targets: Vec<Url>,
+	pub cancel:  mpsc::Receiver<()>,
+}
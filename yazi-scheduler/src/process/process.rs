@@ -1,11 +1,19 @@
+use std::{collections::VecDeque, ffi::{OsStr, OsString}, time::Duration};
+
 use anyhow::Result;
+use futures::{stream, StreamExt};
 use scopeguard::defer;
 use tokio::{io::{AsyncBufReadExt, BufReader}, select, sync::mpsc};
-use yazi_proxy::{AppProxy, HIDER};
+use yazi_config::{open::OpenerOutput, t, TASKS};
+use yazi_proxy::{options::{NotifyLevel, NotifyOpt}, AppProxy, HIDER};
+use yazi_shared::{fs::Url, shell};
 
-use super::{ProcessOpBg, ProcessOpBlock, ProcessOpOrphan, ShellOpt};
+use super::{ProcessOpBg, ProcessOpBlock, ProcessOpEach, ProcessOpOrphan, ShellOpt};
 use crate::TaskProg;
 
+// Only the tail of the output is kept, as a notification can't be scrolled.
+const CAPTURE_LINES: usize = 20;
+
 pub struct Process {
 	prog: mpsc::UnboundedSender<TaskProg>,
 }
@@ -21,7 +29,7 @@ impl Process {
 		let (id, cmd) = (task.id, task.cmd.clone());
 		let result = super::shell(task.into());
 		if let Err(e) = result {
-			AppProxy::notify_warn(&cmd.to_string_lossy(), &format!("Failed to spawn process: {e}"));
+			AppProxy::notify_warn(&cmd.to_string_lossy(), &t!("Failed to spawn process: {e}", e = e));
 			return self.succ(id);
 		}
 
@@ -53,11 +61,13 @@ impl Process {
 
 	pub async fn bg(&self, task: ProcessOpBg) -> Result<()> {
 		self.prog.send(TaskProg::New(task.id, 0))?;
+		let name = task.cmd.to_string_lossy().into_owned();
 		let mut child =
 			super::shell(ShellOpt { cmd: task.cmd, args: task.args, piped: true, ..Default::default() })?;
 
 		let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
 		let mut stderr = BufReader::new(child.stderr.take().unwrap()).lines();
+		let mut captured = (task.output == OpenerOutput::Notify).then(VecDeque::new);
 		let mut cancel = task.cancel;
 		loop {
 			select! {
@@ -67,9 +77,11 @@ impl Process {
 					break;
 				}
 				Ok(Some(line)) = stdout.next_line() => {
+					Self::capture(&mut captured, &line);
 					self.log(task.id, line)?;
 				}
 				Ok(Some(line)) = stderr.next_line() => {
+					Self::capture(&mut captured, &line);
 					self.log(task.id, line)?;
 				}
 				Ok(status) = child.wait() => {
@@ -77,6 +89,9 @@ impl Process {
 						Some(code) => format!("Exited with status code: {code}"),
 						None => "Process terminated by signal".to_string(),
 					})?;
+					if let Some(lines) = captured.take() {
+						Self::notify(&name, lines, status.success());
+					}
 					if !status.success() {
 						return self.fail(task.id, "Process failed".to_string());
 					}
@@ -88,6 +103,74 @@ impl Process {
 		self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
 		self.succ(task.id)
 	}
+
+	// Runs the command once for each of the targets, `shell_jobs` of them at the same time,
+	// and lists the ones that failed in a notification at the end.
+	pub async fn each(&self, task: ProcessOpEach) -> Result<()> {
+		let ProcessOpEach { id, run, targets, mut cancel } = task;
+		let total = targets.len();
+		for _ in 0..total {
+			self.prog.send(TaskProg::New(id, 0))?;
+		}
+
+		let run = &run;
+		let mut jobs = stream::iter(targets)
+			.map(|url| async move {
+				let cmd = Self::expand(run, &url);
+				let result = match super::shell(ShellOpt { cmd, piped: true, ..Default::default() }) {
+					Ok(child) => child.wait_with_output().await.map_err(Into::into),
+					Err(e) => Err(e),
+				};
+				(url, result)
+			})
+			.buffer_unordered(TASKS.load().shell_jobs as usize);
+
+		let mut failed = vec![];
+		loop {
+			select! {
+				_ = cancel.recv() => {
+					cancel.close();
+					return Ok(());
+				}
+				next = jobs.next() => {
+					let Some((url, result)) = next else { break };
+					let reason = match result {
+						Ok(out) if out.status.success() => {
+							self.prog.send(TaskProg::Adv(id, 1, 0))?;
+							continue;
+						}
+						Ok(out) => {
+							let stderr = String::from_utf8_lossy(&out.stderr);
+							let last = stderr.lines().rev().find(|l| !l.trim().is_empty()).unwrap_or_default();
+							match out.status.code() {
+								Some(code) => format!("exited with status code {code}: {last}"),
+								None => format!("terminated by signal: {last}"),
+							}
+						}
+						Err(e) => format!("failed to spawn: {e}"),
+					};
+
+					let name = url.file_name().unwrap_or(url.as_os_str()).to_string_lossy().into_owned();
+					self.fail(id, format!("{name}: {reason}"))?;
+					failed.push(format!("{name}: {reason}"));
+				}
+			}
+		}
+
+		if failed.is_empty() {
+			return self.succ(id);
+		}
+
+		let n = failed.len();
+		let title = t!("`{run}` failed on {n} of {total} files", run = run, n = n, total = total);
+		if failed.len() > CAPTURE_LINES {
+			let more = failed.len() - CAPTURE_LINES;
+			failed.truncate(CAPTURE_LINES);
+			failed.push(t!("... and {more} more", more = more));
+		}
+		AppProxy::notify_warn(&title, &failed.join("\n"));
+		Ok(())
+	}
 }
 
 impl Process {
@@ -103,4 +186,49 @@ impl Process {
 	fn log(&self, id: usize, line: String) -> Result<()> {
 		Ok(self.prog.send(TaskProg::Log(id, line))?)
 	}
+
+	// `%f` is the path of the file, `%d` the directory it's in and `%b` its name, each quoted,
+	// `%%` is a literal `%`
+	fn expand(run: &str, url: &Url) -> OsString {
+		let (mut out, mut buf) = (OsString::new(), String::new());
+		let mut it = run.chars().peekable();
+		while let Some(c) = it.next() {
+			let part = match (c, it.peek()) {
+				('%', Some('f')) => url.as_os_str(),
+				('%', Some('d')) => url.parent().map_or(url.as_os_str(), |p| p.as_os_str()),
+				('%', Some('b')) => url.file_name().unwrap_or_default(),
+				('%', Some('%')) => OsStr::new("%"),
+				_ => {
+					buf.push(c);
+					continue;
+				}
+			};
+
+			let quote = it.next() != Some('%');
+			out.push(&buf);
+			buf.clear();
+			out.push(if quote { shell::escape_os_str(part) } else { part.into() });
+		}
+		out.push(buf);
+		out
+	}
+
+	fn capture(captured: &mut Option<VecDeque<String>>, line: &str) {
+		let Some(lines) = captured else { return };
+		if lines.len() >= CAPTURE_LINES {
+			lines.pop_front();
+		}
+		lines.push_back(line.to_owned());
+	}
+
+	fn notify(name: &str, lines: VecDeque<String>, success: bool) {
+		let content = Vec::from(lines).join("\n");
+		AppProxy::notify(NotifyOpt {
+			title: name.to_owned(),
+			content: if content.is_empty() { "(no output)".to_owned() } else { content },
+			level: if success { NotifyLevel::Info } else { NotifyLevel::Warn },
+			timeout: Duration::from_secs(if success { 5 } else { 10 }),
+			actions: vec![],
+		});
+	}
 }
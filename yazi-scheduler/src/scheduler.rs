@@ -1,16 +1,19 @@
-use std::{borrow::Cow, ffi::OsString, sync::Arc, time::Duration};
+use std::{borrow::Cow, collections::HashMap, ffi::OsString, sync::Arc, time::Duration};
 
+use age::secrecy::Secret;
 use anyhow::Result;
 use futures::{future::BoxFuture, FutureExt};
 use parking_lot::Mutex;
 use tokio::{fs, select, sync::{mpsc::{self, UnboundedReceiver}, oneshot}, task::JoinHandle};
-use yazi_config::{open::Opener, plugin::{Fetcher, Preloader}, TASKS};
+use tracing::error;
+use yazi_config::{open::Opener, plugin::{Fetcher, Preloader}, t, TASKS};
 use yazi_dds::Pump;
-use yazi_proxy::ManagerProxy;
-use yazi_shared::{event::Data, fs::{remove_dir_clean, unique_name, Url}, Throttle};
+use yazi_fs::vfs;
+use yazi_proxy::{options::{PluginTaskOpt, PluginTaskProg}, ManagerProxy};
+use yazi_shared::{event::{Data, DataKey}, fs::{is_case_rename, unique_name, Url}, Throttle};
 
-use super::{Ongoing, TaskProg, TaskStage};
-use crate::{file::{File, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash}, plugin::{Plugin, PluginOpEntry}, prework::{Prework, PreworkOpFetch, PreworkOpLoad, PreworkOpSize}, process::{Process, ProcessOpBg, ProcessOpBlock, ProcessOpOrphan}, TaskKind, TaskOp, HIGH, LOW, NORMAL};
+use super::{Ongoing, Task, TaskProg, TaskStage};
+use crate::{file::{File, FileOpCrypt, FileOpDelete, FileOpHardlink, FileOpImage, FileOpLink, FileOpLinkMany, FileOpPaste, FileOpTouch, FileOpTransfer, FileOpTrash, ImageOp, TouchTime}, plugin::{Plugin, PluginOpEntry}, prework::{Prework, PreworkOpFetch, PreworkOpLoad, PreworkOpMeasure, PreworkOpSize}, process::{Process, ProcessOpBg, ProcessOpBlock, ProcessOpEach, ProcessOpOrphan}, TaskKind, TaskOp, HIGH, LOW, NORMAL};
 
 pub struct Scheduler {
 	pub file:    Arc<File>,
@@ -29,6 +32,7 @@ impl Scheduler {
 		let (micro_tx, micro_rx) = async_priority_channel::unbounded();
 		let (macro_tx, macro_rx) = async_priority_channel::unbounded();
 		let (prog_tx, prog_rx) = mpsc::unbounded_channel();
+		let (micro_workers, macro_workers) = (TASKS.load().micro_workers, TASKS.load().macro_workers);
 
 		let mut scheduler = Self {
 			file:    Arc::new(File::new(macro_tx.clone(), prog_tx.clone())),
@@ -38,14 +42,14 @@ impl Scheduler {
 
 			micro:   micro_tx,
 			prog:    prog_tx,
-			handles: Vec::with_capacity(TASKS.micro_workers as usize + TASKS.macro_workers as usize + 1),
+			handles: Vec::with_capacity(micro_workers as usize + macro_workers as usize + 1),
 			ongoing: Default::default(),
 		};
 
-		for _ in 0..TASKS.micro_workers {
+		for _ in 0..micro_workers {
 			scheduler.handles.push(scheduler.schedule_micro(micro_rx.clone()));
 		}
-		for _ in 0..TASKS.macro_workers {
+		for _ in 0..macro_workers {
 			scheduler.handles.push(scheduler.schedule_macro(micro_rx.clone(), macro_rx.clone()));
 		}
 		scheduler.progress(prog_rx);
@@ -55,12 +59,37 @@ impl Scheduler {
 	pub fn cancel(&self, id: usize) -> bool {
 		let mut ongoing = self.ongoing.lock();
 
+		if let Some(task) = ongoing.get_mut(id) {
+			task.canceled = true;
+		}
 		if let Some(hook) = ongoing.hooks.remove(&id) {
 			self.micro.try_send(hook(true), HIGH).ok();
 			return false;
 		}
 
-		ongoing.all.remove(&id).is_some()
+		ongoing.all.remove(&id).map(Task::report_done).is_some()
+	}
+
+	// Starts the operation that failed over, overwriting what it left at the destination
+	pub fn retry(&self, id: usize) -> bool {
+		let task = {
+			let mut ongoing = self.ongoing.lock();
+			if !ongoing.get(id).is_some_and(Task::retryable) {
+				return false;
+			}
+			ongoing.hooks.remove(&id);
+			ongoing.all.remove(&id)
+		};
+		let Some(Task { target: Some(target), follow, .. }) = task else { return false };
+
+		match (target.op, target.to) {
+			("cut", Some(to)) => self.file_cut(target.from, to, true),
+			("copy", Some(to)) => self.file_copy(target.from, to, true, follow),
+			("delete", _) => self.file_delete(target.from),
+			("trash", _) => self.file_trash(target.from),
+			_ => return false,
+		}
+		true
 	}
 
 	pub fn shutdown(&self) {
@@ -71,10 +100,11 @@ impl Scheduler {
 
 	pub fn file_cut(&self, from: Url, mut to: Url, force: bool) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Cut {:?} to {:?}", from, to));
+		let name = t!("Cut {from} to {to}", from = format!("{from:?}"), to = format!("{to:?}"));
+		let id = ongoing.add_op(name, "cut", &from, Some(&to));
 
-		if to.starts_with(&from) && to != from {
-			self.new_and_fail(id, "Cannot cut directory into itself").ok();
+		if to.starts_with(&from) && to != from && to.domain() == from.domain() {
+			self.new_and_fail(id, t!("Cannot cut directory into itself")).ok();
 			return;
 		}
 
@@ -85,7 +115,11 @@ impl Scheduler {
 			Box::new(move |canceled: bool| {
 				async move {
 					if !canceled {
-						remove_dir_clean(&from).await;
+						let to = ongoing.lock().target_to(id).unwrap_or(to);
+						vfs::remove_dir_clean(&from).await;
+						if from.is_remote() || to.is_remote() {
+							ManagerProxy::refresh();
+						}
 						Pump::push_move(from, to);
 					}
 					ongoing.lock().try_remove(id, TaskStage::Hooked);
@@ -94,16 +128,23 @@ impl Scheduler {
 			})
 		});
 
-		let file = self.file.clone();
+		let (file, ongoing) = (self.file.clone(), self.ongoing.clone());
 		_ = self.micro.try_send(
 			async move {
-				if !force {
-					to = unique_name(to).await;
+				// Cutting `readme.md` to `README.md` in the same directory is renaming it on APFS or NTFS
+				let case = !from.is_remote() && !to.is_remote() && is_case_rename(&from, &to).await;
+				if !force && !case {
+					to = vfs::unique_name(to).await;
+					ongoing.lock().retarget(id, &to);
+				}
+				if from.is_remote() || to.is_remote() {
+					file.transfer(FileOpTransfer { id, from, to, cha: None, cut: true }).await.ok();
+				} else {
+					file
+						.paste(FileOpPaste { id, from, to, meta: None, cut: true, follow: false, retry: 0 })
+						.await
+						.ok();
 				}
-				file
-					.paste(FileOpPaste { id, from, to, meta: None, cut: true, follow: false, retry: 0 })
-					.await
-					.ok();
 			}
 			.boxed(),
 			LOW,
@@ -111,24 +152,53 @@ impl Scheduler {
 	}
 
 	pub fn file_copy(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let name = format!("Copy {:?} to {:?}", from, to);
-		let id = self.ongoing.lock().add(TaskKind::User, name);
+		let mut ongoing = self.ongoing.lock();
+		let name = t!("Copy {from} to {to}", from = format!("{from:?}"), to = format!("{to:?}"));
+		let id = ongoing.add_op(name, "copy", &from, Some(&to));
+		if let Some(task) = ongoing.get_mut(id) {
+			task.follow = follow;
+		}
 
-		if to.starts_with(&from) && to != from {
-			self.new_and_fail(id, "Cannot copy directory into itself").ok();
+		if to.starts_with(&from) && to != from && to.domain() == from.domain() {
+			self.new_and_fail(id, t!("Cannot copy directory into itself")).ok();
 			return;
 		}
 
-		let file = self.file.clone();
+		ongoing.hooks.insert(id, {
+			let ongoing = self.ongoing.clone();
+			let (from, to) = (from.clone(), to.clone());
+
+			Box::new(move |canceled: bool| {
+				async move {
+					if !canceled {
+						let to = ongoing.lock().target_to(id).unwrap_or(to);
+						// The remote directories aren't watched, so they need to be listed again
+						if to.is_remote() {
+							ManagerProxy::refresh();
+						}
+						Pump::push_duplicate(from, to);
+					}
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let (file, ongoing) = (self.file.clone(), self.ongoing.clone());
 		_ = self.micro.try_send(
 			async move {
 				if !force {
-					to = unique_name(to).await;
+					to = vfs::unique_name(to).await;
+					ongoing.lock().retarget(id, &to);
+				}
+				if from.is_remote() || to.is_remote() {
+					file.transfer(FileOpTransfer { id, from, to, cha: None, cut: false }).await.ok();
+				} else {
+					file
+						.paste(FileOpPaste { id, from, to, meta: None, cut: false, follow, retry: 0 })
+						.await
+						.ok();
 				}
-				file
-					.paste(FileOpPaste { id, from, to, meta: None, cut: false, follow, retry: 0 })
-					.await
-					.ok();
 			}
 			.boxed(),
 			LOW,
@@ -136,14 +206,15 @@ impl Scheduler {
 	}
 
 	pub fn file_link(&self, from: Url, mut to: Url, relative: bool, force: bool) {
-		let name = format!("Link {from:?} to {to:?}");
-		let id = self.ongoing.lock().add(TaskKind::User, name);
+		let name = t!("Link {from} to {to}", from = format!("{from:?}"), to = format!("{to:?}"));
+		let id = self.ongoing.lock().add_op(name, "link", &from, Some(&to));
 
-		let file = self.file.clone();
+		let (file, ongoing) = (self.file.clone(), self.ongoing.clone());
 		_ = self.micro.try_send(
 			async move {
 				if !force {
 					to = unique_name(to).await;
+					ongoing.lock().retarget(id, &to);
 				}
 				file
 					.link(FileOpLink { id, from, to, meta: None, resolve: false, relative, delete: false })
@@ -155,20 +226,35 @@ impl Scheduler {
 		);
 	}
 
-	pub fn file_hardlink(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let name = format!("Hardlink {:?} to {:?}", from, to);
+	pub fn file_link_many(&self, targets: Vec<Url>, dest: Url, relative: bool, force: bool) {
+		let name = t!("Link {n} files to {dest}", n = targets.len(), dest = format!("{dest:?}"));
 		let id = self.ongoing.lock().add(TaskKind::User, name);
 
+		let file = self.file.clone();
+		_ = self.micro.try_send(
+			async move {
+				file.link_many(FileOpLinkMany { id, targets, dest, relative, force }).await.ok();
+			}
+			.boxed(),
+			LOW,
+		);
+	}
+
+	pub fn file_hardlink(&self, from: Url, mut to: Url, force: bool, follow: bool) {
+		let name = t!("Hardlink {from} to {to}", from = format!("{from:?}"), to = format!("{to:?}"));
+		let id = self.ongoing.lock().add_op(name, "hardlink", &from, Some(&to));
+
 		if to.starts_with(&from) && to != from {
-			self.new_and_fail(id, "Cannot hardlink directory into itself").ok();
+			self.new_and_fail(id, t!("Cannot hardlink directory into itself")).ok();
 			return;
 		}
 
-		let file = self.file.clone();
+		let (file, ongoing) = (self.file.clone(), self.ongoing.clone());
 		_ = self.micro.try_send(
 			async move {
 				if !force {
 					to = unique_name(to).await;
+					ongoing.lock().retarget(id, &to);
 				}
 				file.hardlink(FileOpHardlink { id, from, to, meta: None, follow }).await.ok();
 			}
@@ -179,7 +265,8 @@ impl Scheduler {
 
 	pub fn file_delete(&self, target: Url) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Delete {:?}", target));
+		let name = t!("Delete {target}", target = format!("{target:?}"));
+		let id = ongoing.add_op(name, "delete", &target, None);
 
 		ongoing.hooks.insert(id, {
 			let target = target.clone();
@@ -187,7 +274,11 @@ impl Scheduler {
 
 			Box::new(move |canceled: bool| {
 				async move {
-					if !canceled {
+					if !canceled && target.is_remote() {
+						vfs::remove_dir_clean(&target).await;
+						ManagerProxy::refresh();
+						Pump::push_delete(target);
+					} else if !canceled {
 						fs::remove_dir_all(&target).await.ok();
 						ManagerProxy::update_task(&target);
 						Pump::push_delete(target);
@@ -210,7 +301,8 @@ impl Scheduler {
 
 	pub fn file_trash(&self, target: Url) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Trash {:?}", target));
+		let name = t!("Trash {target}", target = format!("{target:?}"));
+		let id = ongoing.add_op(name, "trash", &target, None);
 
 		ongoing.hooks.insert(id, {
 			let target = target.clone();
@@ -238,8 +330,76 @@ impl Scheduler {
 		);
 	}
 
-	pub fn plugin_micro(&self, name: String, args: Vec<Data>) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Run micro plugin `{name}`"));
+	pub fn file_crypt(&self, from: Url, to: Url, passphrase: String, encrypt: bool, gpg: bool) {
+		let (name, op) = if encrypt {
+			(t!("Encrypt {from} to {to}", from = format!("{from:?}"), to = format!("{to:?}")), "encrypt")
+		} else {
+			(t!("Decrypt {from} to {to}", from = format!("{from:?}"), to = format!("{to:?}")), "decrypt")
+		};
+		let mut ongoing = self.ongoing.lock();
+		let id = ongoing.add_op(name, op, &from, Some(&to));
+
+		ongoing.hooks.insert(id, {
+			let to = to.clone();
+			let ongoing = self.ongoing.clone();
+
+			Box::new(move |canceled: bool| {
+				async move {
+					if canceled {
+						fs::remove_file(&to).await.ok();
+					}
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let file = self.file.clone();
+		let task = FileOpCrypt { id, from, to, passphrase: Secret::new(passphrase), encrypt, gpg };
+		_ = self.micro.try_send(
+			async move {
+				file.crypt(task).await.ok();
+			}
+			.boxed(),
+			LOW,
+		);
+	}
+
+	pub fn file_image(&self, from: Url, op: ImageOp, quality: u8) {
+		let name = match op {
+			ImageOp::Rotate(_) => t!("Rotate {from}", from = format!("{from:?}")),
+			ImageOp::Resize(max) => t!("Resize {from} to {max}px", from = format!("{from:?}"), max = max),
+			ImageOp::Convert(f) => {
+				t!("Convert {from} to {format}", from = format!("{from:?}"), format = format!("{f:?}"))
+			}
+		};
+		let id = self.ongoing.lock().add_op(name, "image", &from, None);
+
+		let file = self.file.clone();
+		_ = self.micro.try_send(
+			async move {
+				file.image(FileOpImage { id, from, op, quality }).await.ok();
+			}
+			.boxed(),
+			LOW,
+		);
+	}
+
+	pub fn file_touch(&self, targets: Vec<Url>, time: TouchTime, atime: bool, mtime: bool) {
+		let id = self.ongoing.lock().add(TaskKind::User, t!("Touch {n} files", n = targets.len()));
+
+		let file = self.file.clone();
+		_ = self.micro.try_send(
+			async move {
+				file.touch(FileOpTouch { id, targets, time, atime, mtime }).await.ok();
+			}
+			.boxed(),
+			LOW,
+		);
+	}
+
+	pub fn plugin_micro(&self, name: String, args: HashMap<DataKey, Data>) {
+		let id = self.ongoing.lock().add(TaskKind::User, t!("Run micro plugin `{name}`", name = name));
 
 		let plugin = self.plugin.clone();
 		_ = self.micro.try_send(
@@ -251,58 +411,118 @@ impl Scheduler {
 		);
 	}
 
-	pub fn plugin_macro(&self, name: String, args: Vec<Data>) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Run macro plugin `{name}`"));
+	pub fn plugin_macro(&self, name: String, args: HashMap<DataKey, Data>) {
+		let id = self.ongoing.lock().add(TaskKind::User, t!("Run macro plugin `{name}`", name = name));
 
 		self.plugin.macro_(PluginOpEntry { id, name, args }).ok();
 	}
 
-	pub fn fetch_paged(&self, fetcher: &Fetcher, targets: Vec<yazi_shared::fs::File>) {
+	pub fn plugin_task(&self, opt: PluginTaskOpt) {
+		let PluginTaskOpt { name, mut prog, cancel } = opt;
+		let mut ongoing = self.ongoing.lock();
+
+		let id = ongoing.add(TaskKind::User, name);
+		ongoing.hooks.insert(id, {
+			let ongoing = self.ongoing.clone();
+			Box::new(move |canceled: bool| {
+				async move {
+					if canceled {
+						cancel.send(()).ok();
+					}
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let tx = self.prog.clone();
+		let ongoing = self.ongoing.clone();
+		tokio::spawn(async move {
+			while let Some(p) = prog.recv().await {
+				match p {
+					PluginTaskProg::Total(total) => {
+						if let Some(task) = ongoing.lock().get_mut(id) {
+							task.total = total;
+						}
+					}
+					PluginTaskProg::Adv(succ) => _ = tx.send(TaskProg::Adv(id, succ, 0)),
+					PluginTaskProg::Log(line) => _ = tx.send(TaskProg::Log(id, line)),
+					PluginTaskProg::Succ => {
+						// The plugin may finish before reaching the total it reported
+						if let Some(task) = ongoing.lock().get_mut(id) {
+							task.succ = task.succ.max(task.total);
+						}
+						_ = tx.send(TaskProg::Succ(id));
+					}
+					PluginTaskProg::Fail(reason) => _ = tx.send(TaskProg::Fail(id, reason)),
+				}
+			}
+		});
+	}
+
+	pub fn fetch_paged(
+		&self,
+		fetcher: &Fetcher,
+		targets: Vec<yazi_shared::fs::File>,
+		cwd: &Url,
+		visible: bool,
+	) {
 		let id = self.ongoing.lock().add(
 			TaskKind::Preload,
-			format!("Run fetcher `{}` with {} target(s)", fetcher.run.name, targets.len()),
+			t!("Run fetcher `{name}` with {n} target(s)", name = fetcher.run.name, n = targets.len()),
 		);
 
 		let plugin = fetcher.into();
+		let token = self.prework.token(cwd);
 		let prework = self.prework.clone();
 		_ = self.micro.try_send(
 			async move {
-				prework.fetch(PreworkOpFetch { id, plugin, targets }).await.ok();
+				prework.fetch(PreworkOpFetch { id, plugin, targets, token, visible }).await.ok();
 			}
 			.boxed(),
 			NORMAL,
 		);
 	}
 
-	pub fn preload_paged(&self, preloader: &Preloader, target: &yazi_shared::fs::File) {
-		let id =
-			self.ongoing.lock().add(TaskKind::Preload, format!("Run preloader `{}`", preloader.run.name));
+	pub fn preload_paged(
+		&self,
+		preloader: &Preloader,
+		target: &yazi_shared::fs::File,
+		cwd: &Url,
+		visible: bool,
+	) {
+		let name = t!("Run preloader `{name}`", name = preloader.run.name);
+		let id = self.ongoing.lock().add(TaskKind::Preload, name);
 
 		let plugin = preloader.into();
+		let token = self.prework.token(cwd);
 		let target = target.clone();
 		let prework = self.prework.clone();
 		_ = self.micro.try_send(
 			async move {
-				prework.load(PreworkOpLoad { id, plugin, target }).await.ok();
+				prework.load(PreworkOpLoad { id, plugin, target, token, visible }).await.ok();
 			}
 			.boxed(),
 			NORMAL,
 		);
 	}
 
-	pub fn prework_size(&self, targets: Vec<&Url>) {
+	pub fn prework_size(&self, cwd: &Url, targets: Vec<&Url>) {
 		let throttle = Arc::new(Throttle::new(targets.len(), Duration::from_millis(300)));
+		let token = self.prework.token(cwd);
 		let mut ongoing = self.ongoing.lock();
 
 		for target in targets {
-			let id = ongoing.add(TaskKind::Preload, format!("Calculate the size of {:?}", target));
+			let name = t!("Calculate the size of {target}", target = format!("{target:?}"));
+			let id = ongoing.add(TaskKind::Preload, name);
 			let target = target.clone();
 			let throttle = throttle.clone();
+			let token = token.clone();
 
 			let prework = self.prework.clone();
 			_ = self.micro.try_send(
 				async move {
-					prework.size(PreworkOpSize { id, target, throttle }).await.ok();
+					prework.size(PreworkOpSize { id, target, throttle, token }).await.ok();
 				}
 				.boxed(),
 				NORMAL,
@@ -310,6 +530,27 @@ impl Scheduler {
 		}
 	}
 
+	// Shown in the task list, as walking large directories to copy can take a while
+	pub fn prework_measure(
+		&self,
+		targets: Vec<Url>,
+		dest: Url,
+	) -> oneshot::Receiver<(u64, Option<u64>)> {
+		let name = t!("Measure the files to copy to {dest}", dest = format!("{dest:?}"));
+		let id = self.ongoing.lock().add(TaskKind::User, name);
+
+		let (done, rx) = oneshot::channel();
+		let prework = self.prework.clone();
+		_ = self.micro.try_send(
+			async move {
+				prework.measure(PreworkOpMeasure { id, targets, dest, done }).await.ok();
+			}
+			.boxed(),
+			HIGH,
+		);
+		rx
+	}
+
 	pub fn process_open(
 		&self,
 		opener: Cow<'static, Opener>,
@@ -319,9 +560,9 @@ impl Scheduler {
 		let name = {
 			let args = args.iter().map(|a| a.to_string_lossy()).collect::<Vec<_>>().join(" ");
 			if args.is_empty() {
-				format!("Run {:?}", opener.run)
+				t!("Run {run}", run = format!("{:?}", opener.run))
 			} else {
-				format!("Run {:?} with `{args}`", opener.run)
+				t!("Run {run} with `{args}`", run = format!("{:?}", opener.run), args = args)
 			}
 		};
 
@@ -355,7 +596,8 @@ impl Scheduler {
 				} else if opener.orphan {
 					process.orphan(ProcessOpOrphan { id, cmd, args }).await.ok();
 				} else {
-					process.bg(ProcessOpBg { id, cmd, args, cancel: cancel_rx }).await.ok();
+					let output = opener.output;
+					process.bg(ProcessOpBg { id, cmd, args, output, cancel: cancel_rx }).await.ok();
 				}
 			}
 			.boxed(),
@@ -363,6 +605,37 @@ impl Scheduler {
 		);
 	}
 
+	pub fn process_each(&self, run: String, targets: Vec<Url>) {
+		let name = t!("Run {run} on {n} files", run = format!("{run:?}"), n = targets.len());
+
+		let (cancel_tx, cancel_rx) = mpsc::channel(1);
+		let mut ongoing = self.ongoing.lock();
+
+		let id = ongoing.add(TaskKind::User, name);
+		ongoing.hooks.insert(id, {
+			let ongoing = self.ongoing.clone();
+			Box::new(move |canceled: bool| {
+				async move {
+					if canceled {
+						cancel_tx.send(()).await.ok();
+						cancel_tx.closed().await;
+					}
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let process = self.process.clone();
+		_ = self.micro.try_send(
+			async move {
+				process.each(ProcessOpEach { id, run, targets, cancel: cancel_rx }).await.ok();
+			}
+			.boxed(),
+			NORMAL,
+		);
+	}
+
 	fn schedule_micro(
 		&self,
 		rx: async_priority_channel::Receiver<BoxFuture<'static, ()>, u8>,
@@ -433,6 +706,7 @@ impl Scheduler {
 						if let Some(task) = ongoing.get_mut(id) {
 							task.succ += succ;
 							task.processed += processed;
+							task.report_progress();
 						}
 						if succ > 0 {
 							if let Some(fut) = ongoing.try_remove(id, TaskStage::Pending) {
@@ -446,15 +720,23 @@ impl Scheduler {
 						}
 					}
 					TaskProg::Fail(id, reason) => {
-						if let Some(task) = ongoing.lock().get_mut(id) {
+						let mut ongoing = ongoing.lock();
+						if let Some(task) = ongoing.get_mut(id) {
+							error!(task = id, name = %task.name, "{reason}");
 							task.fail += 1;
 							task.logs.push_str(&reason);
 							task.logs.push('\n');
+							if task.target.is_some() {
+								task.errors.push(reason.clone());
+							}
 
 							if let Some(logger) = &task.logger {
 								logger.send(reason).ok();
 							}
 						}
+						if let Some(fut) = ongoing.try_remove(id, TaskStage::Pending) {
+							micro.try_send(fut, LOW).ok();
+						}
 					}
 					TaskProg::Log(id, line) => {
 						if let Some(task) = ongoing.lock().get_mut(id) {
@@ -471,9 +753,9 @@ impl Scheduler {
 		})
 	}
 
-	fn new_and_fail(&self, id: usize, reason: &str) -> Result<()> {
+	fn new_and_fail(&self, id: usize, reason: String) -> Result<()> {
 		self.prog.send(TaskProg::New(id, 0))?;
-		self.prog.send(TaskProg::Fail(id, reason.to_owned()))?;
+		self.prog.send(TaskProg::Fail(id, reason))?;
 		Ok(())
 	}
 }
@@ -1,4 +1,12 @@
+use std::time::Duration;
+
 use tokio::sync::mpsc;
+use yazi_config::t;
+use yazi_dds::{body::{BodyOpDone, BodyOpProgress}, Pubsub};
+use yazi_proxy::{options::{NotifyAction, NotifyLevel, NotifyOpt}, AppProxy, ManagerProxy};
+use yazi_shared::{fs::Url, shell::escape_unix, timestamp_us, Layer};
+
+use crate::FAILED_LINES;
 
 #[derive(Debug, Default)]
 pub struct Task {
@@ -16,12 +24,140 @@ pub struct Task {
 
 	pub logs:   String,
 	pub logger: Option<mpsc::UnboundedSender<String>>,
+
+	pub target:   Option<TaskTarget>,
+	pub errors:   Vec<String>,
+	pub canceled: bool,
+	reported:     u64,
+	// Whether the failure has been notified, and for a copy, whether to follow the symlinks again
+	pub(super) failed: bool,
+	pub(super) follow: bool,
 }
 
 impl Task {
 	pub fn new(id: usize, kind: TaskKind, name: String) -> Self {
 		Self { id, kind, name, ..Default::default() }
 	}
+
+	// At most twice a second for each task, as the copying of small files advances rapidly
+	pub(super) fn report_progress(&mut self) {
+		let now = timestamp_us();
+		if self.target.is_none() || now < self.reported + 500_000 {
+			return;
+		}
+
+		self.reported = now;
+		Pubsub::pub_from_op_progress(BodyOpProgress {
+			id:        self.id,
+			total:     self.total,
+			succ:      self.succ,
+			fail:      self.fail,
+			found:     self.found,
+			processed: self.processed,
+		});
+	}
+
+	// Only a failed operation that's done with the files that didn't fail can be retried
+	#[inline]
+	pub(super) fn retryable(&self) -> bool {
+		self.target.as_ref().is_some_and(TaskTarget::retryable)
+			&& self.fail > 0
+			&& self.succ + self.fail >= self.total
+	}
+
+	// Once the files that didn't fail are done, offers to retry the whole operation, which goes
+	// over all of them again and overwrites what it did, or to reveal where it left them.
+	// The task stays there to be inspected.
+	pub(super) fn report_failed(&mut self) {
+		let Some(target) = self.target.as_ref().filter(|_| !self.failed && !self.canceled) else {
+			return;
+		};
+		self.failed = true;
+
+		let mut actions = vec![];
+		if target.retryable() {
+			actions.push(NotifyAction {
+				desc:  t!("Retry"),
+				run:   format!("retry {}", self.id),
+				layer: Layer::Tasks,
+			});
+		}
+		actions.extend(target.reveal(false));
+
+		let mut lines: Vec<_> = self.errors.iter().take(FAILED_LINES).cloned().collect();
+		if self.errors.len() > FAILED_LINES {
+			lines.push(t!("... and {more} more", more = self.errors.len() - FAILED_LINES));
+		}
+		AppProxy::notify(NotifyOpt {
+			title: self.name.clone(),
+			content: lines.join("\n"),
+			level: NotifyLevel::Error,
+			timeout: Duration::from_secs(10),
+			actions,
+		});
+	}
+
+	pub(super) fn report_done(self) {
+		let Some(target) = self.target else { return };
+		// The free spaces shown have likely changed with the files
+		ManagerProxy::update_space();
+
+		let state = if self.canceled {
+			"canceled"
+		} else if self.fail > 0 {
+			"failed"
+		} else {
+			"succeeded"
+		};
+
+		// Kept in the history only, as there's nothing to be done about it
+		if state == "succeeded" {
+			AppProxy::notify(NotifyOpt {
+				title:   self.name,
+				content: t!("Done"),
+				level:   NotifyLevel::Info,
+				timeout: Duration::ZERO,
+				actions: target.reveal(true).into_iter().collect(),
+			});
+		}
+
+		Pubsub::pub_from_op_done(BodyOpDone {
+			id:        self.id,
+			op:        target.op.to_owned(),
+			from:      target.from,
+			to:        target.to,
+			state:     state.to_owned(),
+			processed: self.processed,
+			errors:    self.errors,
+		});
+	}
+}
+
+// The paths of a file operation, reported as the `op-start`, `op-progress` and `op-done` events
+#[derive(Debug)]
+pub struct TaskTarget {
+	pub op:   &'static str,
+	pub from: Url,
+	pub to:   Option<Url>,
+}
+
+impl TaskTarget {
+	#[inline]
+	fn retryable(&self) -> bool { matches!(self.op, "cut" | "copy" | "delete" | "trash") }
+
+	// The files deleted or trashed have nowhere to be revealed once they're done
+	fn reveal(&self, done: bool) -> Option<NotifyAction> {
+		if done && matches!(self.op, "delete" | "trash") {
+			return None;
+		}
+
+		let url = self.to.as_ref().unwrap_or(&self.from);
+		Some(NotifyAction {
+			desc:  t!("Reveal"),
+			run:   format!("reveal {}", escape_unix(&url.to_string())),
+			layer: Layer::Manager,
+		})
+	}
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -17,6 +17,10 @@ impl CharKind {
 			Self::Other
 		}
 	}
+
+	// Of a grapheme cluster, which is the kind of its base character
+	#[inline]
+	pub fn of(g: &str) -> Self { g.chars().next().map_or(Self::Space, Self::new) }
 }
 
 pub fn strip_trailing_newline(mut s: String) -> String {
@@ -3,7 +3,6 @@ use std::{error::Error, fmt::{self, Display}};
 #[derive(Debug)]
 pub enum InputError {
 	Typed(String),
-	Completed(String, usize),
 	Canceled(String),
 }
 
@@ -11,7 +10,6 @@ impl Display for InputError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Self::Typed(text) => write!(f, "Typed error: {text}"),
-			Self::Completed(text, _) => write!(f, "Completed error: {text}"),
 			Self::Canceled(text) => write!(f, "Canceled error: {text}"),
 		}
 	}
@@ -3,7 +3,7 @@ use std::{any::Any, collections::HashMap, fmt::{self, Display}, mem, str::FromSt
 use anyhow::bail;
 use serde::{de, Deserialize};
 
-use super::Data;
+use super::{AnyData, Data};
 
 #[derive(Debug, Default)]
 pub struct Cmd {
@@ -38,7 +38,7 @@ impl Cmd {
 
 	#[inline]
 	pub fn with_any(mut self, name: impl ToString, data: impl Any + Send) -> Self {
-		self.args.insert(name.to_string(), Data::Any(Box::new(data)));
+		self.args.insert(name.to_string(), Data::Any(AnyData::new(data)));
 		self
 	}
 
@@ -1,4 +1,4 @@
-use std::{any::Any, collections::HashMap};
+use std::{any::Any, collections::HashMap, fmt::{self, Debug}};
 
 use serde::{Deserialize, Serialize};
 
@@ -18,7 +18,7 @@ pub enum Data {
 	#[serde(skip_deserializing)]
 	Url(Url),
 	#[serde(skip)]
-	Any(Box<dyn Any + Send>),
+	Any(AnyData),
 }
 
 impl Data {
@@ -40,14 +40,6 @@ impl Data {
 		}
 	}
 
-	#[inline]
-	pub fn as_any<T: 'static>(&self) -> Option<&T> {
-		match self {
-			Self::Any(b) => b.downcast_ref::<T>(),
-			_ => None,
-		}
-	}
-
 	#[inline]
 	pub fn into_any<T: 'static>(self) -> Option<T> {
 		match self {
@@ -89,6 +81,25 @@ impl Data {
 	}
 }
 
+// --- AnyData
+// The value is only ever moved out of, never borrowed, so sharing the `AnyData` itself
+// can't share the value, which keeps the commands carrying it `Sync` as long as it's `Send`.
+pub struct AnyData(Box<dyn Any + Send>);
+
+unsafe impl Sync for AnyData {}
+
+impl AnyData {
+	#[inline]
+	pub fn new(value: impl Any + Send) -> Self { Self(Box::new(value)) }
+
+	#[inline]
+	pub fn downcast<T: 'static>(self) -> Result<Box<T>, Self> { self.0.downcast().map_err(Self) }
+}
+
+impl Debug for AnyData {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("AnyData") }
+}
+
 // --- Key
 #[derive(Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -1,11 +1,70 @@
-use std::sync::atomic::AtomicBool;
+use std::{cell::Cell, sync::atomic::{AtomicBool, AtomicU8, Ordering}};
+
+use crate::Layer;
 
 pub static NEED_RENDER: AtomicBool = AtomicBool::new(false);
 
+// The layers that requested a render since the last one, each as a bit of `1 << layer as u8`
+pub static DAMAGED: AtomicU8 = AtomicU8::new(0);
+
+// The components of the manager layer to redraw, each as a bit of `1 << region as u8`,
+// which are all of them unless the render is narrowed down with `damage_regions()`
+pub static REGIONS: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Region {
+	Header,
+	Parent,
+	Current,
+	Preview,
+	Status,
+}
+
+impl Region {
+	pub fn from_id(id: &str) -> Option<Self> {
+		Some(match id {
+			"header" => Self::Header,
+			"parent" => Self::Parent,
+			"current" => Self::Current,
+			"preview" => Self::Preview,
+			"status" => Self::Status,
+			_ => return None,
+		})
+	}
+}
+
+thread_local! {
+	static LAYER: Cell<Option<Layer>> = const { Cell::new(None) };
+}
+
+// Marks the part of the screen drawn by the layer whose command is being run,
+// or all of it when requested from elsewhere, e.g. a background task.
+#[inline]
+pub fn damage() {
+	NEED_RENDER.store(true, Ordering::Relaxed);
+	DAMAGED.fetch_or(LAYER.get().map_or(u8::MAX, |l| 1 << l as u8), Ordering::Relaxed);
+	REGIONS.store(u8::MAX, Ordering::Relaxed);
+}
+
+// Same as `damage()`, but only the given components are redrawn, if nothing else
+// in the manager layer changed, e.g. moving the cursor leaves the parent as it is.
+pub fn damage_regions(regions: &[Region]) {
+	NEED_RENDER.store(true, Ordering::Relaxed);
+	DAMAGED.fetch_or(LAYER.get().map_or(u8::MAX, |l| 1 << l as u8), Ordering::Relaxed);
+	REGIONS.fetch_or(regions.iter().fold(0, |b, &r| b | 1 << r as u8), Ordering::Relaxed);
+}
+
+pub fn damage_as<T>(layer: Layer, f: impl FnOnce() -> T) -> T {
+	let old = LAYER.replace(Some(layer));
+	let result = f();
+	LAYER.set(old);
+	result
+}
+
 #[macro_export]
 macro_rules! render {
 	() => {
-		$crate::event::NEED_RENDER.store(true, std::sync::atomic::Ordering::Relaxed);
+		$crate::event::damage();
 	};
 	($cond:expr) => {
 		if $cond {
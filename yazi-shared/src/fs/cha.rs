@@ -17,19 +17,26 @@ bitflags! {
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Cha {
-	pub kind:  ChaKind,
-	pub len:   u64,
-	pub atime: Option<SystemTime>,
-	pub ctime: Option<SystemTime>,
-	pub mtime: Option<SystemTime>,
+	pub kind:   ChaKind,
+	pub len:    u64,
+	pub atime:  Option<SystemTime>,
+	pub ctime:  Option<SystemTime>,
+	pub mtime:  Option<SystemTime>,
+	// The status change time, bumped as well when the permissions or the xattrs change
 	#[cfg(unix)]
-	pub perm:  libc::mode_t,
+	pub chtime: Option<SystemTime>,
 	#[cfg(unix)]
-	pub uid:   libc::uid_t,
+	pub perm:   libc::mode_t,
 	#[cfg(unix)]
-	pub gid:   libc::gid_t,
+	pub uid:    libc::uid_t,
 	#[cfg(unix)]
-	pub nlink: libc::nlink_t,
+	pub gid:    libc::gid_t,
+	#[cfg(unix)]
+	pub nlink:  libc::nlink_t,
+	#[cfg(windows)]
+	pub attrs:  u32,
+	#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+	pub flags:  u32,
 }
 
 impl From<Metadata> for Cha {
@@ -46,6 +53,12 @@ impl From<Metadata> for Cha {
 			ctime: m.created().ok(),
 			mtime: m.modified().ok(),
 
+			#[cfg(unix)]
+			chtime:             {
+				use std::{os::unix::fs::MetadataExt, time::Duration};
+				let (secs, nanos) = (m.ctime(), m.ctime_nsec());
+				(secs >= 0).then(|| SystemTime::UNIX_EPOCH + Duration::new(secs as u64, nanos as u32))
+			},
 			#[cfg(unix)]
 			perm:               {
 				use std::os::unix::prelude::PermissionsExt;
@@ -66,6 +79,21 @@ impl From<Metadata> for Cha {
 				use std::os::unix::fs::MetadataExt;
 				m.nlink() as _
 			},
+			#[cfg(windows)]
+			attrs:              {
+				use std::os::windows::fs::MetadataExt;
+				m.file_attributes()
+			},
+			#[cfg(target_os = "macos")]
+			flags:              {
+				use std::os::macos::fs::MetadataExt;
+				m.st_flags()
+			},
+			#[cfg(target_os = "freebsd")]
+			flags:              {
+				use std::os::freebsd::fs::MetadataExt;
+				m.st_flags()
+			},
 		}
 	}
 }
@@ -132,6 +160,15 @@ impl Cha {
 				self.perm == c.perm
 			}
 			#[cfg(windows)]
+			{
+				self.attrs == c.attrs
+			}
+		} && {
+			#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+			{
+				self.flags == c.flags
+			}
+			#[cfg(not(any(target_os = "macos", target_os = "freebsd")))]
 			{
 				true
 			}
@@ -1,4 +1,4 @@
-use std::{cell::Cell, ffi::OsStr, fs::{FileType, Metadata}, ops::Deref};
+use std::{cell::RefCell, ffi::OsStr, fs::{FileType, Metadata}, ops::Deref};
 
 use anyhow::Result;
 use tokio::fs;
@@ -10,7 +10,7 @@ pub struct File {
 	pub cha:     Cha,
 	pub url:     Url,
 	pub link_to: Option<Url>,
-	pub icon:    Cell<IconCache>,
+	pub icon:    RefCell<IconCache>,
 }
 
 impl Deref for File {
@@ -51,6 +51,13 @@ impl File {
 		if url.is_hidden() {
 			ck |= ChaKind::HIDDEN;
 		}
+		#[cfg(target_os = "macos")]
+		{
+			use std::os::macos::fs::MetadataExt;
+			if meta.st_flags() & super::fns::UF_HIDDEN != 0 {
+				ck |= ChaKind::HIDDEN;
+			}
+		}
 		#[cfg(windows)]
 		{
 			use std::os::windows::fs::MetadataExt;
@@ -23,6 +23,14 @@ pub fn ok_or_not_found(result: io::Result<()>) -> io::Result<()> {
 	}
 }
 
+// The same as `fs::canonicalize()`, without the `\\?\` prefix on Windows
+pub async fn canonicalize(p: impl AsRef<Path>) -> io::Result<PathBuf> {
+	let p = fs::canonicalize(p).await?;
+	#[cfg(windows)]
+	let p = super::strip_verbatim(p);
+	Ok(p)
+}
+
 #[inline]
 pub async fn paths_to_same_file(a: impl AsRef<Path>, b: impl AsRef<Path>) -> bool {
 	_paths_to_same_file(a.as_ref(), b.as_ref()).await.unwrap_or(false)
@@ -54,20 +62,24 @@ async fn _paths_to_same_file(a: &Path, b: &Path) -> std::io::Result<bool> {
 			.await?;
 
 		tokio::task::spawn_blocking(move || {
-			let mut buf = [0u16; MAX_PATH as usize];
-			let len = unsafe {
-				GetFinalPathNameByHandleW(
-					file.as_raw_handle() as HANDLE,
-					buf.as_mut_ptr(),
-					buf.len() as u32,
-					VOLUME_NAME_DOS,
-				)
-			};
-
-			if len == 0 {
-				Err(std::io::Error::last_os_error())
-			} else {
-				Ok(PathBuf::from(OsString::from_wide(&buf[0..len as usize])))
+			// Retried with the required size, which the call returns if the buffer is too small
+			let mut buf = vec![0u16; MAX_PATH as usize];
+			loop {
+				let len = unsafe {
+					GetFinalPathNameByHandleW(
+						file.as_raw_handle() as HANDLE,
+						buf.as_mut_ptr(),
+						buf.len() as u32,
+						VOLUME_NAME_DOS,
+					)
+				} as usize;
+
+				if len == 0 {
+					return Err(std::io::Error::last_os_error());
+				} else if len < buf.len() {
+					return Ok(PathBuf::from(OsString::from_wide(&buf[0..len])));
+				}
+				buf.resize(len, 0);
 			}
 		})
 		.await?
@@ -77,7 +89,7 @@ async fn _paths_to_same_file(a: &Path, b: &Path) -> std::io::Result<bool> {
 }
 
 pub async fn symlink_realpath(path: &Path) -> Result<PathBuf> {
-	let p = fs::canonicalize(path).await?;
+	let p = canonicalize(path).await?;
 	if p == path {
 		return Ok(p);
 	}
@@ -157,7 +169,40 @@ pub async fn symlink_realname<'a>(
 	Ok(name.into())
 }
 
-pub async fn calculate_size(path: &Path) -> u64 {
+// Whether `to` only differs from `from` in the case of its name, and is the same entry as it
+// on a case-insensitive filesystem like APFS or NTFS, so renaming to it isn't an overwrite
+pub async fn is_case_rename(from: &Path, to: &Path) -> bool {
+	let (Some(a), Some(b)) = (from.file_name(), to.file_name()) else { return false };
+	if a == b || from.parent() != to.parent() || !a.eq_ignore_ascii_case(b) {
+		return false;
+	}
+	symlink_realname(to, &mut HashMap::new()).await.is_ok_and(|n| n == a)
+}
+
+// Renames through a temporary name, as some filesystems take a case-only rename as a no-op
+pub async fn rename_case(from: &Path, to: &Path) -> io::Result<()> {
+	let nanos = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map_or(0, |d| d.subsec_nanos());
+
+	let mut tmp = to.as_os_str().to_owned();
+	tmp.push(format!(".yazi-{nanos:x}"));
+	let tmp = PathBuf::from(tmp);
+
+	fs::rename(from, &tmp).await?;
+	if let Err(e) = fs::rename(&tmp, to).await {
+		fs::rename(&tmp, from).await.ok();
+		return Err(e);
+	}
+	Ok(())
+}
+
+#[inline]
+pub async fn calculate_size(path: &Path) -> u64 { calculate_size_with(path, |_| false).await }
+
+// Same as `calculate_size()`, but doesn't descend into the directories that `skip` returns
+// true for.
+pub async fn calculate_size_with(path: &Path, skip: impl Fn(&Path) -> bool) -> u64 {
 	let mut total = 0;
 	let mut stack = VecDeque::from([path.to_path_buf()]);
 	while let Some(path) = stack.pop_front() {
@@ -171,16 +216,32 @@ pub async fn calculate_size(path: &Path) -> u64 {
 		while let Ok(Some(entry)) = it.next_entry().await {
 			let Ok(meta) = entry.metadata().await else { continue };
 
-			if meta.is_dir() {
-				stack.push_back(entry.path());
-			} else {
+			if !meta.is_dir() {
 				total += meta.len();
+				continue;
+			}
+
+			let path = entry.path();
+			if !skip(&path) {
+				stack.push_back(path);
 			}
 		}
 	}
 	total
 }
 
+// With the `io-uring` feature, the io_uring one is used for the small files if the kernel allows it
+async fn copy_file(from: &Path, to: &Path, len: u64) -> io::Result<u64> {
+	#[cfg(all(target_os = "linux", feature = "io-uring"))]
+	if len <= super::URING_COPY_MAX && super::uring_available() {
+		let (from, to) = (from.to_owned(), to.to_owned());
+		return tokio::task::spawn_blocking(move || super::uring_copy(&from, &to)).await?;
+	}
+	#[cfg(not(all(target_os = "linux", feature = "io-uring")))]
+	let _ = len;
+	fs::copy(from, to).await
+}
+
 pub fn copy_with_progress(
 	from: &Path,
 	to: &Path,
@@ -190,7 +251,7 @@ pub fn copy_with_progress(
 	let (tick_tx, mut tick_rx) = oneshot::channel();
 
 	tokio::spawn({
-		let (from, to) = (from.to_owned(), to.to_owned());
+		let (from, to, len) = (from.to_owned(), to.to_owned(), meta.len());
 
 		let mut ft = std::fs::FileTimes::new();
 		meta.accessed().map(|t| ft = ft.set_accessed(t)).ok();
@@ -205,12 +266,25 @@ pub fn copy_with_progress(
 			use std::os::windows::fs::FileTimesExt;
 			meta.created().map(|t| ft = ft.set_created(t)).ok();
 		}
+		#[cfg(target_os = "macos")]
+		let flags = std::os::macos::fs::MetadataExt::st_flags(meta);
+		#[cfg(target_os = "freebsd")]
+		let flags = std::os::freebsd::fs::MetadataExt::st_flags(meta);
 
 		async move {
-			_ = match fs::copy(&from, &to).await {
+			_ = match copy_file(&from, &to, len).await {
 				Ok(len) => {
 					_ = tokio::task::spawn_blocking(move || {
-						std::fs::File::options().write(true).open(to).and_then(|f| f.set_times(ft)).ok();
+						std::fs::File::options().write(true).open(&to).and_then(|f| f.set_times(ft)).ok();
+
+						// Set at last, since the immutable ones would reject the changes above
+						#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+						if flags != 0 {
+							use std::os::unix::ffi::OsStrExt;
+							if let Ok(p) = std::ffi::CString::new(to.as_os_str().as_bytes()) {
+								unsafe { libc::chflags(p.as_ptr(), flags as _) };
+							}
+						}
 					})
 					.await;
 					tick_tx.send(Ok(len))
@@ -328,6 +402,99 @@ pub fn permissions(m: libc::mode_t, dummy: bool) -> String {
 	s
 }
 
+// Convert the file attributes to the `darhsl` mode string of PowerShell
+#[cfg(windows)]
+pub fn attributes(a: u32, dummy: bool) -> String {
+	use windows_sys::Win32::Storage::FileSystem::{FILE_ATTRIBUTE_ARCHIVE, FILE_ATTRIBUTE_DIRECTORY, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_READONLY, FILE_ATTRIBUTE_REPARSE_POINT, FILE_ATTRIBUTE_SYSTEM};
+	if dummy {
+		return "??????".to_owned();
+	}
+
+	[
+		(FILE_ATTRIBUTE_DIRECTORY, 'd'),
+		(FILE_ATTRIBUTE_ARCHIVE, 'a'),
+		(FILE_ATTRIBUTE_READONLY, 'r'),
+		(FILE_ATTRIBUTE_HIDDEN, 'h'),
+		(FILE_ATTRIBUTE_SYSTEM, 's'),
+		(FILE_ATTRIBUTE_REPARSE_POINT, 'l'),
+	]
+	.into_iter()
+	.map(|(f, c)| if a & f != 0 { c } else { '-' })
+	.collect()
+}
+
+// Flip the given attributes of a file, and return the new ones
+#[cfg(windows)]
+pub async fn toggle_attributes(path: PathBuf, flip: u32) -> io::Result<u32> {
+	use std::os::windows::{ffi::OsStrExt, fs::MetadataExt};
+
+	use windows_sys::Win32::Storage::FileSystem::{SetFileAttributesW, FILE_ATTRIBUTE_ARCHIVE, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_NORMAL, FILE_ATTRIBUTE_NOT_CONTENT_INDEXED, FILE_ATTRIBUTE_OFFLINE, FILE_ATTRIBUTE_READONLY, FILE_ATTRIBUTE_SYSTEM, FILE_ATTRIBUTE_TEMPORARY};
+
+	// Only these can be set by `SetFileAttributesW`, the others are ignored or rejected
+	const SETTABLE: u32 = FILE_ATTRIBUTE_ARCHIVE
+		| FILE_ATTRIBUTE_HIDDEN
+		| FILE_ATTRIBUTE_NOT_CONTENT_INDEXED
+		| FILE_ATTRIBUTE_OFFLINE
+		| FILE_ATTRIBUTE_READONLY
+		| FILE_ATTRIBUTE_SYSTEM
+		| FILE_ATTRIBUTE_TEMPORARY;
+
+	tokio::task::spawn_blocking(move || {
+		let new = (std::fs::symlink_metadata(&path)?.file_attributes() ^ flip) & SETTABLE;
+		let new = if new == 0 { FILE_ATTRIBUTE_NORMAL } else { new };
+
+		let wide: Vec<u16> = super::long_path(&path).as_os_str().encode_wide().chain([0]).collect();
+		if unsafe { SetFileAttributesW(wide.as_ptr(), new) } == 0 {
+			return Err(io::Error::last_os_error());
+		}
+		Ok(new)
+	})
+	.await?
+}
+
+// The `chflags` flags as listed by `ls -lO`, they have the same values on macOS and FreeBSD
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub const FILE_FLAGS: [(&str, u32); 8] = [
+	("nodump", 0x00000001),
+	("uchg", 0x00000002),
+	("uappnd", 0x00000004),
+	("opaque", 0x00000008),
+	("hidden", UF_HIDDEN),
+	("arch", 0x00010000),
+	("schg", 0x00020000),
+	("sappnd", 0x00040000),
+];
+
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub(super) const UF_HIDDEN: u32 = 0x00008000;
+
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub fn file_flags(f: u32) -> Vec<&'static str> {
+	FILE_FLAGS.iter().filter(|&&(_, v)| f & v != 0).map(|&(n, _)| n).collect()
+}
+
+// Replace the flags known in `FILE_FLAGS` of a file, keeping the others as is
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub async fn set_file_flags(path: PathBuf, flags: u32) -> io::Result<()> {
+	tokio::task::spawn_blocking(move || {
+		use std::os::unix::ffi::OsStrExt;
+		#[cfg(target_os = "freebsd")]
+		use std::os::freebsd::fs::MetadataExt;
+		#[cfg(target_os = "macos")]
+		use std::os::macos::fs::MetadataExt;
+
+		let known = FILE_FLAGS.iter().fold(0, |acc, &(_, v)| acc | v);
+		let old = std::fs::symlink_metadata(&path)?.st_flags();
+
+		let p = std::ffi::CString::new(path.as_os_str().as_bytes())?;
+		if unsafe { libc::chflags(p.as_ptr(), ((old & !known) | (flags & known)) as _) } != 0 {
+			return Err(io::Error::last_os_error());
+		}
+		Ok(())
+	})
+	.await?
+}
+
 // Find the max common root in a list of files
 // e.g. /a/b/c, /a/b/d       -> /a/b
 //      /aa/bb/cc, /aa/dd/ee -> /aa
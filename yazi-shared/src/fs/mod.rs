@@ -3,6 +3,8 @@ mod file;
 mod fns;
 mod op;
 mod path;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+mod uring;
 mod url;
 
 pub use cha::*;
@@ -10,4 +12,6 @@ pub use file::*;
 pub use fns::*;
 pub use op::*;
 pub use path::*;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+pub use uring::*;
 pub use url::*;
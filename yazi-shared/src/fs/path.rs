@@ -60,6 +60,16 @@ fn _expand_path(p: &Path) -> PathBuf {
 		}
 	}
 
+	// UNC paths, e.g. `\\server\share` or `//server/share`, whose root needs a trailing slash
+	#[cfg(windows)]
+	if s.starts_with("//") || s.starts_with(r"\\") {
+		let mut s = s.replace('/', "\\");
+		if s.trim_start_matches('\\').split('\\').filter(|c| !c.is_empty()).count() == 2 {
+			s.push('\\');
+		}
+		return clean_path(s);
+	}
+
 	let p = Path::new(s.as_ref());
 	if let Ok(rest) = p.strip_prefix("~") {
 		clean_path(dirs::home_dir().unwrap_or_default().join(rest))
@@ -72,6 +82,136 @@ fn _expand_path(p: &Path) -> PathBuf {
 	}
 }
 
+// Removes the `\\?\` prefix that `canonicalize()` adds on Windows, so the result compares equal
+// to the paths the user sees. The std adds it back by itself when a path is too long.
+#[cfg(windows)]
+pub fn strip_verbatim(p: PathBuf) -> PathBuf {
+	use std::path::Prefix;
+
+	let mut it = p.components();
+	let mut out = match it.next() {
+		Some(Component::Prefix(prefix)) => match prefix.kind() {
+			Prefix::VerbatimDisk(d) => PathBuf::from(format!("{}:", d as char)),
+			Prefix::VerbatimUNC(server, share) => {
+				let mut s = OsString::from(r"\\");
+				s.push(server);
+				s.push(r"\");
+				s.push(share);
+				PathBuf::from(s)
+			}
+			_ => return p,
+		},
+		_ => return p,
+	};
+
+	// Names ending with a dot or space are only reachable with the prefix
+	let rest = it.as_path();
+	if rest.components().any(|c| {
+		let b = c.as_os_str().as_encoded_bytes();
+		matches!(c, Component::Normal(_)) && matches!(b.last(), Some(b'.' | b' '))
+	}) {
+		return p;
+	}
+
+	out.push(rest);
+	out
+}
+
+// The opposite of `strip_verbatim()`, for the Win32 APIs called directly instead of through
+// the std, which are limited to `MAX_PATH` otherwise, e.g. in a deep `node_modules`.
+#[cfg(windows)]
+pub fn long_path(p: &Path) -> Cow<'_, Path> {
+	use std::path::Prefix;
+
+	// The verbatim paths are passed to the system as is, without resolving `.`, `..` or `/`
+	let clean = clean_path(p);
+	let mut it = clean.components();
+	let Some(Component::Prefix(prefix)) = it.next() else { return Cow::Borrowed(p) };
+
+	let mut s = match prefix.kind() {
+		Prefix::Disk(d) => OsString::from(format!(r"\\?\{}:", d as char)),
+		Prefix::UNC(server, share) => {
+			let mut s = OsString::from(r"\\?\UNC\");
+			s.push(server);
+			s.push(r"\");
+			s.push(share);
+			s
+		}
+		_ => return Cow::Borrowed(p),
+	};
+
+	let mut empty = true;
+	for c in it {
+		match c {
+			Component::RootDir => {}
+			Component::Normal(n) => {
+				s.push(r"\");
+				s.push(n);
+				empty = false;
+			}
+			_ => return Cow::Borrowed(p),
+		}
+	}
+	if empty {
+		s.push(r"\");
+	}
+	Cow::Owned(PathBuf::from(s))
+}
+
+const RESERVED_NAMES: [&str; 22] = [
+	"CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+	"COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+#[inline]
+fn is_reserved_char(c: char) -> bool {
+	matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') || c < ' '
+}
+
+// The reason why a file name can't be used on Windows, or is too long for most filesystems,
+// for the trees synced to other platforms. `CON.txt` is reserved as much as `CON` is.
+pub fn unportable_name(name: &str) -> Option<String> {
+	let stem = name.split('.').next().unwrap_or(name).trim_end_matches(' ');
+	if RESERVED_NAMES.iter().any(|r| r.eq_ignore_ascii_case(stem)) {
+		return Some(format!("`{stem}` is a reserved name on Windows"));
+	}
+	if let Some(c) = name.chars().find(|&c| is_reserved_char(c)) {
+		return Some(format!("{c:?} is not allowed on Windows"));
+	}
+	if name.ends_with(['.', ' ']) {
+		return Some("trailing dots or spaces are dropped on Windows".to_owned());
+	}
+	if name.len() > 255 || name.encode_utf16().count() > 255 {
+		return Some("longer than 255 bytes".to_owned());
+	}
+	None
+}
+
+// Makes a name pass `unportable_name()`, by replacing the characters not allowed with `_`
+pub fn sanitize_name(name: &str) -> String {
+	let mut s: String = name.chars().map(|c| if is_reserved_char(c) { '_' } else { c }).collect();
+
+	s.truncate(s.trim_end_matches(['.', ' ']).len());
+	if s.is_empty() {
+		s.push('_');
+	}
+
+	let stem_len = s.find('.').unwrap_or(s.len());
+	let stem = s[..stem_len].trim_end_matches(' ');
+	if RESERVED_NAMES.iter().any(|r| r.eq_ignore_ascii_case(stem)) {
+		let at = stem.len();
+		s.insert(at, '_');
+	}
+
+	// Cut from the end of the stem, to keep the extension
+	while s.len() > 255 || s.encode_utf16().count() > 255 {
+		let at = s.rfind('.').filter(|&i| i > 0).unwrap_or(s.len());
+		let Some((i, _)) = s[..at].char_indices().next_back() else { break };
+		s.remove(i);
+	}
+	s
+}
+
 // FIXME: should return a `std::io::Result` to handle errors such as
 // permission denied
 pub async fn unique_name(mut u: Url) -> Url {
@@ -181,4 +321,43 @@ mod tests {
 		assert("C:\\a", "C:\\a\\b\\c", "..\\..\\");
 		assert("C:\\a\\a\\b", "C:\\a\\b\\b", "..\\..\\a\\b");
 	}
+
+	#[test]
+	fn test_sanitize_name() {
+		use super::{sanitize_name, unportable_name};
+
+		fn assert(name: &str, sanitized: &str) {
+			assert!(unportable_name(name).is_some(), "{name} should be unportable");
+			assert_eq!(sanitize_name(name), sanitized);
+			assert_eq!(unportable_name(sanitized), None);
+		}
+
+		assert("con", "con_");
+		assert("CON.tar.gz", "CON_.tar.gz");
+		assert("a:b?.txt", "a_b_.txt");
+		assert("notes. ", "notes");
+		assert("...", "_");
+		assert(&format!("{}.md", "a".repeat(300)), &format!("{}.md", "a".repeat(252)));
+		assert_eq!(unportable_name("console.log"), None);
+	}
+
+	#[cfg(windows)]
+	#[test]
+	fn test_verbatim() {
+		use std::path::PathBuf;
+
+		use super::{long_path, strip_verbatim};
+
+		fn assert(verbatim: &str, normal: &str) {
+			assert_eq!(strip_verbatim(PathBuf::from(verbatim)), Path::new(normal));
+			assert_eq!(long_path(Path::new(normal)), Path::new(verbatim));
+		}
+
+		assert(r"\\?\C:\", r"C:\");
+		assert(r"\\?\C:\a\b", r"C:\a\b");
+		assert(r"\\?\UNC\server\share\", r"\\server\share\");
+		assert(r"\\?\UNC\server\share\a", r"\\server\share\a");
+		assert_eq!(strip_verbatim(PathBuf::from(r"\\?\C:\a.")), Path::new(r"\\?\C:\a."));
+		assert_eq!(long_path(Path::new("C:/a/./b/../c")), Path::new(r"\\?\C:\a\c"));
+	}
 }
@@ -0,0 +1,217 @@
+use std::{cell::RefCell, ffi::CString, io, mem, os::{fd::AsRawFd, unix::ffi::OsStrExt}, path::{Path, PathBuf}, sync::OnceLock, time::{Duration, UNIX_EPOCH}};
+
+use io_uring::{cqueue, opcode, squeue, types, IoUring};
+
+use super::{Cha, ChaKind, File, Url};
+
+const DEPTH: u32 = 256;
+const CHUNK: usize = 128 * 1024;
+
+// Files larger than this are left to `fs::copy()`, which can reflink them or use
+// `copy_file_range()` in the kernel, instead of copying them through the buffers here.
+pub const URING_COPY_MAX: u64 = 4 * 1024 * 1024;
+
+thread_local! {
+	// Each blocking thread gets its own ring, as a ring can't be shared without locking
+	static RING: RefCell<Option<IoUring>> = RefCell::new(IoUring::new(DEPTH).ok());
+}
+
+// Whether the kernel allows io_uring at all, which some containers and hardened ones don't
+pub fn uring_available() -> bool {
+	static AVAILABLE: OnceLock<bool> = OnceLock::new();
+	*AVAILABLE.get_or_init(|| IoUring::new(2).is_ok())
+}
+
+// A failure may leave entries in the queues, which would be taken for the next ones,
+// so the ring is replaced with a new one instead of being used again.
+fn with_ring<T>(f: impl FnOnce(&mut IoUring) -> io::Result<T>) -> io::Result<T> {
+	RING.with_borrow_mut(|slot| {
+		let Some(ring) = slot else { return Err(io::Error::from(io::ErrorKind::Unsupported)) };
+		let result = f(ring);
+		if result.is_err() {
+			*slot = IoUring::new(DEPTH).ok();
+		}
+		result
+	})
+}
+
+// Submits the queued entries and reaps all of the `want` completions of them, retrying if
+// interrupted. If it fails, some of them may still be in flight, so their buffers must be
+// leaked by the caller rather than freed while the kernel can still write to them.
+fn wait(ring: &mut IoUring, want: usize) -> io::Result<Vec<cqueue::Entry>> {
+	let mut done = Vec::with_capacity(want);
+	while done.len() < want {
+		match ring.submit_and_wait(want - done.len()) {
+			Ok(_) => {}
+			Err(e) if matches!(e.raw_os_error(), Some(libc::EINTR | libc::EAGAIN | libc::EBUSY)) => {}
+			Err(e) => return Err(e),
+		}
+		done.extend(ring.completion());
+	}
+	Ok(done)
+}
+
+// `lstat()`s all of the `paths` in batches of one submission each, instead of a syscall per path.
+// io_uring has no `getdents` yet, so the listing itself is still done by `read_dir()`.
+pub fn uring_statx(paths: &[PathBuf]) -> Vec<io::Result<Cha>> {
+	let mut out = Vec::with_capacity(paths.len());
+	for chunk in paths.chunks(DEPTH as usize) {
+		match with_ring(|ring| statx_chunk(ring, chunk)) {
+			Ok(v) => out.extend(v),
+			Err(e) => out.extend(chunk.iter().map(|_| Err(io::Error::from(e.kind())))),
+		}
+	}
+	out
+}
+
+fn statx_chunk(ring: &mut IoUring, paths: &[PathBuf]) -> io::Result<Vec<io::Result<Cha>>> {
+	let names = paths
+		.iter()
+		.map(|p| CString::new(p.as_os_str().as_bytes()).map_err(|_| io::ErrorKind::InvalidInput.into()))
+		.collect::<io::Result<Vec<_>>>()?;
+	let mut bufs: Vec<libc::statx> = vec![unsafe { mem::zeroed() }; paths.len()];
+
+	for (i, (name, buf)) in names.iter().zip(&mut bufs).enumerate() {
+		let entry = opcode::Statx::new(
+			types::Fd(libc::AT_FDCWD),
+			name.as_ptr(),
+			buf as *mut libc::statx as *mut types::statx,
+		)
+		.flags(libc::AT_SYMLINK_NOFOLLOW)
+		.mask(libc::STATX_BASIC_STATS | libc::STATX_BTIME)
+		.build()
+		.user_data(i as u64);
+		unsafe { ring.submission().push(&entry) }.map_err(|_| io::Error::other("ring is full"))?;
+	}
+	let cqes = match wait(ring, paths.len()) {
+		Ok(v) => v,
+		Err(e) => {
+			mem::forget((names, bufs));
+			return Err(e);
+		}
+	};
+
+	let mut results: Vec<_> =
+		(0..paths.len()).map(|_| Err(io::ErrorKind::Interrupted.into())).collect();
+	for cqe in cqes {
+		let i = cqe.user_data() as usize;
+		results[i] = match cqe.result() {
+			n if n < 0 => Err(io::Error::from_raw_os_error(-n)),
+			_ => Ok(Cha::from(&bufs[i])),
+		};
+	}
+	Ok(results)
+}
+
+// Same as `File::from()` for each of the `paths`, with the `lstat()`s batched by `uring_statx()`.
+// The symlinks are resolved one by one, as their targets need another round anyway.
+pub async fn uring_files(paths: Vec<PathBuf>) -> Vec<File> {
+	let (paths, chas) = tokio::task::spawn_blocking(move || {
+		let chas = uring_statx(&paths);
+		(paths, chas)
+	})
+	.await
+	.unwrap_or_default();
+
+	let mut files = Vec::with_capacity(paths.len());
+	for (path, cha) in paths.into_iter().zip(chas) {
+		let url = Url::from(path);
+		files.push(match cha {
+			Ok(cha) if cha.perm & libc::S_IFMT != libc::S_IFLNK => {
+				let hidden = if url.is_hidden() { ChaKind::HIDDEN } else { ChaKind::empty() };
+				File { cha: cha.with_kind(hidden), url, ..Default::default() }
+			}
+			_ => match File::from(url.clone()).await {
+				Ok(f) => f,
+				Err(_) => File::from_dummy(url, None),
+			},
+		});
+	}
+	files
+}
+
+// Copies the file in chunks, each a read linked to the write of it, all submitted at once,
+// so a small file takes a single submission instead of a few syscalls.
+pub fn uring_copy(from: &Path, to: &Path) -> io::Result<u64> {
+	let src = std::fs::File::open(from)?;
+	let meta = src.metadata()?;
+	let dst = std::fs::File::create(to)?;
+	dst.set_permissions(meta.permissions())?;
+
+	let (len, mut off) = (meta.len(), 0u64);
+	let chunk = len.clamp(1, CHUNK as u64);
+	let count = len.div_ceil(chunk).clamp(1, DEPTH as u64 / 2) as usize;
+	with_ring(|ring| {
+		let mut bufs = vec![vec![0u8; chunk as usize]; count];
+		while off < len {
+			let mut pending = 0;
+			for buf in &mut bufs {
+				if off >= len {
+					break;
+				}
+
+				let n = (len - off).min(chunk) as u32;
+				let read = opcode::Read::new(types::Fd(src.as_raw_fd()), buf.as_mut_ptr(), n)
+					.offset(off)
+					.build()
+					.flags(squeue::Flags::IO_LINK)
+					.user_data(n as u64);
+				let write = opcode::Write::new(types::Fd(dst.as_raw_fd()), buf.as_ptr(), n)
+					.offset(off)
+					.build()
+					.user_data(n as u64);
+
+				unsafe { ring.submission().push_multiple(&[read, write]) }
+					.map_err(|_| io::Error::other("ring is full"))?;
+				(off, pending) = (off + n as u64, pending + 2);
+			}
+
+			// All of them are reaped even after a failure, or they'd be taken for the next ones
+			let cqes = match wait(ring, pending) {
+				Ok(v) => v,
+				Err(e) => {
+					mem::forget(bufs);
+					return Err(e);
+				}
+			};
+
+			let mut err = None;
+			for cqe in cqes {
+				match cqe.result() {
+					n if n < 0 => _ = err.get_or_insert(io::Error::from_raw_os_error(-n)),
+					// A short read cancels the linked write, both mean the file changed during the copy
+					n if n as u64 != cqe.user_data() => {
+						_ = err.get_or_insert(io::ErrorKind::UnexpectedEof.into());
+					}
+					_ => {}
+				}
+			}
+			if let Some(e) = err {
+				return Err(e);
+			}
+		}
+		Ok(len)
+	})
+}
+
+impl From<&libc::statx> for Cha {
+	fn from(s: &libc::statx) -> Self {
+		let time = |t: libc::statx_timestamp| {
+			UNIX_EPOCH.checked_add(Duration::new(t.tv_sec as u64, t.tv_nsec)).filter(|_| t.tv_sec >= 0)
+		};
+		let mode = s.stx_mode as libc::mode_t;
+
+		Self {
+			kind:   if mode & libc::S_IFMT == libc::S_IFDIR { ChaKind::DIR } else { ChaKind::empty() },
+			len:    s.stx_size,
+			atime:  time(s.stx_atime),
+			ctime:  (s.stx_mask & libc::STATX_BTIME != 0).then(|| time(s.stx_btime)).flatten(),
+			mtime:  time(s.stx_mtime),
+			chtime: time(s.stx_ctime),
+			perm:   mode,
+			uid:    s.stx_uid,
+			gid:    s.stx_gid,
+			nlink:  s.stx_nlink as _,
+		}
+	}
+}
@@ -5,11 +5,34 @@ use serde::{Deserialize, Serialize};
 
 const ENCODE_SET: &AsciiSet = &CONTROLS.add(b'#');
 
+// The domain of an image is the path to it
+const DOMAIN_SET: &AsciiSet = &ENCODE_SET.add(b'%').add(b'/');
+
+// What can't be left as is in the path of a URI, i.e. all but the unreserved characters,
+// the sub-delims, `:`, `@` and `/`, for the `file://` URIs and the paths sent to the servers
+pub const PATH_ENCODE_SET: &AsciiSet = &CONTROLS
+	.add(b' ')
+	.add(b'"')
+	.add(b'#')
+	.add(b'%')
+	.add(b'<')
+	.add(b'>')
+	.add(b'?')
+	.add(b'[')
+	.add(b'\\')
+	.add(b']')
+	.add(b'^')
+	.add(b'`')
+	.add(b'{')
+	.add(b'|')
+	.add(b'}');
+
 #[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Url {
 	scheme: UrlScheme,
 	path:   PathBuf,
 	frag:   String,
+	domain: String,
 }
 
 #[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -17,7 +40,14 @@ pub enum UrlScheme {
 	#[default]
 	Regular,
 	Search,
+	Recent,
 	Archive,
+	Sftp,
+	S3,
+	Dav,
+	Davs,
+	Mtp,
+	Iso,
 }
 
 impl Deref for Url {
@@ -31,7 +61,14 @@ impl DerefMut for Url {
 }
 
 impl Debug for Url {
-	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "{}", self.path.display()) }
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		if self.is_remote() && self.domain.is_empty() {
+			return write!(f, "{self}");
+		} else if self.is_remote() {
+			return write!(f, "{}:{}", self.domain, self.path.display());
+		}
+		write!(f, "{}", self.path.display())
+	}
 }
 
 impl From<PathBuf> for Url {
@@ -66,6 +103,12 @@ impl From<&str> for Url {
 				url.path = PathBuf::from(b);
 				return url;
 			}
+			Some((a, b)) if a.is_remote() => {
+				let (domain, rest) = b.split_at(b.find('/').unwrap_or(b.len()));
+				url.scheme = a;
+				url.domain = percent_decode_str(domain).decode_utf8_lossy().into_owned();
+				path = if rest.is_empty() { "/" } else { rest };
+			}
 			Some((a, b)) => {
 				url.scheme = a;
 				path = b;
@@ -105,11 +148,19 @@ impl Display for Url {
 		let scheme = match self.scheme {
 			UrlScheme::Regular => unreachable!(),
 			UrlScheme::Search => "search://",
+			UrlScheme::Recent => "recent://",
 			UrlScheme::Archive => "archive://",
+			UrlScheme::Sftp => "sftp://",
+			UrlScheme::S3 => "s3://",
+			UrlScheme::Dav => "dav://",
+			UrlScheme::Davs => "davs://",
+			UrlScheme::Mtp => "mtp://",
+			UrlScheme::Iso => "iso://",
 		};
 		let path = percent_encode(self.path.as_os_str().as_encoded_bytes(), ENCODE_SET);
 
-		write!(f, "{scheme}{path}")?;
+		let domain = percent_encode(self.domain.as_bytes(), DOMAIN_SET);
+		write!(f, "{scheme}{domain}{path}")?;
 		if !self.frag.is_empty() {
 			write!(f, "#{}", self.frag)?;
 		}
@@ -124,19 +175,26 @@ impl Url {
 		let url = Self::from(self.path.join(path));
 		match self.scheme {
 			UrlScheme::Regular => url,
-			UrlScheme::Search => url,
+			UrlScheme::Search | UrlScheme::Recent => url,
 			UrlScheme::Archive => url.into_archive(),
+			s => url.into_remote(s, self.domain.clone()),
 		}
 	}
 
 	#[inline]
 	pub fn parent_url(&self) -> Option<Url> {
+		// Leaving the root of an image goes back to the directory it's in
+		if self.scheme == UrlScheme::Iso && self.path.parent().is_none() {
+			return Path::new(&self.domain).parent().map(Self::from);
+		}
+
 		self.path.parent().map(|p| {
 			let url = Self::from(p);
 			match self.scheme {
 				UrlScheme::Regular => url,
-				UrlScheme::Search => url,
+				UrlScheme::Search | UrlScheme::Recent => url,
 				UrlScheme::Archive => url,
+				s => url.into_remote(s, self.domain.clone()),
 			}
 		})
 	}
@@ -168,6 +226,7 @@ impl Url {
 	pub fn into_regular(mut self) -> Self {
 		self.scheme = UrlScheme::Regular;
 		self.frag = String::new();
+		self.domain = String::new();
 		self
 	}
 
@@ -184,6 +243,9 @@ impl Url {
 		self
 	}
 
+	#[inline]
+	pub fn is_recent(&self) -> bool { self.scheme == UrlScheme::Recent }
+
 	#[inline]
 	pub fn is_archive(&self) -> bool { self.scheme == UrlScheme::Archive }
 
@@ -197,6 +259,20 @@ impl Url {
 		self
 	}
 
+	#[inline]
+	pub fn is_remote(&self) -> bool { self.scheme.is_remote() }
+
+	#[inline]
+	pub fn into_remote(mut self, scheme: UrlScheme, domain: String) -> Self {
+		self.scheme = scheme;
+		self.frag = String::new();
+		self.domain = domain;
+		self
+	}
+
+	#[inline]
+	pub fn scheme(&self) -> UrlScheme { self.scheme }
+
 	// --- Path
 	#[inline]
 	pub fn set_path(&mut self, path: PathBuf) { self.path = path; }
@@ -204,18 +280,38 @@ impl Url {
 	// --- Frag
 	#[inline]
 	pub fn frag(&self) -> &str { &self.frag }
+
+	// --- Domain
+	// The `user@host` or bucket of a remote one, the path to the image of an ISO one,
+	// or empty for the local ones
+	#[inline]
+	pub fn domain(&self) -> &str { &self.domain }
 }
 
 impl From<&str> for UrlScheme {
 	fn from(value: &str) -> Self {
 		match value {
 			"search" => UrlScheme::Search,
+			"recent" => UrlScheme::Recent,
 			"archive" => UrlScheme::Archive,
+			"sftp" => UrlScheme::Sftp,
+			"s3" => UrlScheme::S3,
+			"dav" => UrlScheme::Dav,
+			"davs" => UrlScheme::Davs,
+			"mtp" => UrlScheme::Mtp,
+			"iso" => UrlScheme::Iso,
 			_ => UrlScheme::Regular,
 		}
 	}
 }
 
+impl UrlScheme {
+	#[inline]
+	pub fn is_remote(self) -> bool {
+		matches!(self, Self::Sftp | Self::S3 | Self::Dav | Self::Davs | Self::Mtp | Self::Iso)
+	}
+}
+
 impl Serialize for Url {
 	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
 		serializer.collect_str(self)
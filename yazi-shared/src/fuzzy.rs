@@ -0,0 +1,50 @@
+// Scores `s` against the subsequence `pat`, or `None` if it doesn't match. Consecutive chars
+// and the ones at the start of a word score higher. It's case-insensitive unless `pat` has
+// an uppercase char in it.
+pub fn fuzzy_score(pat: &str, s: &str) -> Option<usize> {
+	if pat.is_empty() {
+		return Some(0);
+	}
+
+	let sensitive = pat.chars().any(|c| c.is_uppercase());
+	let eq = |a: char, b: char| {
+		if sensitive { a == b } else { a.to_lowercase().eq(b.to_lowercase()) }
+	};
+
+	let mut pat = pat.chars().peekable();
+	let (mut score, mut prev, mut streak) = (0, None::<char>, 0);
+	for c in s.chars() {
+		let Some(&p) = pat.peek() else { break };
+		if !eq(p, c) {
+			(prev, streak) = (Some(c), 0);
+			continue;
+		}
+
+		streak += 1;
+		score += 1 + streak * 2;
+		if prev.map_or(true, |b| !b.is_alphanumeric() || (b.is_lowercase() && c.is_uppercase())) {
+			score += 4;
+		}
+
+		pat.next();
+		prev = Some(c);
+	}
+
+	if pat.peek().is_some() { None } else { Some(score) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fuzzy_score() {
+		assert_eq!(fuzzy_score("", "abc"), Some(0));
+		assert_eq!(fuzzy_score("abd", "abc"), None);
+		assert_eq!(fuzzy_score("NV", "nvim"), None);
+
+		assert!(fuzzy_score("vim", "nvim") > fuzzy_score("vim", "vaiam"));
+		assert!(fuzzy_score("ed", "rename-dir") > fuzzy_score("ed", "shred"));
+		assert!(fuzzy_score("sf", "SomeFile") > fuzzy_score("sf", "subsfx"));
+	}
+}
@@ -7,18 +7,22 @@ mod env;
 mod errors;
 pub mod event;
 pub mod fs;
+mod fuzzy;
 mod layer;
 mod natsort;
 mod number;
 mod os;
+mod perf;
 mod rand;
 mod ro_cell;
 pub mod shell;
+mod swap_cell;
 mod terminal;
 pub mod theme;
 mod throttle;
 mod time;
 mod translit;
+mod width;
 mod xdg;
 
 pub use chars::*;
@@ -26,17 +30,21 @@ pub use condition::*;
 pub use debounce::*;
 pub use env::*;
 pub use errors::*;
+pub use fuzzy::*;
 pub use layer::*;
 pub use natsort::*;
 pub use number::*;
 #[cfg(unix)]
 pub use os::*;
+pub use perf::*;
 pub use rand::*;
 pub use ro_cell::*;
+pub use swap_cell::*;
 pub use terminal::*;
 pub use throttle::*;
 pub use time::*;
 pub use translit::*;
+pub use width::*;
 pub use xdg::*;
 
 pub fn init() { event::Event::init(); }
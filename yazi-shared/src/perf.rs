@@ -0,0 +1,119 @@
+use std::{collections::VecDeque, sync::atomic::{AtomicBool, Ordering}, time::{Duration, Instant}};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+
+pub static PERF: Perf = Perf::new();
+
+// Only the last this many of the frames and previews are kept
+const SAMPLES: usize = 300;
+
+// The timings recorded with `--debug-perf`, except for the startup phases,
+// which are always recorded since there're only a few of them.
+pub struct Perf {
+	enabled: AtomicBool,
+	visible: AtomicBool,
+	started: Mutex<Option<Instant>>,
+	report:  Mutex<Report>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct Report {
+	pub phases:   Vec<Phase>,
+	pub startup:  f64,
+	pub frames:   VecDeque<Frame>,
+	pub previews: VecDeque<f64>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct Phase {
+	pub name: &'static str,
+	pub ms:   f64,
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub struct Frame {
+	pub ms:    f64,
+	pub queue: usize,
+}
+
+impl Perf {
+	const fn new() -> Self {
+		Self {
+			enabled: AtomicBool::new(false),
+			visible: AtomicBool::new(false),
+			started: Mutex::new(None),
+			report:  Mutex::new(Report {
+				phases:   Vec::new(),
+				startup:  0.0,
+				frames:   VecDeque::new(),
+				previews: VecDeque::new(),
+			}),
+		}
+	}
+
+	pub fn enable(&self) {
+		self.enabled.store(true, Ordering::Relaxed);
+		self.visible.store(true, Ordering::Relaxed);
+	}
+
+	#[inline]
+	pub fn enabled(&self) -> bool { self.enabled.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn visible(&self) -> bool { self.enabled() && self.visible.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn toggle(&self) { self.visible.fetch_xor(true, Ordering::Relaxed); }
+
+	pub fn phase<T>(&self, name: &'static str, f: impl FnOnce() -> T) -> T {
+		let now = Instant::now();
+		self.started.lock().get_or_insert(now);
+
+		let result = f();
+		self.report.lock().phases.push(Phase { name, ms: Self::ms(now.elapsed()) });
+		result
+	}
+
+	// Called once the first frame is drawn, since the start of the first phase
+	pub fn ready(&self) {
+		if let Some(started) = *self.started.lock() {
+			self.report.lock().startup = Self::ms(started.elapsed());
+		}
+	}
+
+	pub fn frame(&self, elapsed: Duration, queue: usize) {
+		if self.enabled() {
+			Self::push(&mut self.report.lock().frames, Frame { ms: Self::ms(elapsed), queue });
+		}
+	}
+
+	pub fn preview(&self, elapsed: Duration) {
+		if self.enabled() {
+			Self::push(&mut self.report.lock().previews, Self::ms(elapsed));
+		}
+	}
+
+	#[inline]
+	pub fn report(&self) -> Report { self.report.lock().clone() }
+
+	#[inline]
+	fn push<T>(q: &mut VecDeque<T>, v: T) {
+		if q.len() >= SAMPLES {
+			q.pop_front();
+		}
+		q.push_back(v);
+	}
+
+	#[inline]
+	fn ms(d: Duration) -> f64 { d.as_secs_f64() * 1000.0 }
+}
+
+impl Report {
+	// The average and the maximum of the samples
+	pub fn stats(samples: impl ExactSizeIterator<Item = f64>) -> (f64, f64) {
+		let len = samples.len().max(1) as f64;
+		let (sum, max) = samples.fold((0.0, 0.0f64), |(s, m), v| (s + v, m.max(v)));
+		(sum / len, max)
+	}
+}
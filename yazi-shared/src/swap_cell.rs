@@ -0,0 +1,45 @@
+use std::{ops::Deref, sync::Arc};
+
+use arc_swap::{ArcSwapOption, Guard};
+
+// Same as `RoCell`, but the value can be replaced while other threads are reading it.
+//
+// The value is `load()`ed as a guard, which keeps the one it got alive until it's dropped,
+// so a replaced value is freed as soon as the last of those reading it is done with it.
+pub struct SwapCell<T> {
+	inner: ArcSwapOption<T>,
+}
+
+impl<T> SwapCell<T> {
+	#[inline]
+	pub const fn new() -> Self { Self { inner: ArcSwapOption::const_empty() } }
+
+	#[inline]
+	pub fn init(&self, value: T) {
+		debug_assert!(self.inner.load().is_none());
+		self.inner.store(Some(Arc::new(value)));
+	}
+
+	#[inline]
+	pub fn load(&self) -> SwapGuard<T> { SwapGuard(self.inner.load()) }
+
+	// For the ones kept for longer, as the guards are meant to be short-lived
+	#[inline]
+	pub fn load_full(&self) -> Arc<T> { self.inner.load_full().expect("SwapCell is not initialized") }
+
+	#[inline]
+	pub fn replace(&self, value: T) { self.inner.store(Some(Arc::new(value))); }
+}
+
+impl<T> Default for SwapCell<T> {
+	fn default() -> Self { Self::new() }
+}
+
+pub struct SwapGuard<T>(Guard<Option<Arc<T>>>);
+
+impl<T> Deref for SwapGuard<T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.0.as_ref().expect("SwapCell is not initialized") }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use super::Style;
 
 #[derive(Clone, Debug)]
@@ -6,10 +8,11 @@ pub struct Icon {
 	pub style: Style,
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+// Owned, as the theme the icon was matched in can be replaced while the file is still around
+#[derive(Clone, Debug, Default)]
 pub enum IconCache {
 	#[default]
 	Missing,
 	Undefined,
-	Icon(&'static Icon),
+	Icon(Arc<Icon>),
 }
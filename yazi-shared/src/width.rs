@@ -0,0 +1,52 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+static EMOJI: AtomicU8 = AtomicU8::new(2);
+
+// How many columns the emoji take, which varies across terminals and fonts,
+// e.g. 1 on the ones without color glyphs.
+pub fn set_emoji_width(emoji: u8) { EMOJI.store(emoji, Ordering::Relaxed); }
+
+// The width of a grapheme cluster, so a ZWJ sequence or a flag takes the width
+// of one emoji, rather than the sum of the code points in it.
+pub fn grapheme_width(g: &str) -> usize {
+	let mut chars = g.chars();
+	let Some(first) = chars.next() else { return 0 };
+
+	if is_emoji(first, g) {
+		return EMOJI.load(Ordering::Relaxed) as usize;
+	}
+
+	// The East Asian Ambiguous ones are always narrow, the same as ratatui draws them
+	let width = |c: char| c.width().unwrap_or(0);
+	width(first) + chars.map(width).sum::<usize>()
+}
+
+#[inline]
+pub fn str_width(s: &str) -> usize { s.graphemes(true).map(grapheme_width).sum() }
+
+fn is_emoji(first: char, g: &str) -> bool {
+	match first as u32 {
+		// Pictographs, emoticons, transport, supplemental symbols, and the regional indicators
+		0x1F1E6..=0x1F1FF | 0x1F300..=0x1F64F | 0x1F680..=0x1F6FF | 0x1F900..=0x1FAFF => true,
+		// The text ones only with a VS16, e.g. `❤️`, or a keycap like `1️⃣`
+		_ => g.contains('\u{FE0F}') || g.contains('\u{20E3}'),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_str_width() {
+		assert_eq!(str_width("abc"), 3);
+		assert_eq!(str_width("中文"), 4);
+		assert_eq!(str_width("e\u{0301}"), 1);
+		assert_eq!(str_width("👨\u{200D}👩\u{200D}👧"), 2);
+		assert_eq!(str_width("❤\u{FE0F}"), 2);
+		assert_eq!(str_width("🇯🇵"), 2);
+	}
+}